@@ -26,6 +26,15 @@ interface IOverwatchNode  {
       uint256 overwatchNodeId,
       OverwatchReveal[] calldata reveals
   ) external;
+  function commitBeaconEntropy(uint256 overwatchNodeId, bytes32 commitment) external;
+  function revealBeaconEntropy(uint256 overwatchNodeId, bytes32 entropy) external;
+  function challengeSubnetNode(
+    uint256 overwatchNodeId,
+    uint256 subnetId,
+    uint256 subnetNodeId,
+    bytes32 evidenceHash
+  ) external;
+  function voteSubnetNodeChallenge(uint256 overwatchNodeId, uint256 challengeId, bool approve) external;
   function accountOverwatchStake(address hotkey) external view returns (uint256);
   function totalOverwatchStake() external view returns (uint256);
   function overwatchNodeBlacklist(address coldkey) external view returns (bool);
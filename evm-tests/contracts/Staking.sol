@@ -18,6 +18,15 @@ interface Staking  {
 
   function claimUnbondings() external;
 
+  function restakeUnbonding(
+    uint256 targetType,
+    uint256 id,
+    uint256 subnetNodeId,
+    uint256 amount
+  ) external;
+
+  function withdrawUnbondingEarly(uint256 amount) external;
+
   function addToDelegateStake(
     uint256 subnetId,
     uint256 stakeToBeAdded
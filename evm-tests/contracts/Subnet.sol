@@ -8,6 +8,11 @@ interface Subnet {
         uint256 count;
     }
 
+    struct ConsensusDataPolicy {
+        uint256 kind;
+        uint256 value;
+    }
+
     struct Bootnode {
         string peerId;
         bytes multiaddr;
@@ -206,6 +211,38 @@ interface Subnet {
         uint256 value
     ) external;
 
+    function ownerUpdateConsensusDataPolicies(
+        uint256 subnetId,
+        ConsensusDataPolicy[] calldata policies
+    ) external;
+
+    function ownerUpdateValidatorElectionMode(
+        uint256 subnetId,
+        bool weighted,
+        uint256 stakeExponent,
+        uint256 nodeReputationExponent,
+        uint256 validatorReputationExponent,
+        uint256 maxValidatorProbability
+    ) external;
+
+    function ownerUpdateFallbackProposers(
+        uint256 subnetId,
+        bool enabled,
+        uint256 startProgression,
+        uint256 maxProposers
+    ) external;
+
+    function ownerUpdateAttestationWeighting(
+        uint256 subnetId,
+        uint256 weighting
+    ) external;
+
+    function ownerUpdateNodeConcentrationCaps(
+        uint256 subnetId,
+        uint256 maxNodesPerColdkey,
+        uint256 maxNodesPerValidator
+    ) external;
+
     function ownerUpdateTargetRegistrationsPerEpoch(
         uint256 subnetId,
         uint256 value
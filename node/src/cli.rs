@@ -1,4 +1,4 @@
-use crate::service::EthConfiguration;
+use crate::{indexer::IndexerConfiguration, service::EthConfiguration};
 
 /// Available Sealing methods.
 #[derive(Copy, Clone, Debug, Default, clap::ValueEnum)]
@@ -65,4 +65,17 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     FrontierDb(fc_cli::FrontierDbCmd),
+
+    /// Run the node and index network and council events of finalized blocks into SQLite.
+    Index(IndexCmd),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct IndexCmd {
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub run: sc_cli::RunCmd,
+
+    #[command(flatten)]
+    pub indexer: IndexerConfiguration,
 }
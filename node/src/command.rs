@@ -231,10 +231,19 @@ pub fn run() -> sc_cli::Result<()> {
                 cmd.run(client, frontier_backend)
             })
         }
+        Some(Subcommand::Index(cmd)) => {
+            let runner = cli.create_runner(&cmd.run)?;
+            let indexer = cmd.indexer.clone();
+            runner.run_node_until_exit(|config| async move {
+                service::build_full(config, cli.eth, cli.sealing, Some(indexer))
+                    .map_err(Into::into)
+                    .await
+            })
+        }
         None => {
             let runner = cli.create_runner(&cli.run)?;
             runner.run_node_until_exit(|config| async move {
-                service::build_full(config, cli.eth, cli.sealing)
+                service::build_full(config, cli.eth, cli.sealing, None)
                     .map_err(Into::into)
                     .await
             })
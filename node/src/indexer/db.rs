@@ -0,0 +1,486 @@
+//! SQLite schema and writes of the indexer.
+
+use std::path::Path;
+
+use sp_core::H256;
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions},
+    Row, SqliteConnection,
+};
+
+use super::{events, EventRecord};
+
+/// Tables created on open. Balances are stored as decimal text, SQLite integers are 64 bit
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS blocks (
+        number INTEGER PRIMARY KEY,
+        hash TEXT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS events (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        pallet TEXT NOT NULL,
+        name TEXT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (block_number, event_index)
+    )",
+    "CREATE TABLE IF NOT EXISTS subnets (
+        subnet_id INTEGER PRIMARY KEY,
+        name TEXT,
+        owner TEXT,
+        registered_block INTEGER,
+        activated_block INTEGER,
+        removed_block INTEGER,
+        removal_reason TEXT
+    )",
+    "CREATE TABLE IF NOT EXISTS subnet_nodes (
+        subnet_id INTEGER NOT NULL,
+        subnet_node_id INTEGER NOT NULL,
+        validator_id INTEGER,
+        coldkey TEXT,
+        hotkey TEXT,
+        peer_id TEXT,
+        registered_block INTEGER,
+        activated_block INTEGER,
+        removed_block INTEGER,
+        PRIMARY KEY (subnet_id, subnet_node_id)
+    )",
+    "CREATE TABLE IF NOT EXISTS stake_changes (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        account TEXT,
+        subnet_id INTEGER,
+        subnet_node_id INTEGER,
+        to_subnet_id INTEGER,
+        to_subnet_node_id INTEGER,
+        amount TEXT NOT NULL,
+        PRIMARY KEY (block_number, event_index)
+    )",
+    "CREATE TABLE IF NOT EXISTS attestations (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        subnet_id INTEGER NOT NULL,
+        subnet_node_id INTEGER,
+        account TEXT,
+        epoch INTEGER NOT NULL,
+        PRIMARY KEY (block_number, event_index)
+    )",
+    "CREATE TABLE IF NOT EXISTS rewards (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        subnet_id INTEGER,
+        node_id INTEGER,
+        account TEXT,
+        amount TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS rewards_block ON rewards (block_number, event_index)",
+    "CREATE TABLE IF NOT EXISTS removals (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        subnet_id INTEGER,
+        node_id INTEGER,
+        reason TEXT,
+        PRIMARY KEY (block_number, event_index)
+    )",
+    "CREATE TABLE IF NOT EXISTS council (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        proposal_hash TEXT NOT NULL,
+        proposal_index INTEGER,
+        account TEXT,
+        approve INTEGER,
+        yes INTEGER,
+        no INTEGER,
+        result TEXT,
+        PRIMARY KEY (block_number, event_index)
+    )",
+];
+
+pub struct IndexDb {
+    pool: SqlitePool,
+}
+
+impl IndexDb {
+    pub async fn open(path: &Path) -> Result<Self, String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal);
+
+        // The indexer writes one block at a time, a single connection keeps writes ordered
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .map_err(|e| format!("Unable to open {}: {e}", path.display()))?;
+
+        for statement in SCHEMA {
+            sqlx::query(statement)
+                .execute(&pool)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        Ok(Self { pool })
+    }
+
+    pub async fn last_indexed_block(&self) -> Result<Option<u64>, String> {
+        let row = sqlx::query("SELECT MAX(number) FROM blocks")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let last: Option<i64> = row.try_get(0).map_err(|e| e.to_string())?;
+
+        Ok(last.map(|n| n as u64))
+    }
+
+    #[cfg(test)]
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// Write a block and its events in one transaction so a crash never leaves it half indexed
+    pub async fn index_block(
+        &self,
+        number: u64,
+        hash: H256,
+        records: &[EventRecord],
+    ) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let block_number = number as i64;
+
+        sqlx::query("INSERT INTO blocks (number, hash) VALUES (?, ?)")
+            .bind(block_number)
+            .bind(format!("{hash:?}"))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        for (event_index, record) in records.iter().enumerate() {
+            let row = EventRow {
+                block_number,
+                event_index: event_index as u32,
+            };
+            if let Some((pallet, name, data)) = events::describe(&record.event) {
+                sqlx::query(
+                    "INSERT INTO events (block_number, event_index, pallet, name, data)
+                    VALUES (?, ?, ?, ?, ?)",
+                )
+                .bind(row.block_number)
+                .bind(row.event_index)
+                .bind(pallet)
+                .bind(name)
+                .bind(data)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+            }
+            events::apply(&mut *tx, row, &record.event)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())
+    }
+}
+
+/// Position of an event in the chain
+#[derive(Clone, Copy)]
+pub struct EventRow {
+    pub block_number: i64,
+    pub event_index: u32,
+}
+
+pub async fn upsert_subnet(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    name: &str,
+    owner: &str,
+    registered_block: i64,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO subnets (subnet_id, name, owner, registered_block) VALUES (?, ?, ?, ?)
+        ON CONFLICT (subnet_id) DO UPDATE SET
+            name = excluded.name,
+            owner = excluded.owner,
+            registered_block = excluded.registered_block",
+    )
+    .bind(subnet_id)
+    .bind(name)
+    .bind(owner)
+    .bind(registered_block)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+pub async fn upsert_subnet_node(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    validator_id: u32,
+    coldkey: &str,
+    hotkey: &str,
+    peer_id: &str,
+    registered_block: i64,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO subnet_nodes
+            (subnet_id, subnet_node_id, validator_id, coldkey, hotkey, peer_id, registered_block)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT (subnet_id, subnet_node_id) DO UPDATE SET
+            validator_id = excluded.validator_id,
+            coldkey = excluded.coldkey,
+            hotkey = excluded.hotkey,
+            peer_id = excluded.peer_id,
+            registered_block = excluded.registered_block",
+    )
+    .bind(subnet_id)
+    .bind(subnet_node_id)
+    .bind(validator_id)
+    .bind(coldkey)
+    .bind(hotkey)
+    .bind(peer_id)
+    .bind(registered_block)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+/// Set a block column of a subnet, e.g. `activated_block`
+pub async fn set_subnet_block(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    column: &'static str,
+    block_number: i64,
+) -> sqlx::Result<()> {
+    sqlx::query(&format!(
+        "INSERT INTO subnets (subnet_id, {column}) VALUES (?, ?)
+        ON CONFLICT (subnet_id) DO UPDATE SET {column} = excluded.{column}"
+    ))
+    .bind(subnet_id)
+    .bind(block_number)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+/// Set a block column of a subnet node, e.g. `activated_block`
+pub async fn set_subnet_node_block(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    column: &'static str,
+    block_number: i64,
+) -> sqlx::Result<()> {
+    sqlx::query(&format!(
+        "INSERT INTO subnet_nodes (subnet_id, subnet_node_id, {column}) VALUES (?, ?, ?)
+        ON CONFLICT (subnet_id, subnet_node_id) DO UPDATE SET {column} = excluded.{column}"
+    ))
+    .bind(subnet_id)
+    .bind(subnet_node_id)
+    .bind(block_number)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+pub async fn set_subnet_removal_reason(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    reason: &str,
+) -> sqlx::Result<()> {
+    sqlx::query("UPDATE subnets SET removal_reason = ? WHERE subnet_id = ?")
+        .bind(reason)
+        .bind(subnet_id)
+        .execute(conn)
+        .await
+        .map(|_| ())
+}
+
+pub async fn set_subnet_name(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    name: &str,
+) -> sqlx::Result<()> {
+    sqlx::query("UPDATE subnets SET name = ? WHERE subnet_id = ?")
+        .bind(name)
+        .bind(subnet_id)
+        .execute(conn)
+        .await
+        .map(|_| ())
+}
+
+pub async fn set_subnet_node_peer_id(
+    conn: &mut SqliteConnection,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    peer_id: &str,
+) -> sqlx::Result<()> {
+    sqlx::query("UPDATE subnet_nodes SET peer_id = ? WHERE subnet_id = ? AND subnet_node_id = ?")
+        .bind(peer_id)
+        .bind(subnet_id)
+        .bind(subnet_node_id)
+        .execute(conn)
+        .await
+        .map(|_| ())
+}
+
+/// Replace a key, `column` is `coldkey` or `hotkey`, removed nodes keep the key they had
+pub async fn replace_subnet_node_key(
+    conn: &mut SqliteConnection,
+    column: &'static str,
+    key: &str,
+    new_key: &str,
+) -> sqlx::Result<()> {
+    sqlx::query(&format!(
+        "UPDATE subnet_nodes SET {column} = ? WHERE {column} = ? AND removed_block IS NULL"
+    ))
+    .bind(new_key)
+    .bind(key)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+pub async fn insert_stake_change(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    kind: &str,
+    account: &str,
+    (subnet_id, subnet_node_id): (Option<u32>, Option<u32>),
+    (to_subnet_id, to_subnet_node_id): (Option<u32>, Option<u32>),
+    amount: u128,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO stake_changes (block_number, event_index, kind, account, subnet_id,
+            subnet_node_id, to_subnet_id, to_subnet_node_id, amount)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(row.block_number)
+    .bind(row.event_index)
+    .bind(kind)
+    .bind(account)
+    .bind(subnet_id)
+    .bind(subnet_node_id)
+    .bind(to_subnet_id)
+    .bind(to_subnet_node_id)
+    .bind(amount.to_string())
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+pub async fn insert_attestation(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    kind: &str,
+    subnet_id: u32,
+    subnet_node_id: Option<u32>,
+    account: Option<&str>,
+    epoch: u32,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO attestations
+            (block_number, event_index, kind, subnet_id, subnet_node_id, account, epoch)
+        VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(row.block_number)
+    .bind(row.event_index)
+    .bind(kind)
+    .bind(subnet_id)
+    .bind(subnet_node_id)
+    .bind(account)
+    .bind(epoch)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+/// One row per recipient, `node_id` is a subnet node ID or an overwatch node ID depending on
+/// `kind`
+pub async fn insert_reward(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    kind: &str,
+    (subnet_id, node_id): (Option<u32>, Option<u32>),
+    account: Option<&str>,
+    amount: u128,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO rewards (block_number, event_index, kind, subnet_id, node_id, account, amount)
+        VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(row.block_number)
+    .bind(row.event_index)
+    .bind(kind)
+    .bind(subnet_id)
+    .bind(node_id)
+    .bind(account)
+    .bind(amount.to_string())
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+pub async fn insert_removal(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    kind: &str,
+    (subnet_id, node_id): (Option<u32>, Option<u32>),
+    reason: Option<&str>,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO removals (block_number, event_index, kind, subnet_id, node_id, reason)
+        VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(row.block_number)
+    .bind(row.event_index)
+    .bind(kind)
+    .bind(subnet_id)
+    .bind(node_id)
+    .bind(reason)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+pub async fn insert_council(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    kind: &str,
+    proposal_hash: H256,
+    proposal_index: Option<u32>,
+    account: Option<&str>,
+    (approve, yes, no): (Option<bool>, Option<u32>, Option<u32>),
+    result: Option<&str>,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO council (block_number, event_index, kind, proposal_hash, proposal_index,
+            account, approve, yes, no, result)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(row.block_number)
+    .bind(row.event_index)
+    .bind(kind)
+    .bind(format!("{proposal_hash:?}"))
+    .bind(proposal_index)
+    .bind(account)
+    .bind(approve)
+    .bind(yes)
+    .bind(no)
+    .bind(result)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
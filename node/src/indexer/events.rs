@@ -0,0 +1,599 @@
+//! Mapping of `pallet_network` and `pallet_collective` events onto the indexer tables.
+
+use sp_core::{hexdisplay::HexDisplay, OpaquePeerId as PeerId};
+use sqlx::SqliteConnection;
+// Runtime
+use hypertensor_runtime::{AccountId, RuntimeEvent};
+use pallet_collective::Event as CollectiveEvent;
+use pallet_network::Event as NetworkEvent;
+
+use super::db::{self, EventRow};
+
+/// Pallet, variant name and debug dump of the events kept in the raw `events` table
+pub fn describe(event: &RuntimeEvent) -> Option<(&'static str, String, String)> {
+    let (pallet, data) = match event {
+        RuntimeEvent::Network(event) => ("Network", format!("{event:?}")),
+        RuntimeEvent::Collective(event) => ("Collective", format!("{event:?}")),
+        _ => return None,
+    };
+
+    let name = data
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    Some((pallet, name, data))
+}
+
+/// Apply an event to the typed tables, events without a table are only kept in `events`
+pub async fn apply(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    event: &RuntimeEvent,
+) -> sqlx::Result<()> {
+    match event {
+        RuntimeEvent::Network(event) => apply_network(conn, row, event).await,
+        RuntimeEvent::Collective(event) => apply_collective(conn, row, event).await,
+        _ => Ok(()),
+    }
+}
+
+async fn apply_network(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    event: &NetworkEvent<hypertensor_runtime::Runtime>,
+) -> sqlx::Result<()> {
+    let block = row.block_number;
+
+    match event {
+        // Subnets
+        NetworkEvent::SubnetRegistered {
+            owner,
+            name,
+            subnet_id,
+        } => {
+            db::upsert_subnet(
+                conn,
+                *subnet_id,
+                &String::from_utf8_lossy(name),
+                &account(owner),
+                block,
+            )
+            .await
+        }
+        NetworkEvent::SubnetActivated { subnet_id } => {
+            db::set_subnet_block(conn, *subnet_id, "activated_block", block).await
+        }
+        NetworkEvent::SubnetDeactivated { subnet_id, reason } => {
+            let reason = format!("{reason:?}");
+            db::set_subnet_block(conn, *subnet_id, "removed_block", block).await?;
+            db::set_subnet_removal_reason(conn, *subnet_id, &reason).await?;
+            db::insert_removal(conn, row, "subnet", (Some(*subnet_id), None), Some(&reason)).await
+        }
+        NetworkEvent::SubnetNameUpdate {
+            subnet_id, value, ..
+        } => db::set_subnet_name(conn, *subnet_id, &String::from_utf8_lossy(value)).await,
+
+        // Subnet nodes
+        NetworkEvent::SubnetNodeRegistered {
+            subnet_id,
+            subnet_node_id,
+            coldkey,
+            hotkey,
+            data,
+        } => {
+            db::upsert_subnet_node(
+                conn,
+                *subnet_id,
+                *subnet_node_id,
+                data.validator_id,
+                &account(coldkey),
+                &account(hotkey),
+                &peer_id(&data.peer_info.peer_id),
+                block,
+            )
+            .await
+        }
+        NetworkEvent::SubnetNodeActivated {
+            subnet_id,
+            subnet_node_id,
+        } => {
+            db::set_subnet_node_block(conn, *subnet_id, *subnet_node_id, "activated_block", block)
+                .await
+        }
+        NetworkEvent::SubnetNodeUpdatePeerInfo {
+            subnet_id,
+            subnet_node_id,
+            peer_info,
+        } => {
+            db::set_subnet_node_peer_id(
+                conn,
+                *subnet_id,
+                *subnet_node_id,
+                &peer_id(&peer_info.peer_id),
+            )
+            .await
+        }
+        NetworkEvent::UpdateColdkey {
+            coldkey,
+            new_coldkey,
+        } => {
+            db::replace_subnet_node_key(conn, "coldkey", &account(coldkey), &account(new_coldkey))
+                .await
+        }
+        NetworkEvent::UpdateHotkey { hotkey, new_hotkey } => {
+            db::replace_subnet_node_key(conn, "hotkey", &account(hotkey), &account(new_hotkey))
+                .await
+        }
+        NetworkEvent::SubnetNodeRemoved {
+            subnet_id,
+            subnet_node_id,
+        } => {
+            db::set_subnet_node_block(conn, *subnet_id, *subnet_node_id, "removed_block", block)
+                .await?;
+            db::insert_removal(
+                conn,
+                row,
+                "subnet_node",
+                (Some(*subnet_id), Some(*subnet_node_id)),
+                None,
+            )
+            .await
+        }
+        NetworkEvent::QueuedNodeRemoved {
+            subnet_id,
+            subnet_node_id,
+        } => {
+            db::set_subnet_node_block(conn, *subnet_id, *subnet_node_id, "removed_block", block)
+                .await?;
+            db::insert_removal(
+                conn,
+                row,
+                "queued_subnet_node",
+                (Some(*subnet_id), Some(*subnet_node_id)),
+                None,
+            )
+            .await
+        }
+        NetworkEvent::CollectiveRemoveSubnetNode(subnet_id, subnet_node_id) => {
+            db::insert_removal(
+                conn,
+                row,
+                "collective_subnet_node",
+                (Some(*subnet_id), Some(*subnet_node_id)),
+                None,
+            )
+            .await
+        }
+        NetworkEvent::CollectiveRemoveOverwatchNode(overwatch_node_id) => {
+            db::insert_removal(
+                conn,
+                row,
+                "collective_overwatch_node",
+                (None, Some(*overwatch_node_id)),
+                None,
+            )
+            .await
+        }
+
+        // Stake
+        NetworkEvent::StakeAdded(subnet_id, coldkey, _hotkey, amount) => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "stake_added",
+                &account(coldkey),
+                (Some(*subnet_id), None),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::StakeRemoved(subnet_id, coldkey, _hotkey, amount) => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "stake_removed",
+                &account(coldkey),
+                (Some(*subnet_id), None),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::SubnetDelegateStakeAdded(subnet_id, account_id, amount) => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "subnet_delegate_stake_added",
+                &account(account_id),
+                (Some(*subnet_id), None),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::SubnetDelegateStakeRemoved(subnet_id, account_id, amount) => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "subnet_delegate_stake_removed",
+                &account(account_id),
+                (Some(*subnet_id), None),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::SubnetDelegateStakeSwapped(
+            from_subnet_id,
+            to_subnet_id,
+            account_id,
+            amount,
+        ) => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "subnet_delegate_stake_swapped",
+                &account(account_id),
+                (Some(*from_subnet_id), None),
+                (Some(*to_subnet_id), None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::DelegateNodeStakeAdded {
+            account_id,
+            subnet_id,
+            subnet_node_id,
+            amount,
+        } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "node_delegate_stake_added",
+                &account(account_id),
+                (Some(*subnet_id), Some(*subnet_node_id)),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::DelegateNodeStakeRemoved {
+            account_id,
+            subnet_id,
+            subnet_node_id,
+            amount,
+        } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "node_delegate_stake_removed",
+                &account(account_id),
+                (Some(*subnet_id), Some(*subnet_node_id)),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::DelegateNodeStakeSwapped {
+            account_id,
+            from_subnet_id,
+            from_subnet_node_id,
+            to_subnet_id,
+            to_subnet_node_id,
+            amount,
+        } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "node_delegate_stake_swapped",
+                &account(account_id),
+                (Some(*from_subnet_id), Some(*from_subnet_node_id)),
+                (Some(*to_subnet_id), Some(*to_subnet_node_id)),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::DelegateNodeToSubnetDelegateStakeSwapped {
+            account_id,
+            from_subnet_id,
+            from_subnet_node_id,
+            to_subnet_id,
+            amount,
+        } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "node_to_subnet_delegate_stake_swapped",
+                &account(account_id),
+                (Some(*from_subnet_id), Some(*from_subnet_node_id)),
+                (Some(*to_subnet_id), None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::SubnetDelegateToNodeDelegateStakeSwapped {
+            account_id,
+            from_subnet_id,
+            to_subnet_id,
+            to_subnet_node_id,
+            amount,
+        } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "subnet_to_node_delegate_stake_swapped",
+                &account(account_id),
+                (Some(*from_subnet_id), None),
+                (Some(*to_subnet_id), Some(*to_subnet_node_id)),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::DelegateBalanceRemoved { account_id, amount } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "delegate_balance_removed",
+                &account(account_id),
+                (None, None),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+        NetworkEvent::Slashing {
+            subnet_id,
+            account_id,
+            amount,
+        } => {
+            db::insert_stake_change(
+                conn,
+                row,
+                "slashed",
+                &account(account_id),
+                (Some(*subnet_id), None),
+                (None, None),
+                *amount,
+            )
+            .await
+        }
+
+        // Consensus
+        NetworkEvent::ValidatorSubmission {
+            subnet_id,
+            account_id,
+            epoch,
+        } => {
+            db::insert_attestation(
+                conn,
+                row,
+                "proposal",
+                *subnet_id,
+                None,
+                Some(&account(account_id)),
+                *epoch,
+            )
+            .await
+        }
+        NetworkEvent::Attestation {
+            subnet_id,
+            subnet_node_id,
+            epoch,
+        } => {
+            db::insert_attestation(
+                conn,
+                row,
+                "attestation",
+                *subnet_id,
+                Some(*subnet_node_id),
+                None,
+                *epoch,
+            )
+            .await
+        }
+
+        // Rewards
+        NetworkEvent::SubnetRewards {
+            subnet_id,
+            node_rewards,
+            delegate_stake_reward,
+            node_delegate_stake_rewards,
+            node_delegate_account_allocations,
+        } => {
+            let subnet_id = Some(*subnet_id);
+            for (subnet_node_id, amount) in node_rewards {
+                db::insert_reward(
+                    conn,
+                    row,
+                    "subnet_node",
+                    (subnet_id, Some(*subnet_node_id)),
+                    None,
+                    *amount,
+                )
+                .await?;
+            }
+            if *delegate_stake_reward > 0 {
+                db::insert_reward(
+                    conn,
+                    row,
+                    "subnet_delegate_stake",
+                    (subnet_id, None),
+                    None,
+                    *delegate_stake_reward,
+                )
+                .await?;
+            }
+            for (subnet_node_id, amount) in node_delegate_stake_rewards {
+                db::insert_reward(
+                    conn,
+                    row,
+                    "node_delegate_stake",
+                    (subnet_id, Some(*subnet_node_id)),
+                    None,
+                    *amount,
+                )
+                .await?;
+            }
+            for (subnet_node_id, (account_id, amount)) in node_delegate_account_allocations {
+                db::insert_reward(
+                    conn,
+                    row,
+                    "delegate_account",
+                    (subnet_id, Some(*subnet_node_id)),
+                    Some(&account(account_id)),
+                    *amount,
+                )
+                .await?;
+            }
+            Ok(())
+        }
+        NetworkEvent::OverwatchRewards { node_rewards } => {
+            for (overwatch_node_id, amount) in node_rewards {
+                db::insert_reward(
+                    conn,
+                    row,
+                    "overwatch_node",
+                    (None, Some(*overwatch_node_id)),
+                    None,
+                    *amount,
+                )
+                .await?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+async fn apply_collective(
+    conn: &mut SqliteConnection,
+    row: EventRow,
+    event: &CollectiveEvent<hypertensor_runtime::Runtime, pallet_collective::Instance1>,
+) -> sqlx::Result<()> {
+    match event {
+        CollectiveEvent::Proposed {
+            account: proposer,
+            proposal_index,
+            proposal_hash,
+            ..
+        } => {
+            db::insert_council(
+                conn,
+                row,
+                "proposed",
+                *proposal_hash,
+                Some(*proposal_index),
+                Some(&account(proposer)),
+                (None, None, None),
+                None,
+            )
+            .await
+        }
+        CollectiveEvent::Voted {
+            account: voter,
+            proposal_hash,
+            voted,
+            yes,
+            no,
+        } => {
+            db::insert_council(
+                conn,
+                row,
+                "voted",
+                *proposal_hash,
+                None,
+                Some(&account(voter)),
+                (Some(*voted), Some(*yes), Some(*no)),
+                None,
+            )
+            .await
+        }
+        CollectiveEvent::Approved { proposal_hash } => {
+            db::insert_council(
+                conn,
+                row,
+                "approved",
+                *proposal_hash,
+                None,
+                None,
+                (None, None, None),
+                None,
+            )
+            .await
+        }
+        CollectiveEvent::Disapproved { proposal_hash } => {
+            db::insert_council(
+                conn,
+                row,
+                "disapproved",
+                *proposal_hash,
+                None,
+                None,
+                (None, None, None),
+                None,
+            )
+            .await
+        }
+        CollectiveEvent::Executed {
+            proposal_hash,
+            result,
+        } => {
+            db::insert_council(
+                conn,
+                row,
+                "executed",
+                *proposal_hash,
+                None,
+                None,
+                (None, None, None),
+                Some(&format!("{result:?}")),
+            )
+            .await
+        }
+        CollectiveEvent::MemberExecuted {
+            proposal_hash,
+            result,
+        } => {
+            db::insert_council(
+                conn,
+                row,
+                "member_executed",
+                *proposal_hash,
+                None,
+                None,
+                (None, None, None),
+                Some(&format!("{result:?}")),
+            )
+            .await
+        }
+        CollectiveEvent::Closed {
+            proposal_hash,
+            yes,
+            no,
+        } => {
+            db::insert_council(
+                conn,
+                row,
+                "closed",
+                *proposal_hash,
+                None,
+                None,
+                (None, Some(*yes), Some(*no)),
+                None,
+            )
+            .await
+        }
+        _ => Ok(()),
+    }
+}
+
+fn account(account: &AccountId) -> String {
+    format!("0x{}", HexDisplay::from(&account.0))
+}
+
+fn peer_id(peer_id: &PeerId) -> String {
+    bs58::encode(&peer_id.0).into_string()
+}
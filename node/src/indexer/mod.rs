@@ -0,0 +1,210 @@
+//! Off-chain indexer that follows finalized blocks and writes network and council events to
+//! SQLite, see `hypertensor index`.
+
+mod db;
+mod events;
+#[cfg(test)]
+mod tests;
+
+use std::{path::PathBuf, sync::Arc};
+
+use futures::StreamExt;
+use scale_codec::{Compact, Decode};
+// Substrate
+use sc_client_api::{Backend as BackendT, BlockchainEvents, StorageProvider};
+use sc_service::Configuration;
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, twox_128, H256};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+// Runtime
+use hypertensor_runtime::RuntimeEvent;
+
+use crate::eth::db_config_dir;
+use db::IndexDb;
+
+const LOG_TARGET: &str = "indexer";
+
+type EventRecord = frame_system::EventRecord<RuntimeEvent, H256>;
+
+/// The indexer configuration used by `hypertensor index`.
+#[derive(Clone, Debug, clap::Parser)]
+pub struct IndexerConfiguration {
+    /// SQLite database to write to. Defaults to `index.sqlite` in the chain config directory.
+    #[arg(long, value_name = "PATH")]
+    pub index_database: Option<PathBuf>,
+
+    /// First block to index when the database is empty.
+    ///
+    /// Indexing past blocks reads their state, so the node must keep it, e.g.
+    /// `--state-pruning archive`.
+    #[arg(long, default_value = "0")]
+    pub index_from_block: u64,
+}
+
+impl IndexerConfiguration {
+    pub fn database_path(&self, config: &Configuration) -> PathBuf {
+        self.index_database
+            .clone()
+            .unwrap_or_else(|| db_config_dir(config).join("index.sqlite"))
+    }
+}
+
+/// Index finalized blocks into the SQLite database at `path`.
+///
+/// Resumes after the last indexed block, or starts at `from_block` on an empty database, then
+/// follows finality notifications until the node shuts down.
+pub async fn run<B, C, BE>(client: Arc<C>, path: PathBuf, from_block: u64)
+where
+    B: BlockT<Hash = H256>,
+    C: BlockchainEvents<B> + HeaderBackend<B> + StorageProvider<B, BE> + Send + Sync + 'static,
+    BE: BackendT<B> + 'static,
+{
+    if let Err(err) = index(client, path, from_block).await {
+        log::error!(target: LOG_TARGET, "Indexer stopped: {err}");
+    }
+}
+
+async fn index<B, C, BE>(client: Arc<C>, path: PathBuf, from_block: u64) -> Result<(), String>
+where
+    B: BlockT<Hash = H256>,
+    C: BlockchainEvents<B> + HeaderBackend<B> + StorageProvider<B, BE> + Send + Sync + 'static,
+    BE: BackendT<B> + 'static,
+{
+    let db = IndexDb::open(&path).await?;
+
+    let mut next = match db.last_indexed_block().await? {
+        Some(last) => last + 1,
+        None => from_block,
+    };
+
+    log::info!(
+        target: LOG_TARGET,
+        "Indexing finalized blocks from #{next} into {}",
+        path.display()
+    );
+
+    // Subscribe before catching up so blocks finalized in the meantime are not missed
+    let mut finality_notifications = client.finality_notification_stream();
+
+    let finalized = client.info().finalized_number.unique_saturated_into();
+    next = index_until(&*client, &db, next, finalized).await?;
+
+    while let Some(notification) = finality_notifications.next().await {
+        let finalized = (*notification.header.number()).unique_saturated_into();
+        next = index_until(&*client, &db, next, finalized).await?;
+    }
+
+    Ok(())
+}
+
+/// Index blocks `next..=finalized`, returns the next block to index
+async fn index_until<B, C, BE>(
+    client: &C,
+    db: &IndexDb,
+    mut next: u64,
+    finalized: u64,
+) -> Result<u64, String>
+where
+    B: BlockT<Hash = H256>,
+    C: HeaderBackend<B> + StorageProvider<B, BE>,
+    BE: BackendT<B>,
+{
+    while next <= finalized {
+        let Some(hash) = client
+            .hash(next.unique_saturated_into())
+            .map_err(|e| format!("Unable to get hash of block #{next}: {e}"))?
+        else {
+            break;
+        };
+
+        let records = block_events(client, hash)
+            .map_err(|e| format!("Unable to read events of block #{next}: {e}"))?;
+
+        db.index_block(next, hash, &records).await?;
+
+        if next % 1000 == 0 {
+            log::info!(target: LOG_TARGET, "Indexed block #{next}");
+        }
+
+        next += 1;
+    }
+
+    Ok(next)
+}
+
+/// Events of a block, events the native runtime can't decode are logged and skipped
+fn block_events<B, C, BE>(client: &C, hash: H256) -> Result<Vec<EventRecord>, String>
+where
+    B: BlockT<Hash = H256>,
+    C: StorageProvider<B, BE>,
+    BE: BackendT<B>,
+{
+    let key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+
+    let Some(data) = client.storage(hash, &key).map_err(|e| e.to_string())? else {
+        return Ok(Vec::new());
+    };
+
+    let (records, skipped) = decode_event_records(&data.0);
+    if let Some(skipped) = skipped {
+        log::warn!(
+            target: LOG_TARGET,
+            "Skipped {} events of block {hash:?} from event #{}: {}",
+            skipped.count,
+            skipped.index,
+            skipped.error,
+        );
+    }
+
+    Ok(records)
+}
+
+/// Events of a block that could not be decoded, see `decode_event_records`
+#[derive(Debug, PartialEq, Eq)]
+struct SkippedEvents {
+    /// Index of the first event that failed to decode
+    index: u32,
+    /// Number of events skipped, the failed event and every event after it
+    count: u32,
+    error: String,
+}
+
+/// Decode an encoded `Vec<EventRecord>` one record at a time
+///
+/// Blocks of other runtime versions can hold events the native `RuntimeEvent` doesn't know.
+/// Records aren't length prefixed, so decoding stops at the first record that fails and the
+/// records before it are returned.
+fn decode_event_records(mut data: &[u8]) -> (Vec<EventRecord>, Option<SkippedEvents>) {
+    let count = match Compact::<u32>::decode(&mut data) {
+        Ok(count) => count.0,
+        Err(e) => {
+            return (
+                Vec::new(),
+                Some(SkippedEvents {
+                    index: 0,
+                    count: 0,
+                    error: e.to_string(),
+                }),
+            )
+        }
+    };
+
+    let mut records = Vec::new();
+    for index in 0..count {
+        match EventRecord::decode(&mut data) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                return (
+                    records,
+                    Some(SkippedEvents {
+                        index,
+                        count: count - index,
+                        error: e.to_string(),
+                    }),
+                )
+            }
+        }
+    }
+
+    (records, None)
+}
@@ -0,0 +1,168 @@
+use scale_codec::{Compact, Encode};
+use sp_core::H256;
+use sqlx::Row;
+// Runtime
+use hypertensor_runtime::{AccountId, Runtime, RuntimeEvent};
+use pallet_network::{Event as NetworkEvent, SubnetRemovalReason};
+
+use super::{db::IndexDb, decode_event_records, events, EventRecord, SkippedEvents};
+
+fn record(event: NetworkEvent<Runtime>) -> EventRecord {
+    EventRecord {
+        phase: frame_system::Phase::ApplyExtrinsic(0),
+        event: RuntimeEvent::Network(event),
+        topics: Vec::new(),
+    }
+}
+
+fn account(n: u8) -> AccountId {
+    AccountId::from([n; 20])
+}
+
+async fn open_db(name: &str) -> IndexDb {
+    let path = std::env::temp_dir().join(format!(
+        "hypertensor-indexer-{}-{name}.sqlite",
+        std::process::id()
+    ));
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+    }
+
+    IndexDb::open(&path).await.unwrap()
+}
+
+async fn count(db: &IndexDb, table: &str) -> i64 {
+    sqlx::query(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(db.pool())
+        .await
+        .unwrap()
+        .get(0)
+}
+
+#[test]
+fn decode_event_records_decodes_every_record() {
+    let records = vec![
+        record(NetworkEvent::SubnetActivated { subnet_id: 1 }),
+        record(NetworkEvent::SubnetActivated { subnet_id: 2 }),
+    ];
+
+    let (decoded, skipped) = decode_event_records(&records.encode());
+
+    assert_eq!(decoded, records);
+    assert_eq!(skipped, None);
+}
+
+#[test]
+fn decode_event_records_keeps_records_before_an_undecodable_event() {
+    let records = vec![
+        record(NetworkEvent::SubnetActivated { subnet_id: 1 }),
+        record(NetworkEvent::SubnetActivated { subnet_id: 2 }),
+    ];
+
+    // A third record with an unknown phase, e.g. from another runtime version
+    let mut data = Compact(3u32).encode();
+    for record in &records {
+        data.extend(record.encode());
+    }
+    data.extend([0xff, 0xff, 0xff]);
+
+    let (decoded, skipped) = decode_event_records(&data);
+
+    assert_eq!(decoded, records);
+    let skipped = skipped.unwrap();
+    assert_eq!(skipped.index, 2);
+    assert_eq!(skipped.count, 1);
+}
+
+#[test]
+fn decode_event_records_without_length() {
+    let (decoded, skipped) = decode_event_records(&[]);
+
+    assert!(decoded.is_empty());
+    assert!(matches!(skipped, Some(SkippedEvents { count: 0, .. })));
+}
+
+#[test]
+fn describe_names_network_events() {
+    let (pallet, name, data) =
+        events::describe(&record(NetworkEvent::SubnetActivated { subnet_id: 1 }).event).unwrap();
+
+    assert_eq!(pallet, "Network");
+    assert_eq!(name, "SubnetActivated");
+    assert!(data.contains("subnet_id: 1"));
+
+    let system = RuntimeEvent::System(frame_system::Event::CodeUpdated);
+    assert!(events::describe(&system).is_none());
+}
+
+#[tokio::test]
+async fn index_block_writes_events_and_tables() {
+    let db = open_db("index-block").await;
+    assert_eq!(db.last_indexed_block().await.unwrap(), None);
+
+    let records = vec![
+        record(NetworkEvent::SubnetRegistered {
+            owner: account(1),
+            name: b"subnet-name".to_vec(),
+            subnet_id: 1,
+        }),
+        record(NetworkEvent::StakeAdded(1, account(2), account(3), 1000)),
+        record(NetworkEvent::SubnetDeactivated {
+            subnet_id: 1,
+            reason: SubnetRemovalReason::Owner,
+        }),
+    ];
+
+    db.index_block(7, H256::repeat_byte(7), &records)
+        .await
+        .unwrap();
+
+    assert_eq!(db.last_indexed_block().await.unwrap(), Some(7));
+    assert_eq!(count(&db, "events").await, 3);
+
+    let subnet = sqlx::query(
+        "SELECT name, owner, registered_block, removed_block, removal_reason FROM subnets
+        WHERE subnet_id = 1",
+    )
+    .fetch_one(db.pool())
+    .await
+    .unwrap();
+    assert_eq!(subnet.get::<String, _>(0), "subnet-name");
+    assert_eq!(subnet.get::<String, _>(1), format!("0x{}", "01".repeat(20)));
+    assert_eq!(subnet.get::<i64, _>(2), 7);
+    assert_eq!(subnet.get::<i64, _>(3), 7);
+    assert_eq!(subnet.get::<String, _>(4), "Owner");
+
+    let stake = sqlx::query("SELECT kind, event_index, amount FROM stake_changes")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(stake.get::<String, _>(0), "stake_added");
+    assert_eq!(stake.get::<i64, _>(1), 1);
+    assert_eq!(stake.get::<String, _>(2), "1000");
+
+    assert_eq!(count(&db, "removals").await, 1);
+}
+
+#[tokio::test]
+async fn index_block_writes_nothing_on_failure() {
+    let db = open_db("index-block-failure").await;
+
+    let records = vec![record(NetworkEvent::SubnetActivated { subnet_id: 1 })];
+    db.index_block(1, H256::repeat_byte(1), &records)
+        .await
+        .unwrap();
+
+    // The block is already indexed, none of its events are written again
+    let records = vec![
+        record(NetworkEvent::SubnetActivated { subnet_id: 2 }),
+        record(NetworkEvent::SubnetActivated { subnet_id: 3 }),
+    ];
+    assert!(db
+        .index_block(1, H256::repeat_byte(1), &records)
+        .await
+        .is_err());
+
+    assert_eq!(count(&db, "events").await, 1);
+    assert_eq!(count(&db, "subnets").await, 1);
+}
@@ -15,6 +15,7 @@ mod cli;
 mod client;
 mod command;
 mod eth;
+mod indexer;
 mod rpc;
 mod service;
 
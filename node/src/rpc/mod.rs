@@ -91,7 +91,7 @@ where
 
     io.merge(System::new(client.clone(), pool).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-    io.merge(NetworkCustom::new(client).into_rpc())?;
+    io.merge(NetworkCustom::new(client, subscription_task_executor.clone()).into_rpc())?;
 
     if let Some(command_sink) = command_sink {
         io.merge(
@@ -32,6 +32,7 @@ use crate::{
         FrontierBackend, FrontierBlockImport, FrontierPartialComponents, StorageOverride,
         StorageOverrideHandler,
     },
+    indexer::{self, IndexerConfiguration},
 };
 
 /// Only enable the benchmarking host functions when we actually want to benchmark.
@@ -285,6 +286,7 @@ pub async fn new_full<B, RA, HF, NB>(
     mut config: Configuration,
     eth_config: EthConfiguration,
     sealing: Option<Sealing>,
+    indexer: Option<IndexerConfiguration>,
 ) -> Result<TaskManager, ServiceError>
 where
     B: BlockT<Hash = H256>,
@@ -393,6 +395,18 @@ where
         );
     }
 
+    if let Some(indexer) = indexer {
+        task_manager.spawn_handle().spawn(
+            "network-indexer",
+            None,
+            indexer::run::<B, _, _>(
+                client.clone(),
+                indexer.database_path(&config),
+                indexer.index_from_block,
+            ),
+        );
+    }
+
     let role = config.role;
     let force_authoring = config.force_authoring;
     let name = config.network.node_name.clone();
@@ -744,9 +758,10 @@ pub async fn build_full(
     config: Configuration,
     eth_config: EthConfiguration,
     sealing: Option<Sealing>,
+    indexer: Option<IndexerConfiguration>,
 ) -> Result<TaskManager, ServiceError> {
     new_full::<Block, RuntimeApi, HostFunctions, sc_network::NetworkWorker<_, _>>(
-        config, eth_config, sealing,
+        config, eth_config, sealing, indexer,
     )
     .await
 }
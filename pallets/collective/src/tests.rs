@@ -135,6 +135,7 @@ impl pallet_network::Config for Test {
     type WeightInfo = ();
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type MajorityCollectiveOrigin =
         pallet_collective::EnsureProportionAtLeast<AccountId, Instance1, 2, 3>;
     type SuperMajorityCollectiveOrigin =
@@ -143,6 +144,7 @@ impl pallet_network::Config for Test {
     type EpochsPerYear = EpochsPerYear;
     type InitialTxRateLimit = ConstU32<0>;
     type Randomness = InsecureRandomnessCollectiveFlip;
+    type RandomnessBeacon = ();
     type PalletId = NetworkPalletId;
     type TreasuryAccount = ();
     type OverwatchEpochEmissions = OverwatchEpochEmissions;
@@ -1743,6 +1745,7 @@ fn genesis_build_panics_with_duplicate_members() {
 
 // Make admin pallet function call with 100% approval
 #[test]
+#[allow(deprecated)]
 fn proposal_network_pallet_vote_2_3() {
     ExtBuilder::default().build_and_execute(|| {
         let min = pallet_network::MinSubnetRemovalInterval::<Test>::get() + 2;
@@ -1780,6 +1783,7 @@ fn proposal_network_pallet_vote_2_3() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn proposal_network_pallet_vote_1_3() {
     ExtBuilder::default().build_and_execute(|| {
         let min = pallet_network::MinSubnetRemovalInterval::<Test>::get();
@@ -1816,6 +1820,7 @@ fn proposal_network_pallet_vote_1_3() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn proposal_network_pallet_vote_4_5() {
     ExtBuilder::default().build_and_execute(|| {
         let value = pallet_network::MinSubnetDelegateStakeFactor::<Test>::get();
@@ -1851,6 +1856,7 @@ fn proposal_network_pallet_vote_4_5() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn proposal_network_pallet_vote_1_5() {
     ExtBuilder::default().build_and_execute(|| {
         let value = pallet_network::MinSubnetDelegateStakeFactor::<Test>::get();
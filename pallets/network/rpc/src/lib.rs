@@ -1,19 +1,38 @@
+use futures::{future, stream, StreamExt};
 use jsonrpsee::{
     core::RpcResult,
     proc_macros::rpc,
     types::{error::ErrorObject, ErrorObjectOwned},
+    PendingSubscriptionSink,
 };
 
+use sc_client_api::BlockchainEvents;
+use sc_rpc::{
+    utils::{pipe_from_stream, spawn_subscription_task},
+    SubscriptionTaskExecutor,
+};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use sp_api::ProvideRuntimeApi;
 
+use codec::DecodeAll;
 use fp_account::AccountId20;
 use frame_support::storage::bounded_vec::BoundedVec;
 pub use network_custom_rpc_runtime_api::NetworkRuntimeApi;
 
+pub mod types;
+use types::{
+    AllSubnetBootnodes, ConsensusSimulation, DelegateStakeInfo, DelegateStakeProjection,
+    ElectedValidatorInfo, NetworkParameterInfo, NodeDelegateStakeInfo, NodeStakeInfo,
+    OverwatchCommit, OverwatchNodeInfo, OverwatchReveal, OverwatchSubnetWeightBreakdown, Page,
+    RewardRecord, ScheduledParameterChangeInfo, SubnetColdkeyConcentration, SubnetInfo,
+    SubnetLifecycleNotification, SubnetLifecycleUpdate, SubnetNodeConsensusData, SubnetNodeInfo,
+    SubnetNodeInfoFilter, SubnetRewardsLedgerEntry, U128String, ValidatorsAndAttestors, Versioned,
+};
+
 #[rpc(client, server)]
 pub trait NetworkCustomApi<BlockHash> {
     #[method(name = "network_getSubnetInfo")]
@@ -76,6 +95,19 @@ pub trait NetworkCustomApi<BlockHash> {
         overwatch_node_id: u32,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getUnrevealedOverwatchCommits")]
+    fn get_unrevealed_overwatch_commits(
+        &self,
+        epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getOverwatchSubnetWeightBreakdown")]
+    fn get_overwatch_subnet_weight_breakdown(
+        &self,
+        epoch: u32,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
     #[method(name = "network_getElectedValidatorInfo")]
     fn get_elected_validator_info(
         &self,
@@ -91,6 +123,301 @@ pub trait NetworkCustomApi<BlockHash> {
     ) -> RpcResult<Vec<u8>>;
     #[method(name = "network_getAllOverwatchNodesInfo")]
     fn get_all_overwatch_nodes_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getSubnetNodesInfoPaged")]
+    fn get_subnet_nodes_info_paged(
+        &self,
+        filter: SubnetNodeInfoFilter,
+        cursor: Option<(u32, u32)>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getAllSubnetsInfoPaged")]
+    fn get_all_subnets_info_paged(
+        &self,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getDelegateStakesPaged")]
+    fn get_delegate_stakes_paged(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getSubnetRewardsLedger")]
+    fn get_subnet_rewards_ledger(
+        &self,
+        subnet_id: u32,
+        from_epoch: u32,
+        to_epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getSubnetNodeRewardsHistory")]
+    fn get_subnet_node_rewards_history(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getAccountRewardsHistory")]
+    fn get_account_rewards_history(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_simulateConsensus")]
+    fn simulate_consensus(
+        &self,
+        subnet_id: u32,
+        data: Vec<SubnetNodeConsensusData>,
+        attestor_subnet_node_ids: Vec<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getSubnetDelegateStakeProjection")]
+    fn get_subnet_delegate_stake_projection(
+        &self,
+        subnet_id: u32,
+        amount: U128String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getValidatorDelegateStakeProjection")]
+    fn get_validator_delegate_stake_projection(
+        &self,
+        validator_id: u32,
+        amount: U128String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getSubnetLifecycleNotifications")]
+    fn get_subnet_lifecycle_notifications(
+        &self,
+        subnet_id: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getSubnetColdkeyConcentration")]
+    fn get_subnet_coldkey_concentration(
+        &self,
+        subnet_id: u32,
+        top_n: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getNetworkParameters")]
+    fn get_network_parameters(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "network_getScheduledParameterChanges")]
+    fn get_scheduled_parameter_changes(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    // JSON versions of the SCALE methods above, see `types`
+    #[method(name = "network_getSubnetInfoJson")]
+    fn get_subnet_info_json(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Option<SubnetInfo>>>;
+    #[method(name = "network_getAllSubnetsInfoJson")]
+    fn get_all_subnets_info_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<SubnetInfo>>>;
+    #[method(name = "network_getSubnetNodeInfoJson")]
+    fn get_subnet_node_info_json(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Option<SubnetNodeInfo>>>;
+    #[method(name = "network_getSubnetNodesInfoJson")]
+    fn get_subnet_nodes_info_json(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<SubnetNodeInfo>>>;
+    #[method(name = "network_getAllSubnetNodesInfoJson")]
+    fn get_all_subnet_nodes_info_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<SubnetNodeInfo>>>;
+    #[method(name = "network_getBootnodesJson")]
+    fn get_bootnodes_json(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<AllSubnetBootnodes>>;
+    #[method(name = "network_getValidatorSubnetNodesInfoJson")]
+    fn get_validator_subnet_nodes_info_json(
+        &self,
+        validator_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<SubnetNodeInfo>>>;
+    #[method(name = "network_getValidatorStakesJson")]
+    fn get_validator_stakes_json(
+        &self,
+        validator_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<NodeStakeInfo>>>;
+    #[method(name = "network_getDelegateStakesJson")]
+    fn get_delegate_stakes_json(
+        &self,
+        account_id: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<DelegateStakeInfo>>>;
+    #[method(name = "network_getNodeDelegateStakesJson")]
+    fn get_node_delegate_stakes_json(
+        &self,
+        account_id: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<NodeDelegateStakeInfo>>>;
+    #[method(name = "network_getOverwatchCommitsForEpochAndNodeJson")]
+    fn get_overwatch_commits_for_epoch_and_node_json(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<OverwatchCommit>>>;
+    #[method(name = "network_getOverwatchRevealsForEpochAndNodeJson")]
+    fn get_overwatch_reveals_for_epoch_and_node_json(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<OverwatchReveal>>>;
+    #[method(name = "network_getUnrevealedOverwatchCommitsJson")]
+    fn get_unrevealed_overwatch_commits_json(
+        &self,
+        epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<BTreeMap<u32, Vec<u32>>>>;
+    #[method(name = "network_getOverwatchSubnetWeightBreakdownJson")]
+    fn get_overwatch_subnet_weight_breakdown_json(
+        &self,
+        epoch: u32,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Option<OverwatchSubnetWeightBreakdown>>>;
+    #[method(name = "network_getElectedValidatorInfoJson")]
+    fn get_elected_validator_info_json(
+        &self,
+        subnet_id: u32,
+        subnet_epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<ElectedValidatorInfo>>;
+    #[method(name = "network_getValidatorsAndAttestorsJson")]
+    fn get_validators_and_attestors_json(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<ValidatorsAndAttestors>>;
+    #[method(name = "network_getAllOverwatchNodesInfoJson")]
+    fn get_all_overwatch_nodes_info_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<OverwatchNodeInfo>>>;
+    #[method(name = "network_getSubnetNodesInfoPagedJson")]
+    fn get_subnet_nodes_info_paged_json(
+        &self,
+        filter: SubnetNodeInfoFilter,
+        cursor: Option<(u32, u32)>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Page<SubnetNodeInfo, (u32, u32)>>>;
+    #[method(name = "network_getAllSubnetsInfoPagedJson")]
+    fn get_all_subnets_info_paged_json(
+        &self,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Page<SubnetInfo, u32>>>;
+    #[method(name = "network_getDelegateStakesPagedJson")]
+    fn get_delegate_stakes_paged_json(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Page<DelegateStakeInfo, u32>>>;
+    #[method(name = "network_getSubnetRewardsLedgerJson")]
+    fn get_subnet_rewards_ledger_json(
+        &self,
+        subnet_id: u32,
+        from_epoch: u32,
+        to_epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<(u32, SubnetRewardsLedgerEntry)>>>;
+    #[method(name = "network_getSubnetNodeRewardsHistoryJson")]
+    fn get_subnet_node_rewards_history_json(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<RewardRecord>>>;
+    #[method(name = "network_getAccountRewardsHistoryJson")]
+    fn get_account_rewards_history_json(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Page<RewardRecord, u32>>>;
+    #[method(name = "network_simulateConsensusJson")]
+    fn simulate_consensus_json(
+        &self,
+        subnet_id: u32,
+        data: Vec<SubnetNodeConsensusData>,
+        attestor_subnet_node_ids: Vec<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Option<ConsensusSimulation>>>;
+    #[method(name = "network_getSubnetDelegateStakeProjectionJson")]
+    fn get_subnet_delegate_stake_projection_json(
+        &self,
+        subnet_id: u32,
+        amount: U128String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<DelegateStakeProjection>>;
+    #[method(name = "network_getValidatorDelegateStakeProjectionJson")]
+    fn get_validator_delegate_stake_projection_json(
+        &self,
+        validator_id: u32,
+        amount: U128String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<DelegateStakeProjection>>;
+    #[method(name = "network_getSubnetLifecycleNotificationsJson")]
+    fn get_subnet_lifecycle_notifications_json(
+        &self,
+        subnet_id: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<SubnetLifecycleNotification>>>;
+    #[method(name = "network_getSubnetColdkeyConcentrationJson")]
+    fn get_subnet_coldkey_concentration_json(
+        &self,
+        subnet_id: u32,
+        top_n: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Option<SubnetColdkeyConcentration>>>;
+    #[method(name = "network_getNetworkParametersJson")]
+    fn get_network_parameters_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<NetworkParameterInfo>>>;
+    #[method(name = "network_getScheduledParameterChangesJson")]
+    fn get_scheduled_parameter_changes_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Versioned<Vec<ScheduledParameterChangeInfo>>>;
+
+    // Pub-sub
+    /// Lifecycle notifications of a subnet from each block that joins the best chain
+    ///
+    /// With `subnet_node_id` only the notifications about that node and the ones about the whole
+    /// subnet are pushed. On a reorg every block that left the best chain is pushed as
+    /// `Retracted`, newest first, before the notifications of the new best chain.
+    #[subscription(
+        name = "network_subscribeSubnetLifecycle" => "network_subnetLifecycle",
+        unsubscribe = "network_unsubscribeSubnetLifecycle",
+        item = Versioned<SubnetLifecycleUpdate<BlockHash>>
+    )]
+    fn subscribe_subnet_lifecycle(&self, subnet_id: u32, subnet_node_id: Option<u32>);
 }
 
 /// A struct that implements the `NetworkCustomApi`.
@@ -98,14 +425,17 @@ pub struct NetworkCustom<C, Block> {
     // If you have more generics, no need to NetworkCustom<C, M, N, P, ...>
     // just use a tuple like NetworkCustom<C, (M, N, P, ...)>
     client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
     _marker: std::marker::PhantomData<Block>,
 }
 
 impl<C, Block> NetworkCustom<C, Block> {
-    /// Create new `NetworkCustom` instance with the given reference to the client.
-    pub fn new(client: Arc<C>) -> Self {
+    /// Create new `NetworkCustom` instance with the given reference to the client and the
+    /// executor subscriptions are spawned on.
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
         Self {
             client,
+            executor,
             _marker: Default::default(),
         }
     }
@@ -115,12 +445,15 @@ impl<C, Block> NetworkCustom<C, Block> {
 pub enum Error {
     /// The call to runtime failed.
     RuntimeError(String),
+    /// The runtime response doesn't match the JSON schema.
+    DecodeError(String),
 }
 
 impl From<Error> for ErrorObjectOwned {
     fn from(e: Error) -> Self {
         match e {
             Error::RuntimeError(e) => ErrorObject::owned(1, e, None::<()>),
+            Error::DecodeError(e) => ErrorObject::owned(2, e, None::<()>),
         }
     }
 }
@@ -129,14 +462,57 @@ impl From<Error> for i32 {
     fn from(e: Error) -> i32 {
         match e {
             Error::RuntimeError(_) => 1,
+            Error::DecodeError(_) => 2,
         }
     }
 }
 
+/// Lifecycle notifications of `subnet_id` in a block, or the error that prevented reading them
+fn subnet_lifecycle_updates<C, Block>(
+    client: &C,
+    block_hash: Block::Hash,
+    subnet_id: u32,
+) -> Vec<SubnetLifecycleUpdate<Block::Hash>>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: NetworkRuntimeApi<Block>,
+{
+    let notifications = client
+        .runtime_api()
+        .get_subnet_lifecycle_notifications(block_hash, Some(subnet_id))
+        .map_err(|e| format!("Unable to get subnet lifecycle notifications: {:?}", e))
+        .and_then(|encoded| {
+            Vec::<SubnetLifecycleNotification>::decode_all(&mut &encoded[..])
+                .map_err(|e| format!("Unable to decode runtime response: {:?}", e))
+        });
+
+    match notifications {
+        Ok(notifications) => notifications
+            .into_iter()
+            .map(|notification| SubnetLifecycleUpdate::Notification {
+                block_hash,
+                notification,
+            })
+            .collect(),
+        Err(error) => vec![SubnetLifecycleUpdate::Error { block_hash, error }],
+    }
+}
+
+/// Decode a SCALE encoded runtime API response into its versioned JSON view
+fn decode_versioned<T: DecodeAll>(encoded: Vec<u8>) -> RpcResult<Versioned<T>> {
+    T::decode_all(&mut &encoded[..])
+        .map(Versioned::new)
+        .map_err(|e| {
+            Error::DecodeError(format!("Unable to decode runtime response: {:?}", e)).into()
+        })
+}
+
 impl<C, Block> NetworkCustomApiServer<<Block as BlockT>::Hash> for NetworkCustom<C, Block>
 where
     Block: BlockT,
-    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+    C: Send + Sync + 'static,
     C::Api: NetworkRuntimeApi<Block>,
 {
     fn get_subnet_info(
@@ -308,6 +684,41 @@ where
             })
     }
 
+    fn get_unrevealed_overwatch_commits(
+        &self,
+        epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_unrevealed_overwatch_commits(at, epoch)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get unrevealed overwatch commits: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_overwatch_subnet_weight_breakdown(
+        &self,
+        epoch: u32,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_overwatch_subnet_weight_breakdown(at, epoch, subnet_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get overwatch subnet weight breakdown: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
     fn get_elected_validator_info(
         &self,
         subnet_id: u32,
@@ -349,4 +760,517 @@ where
             Error::RuntimeError(format!("Unable to get all overwatch nodes info: {:?}", e)).into()
         })
     }
+
+    fn get_subnet_nodes_info_paged(
+        &self,
+        filter: SubnetNodeInfoFilter,
+        cursor: Option<(u32, u32)>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_subnet_nodes_info_paged(at, filter.into(), cursor, limit)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get subnet nodes info page: {:?}", e)).into()
+            })
+    }
+
+    fn get_all_subnets_info_paged(
+        &self,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_all_subnets_info_paged(at, cursor, limit)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get subnets info page: {:?}", e)).into()
+            })
+    }
+
+    fn get_delegate_stakes_paged(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_delegate_stakes_paged(at, account_id, cursor, limit)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get delegate stakes page: {:?}", e)).into()
+            })
+    }
+
+    fn get_subnet_rewards_ledger(
+        &self,
+        subnet_id: u32,
+        from_epoch: u32,
+        to_epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_subnet_rewards_ledger(at, subnet_id, from_epoch, to_epoch)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get subnet rewards ledger: {:?}", e)).into()
+            })
+    }
+
+    fn get_subnet_node_rewards_history(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_subnet_node_rewards_history(at, subnet_id, subnet_node_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get subnet node rewards history: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_account_rewards_history(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_account_rewards_history(at, account_id, cursor, limit)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get account rewards history: {:?}", e))
+                    .into()
+            })
+    }
+
+    fn simulate_consensus(
+        &self,
+        subnet_id: u32,
+        data: Vec<SubnetNodeConsensusData>,
+        attestor_subnet_node_ids: Vec<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let data = data.into_iter().map(Into::into).collect();
+        api.simulate_consensus(at, subnet_id, data, attestor_subnet_node_ids)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to simulate consensus: {:?}", e)).into()
+            })
+    }
+
+    fn get_subnet_delegate_stake_projection(
+        &self,
+        subnet_id: u32,
+        amount: U128String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_subnet_delegate_stake_projection(at, subnet_id, amount.0)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get subnet delegate stake projection: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_validator_delegate_stake_projection(
+        &self,
+        validator_id: u32,
+        amount: U128String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_validator_delegate_stake_projection(at, validator_id, amount.0)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get validator delegate stake projection: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_subnet_lifecycle_notifications(
+        &self,
+        subnet_id: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_subnet_lifecycle_notifications(at, subnet_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get subnet lifecycle notifications: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_subnet_coldkey_concentration(
+        &self,
+        subnet_id: u32,
+        top_n: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_subnet_coldkey_concentration(at, subnet_id, top_n)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get subnet coldkey concentration: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_network_parameters(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_network_parameters(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get network parameters: {:?}", e)).into()
+        })
+    }
+
+    fn get_scheduled_parameter_changes(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.get_scheduled_parameter_changes(at).map_err(|e| {
+            Error::RuntimeError(format!(
+                "Unable to get scheduled parameter changes: {:?}",
+                e
+            ))
+            .into()
+        })
+    }
+
+    fn get_subnet_info_json(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Option<SubnetInfo>>> {
+        decode_versioned(self.get_subnet_info(subnet_id, at)?)
+    }
+
+    fn get_all_subnets_info_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<SubnetInfo>>> {
+        decode_versioned(self.get_all_subnets_info(at)?)
+    }
+
+    fn get_subnet_node_info_json(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Option<SubnetNodeInfo>>> {
+        decode_versioned(self.get_subnet_node_info(subnet_id, subnet_node_id, at)?)
+    }
+
+    fn get_subnet_nodes_info_json(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<SubnetNodeInfo>>> {
+        decode_versioned(self.get_subnet_nodes_info(subnet_id, at)?)
+    }
+
+    fn get_all_subnet_nodes_info_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<SubnetNodeInfo>>> {
+        decode_versioned(self.get_all_subnet_nodes_info(at)?)
+    }
+
+    fn get_bootnodes_json(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<AllSubnetBootnodes>> {
+        decode_versioned(self.get_bootnodes(subnet_id, at)?)
+    }
+
+    fn get_validator_subnet_nodes_info_json(
+        &self,
+        validator_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<SubnetNodeInfo>>> {
+        decode_versioned(self.get_validator_subnet_nodes_info(validator_id, at)?)
+    }
+
+    fn get_validator_stakes_json(
+        &self,
+        validator_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<NodeStakeInfo>>> {
+        decode_versioned(self.get_validator_stakes(validator_id, at)?)
+    }
+
+    fn get_delegate_stakes_json(
+        &self,
+        account_id: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<DelegateStakeInfo>>> {
+        decode_versioned(self.get_delegate_stakes(account_id, at)?)
+    }
+
+    fn get_node_delegate_stakes_json(
+        &self,
+        account_id: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<NodeDelegateStakeInfo>>> {
+        decode_versioned(self.get_node_delegate_stakes(account_id, at)?)
+    }
+
+    fn get_overwatch_commits_for_epoch_and_node_json(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<OverwatchCommit>>> {
+        decode_versioned(self.get_overwatch_commits_for_epoch_and_node(
+            epoch,
+            overwatch_node_id,
+            at,
+        )?)
+    }
+
+    fn get_overwatch_reveals_for_epoch_and_node_json(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<OverwatchReveal>>> {
+        decode_versioned(self.get_overwatch_reveals_for_epoch_and_node(
+            epoch,
+            overwatch_node_id,
+            at,
+        )?)
+    }
+
+    fn get_unrevealed_overwatch_commits_json(
+        &self,
+        epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<BTreeMap<u32, Vec<u32>>>> {
+        decode_versioned(self.get_unrevealed_overwatch_commits(epoch, at)?)
+    }
+
+    fn get_overwatch_subnet_weight_breakdown_json(
+        &self,
+        epoch: u32,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Option<OverwatchSubnetWeightBreakdown>>> {
+        decode_versioned(self.get_overwatch_subnet_weight_breakdown(epoch, subnet_id, at)?)
+    }
+
+    fn get_elected_validator_info_json(
+        &self,
+        subnet_id: u32,
+        subnet_epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<ElectedValidatorInfo>> {
+        decode_versioned(self.get_elected_validator_info(subnet_id, subnet_epoch, at)?)
+    }
+
+    fn get_validators_and_attestors_json(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<ValidatorsAndAttestors>> {
+        decode_versioned(self.get_validators_and_attestors(subnet_id, at)?)
+    }
+
+    fn get_all_overwatch_nodes_info_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<OverwatchNodeInfo>>> {
+        decode_versioned(self.get_all_overwatch_nodes_info(at)?)
+    }
+
+    fn get_subnet_nodes_info_paged_json(
+        &self,
+        filter: SubnetNodeInfoFilter,
+        cursor: Option<(u32, u32)>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Page<SubnetNodeInfo, (u32, u32)>>> {
+        decode_versioned(self.get_subnet_nodes_info_paged(filter, cursor, limit, at)?)
+    }
+
+    fn get_all_subnets_info_paged_json(
+        &self,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Page<SubnetInfo, u32>>> {
+        decode_versioned(self.get_all_subnets_info_paged(cursor, limit, at)?)
+    }
+
+    fn get_delegate_stakes_paged_json(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Page<DelegateStakeInfo, u32>>> {
+        decode_versioned(self.get_delegate_stakes_paged(account_id, cursor, limit, at)?)
+    }
+
+    fn get_subnet_rewards_ledger_json(
+        &self,
+        subnet_id: u32,
+        from_epoch: u32,
+        to_epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<(u32, SubnetRewardsLedgerEntry)>>> {
+        decode_versioned(self.get_subnet_rewards_ledger(subnet_id, from_epoch, to_epoch, at)?)
+    }
+
+    fn get_subnet_node_rewards_history_json(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<RewardRecord>>> {
+        decode_versioned(self.get_subnet_node_rewards_history(subnet_id, subnet_node_id, at)?)
+    }
+
+    fn get_account_rewards_history_json(
+        &self,
+        account_id: AccountId20,
+        cursor: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Page<RewardRecord, u32>>> {
+        decode_versioned(self.get_account_rewards_history(account_id, cursor, limit, at)?)
+    }
+
+    fn simulate_consensus_json(
+        &self,
+        subnet_id: u32,
+        data: Vec<SubnetNodeConsensusData>,
+        attestor_subnet_node_ids: Vec<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Option<ConsensusSimulation>>> {
+        decode_versioned(self.simulate_consensus(subnet_id, data, attestor_subnet_node_ids, at)?)
+    }
+
+    fn get_subnet_delegate_stake_projection_json(
+        &self,
+        subnet_id: u32,
+        amount: U128String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<DelegateStakeProjection>> {
+        decode_versioned(self.get_subnet_delegate_stake_projection(subnet_id, amount, at)?)
+    }
+
+    fn get_validator_delegate_stake_projection_json(
+        &self,
+        validator_id: u32,
+        amount: U128String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<DelegateStakeProjection>> {
+        decode_versioned(self.get_validator_delegate_stake_projection(validator_id, amount, at)?)
+    }
+
+    fn get_subnet_lifecycle_notifications_json(
+        &self,
+        subnet_id: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<SubnetLifecycleNotification>>> {
+        decode_versioned(self.get_subnet_lifecycle_notifications(subnet_id, at)?)
+    }
+
+    fn get_subnet_coldkey_concentration_json(
+        &self,
+        subnet_id: u32,
+        top_n: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Option<SubnetColdkeyConcentration>>> {
+        decode_versioned(self.get_subnet_coldkey_concentration(subnet_id, top_n, at)?)
+    }
+
+    fn get_network_parameters_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<NetworkParameterInfo>>> {
+        decode_versioned(self.get_network_parameters(at)?)
+    }
+
+    fn get_scheduled_parameter_changes_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Versioned<Vec<ScheduledParameterChangeInfo>>> {
+        decode_versioned(self.get_scheduled_parameter_changes(at)?)
+    }
+
+    fn subscribe_subnet_lifecycle(
+        &self,
+        pending: PendingSubscriptionSink,
+        subnet_id: u32,
+        subnet_node_id: Option<u32>,
+    ) {
+        let client = self.client.clone();
+
+        // Blocks that are imported but never become best are skipped, their events are not final.
+        // A new best block that reorgs the chain retracts the blocks of the old best chain and
+        // enacts the blocks of its own chain that were skipped when imported.
+        let notifications = self
+            .client
+            .import_notification_stream()
+            .filter(|block| future::ready(block.is_new_best))
+            .map(move |block| {
+                let mut updates = Vec::new();
+                if let Some(tree_route) = &block.tree_route {
+                    updates.extend(tree_route.retracted().iter().map(|retracted| {
+                        SubnetLifecycleUpdate::Retracted {
+                            block_hash: retracted.hash,
+                        }
+                    }));
+                    for enacted in tree_route.enacted() {
+                        updates.extend(subnet_lifecycle_updates(&*client, enacted.hash, subnet_id));
+                    }
+                }
+                updates.extend(subnet_lifecycle_updates(&*client, block.hash, subnet_id));
+                stream::iter(updates)
+            })
+            .flatten()
+            .filter(move |update| {
+                future::ready(match (subnet_node_id, update) {
+                    (Some(id), SubnetLifecycleUpdate::Notification { notification, .. }) => {
+                        notification
+                            .subnet_node_id()
+                            .map_or(true, |node_id| node_id == id)
+                    }
+                    _ => true,
+                })
+            })
+            .map(Versioned::new)
+            .boxed();
+
+        spawn_subscription_task(&self.executor, pipe_from_stream(pending, notifications));
+    }
 }
@@ -0,0 +1,1282 @@
+//! JSON views of the SCALE-encoded values returned by `NetworkRuntimeApi`.
+//!
+//! Each struct decodes from the exact SCALE layout of its pallet counterpart (see
+//! `pallet_network::SubnetInfo`, `pallet_network::SubnetNodeInfo`, ...) so fields must be kept in
+//! the same order. Responses are decoded with `DecodeAll`, so a layout change fails the request
+//! instead of returning misaligned data.
+//!
+//! Any change to a field name, type or order must bump `SCHEMA_VERSION`.
+
+use codec::Decode;
+use fp_account::AccountId20;
+use pallet_network::multiaddr::Multiaddr;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use sp_core::H256;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Current schema version of all JSON responses
+pub const SCHEMA_VERSION: u32 = 5;
+
+/// A JSON response tagged with the schema version it was encoded with
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub version: u32,
+    pub data: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            data,
+        }
+    }
+}
+
+/// `u128` encoded as a decimal string, JSON numbers can't hold it without precision loss
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Decode)]
+pub struct U128String(pub u128);
+
+impl Serialize for U128String {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for U128String {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse::<u128>().map(U128String).map_err(D::Error::custom)
+    }
+}
+
+/// UTF-8 text such as subnet names, invalid sequences are replaced
+#[derive(Clone, Debug, Default, PartialEq, Eq, Decode)]
+pub struct Text(pub Vec<u8>);
+
+impl Serialize for Text {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf8_lossy(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Text(String::deserialize(deserializer)?.into_bytes()))
+    }
+}
+
+/// Arbitrary bytes encoded as `0x`-prefixed hex
+#[derive(Clone, Debug, Default, PartialEq, Eq, Decode)]
+pub struct HexBytes(pub Vec<u8>);
+
+impl Serialize for HexBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(&self.0)))
+    }
+}
+
+impl<'de> Deserialize<'de> for HexBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s.trim_start_matches("0x"))
+            .map(HexBytes)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Peer ID encoded as base58, i.e. `12D3KooW...`
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Decode)]
+pub struct PeerId(pub Vec<u8>);
+
+impl Serialize for PeerId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&bs58::encode(&self.0).into_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PeerId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        bs58::decode(s)
+            .into_vec()
+            .map(PeerId)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Multiaddr encoded as its text form, i.e. `/ip4/127.0.0.1/tcp/38960/p2p/12D3KooW...`
+///
+/// Bytes that are not a valid multiaddr are encoded as `0x`-prefixed hex.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Decode)]
+pub struct MultiaddrString(pub Vec<u8>);
+
+impl Serialize for MultiaddrString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match Multiaddr::verify(&self.0).and_then(|multiaddr| multiaddr.to_vec()) {
+            Ok(segments) => serializer.serialize_str(&segments.concat()),
+            Err(_) => HexBytes(self.0.clone()).serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiaddrString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        if s.starts_with("0x") {
+            return hex::decode(&s[2..])
+                .map(MultiaddrString)
+                .map_err(D::Error::custom);
+        }
+        Multiaddr::from_str(&s)
+            .map(|multiaddr| MultiaddrString(multiaddr.to_bytes()))
+            .map_err(|e| D::Error::custom(format!("{:?}", e)))
+    }
+}
+
+/// See `pallet_network::SubnetState`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum SubnetState {
+    Registered,
+    Active,
+    Paused,
+}
+
+/// See `pallet_network::SubnetRemovalReason`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum SubnetRemovalReason {
+    MinReputation,
+    MinSubnetNodes,
+    MinSubnetDelegateStake,
+    Council,
+    EnactmentPeriod,
+    MaxSubnets,
+    Owner,
+    PauseExpired,
+}
+
+/// See `pallet_network::SubnetNodeClass`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum SubnetNodeClass {
+    Registered,
+    Idle,
+    Included,
+    Validator,
+}
+
+/// See `pallet_network::SubnetNodeClassification`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetNodeClassification {
+    pub node_class: SubnetNodeClass,
+    pub start_epoch: u32,
+}
+
+/// See `pallet_network::SubnetReputationFactors`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetReputationFactors {
+    pub absent_decrease: U128String,
+    pub included_increase: U128String,
+    pub below_min_weight_decrease: U128String,
+    pub non_attestor_decrease: U128String,
+    pub non_consensus_attestor_decrease: U128String,
+    pub validator_absent_decrease: U128String,
+    pub validator_non_consensus_decrease: U128String,
+}
+
+/// See `pallet_network::PendingSubnetReputationFactors`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct PendingSubnetReputationFactors {
+    pub effective_subnet_epoch: u32,
+    pub factors: SubnetReputationFactors,
+}
+
+/// See `pallet_network::SubnetInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetInfo {
+    pub id: u32,
+    pub friendly_id: Option<u32>,
+    pub name: Text,
+    pub repo: Text,
+    pub description: Text,
+    pub misc: Text,
+    pub state: SubnetState,
+    pub start_epoch: u32,
+    pub churn_limit: u32,
+    pub churn_limit_multiplier: u32,
+    pub min_stake: U128String,
+    pub max_stake: U128String,
+    pub queue_immunity_epochs: u32,
+    pub target_node_registrations_per_epoch: u32,
+    pub node_registrations_this_epoch: u32,
+    pub subnet_node_queue_epochs: u32,
+    pub idle_classification_epochs: u32,
+    pub included_classification_epochs: u32,
+    pub delegate_stake_percentage: U128String,
+    pub last_delegate_stake_rewards_update: u32,
+    pub node_burn_rate_alpha: U128String,
+    pub current_node_burn_rate: U128String,
+    pub initial_validators: Option<BTreeMap<u32, u32>>,
+    pub initial_validator_data: Option<BTreeMap<u32, u32>>,
+    pub max_registered_nodes: u32,
+    pub owner: Option<AccountId20>,
+    pub pending_owner: Option<AccountId20>,
+    pub registration_epoch: Option<u32>,
+    pub prev_pause_epoch: u32,
+    pub slot_index: Option<u32>,
+    pub slot_assignment: Option<u32>,
+    pub subnet_node_min_weight_decrease_reputation_threshold: U128String,
+    pub reputation: U128String,
+    pub min_subnet_node_reputation: U128String,
+    pub absent_decrease_reputation_factor: U128String,
+    pub included_increase_reputation_factor: U128String,
+    pub below_min_weight_decrease_reputation_factor: U128String,
+    pub non_attestor_decrease_reputation_factor: U128String,
+    pub non_consensus_attestor_decrease_reputation_factor: U128String,
+    pub validator_absent_subnet_node_reputation_factor: U128String,
+    pub validator_non_consensus_subnet_node_reputation_factor: U128String,
+    pub pending_reputation_factors: Option<PendingSubnetReputationFactors>,
+    pub bootnode_access: BTreeSet<AccountId20>,
+    pub bootnodes: BTreeMap<PeerId, MultiaddrString>,
+    pub total_nodes: u32,
+    pub total_active_nodes: u32,
+    pub total_electable_nodes: u32,
+    pub current_min_delegate_stake: U128String,
+    pub total_subnet_stake: U128String,
+    pub total_subnet_delegate_stake_shares: U128String,
+    pub total_subnet_delegate_stake_balance: U128String,
+}
+
+/// See `pallet_network::PeerInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct PeerInfo {
+    pub peer_id: PeerId,
+    pub multiaddr: Option<MultiaddrString>,
+}
+
+/// See `pallet_network::SubnetNodeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetNodeInfo {
+    pub validator_id: Option<u32>,
+    pub subnet_id: u32,
+    pub subnet_node_id: u32,
+    pub coldkey: AccountId20,
+    pub hotkey: AccountId20,
+    pub peer_info: PeerInfo,
+    pub bootnode_peer_info: Option<PeerInfo>,
+    pub client_peer_info: Option<PeerInfo>,
+    pub classification: SubnetNodeClassification,
+    pub unique: Option<HexBytes>,
+    pub non_unique: Option<HexBytes>,
+    pub stake_balance: U128String,
+    pub subnet_node_reputation: Option<U128String>,
+    pub node_slot_index: Option<u32>,
+    pub consecutive_idle_epochs: u32,
+    pub consecutive_included_epochs: u32,
+}
+
+/// See `pallet_network::WeightedValidatorElection`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct WeightedValidatorElection {
+    pub stake_exponent: U128String,
+    pub node_reputation_exponent: U128String,
+    pub validator_reputation_exponent: U128String,
+    pub max_validator_probability: U128String,
+}
+
+/// See `pallet_network::ValidatorElectionMode`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum ValidatorElectionMode {
+    Uniform,
+    Weighted(WeightedValidatorElection),
+}
+
+/// See `pallet_network::ElectedValidatorInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct ElectedValidatorInfo {
+    pub validator: Option<SubnetNodeInfo>,
+    pub election_mode: ValidatorElectionMode,
+    pub election_probabilities: BTreeMap<u32, U128String>,
+}
+
+/// See `pallet_network::AttestationWeighting`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum AttestationWeighting {
+    Count,
+    Stake,
+    Reputation,
+}
+
+/// See `pallet_network::ValidatorsAndAttestors`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct ValidatorsAndAttestors {
+    pub validators: Vec<SubnetNodeInfo>,
+    pub attestation_weighting: AttestationWeighting,
+    pub attestation_weights: BTreeMap<u32, U128String>,
+    pub attestation_ratio: Option<U128String>,
+}
+
+/// See `pallet_network::AllSubnetBootnodes`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct AllSubnetBootnodes {
+    pub subnet_bootnodes: BTreeMap<PeerId, MultiaddrString>,
+    pub node_bootnodes: BTreeMap<PeerId, Option<MultiaddrString>>,
+    pub registered_bootnodes: BTreeMap<PeerId, Option<MultiaddrString>>,
+}
+
+/// See `pallet_network::NodeStakeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct NodeStakeInfo {
+    pub subnet_id: Option<u32>,
+    pub subnet_node_id: Option<u32>,
+    pub balance: U128String,
+}
+
+/// See `pallet_network::DelegateStakeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct DelegateStakeInfo {
+    pub subnet_id: u32,
+    pub shares: U128String,
+    pub balance: U128String,
+}
+
+/// See `pallet_network::NodeDelegateStakeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct NodeDelegateStakeInfo {
+    pub subnet_id: u32,
+    pub subnet_node_id: u32,
+    pub shares: U128String,
+    pub balance: U128String,
+}
+
+/// `(subnet_id, commit)` pair of `Pallet::get_overwatch_commits_for_epoch_and_node`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct OverwatchCommit {
+    pub subnet_id: u32,
+    pub commit: H256,
+}
+
+/// `(subnet_id, weight)` pair of `Pallet::get_overwatch_reveals_for_epoch_and_node`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct OverwatchReveal {
+    pub subnet_id: u32,
+    pub weight: U128String,
+}
+
+/// See `pallet_network::OverwatchNodeWeightDeviation`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct OverwatchNodeWeightDeviation {
+    pub overwatch_node_id: u32,
+    pub weight: U128String,
+    pub deviation: U128String,
+    pub clipped: bool,
+}
+
+/// See `pallet_network::OverwatchSubnetWeightBreakdown`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct OverwatchSubnetWeightBreakdown {
+    pub overwatch_epoch: u32,
+    pub subnet_id: u32,
+    pub weight: U128String,
+    pub clip_band: U128String,
+    pub nodes: Vec<OverwatchNodeWeightDeviation>,
+}
+
+/// See `pallet_network::Reputation`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct Reputation {
+    pub start_epoch: u32,
+    pub score: U128String,
+    pub lifetime_node_count: u32,
+    pub total_active_nodes: u32,
+    pub total_increases: u32,
+    pub total_decreases: u32,
+    pub average_attestation: U128String,
+    pub last_validator_epoch: u32,
+    pub ow_score: U128String,
+}
+
+/// See `pallet_network::OverwatchNodeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct OverwatchNodeInfo {
+    pub overwatch_node_id: u32,
+    pub hotkey: Option<AccountId20>,
+    pub peer_ids: BTreeMap<u32, PeerId>,
+    pub reputation: Reputation,
+    pub account_overwatch_stake: U128String,
+}
+
+/// A page of a paginated query, see `pallet_network::Page`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct Page<Item, Cursor> {
+    pub items: Vec<Item>,
+    pub next_cursor: Option<Cursor>,
+}
+
+/// See `pallet_network::RewardsData`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct RewardsData {
+    pub overall_subnet_reward: U128String,
+    pub subnet_owner_reward: U128String,
+    pub subnet_rewards: U128String,
+    pub delegate_stake_rewards: U128String,
+    pub subnet_node_rewards: U128String,
+}
+
+/// See `pallet_network::SubnetNodeRewardRecord`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetNodeRewardRecord {
+    pub subnet_node_id: u32,
+    pub validator_id: u32,
+    pub reward: U128String,
+}
+
+/// See `pallet_network::ValidatorDelegateStakeRewardRecord`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct ValidatorDelegateStakeRewardRecord {
+    pub validator_id: u32,
+    pub reward: U128String,
+    pub total_shares: U128String,
+}
+
+/// See `pallet_network::SubnetRewardsLedgerEntry`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetRewardsLedgerEntry {
+    pub rewards_data: RewardsData,
+    pub attestation_ratio: U128String,
+    pub in_consensus: bool,
+    pub validator_subnet_node_id: u32,
+    pub validator_id: Option<u32>,
+    pub validator_reward: U128String,
+    pub subnet_owner: Option<AccountId20>,
+    pub subnet_owner_reward: U128String,
+    pub capacitor_reward: U128String,
+    pub node_rewards: Vec<SubnetNodeRewardRecord>,
+    pub delegate_stake_reward: U128String,
+    pub delegate_stake_total_shares: U128String,
+    pub validator_delegate_stake_rewards: Vec<ValidatorDelegateStakeRewardRecord>,
+    pub delegate_account_allocations: Vec<(u32, (AccountId20, U128String))>,
+}
+
+/// See `pallet_network::RewardSource`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum RewardSource {
+    Validator,
+    SubnetNode,
+    SubnetOwner,
+    DelegateAccount,
+    SubnetDelegateStake,
+    ValidatorDelegateStake,
+}
+
+/// See `pallet_network::RewardRecord`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct RewardRecord {
+    pub epoch: u32,
+    pub subnet_id: u32,
+    pub subnet_node_id: Option<u32>,
+    pub source: RewardSource,
+    pub amount: U128String,
+}
+
+/// Score of a subnet node in a proposal, see `pallet_network::SubnetNodeConsensusData`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetNodeConsensusData {
+    pub subnet_node_id: u32,
+    pub score: U128String,
+}
+
+impl From<SubnetNodeConsensusData> for pallet_network::SubnetNodeConsensusData {
+    fn from(data: SubnetNodeConsensusData) -> Self {
+        Self {
+            subnet_node_id: data.subnet_node_id,
+            score: data.score.0,
+        }
+    }
+}
+
+/// See `pallet_network::SimulatedNodeOutcome`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SimulatedNodeOutcome {
+    pub subnet_node_id: u32,
+    pub prev_reputation: U128String,
+    pub reputation: Option<U128String>,
+    pub prev_class: SubnetNodeClass,
+    pub class: Option<SubnetNodeClass>,
+    pub prev_stake: U128String,
+    pub stake: U128String,
+    pub pending_slash: U128String,
+}
+
+/// See `pallet_network::ConsensusSimulation`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct ConsensusSimulation {
+    pub subnet_epoch: u32,
+    pub validator_subnet_node_id: u32,
+    pub attestation_ratio: U128String,
+    pub weight_sum: U128String,
+    pub rewards_data: RewardsData,
+    pub reputation_factors: SubnetReputationFactors,
+    pub rewards: SubnetRewardsLedgerEntry,
+    pub prev_subnet_reputation: U128String,
+    pub subnet_reputation: U128String,
+    pub nodes: Vec<SimulatedNodeOutcome>,
+}
+
+/// See `pallet_network::DelegateStakeProjection`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct DelegateStakeProjection {
+    pub amount: U128String,
+    pub epoch_emissions: U128String,
+    pub pool_balance: U128String,
+    pub pool_epoch_reward: U128String,
+    pub epoch_reward: U128String,
+    pub annual_reward: U128String,
+    pub apr: U128String,
+    pub apy: U128String,
+}
+
+/// See `pallet_network::ColdkeyConcentration`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct ColdkeyConcentration {
+    pub coldkey: AccountId20,
+    pub nodes: u32,
+    pub stake: U128String,
+}
+
+/// See `pallet_network::SubnetColdkeyConcentration`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct SubnetColdkeyConcentration {
+    pub subnet_id: u32,
+    pub total_nodes: u32,
+    pub total_stake: U128String,
+    pub top_node_share: U128String,
+    pub top_stake_share: U128String,
+    pub coldkeys: Vec<ColdkeyConcentration>,
+}
+
+/// See `pallet_network::NetworkParameterInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct NetworkParameterInfo {
+    pub index: u8,
+    pub name: Text,
+    pub values: Vec<U128String>,
+    pub min: U128String,
+    pub max: U128String,
+    pub super_majority: bool,
+}
+
+/// See `pallet_network::ScheduledParameterChangeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub struct ScheduledParameterChangeInfo {
+    pub id: u32,
+    pub epoch: u32,
+    pub enactment_epoch: u32,
+    pub parameters: Vec<NetworkParameterInfo>,
+}
+
+/// Subnet node filter, see `pallet_network::SubnetNodeInfoFilter`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubnetNodeInfoFilter {
+    pub subnet_id: Option<u32>,
+    pub min_class: Option<SubnetNodeClass>,
+    pub min_stake: Option<U128String>,
+    pub validator_id: Option<u32>,
+    pub min_reputation: Option<U128String>,
+    pub max_reputation: Option<U128String>,
+}
+
+impl From<SubnetNodeClass> for pallet_network::SubnetNodeClass {
+    fn from(class: SubnetNodeClass) -> Self {
+        match class {
+            SubnetNodeClass::Registered => Self::Registered,
+            SubnetNodeClass::Idle => Self::Idle,
+            SubnetNodeClass::Included => Self::Included,
+            SubnetNodeClass::Validator => Self::Validator,
+        }
+    }
+}
+
+impl From<SubnetNodeInfoFilter> for pallet_network::SubnetNodeInfoFilter {
+    fn from(filter: SubnetNodeInfoFilter) -> Self {
+        Self {
+            subnet_id: filter.subnet_id,
+            min_class: filter.min_class.map(Into::into),
+            min_stake: filter.min_stake.map(|stake| stake.0),
+            validator_id: filter.validator_id,
+            min_reputation: filter.min_reputation.map(|reputation| reputation.0),
+            max_reputation: filter.max_reputation.map(|reputation| reputation.0),
+        }
+    }
+}
+
+/// See `pallet_network::SubnetLifecycleNotification`
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Serialize, Deserialize)]
+pub enum SubnetLifecycleNotification {
+    SubnetStateChanged {
+        subnet_id: u32,
+        state: SubnetState,
+    },
+    SubnetRemoved {
+        subnet_id: u32,
+        reason: SubnetRemovalReason,
+    },
+    SubnetNodeActivated {
+        subnet_id: u32,
+        subnet_node_id: u32,
+    },
+    SubnetNodeRemoved {
+        subnet_id: u32,
+        subnet_node_id: u32,
+    },
+    SubnetNodeClassGraduated {
+        subnet_id: u32,
+        subnet_node_id: u32,
+        classification: SubnetNodeClassification,
+    },
+    ValidatorElected {
+        subnet_id: u32,
+        subnet_epoch: u32,
+        subnet_node_id: u32,
+    },
+    AttestationWindowOpen {
+        subnet_id: u32,
+        subnet_epoch: u32,
+    },
+}
+
+impl SubnetLifecycleNotification {
+    /// Subnet node the notification is about, `None` if it applies to the whole subnet
+    pub fn subnet_node_id(&self) -> Option<u32> {
+        match self {
+            Self::SubnetNodeActivated { subnet_node_id, .. }
+            | Self::SubnetNodeRemoved { subnet_node_id, .. }
+            | Self::SubnetNodeClassGraduated { subnet_node_id, .. }
+            | Self::ValidatorElected { subnet_node_id, .. } => Some(*subnet_node_id),
+            _ => None,
+        }
+    }
+}
+
+/// Item of the subnet lifecycle subscription
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubnetLifecycleUpdate<Hash> {
+    /// Notification of a block that joined the best chain
+    Notification {
+        block_hash: Hash,
+        notification: SubnetLifecycleNotification,
+    },
+    /// A block left the best chain on a reorg, the notifications pushed for it are void
+    Retracted { block_hash: Hash },
+    /// The notifications of a block could not be read, none were pushed for it
+    Error { block_hash: Hash, error: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::{DecodeAll, Encode};
+    use frame_support::BoundedVec;
+    use hypertensor_runtime::Runtime;
+    use sp_core::OpaquePeerId;
+
+    /// Encodes a pallet value and decodes it as its mirror, leftover bytes fail the decode
+    fn round_trip<P: Encode, M: DecodeAll>(value: &P) -> M {
+        M::decode_all(&mut &value.encode()[..]).expect("mirror decodes the pallet layout")
+    }
+
+    fn account(seed: u8) -> AccountId20 {
+        AccountId20::from([seed; 20])
+    }
+
+    fn bytes(seed: u8) -> pallet_network::NetworkBytes<Runtime> {
+        BoundedVec::truncate_from(vec![seed; 8])
+    }
+
+    fn peer_id(seed: u8) -> OpaquePeerId {
+        OpaquePeerId(vec![seed; 4])
+    }
+
+    fn reputation_factors(
+        seed: u128,
+    ) -> (
+        pallet_network::SubnetReputationFactors,
+        SubnetReputationFactors,
+    ) {
+        (
+            pallet_network::SubnetReputationFactors {
+                absent_decrease: seed,
+                included_increase: seed + 1,
+                below_min_weight_decrease: seed + 2,
+                non_attestor_decrease: seed + 3,
+                non_consensus_attestor_decrease: seed + 4,
+                validator_absent_decrease: seed + 5,
+                validator_non_consensus_decrease: seed + 6,
+            },
+            SubnetReputationFactors {
+                absent_decrease: U128String(seed),
+                included_increase: U128String(seed + 1),
+                below_min_weight_decrease: U128String(seed + 2),
+                non_attestor_decrease: U128String(seed + 3),
+                non_consensus_attestor_decrease: U128String(seed + 4),
+                validator_absent_decrease: U128String(seed + 5),
+                validator_non_consensus_decrease: U128String(seed + 6),
+            },
+        )
+    }
+
+    fn subnet_node_info(
+        subnet_node_id: u32,
+    ) -> (pallet_network::SubnetNodeInfo<Runtime>, SubnetNodeInfo) {
+        (
+            pallet_network::SubnetNodeInfo::<Runtime> {
+                validator_id: Some(1),
+                subnet_id: 2,
+                subnet_node_id,
+                coldkey: account(4),
+                hotkey: account(5),
+                peer_info: pallet_network::PeerInfo::<Runtime> {
+                    peer_id: peer_id(6),
+                    multiaddr: Some(bytes(7)),
+                },
+                bootnode_peer_info: Some(pallet_network::PeerInfo::<Runtime> {
+                    peer_id: peer_id(8),
+                    multiaddr: None,
+                }),
+                client_peer_info: None,
+                classification: pallet_network::SubnetNodeClassification {
+                    node_class: pallet_network::SubnetNodeClass::Validator,
+                    start_epoch: 9,
+                },
+                unique: Some(bytes(10)),
+                non_unique: None,
+                stake_balance: 11,
+                subnet_node_reputation: Some(12),
+                node_slot_index: Some(13),
+                consecutive_idle_epochs: 14,
+                consecutive_included_epochs: 15,
+            },
+            SubnetNodeInfo {
+                validator_id: Some(1),
+                subnet_id: 2,
+                subnet_node_id,
+                coldkey: account(4),
+                hotkey: account(5),
+                peer_info: PeerInfo {
+                    peer_id: PeerId(vec![6; 4]),
+                    multiaddr: Some(MultiaddrString(vec![7; 8])),
+                },
+                bootnode_peer_info: Some(PeerInfo {
+                    peer_id: PeerId(vec![8; 4]),
+                    multiaddr: None,
+                }),
+                client_peer_info: None,
+                classification: SubnetNodeClassification {
+                    node_class: SubnetNodeClass::Validator,
+                    start_epoch: 9,
+                },
+                unique: Some(HexBytes(vec![10; 8])),
+                non_unique: None,
+                stake_balance: U128String(11),
+                subnet_node_reputation: Some(U128String(12)),
+                node_slot_index: Some(13),
+                consecutive_idle_epochs: 14,
+                consecutive_included_epochs: 15,
+            },
+        )
+    }
+
+    #[test]
+    fn test_subnet_info_round_trip() {
+        let (pallet_factors, factors) = reputation_factors(100);
+        let subnet_info = pallet_network::SubnetInfo::<Runtime> {
+            id: 1,
+            friendly_id: Some(2),
+            name: b"name".to_vec(),
+            repo: b"repo".to_vec(),
+            description: b"description".to_vec(),
+            misc: b"misc".to_vec(),
+            state: pallet_network::SubnetState::Paused,
+            start_epoch: 3,
+            churn_limit: 4,
+            churn_limit_multiplier: 5,
+            min_stake: 6,
+            max_stake: 7,
+            queue_immunity_epochs: 8,
+            target_node_registrations_per_epoch: 9,
+            node_registrations_this_epoch: 10,
+            subnet_node_queue_epochs: 11,
+            idle_classification_epochs: 12,
+            included_classification_epochs: 13,
+            delegate_stake_percentage: 14,
+            last_delegate_stake_rewards_update: 15,
+            node_burn_rate_alpha: 16,
+            current_node_burn_rate: 17,
+            initial_validators: Some(BTreeMap::from([(18, 19)])),
+            initial_validator_data: None,
+            max_registered_nodes: 20,
+            owner: Some(account(21)),
+            pending_owner: None,
+            registration_epoch: Some(22),
+            prev_pause_epoch: 23,
+            slot_index: Some(24),
+            slot_assignment: None,
+            subnet_node_min_weight_decrease_reputation_threshold: 25,
+            reputation: 26,
+            min_subnet_node_reputation: 27,
+            absent_decrease_reputation_factor: 28,
+            included_increase_reputation_factor: 29,
+            below_min_weight_decrease_reputation_factor: 30,
+            non_attestor_decrease_reputation_factor: 31,
+            non_consensus_attestor_decrease_reputation_factor: 32,
+            validator_absent_subnet_node_reputation_factor: 33,
+            validator_non_consensus_subnet_node_reputation_factor: 34,
+            pending_reputation_factors: Some(pallet_network::PendingSubnetReputationFactors {
+                effective_subnet_epoch: 35,
+                factors: pallet_factors,
+            }),
+            bootnode_access: BTreeSet::from([account(36)]),
+            bootnodes: BTreeMap::from([(peer_id(37), bytes(38))]),
+            total_nodes: 39,
+            total_active_nodes: 40,
+            total_electable_nodes: 41,
+            current_min_delegate_stake: 42,
+            total_subnet_stake: 43,
+            total_subnet_delegate_stake_shares: 44,
+            total_subnet_delegate_stake_balance: 45,
+        };
+
+        assert_eq!(
+            round_trip::<_, SubnetInfo>(&subnet_info),
+            SubnetInfo {
+                id: 1,
+                friendly_id: Some(2),
+                name: Text(b"name".to_vec()),
+                repo: Text(b"repo".to_vec()),
+                description: Text(b"description".to_vec()),
+                misc: Text(b"misc".to_vec()),
+                state: SubnetState::Paused,
+                start_epoch: 3,
+                churn_limit: 4,
+                churn_limit_multiplier: 5,
+                min_stake: U128String(6),
+                max_stake: U128String(7),
+                queue_immunity_epochs: 8,
+                target_node_registrations_per_epoch: 9,
+                node_registrations_this_epoch: 10,
+                subnet_node_queue_epochs: 11,
+                idle_classification_epochs: 12,
+                included_classification_epochs: 13,
+                delegate_stake_percentage: U128String(14),
+                last_delegate_stake_rewards_update: 15,
+                node_burn_rate_alpha: U128String(16),
+                current_node_burn_rate: U128String(17),
+                initial_validators: Some(BTreeMap::from([(18, 19)])),
+                initial_validator_data: None,
+                max_registered_nodes: 20,
+                owner: Some(account(21)),
+                pending_owner: None,
+                registration_epoch: Some(22),
+                prev_pause_epoch: 23,
+                slot_index: Some(24),
+                slot_assignment: None,
+                subnet_node_min_weight_decrease_reputation_threshold: U128String(25),
+                reputation: U128String(26),
+                min_subnet_node_reputation: U128String(27),
+                absent_decrease_reputation_factor: U128String(28),
+                included_increase_reputation_factor: U128String(29),
+                below_min_weight_decrease_reputation_factor: U128String(30),
+                non_attestor_decrease_reputation_factor: U128String(31),
+                non_consensus_attestor_decrease_reputation_factor: U128String(32),
+                validator_absent_subnet_node_reputation_factor: U128String(33),
+                validator_non_consensus_subnet_node_reputation_factor: U128String(34),
+                pending_reputation_factors: Some(PendingSubnetReputationFactors {
+                    effective_subnet_epoch: 35,
+                    factors,
+                }),
+                bootnode_access: BTreeSet::from([account(36)]),
+                bootnodes: BTreeMap::from([(PeerId(vec![37; 4]), MultiaddrString(vec![38; 8]))]),
+                total_nodes: 39,
+                total_active_nodes: 40,
+                total_electable_nodes: 41,
+                current_min_delegate_stake: U128String(42),
+                total_subnet_stake: U128String(43),
+                total_subnet_delegate_stake_shares: U128String(44),
+                total_subnet_delegate_stake_balance: U128String(45),
+            }
+        );
+    }
+
+    #[test]
+    fn test_subnet_node_info_round_trip() {
+        let (pallet_info, info) = subnet_node_info(3);
+        assert_eq!(round_trip::<_, SubnetNodeInfo>(&pallet_info), info);
+    }
+
+    #[test]
+    fn test_all_subnet_bootnodes_round_trip() {
+        let bootnodes = pallet_network::AllSubnetBootnodes::<Runtime> {
+            subnet_bootnodes: BTreeMap::from([(peer_id(1), bytes(2))]),
+            node_bootnodes: BTreeMap::from([(peer_id(3), Some(bytes(4))), (peer_id(5), None)]),
+            registered_bootnodes: BTreeMap::from([(peer_id(6), Some(bytes(7)))]),
+        };
+
+        assert_eq!(
+            round_trip::<_, AllSubnetBootnodes>(&bootnodes),
+            AllSubnetBootnodes {
+                subnet_bootnodes: BTreeMap::from([(
+                    PeerId(vec![1; 4]),
+                    MultiaddrString(vec![2; 8])
+                )]),
+                node_bootnodes: BTreeMap::from([
+                    (PeerId(vec![3; 4]), Some(MultiaddrString(vec![4; 8]))),
+                    (PeerId(vec![5; 4]), None),
+                ]),
+                registered_bootnodes: BTreeMap::from([(
+                    PeerId(vec![6; 4]),
+                    Some(MultiaddrString(vec![7; 8]))
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stake_info_round_trip() {
+        let node_stake = pallet_network::NodeStakeInfo {
+            subnet_id: Some(1),
+            subnet_node_id: None,
+            balance: 2,
+        };
+        assert_eq!(
+            round_trip::<_, NodeStakeInfo>(&node_stake),
+            NodeStakeInfo {
+                subnet_id: Some(1),
+                subnet_node_id: None,
+                balance: U128String(2),
+            }
+        );
+
+        let delegate_stake = pallet_network::DelegateStakeInfo {
+            subnet_id: 1,
+            shares: 2,
+            balance: 3,
+        };
+        assert_eq!(
+            round_trip::<_, DelegateStakeInfo>(&delegate_stake),
+            DelegateStakeInfo {
+                subnet_id: 1,
+                shares: U128String(2),
+                balance: U128String(3),
+            }
+        );
+
+        let node_delegate_stake = pallet_network::NodeDelegateStakeInfo {
+            subnet_id: 1,
+            subnet_node_id: 2,
+            shares: 3,
+            balance: 4,
+        };
+        assert_eq!(
+            round_trip::<_, NodeDelegateStakeInfo>(&node_delegate_stake),
+            NodeDelegateStakeInfo {
+                subnet_id: 1,
+                subnet_node_id: 2,
+                shares: U128String(3),
+                balance: U128String(4),
+            }
+        );
+    }
+
+    #[test]
+    fn test_overwatch_node_info_round_trip() {
+        let overwatch_node = pallet_network::OverwatchNodeInfo::<AccountId20> {
+            overwatch_node_id: 1,
+            hotkey: Some(account(2)),
+            peer_ids: BTreeMap::from([(3, peer_id(4))]),
+            reputation: pallet_network::Reputation {
+                start_epoch: 5,
+                score: 6,
+                lifetime_node_count: 7,
+                total_active_nodes: 8,
+                total_increases: 9,
+                total_decreases: 10,
+                average_attestation: 11,
+                last_validator_epoch: 12,
+                ow_score: 13,
+            },
+            account_overwatch_stake: 14,
+        };
+
+        assert_eq!(
+            round_trip::<_, OverwatchNodeInfo>(&overwatch_node),
+            OverwatchNodeInfo {
+                overwatch_node_id: 1,
+                hotkey: Some(account(2)),
+                peer_ids: BTreeMap::from([(3, PeerId(vec![4; 4]))]),
+                reputation: Reputation {
+                    start_epoch: 5,
+                    score: U128String(6),
+                    lifetime_node_count: 7,
+                    total_active_nodes: 8,
+                    total_increases: 9,
+                    total_decreases: 10,
+                    average_attestation: U128String(11),
+                    last_validator_epoch: 12,
+                    ow_score: U128String(13),
+                },
+                account_overwatch_stake: U128String(14),
+            }
+        );
+    }
+
+    #[test]
+    fn test_overwatch_commits_and_reveals_round_trip() {
+        let commits: Vec<(u32, H256)> = vec![(1, H256::repeat_byte(2))];
+        assert_eq!(
+            round_trip::<_, Vec<OverwatchCommit>>(&commits),
+            vec![OverwatchCommit {
+                subnet_id: 1,
+                commit: H256::repeat_byte(2),
+            }]
+        );
+
+        let reveals: Vec<(u32, u128)> = vec![(1, 2)];
+        assert_eq!(
+            round_trip::<_, Vec<OverwatchReveal>>(&reveals),
+            vec![OverwatchReveal {
+                subnet_id: 1,
+                weight: U128String(2),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_subnet_lifecycle_notification_round_trip() {
+        use pallet_network::SubnetLifecycleNotification as Notification;
+
+        let notifications = vec![
+            Notification::SubnetStateChanged {
+                subnet_id: 1,
+                state: pallet_network::SubnetState::Active,
+            },
+            Notification::SubnetRemoved {
+                subnet_id: 2,
+                reason: pallet_network::SubnetRemovalReason::PauseExpired,
+            },
+            Notification::SubnetNodeActivated {
+                subnet_id: 3,
+                subnet_node_id: 4,
+            },
+            Notification::SubnetNodeRemoved {
+                subnet_id: 5,
+                subnet_node_id: 6,
+            },
+            Notification::SubnetNodeClassGraduated {
+                subnet_id: 7,
+                subnet_node_id: 8,
+                classification: pallet_network::SubnetNodeClassification {
+                    node_class: pallet_network::SubnetNodeClass::Included,
+                    start_epoch: 9,
+                },
+            },
+            Notification::ValidatorElected {
+                subnet_id: 10,
+                subnet_epoch: 11,
+                subnet_node_id: 12,
+            },
+            Notification::AttestationWindowOpen {
+                subnet_id: 13,
+                subnet_epoch: 14,
+            },
+        ];
+
+        assert_eq!(
+            round_trip::<_, Vec<SubnetLifecycleNotification>>(&notifications),
+            vec![
+                SubnetLifecycleNotification::SubnetStateChanged {
+                    subnet_id: 1,
+                    state: SubnetState::Active,
+                },
+                SubnetLifecycleNotification::SubnetRemoved {
+                    subnet_id: 2,
+                    reason: SubnetRemovalReason::PauseExpired,
+                },
+                SubnetLifecycleNotification::SubnetNodeActivated {
+                    subnet_id: 3,
+                    subnet_node_id: 4,
+                },
+                SubnetLifecycleNotification::SubnetNodeRemoved {
+                    subnet_id: 5,
+                    subnet_node_id: 6,
+                },
+                SubnetLifecycleNotification::SubnetNodeClassGraduated {
+                    subnet_id: 7,
+                    subnet_node_id: 8,
+                    classification: SubnetNodeClassification {
+                        node_class: SubnetNodeClass::Included,
+                        start_epoch: 9,
+                    },
+                },
+                SubnetLifecycleNotification::ValidatorElected {
+                    subnet_id: 10,
+                    subnet_epoch: 11,
+                    subnet_node_id: 12,
+                },
+                SubnetLifecycleNotification::AttestationWindowOpen {
+                    subnet_id: 13,
+                    subnet_epoch: 14,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_elected_validator_info_round_trip() {
+        let (pallet_validator, validator) = subnet_node_info(3);
+        let elected_validator = pallet_network::ElectedValidatorInfo::<Runtime> {
+            validator: Some(pallet_validator),
+            election_mode: pallet_network::ValidatorElectionMode::Weighted(
+                pallet_network::WeightedValidatorElection {
+                    stake_exponent: 1,
+                    node_reputation_exponent: 2,
+                    validator_reputation_exponent: 3,
+                    max_validator_probability: 4,
+                },
+            ),
+            election_probabilities: BTreeMap::from([(3, 5), (6, 7)]),
+        };
+
+        assert_eq!(
+            round_trip::<_, ElectedValidatorInfo>(&elected_validator),
+            ElectedValidatorInfo {
+                validator: Some(validator),
+                election_mode: ValidatorElectionMode::Weighted(WeightedValidatorElection {
+                    stake_exponent: U128String(1),
+                    node_reputation_exponent: U128String(2),
+                    validator_reputation_exponent: U128String(3),
+                    max_validator_probability: U128String(4),
+                }),
+                election_probabilities: BTreeMap::from([(3, U128String(5)), (6, U128String(7))]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_validators_and_attestors_round_trip() {
+        let (pallet_validator, validator) = subnet_node_info(3);
+        let validators_and_attestors = pallet_network::ValidatorsAndAttestors::<Runtime> {
+            validators: vec![pallet_validator],
+            attestation_weighting: pallet_network::AttestationWeighting::Reputation,
+            attestation_weights: BTreeMap::from([(3, 4)]),
+            attestation_ratio: Some(5),
+        };
+
+        assert_eq!(
+            round_trip::<_, ValidatorsAndAttestors>(&validators_and_attestors),
+            ValidatorsAndAttestors {
+                validators: vec![validator],
+                attestation_weighting: AttestationWeighting::Reputation,
+                attestation_weights: BTreeMap::from([(3, U128String(4))]),
+                attestation_ratio: Some(U128String(5)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_subnet_coldkey_concentration_round_trip() {
+        let concentration = pallet_network::SubnetColdkeyConcentration::<AccountId20> {
+            subnet_id: 1,
+            total_nodes: 2,
+            total_stake: 3,
+            top_node_share: 4,
+            top_stake_share: 5,
+            coldkeys: vec![pallet_network::ColdkeyConcentration {
+                coldkey: account(6),
+                nodes: 7,
+                stake: 8,
+            }],
+        };
+
+        assert_eq!(
+            round_trip::<_, SubnetColdkeyConcentration>(&concentration),
+            SubnetColdkeyConcentration {
+                subnet_id: 1,
+                total_nodes: 2,
+                total_stake: U128String(3),
+                top_node_share: U128String(4),
+                top_stake_share: U128String(5),
+                coldkeys: vec![ColdkeyConcentration {
+                    coldkey: account(6),
+                    nodes: 7,
+                    stake: U128String(8),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_overwatch_subnet_weight_breakdown_round_trip() {
+        let breakdown = pallet_network::OverwatchSubnetWeightBreakdown {
+            overwatch_epoch: 1,
+            subnet_id: 2,
+            weight: 3,
+            clip_band: 4,
+            nodes: vec![pallet_network::OverwatchNodeWeightDeviation {
+                overwatch_node_id: 5,
+                weight: 6,
+                deviation: 7,
+                clipped: true,
+            }],
+        };
+
+        assert_eq!(
+            round_trip::<_, OverwatchSubnetWeightBreakdown>(&breakdown),
+            OverwatchSubnetWeightBreakdown {
+                overwatch_epoch: 1,
+                subnet_id: 2,
+                weight: U128String(3),
+                clip_band: U128String(4),
+                nodes: vec![OverwatchNodeWeightDeviation {
+                    overwatch_node_id: 5,
+                    weight: U128String(6),
+                    deviation: U128String(7),
+                    clipped: true,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_scheduled_parameter_change_info_round_trip() {
+        let change = pallet_network::ScheduledParameterChangeInfo {
+            id: 1,
+            epoch: 2,
+            enactment_epoch: 3,
+            parameters: vec![pallet_network::NetworkParameterInfo {
+                index: 4,
+                name: b"MinSubnetNodes".to_vec(),
+                values: vec![5, 6],
+                min: 7,
+                max: 8,
+                super_majority: true,
+            }],
+        };
+
+        assert_eq!(
+            round_trip::<_, ScheduledParameterChangeInfo>(&change),
+            ScheduledParameterChangeInfo {
+                id: 1,
+                epoch: 2,
+                enactment_epoch: 3,
+                parameters: vec![NetworkParameterInfo {
+                    index: 4,
+                    name: Text(b"MinSubnetNodes".to_vec()),
+                    values: vec![U128String(5), U128String(6)],
+                    min: U128String(7),
+                    max: U128String(8),
+                    super_majority: true,
+                }],
+            }
+        );
+    }
+}
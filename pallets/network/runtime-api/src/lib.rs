@@ -19,9 +19,11 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 use fp_account::AccountId20;
+use pallet_network::{SubnetNodeConsensusData, SubnetNodeInfoFilter};
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+  #[api_version(2)]
   pub trait NetworkRuntimeApi {
     fn get_subnet_info(subnet_id: u32) -> Vec<u8>;
     fn get_all_subnets_info() -> Vec<u8>;
@@ -39,5 +41,35 @@ sp_api::decl_runtime_apis! {
     fn get_elected_validator_info(subnet_id: u32,subnet_epoch: u32) -> Vec<u8>;
     fn get_validators_and_attestors(subnet_id: u32) -> Vec<u8>;
     fn get_all_overwatch_nodes_info() -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_nodes_info_paged(filter: SubnetNodeInfoFilter, cursor: Option<(u32, u32)>, limit: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_all_subnets_info_paged(cursor: Option<u32>, limit: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_delegate_stakes_paged(account_id: AccountId20, cursor: Option<u32>, limit: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_rewards_ledger(subnet_id: u32, from_epoch: u32, to_epoch: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_node_rewards_history(subnet_id: u32, subnet_node_id: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_account_rewards_history(account_id: AccountId20, cursor: Option<u32>, limit: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn simulate_consensus(subnet_id: u32, data: Vec<SubnetNodeConsensusData>, attestor_subnet_node_ids: Vec<u32>) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_delegate_stake_projection(subnet_id: u32, amount: u128) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_validator_delegate_stake_projection(validator_id: u32, amount: u128) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_lifecycle_notifications(subnet_id: Option<u32>) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_coldkey_concentration(subnet_id: u32, top_n: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_unrevealed_overwatch_commits(overwatch_epoch: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_overwatch_subnet_weight_breakdown(overwatch_epoch: u32, subnet_id: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_network_parameters() -> Vec<u8>;
+    #[api_version(2)]
+    fn get_scheduled_parameter_changes() -> Vec<u8>;
   }
 }
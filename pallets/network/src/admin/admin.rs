@@ -14,6 +14,8 @@
 // limitations under the License.
 
 use super::*;
+use crate::ledger::MAX_REWARDS_LEDGER_RETENTION_EPOCHS;
+use crate::slashing::MAX_SLASH_APPEAL_EPOCHS;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 
 impl<T: Config> Pallet<T> {
@@ -248,6 +250,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidPercent
         );
 
+        // The super majority can't be below the minimum to reach consensus
+        ensure!(
+            value <= SuperMajorityAttestationRatio::<T>::get(),
+            Error::<T>::InvalidSuperMajorityAttestationRatio
+        );
+
         MinAttestationPercentage::<T>::set(value);
 
         Self::deposit_event(Event::SetMinAttestationPercentage(value));
@@ -391,7 +399,8 @@ impl<T: Config> Pallet<T> {
     }
     pub fn do_set_overwatch_commit_cutoff_percent(value: u128) -> DispatchResult {
         ensure!(
-            value <= 950000000000000000, // 95%
+            value <= 950000000000000000 // 95%
+                && value < OverwatchRevealCutoffPercent::<T>::get(),
             Error::<T>::InvalidPercent
         );
 
@@ -401,6 +410,98 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+    pub fn do_set_overwatch_reveal_cutoff_percent(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128()
+                && value > OverwatchCommitCutoffPercent::<T>::get(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchRevealCutoffPercent::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchRevealCutoffPercent(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_non_reveal_reputation_factor(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchNonRevealReputationFactor::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchNonRevealReputationFactor(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_weight_clip_band(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchWeightClipBand::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchWeightClipBand(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_clip_reputation_factor(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchClipReputationFactor::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchClipReputationFactor(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_challenge_bond(value: u128) -> DispatchResult {
+        OverwatchChallengeBond::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchChallengeBond(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_challenge_quorum(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchChallengeQuorum::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchChallengeQuorum(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_challenge_reputation_factor(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchChallengeReputationFactor::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchChallengeReputationFactor(value));
+
+        Ok(())
+    }
+    pub fn do_set_overwatch_non_reveal_stake_penalty(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        OverwatchNonRevealStakePenalty::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchNonRevealStakePenalty(value));
+
+        Ok(())
+    }
     pub fn do_set_overwatch_min_diversification_ratio(value: u128) -> DispatchResult {
         OverwatchMinDiversificationRatio::<T>::set(value);
 
@@ -437,6 +538,103 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub fn do_set_min_beacon_reveals(value: u32) -> DispatchResult {
+        ensure!(value > 0, Error::<T>::InvalidMinBeaconReveals);
+
+        MinBeaconReveals::<T>::set(value);
+
+        Self::deposit_event(Event::SetMinBeaconReveals(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_rewards_ledger_retention_epochs(value: u32) -> DispatchResult {
+        ensure!(
+            value > 0 && value <= MAX_REWARDS_LEDGER_RETENTION_EPOCHS,
+            Error::<T>::InvalidRewardsLedgerRetentionEpochs
+        );
+
+        RewardsLedgerRetentionEpochs::<T>::set(value);
+
+        Self::deposit_event(Event::SetRewardsLedgerRetentionEpochs(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_slash_appeal_epochs(value: u32) -> DispatchResult {
+        ensure!(
+            value <= MAX_SLASH_APPEAL_EPOCHS,
+            Error::<T>::InvalidSlashAppealEpochs
+        );
+
+        SlashAppealEpochs::<T>::set(value);
+
+        Self::deposit_event(Event::SetSlashAppealEpochs(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_parameter_change_delay_epochs(value: u32) -> DispatchResult {
+        ensure!(value > 0, Error::<T>::InvalidValues);
+
+        ParameterChangeDelayEpochs::<T>::set(value);
+
+        Self::deposit_event(Event::SetParameterChangeDelayEpochs(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_swap_queue_deposit(value: u128) -> DispatchResult {
+        SwapQueueDeposit::<T>::set(value);
+
+        Self::deposit_event(Event::SetSwapQueueDeposit(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_overwatch_weight_quorum(value: u128) -> DispatchResult {
+        OverwatchWeightQuorum::<T>::set(value);
+
+        Self::deposit_event(Event::SetOverwatchWeightQuorum(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_early_unbonding_penalty(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        EarlyUnbondingPenalty::<T>::set(value);
+
+        Self::deposit_event(Event::SetEarlyUnbondingPenalty(value));
+
+        Ok(())
+    }
+
+    pub fn do_cancel_pending_slash(id: u32) -> DispatchResult {
+        let execute_epoch =
+            PendingSlashExecuteEpoch::<T>::take(id).ok_or(Error::<T>::PendingSlashNotFound)?;
+        let pending_slash =
+            PendingSlashes::<T>::take(execute_epoch, id).ok_or(Error::<T>::PendingSlashNotFound)?;
+
+        Self::unlock_pending_slash_stake(
+            pending_slash.subnet_node_id,
+            pending_slash.subnet_id,
+            pending_slash.amount,
+        );
+
+        Self::deposit_event(Event::PendingSlashCancelled {
+            id,
+            subnet_id: pending_slash.subnet_id,
+            subnet_node_id: pending_slash.subnet_node_id,
+            amount: pending_slash.amount,
+        });
+
+        Ok(())
+    }
+
     pub fn do_set_min_max_subnet_node(min: u32, max: u32) -> DispatchResult {
         ensure!(min < max && min > 0, Error::<T>::InvalidValues);
 
@@ -1,2 +1,4 @@
 use super::*;
 pub mod admin;
+pub mod parameter_schedule;
+pub mod parameters;
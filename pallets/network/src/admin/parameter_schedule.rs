@@ -0,0 +1,163 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Time-locked network parameter changes, see `ScheduledParameterChanges`
+
+use super::*;
+use crate::parameters::MAX_NETWORK_PARAMETER_UPDATES;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+
+/// Maximum parameter changes each collective can have scheduled at once
+pub const MAX_SCHEDULED_PARAMETER_CHANGES: u32 = 16;
+
+impl<T: Config> Pallet<T> {
+    /// Queue parameter changes to be enacted in `epoch + ParameterChangeDelayEpochs`
+    ///
+    /// Bounds are checked when scheduling, the setters run their own checks when the changes are
+    /// enacted by `enact_scheduled_parameter_changes`.
+    ///
+    /// `super_majority` is whether the super majority collective schedules the changes, each
+    /// collective has its own `MAX_SCHEDULED_PARAMETER_CHANGES` budget.
+    pub fn do_schedule_parameter_change(
+        parameters: Vec<NetworkParameter>,
+        super_majority: bool,
+    ) -> DispatchResult {
+        ensure!(!parameters.is_empty(), Error::<T>::InvalidValues);
+        ensure!(
+            parameters.len() as u32 <= MAX_NETWORK_PARAMETER_UPDATES,
+            Error::<T>::TooManyParameters
+        );
+        ensure!(
+            ScheduledParameterChanges::<T>::iter_values()
+                .filter(|scheduled_change| scheduled_change.super_majority == super_majority)
+                .count()
+                < MAX_SCHEDULED_PARAMETER_CHANGES as usize,
+            Error::<T>::TooManyScheduledParameterChanges
+        );
+
+        for parameter in &parameters {
+            Self::ensure_network_parameter_in_bounds(parameter)?;
+        }
+
+        let id = NextScheduledParameterChangeId::<T>::get();
+        let epoch = Self::get_current_epoch_as_u32();
+        let enactment_epoch = epoch.saturating_add(ParameterChangeDelayEpochs::<T>::get());
+
+        ScheduledParameterChanges::<T>::insert(
+            id,
+            ScheduledParameterChange {
+                parameters: parameters.clone(),
+                epoch,
+                enactment_epoch,
+                super_majority,
+            },
+        );
+
+        NextScheduledParameterChangeId::<T>::put(id.saturating_add(1));
+
+        Self::deposit_event(Event::ParameterChangeScheduled {
+            id,
+            parameters,
+            enactment_epoch,
+        });
+
+        Ok(())
+    }
+
+    /// Enact scheduled parameter changes now instead of in their enactment epoch
+    pub fn do_fast_track_parameter_change(id: u32) -> DispatchResult {
+        let scheduled = ScheduledParameterChanges::<T>::take(id)
+            .ok_or(Error::<T>::ScheduledParameterChangeNotFound)?;
+
+        Self::do_set_parameters(scheduled.parameters)?;
+
+        Self::deposit_event(Event::ParameterChangeFastTracked { id });
+
+        Ok(())
+    }
+
+    /// Cancel scheduled parameter changes
+    pub fn do_veto_parameter_change(id: u32) -> DispatchResult {
+        ScheduledParameterChanges::<T>::take(id)
+            .ok_or(Error::<T>::ScheduledParameterChangeNotFound)?;
+
+        Self::deposit_event(Event::ParameterChangeVetoed { id });
+
+        Ok(())
+    }
+
+    /// Enact each scheduled parameter change whose enactment epoch is `epoch` or earlier
+    ///
+    /// Each change is applied all or nothing. Changes that fail a setter check, e.g. a min above
+    /// the current max, are dropped with `ParameterChangeFailed`.
+    pub fn enact_scheduled_parameter_changes(weight_meter: &mut WeightMeter, epoch: u32) {
+        let db_weight = T::DbWeight::get();
+
+        let mut due: Vec<(u32, ScheduledParameterChange)> = Vec::new();
+        let mut scheduled: u64 = 0;
+        for (id, scheduled_change) in ScheduledParameterChanges::<T>::iter() {
+            scheduled = scheduled.saturating_add(1);
+            if scheduled_change.enactment_epoch <= epoch {
+                due.push((id, scheduled_change));
+            }
+        }
+
+        // ScheduledParameterChanges
+        weight_meter.consume(db_weight.reads(scheduled));
+
+        for (id, scheduled_change) in due {
+            let total_parameters = scheduled_change.parameters.len() as u64;
+
+            ScheduledParameterChanges::<T>::remove(id);
+
+            let result: DispatchResult = with_transaction(|| {
+                let result = Self::do_set_parameters(scheduled_change.parameters);
+
+                match result {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(error) => TransactionOutcome::Rollback(Err(error)),
+                }
+            });
+
+            // ScheduledParameterChanges | Each setter reads its dynamic bounds and writes at most
+            // two values
+            weight_meter.consume(db_weight.reads_writes(
+                total_parameters.saturating_mul(2),
+                total_parameters.saturating_mul(2).saturating_add(1),
+            ));
+
+            match result {
+                Ok(()) => Self::deposit_event(Event::ParameterChangeEnacted { id }),
+                Err(error) => Self::deposit_event(Event::ParameterChangeFailed { id, error }),
+            }
+        }
+    }
+
+    /// Every scheduled parameter change with the new values and bounds of its parameters
+    pub fn get_scheduled_parameter_changes() -> Vec<ScheduledParameterChangeInfo> {
+        ScheduledParameterChanges::<T>::iter()
+            .map(|(id, scheduled_change)| ScheduledParameterChangeInfo {
+                id,
+                epoch: scheduled_change.epoch,
+                enactment_epoch: scheduled_change.enactment_epoch,
+                parameters: scheduled_change
+                    .parameters
+                    .iter()
+                    .map(|parameter| Self::network_parameter_info(parameter))
+                    .collect(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,953 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Typed registry over the tunable network parameters
+
+use super::*;
+use crate::ledger::MAX_REWARDS_LEDGER_RETENTION_EPOCHS;
+use crate::slashing::MAX_SLASH_APPEAL_EPOCHS;
+use sp_std::vec;
+
+/// Maximum parameters that can be updated in a single `set_parameters` call
+pub const MAX_NETWORK_PARAMETER_UPDATES: u32 = 32;
+
+/// Static description of a `NetworkParameter`
+pub struct NetworkParameterSpec {
+    pub name: &'static str,
+    /// Inclusive lower bound applied to every value of the parameter
+    pub min: u128,
+    /// Inclusive upper bound applied to every value of the parameter
+    pub max: u128,
+    /// Whether the parameter requires the super majority collective
+    pub super_majority: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Name, bounds and required origin of a parameter
+    ///
+    /// Bounds are static sanity limits, the underlying setters still run their own checks against
+    /// current storage (e.g. min <= max)
+    pub fn network_parameter_spec(parameter: &NetworkParameter) -> NetworkParameterSpec {
+        let one = Self::percentage_factor_as_u128();
+        // 1.0 of the native token
+        let token: u128 = 1000000000000000000;
+        let cooldown_epochs = T::EpochsPerYear::get() as u128;
+        let (name, min, max, super_majority) = match parameter {
+            NetworkParameter::SubnetOwnerPercentage(..) => {
+                ("SubnetOwnerPercentage", 0, one / 2, true)
+            }
+            NetworkParameter::MaxSubnets(..) => (
+                "MaxSubnets",
+                0,
+                T::EpochLength::get().saturating_sub(T::DesignatedEpochSlots::get()) as u128,
+                true,
+            ),
+            NetworkParameter::MaxBootnodes(..) => ("MaxBootnodes", 0, 256, false),
+            NetworkParameter::MaxSubnetBootnodeAccess(..) => {
+                ("MaxSubnetBootnodeAccess", 0, 256, false)
+            }
+            NetworkParameter::MaxSubnetPauseEpochs(..) => {
+                ("MaxSubnetPauseEpochs", 1, u32::MAX as u128, false)
+            }
+            NetworkParameter::MinRegistrationCost(..) => {
+                ("MinRegistrationCost", token / 1000, 1000000 * token, true)
+            }
+            NetworkParameter::RegistrationCostDecayBlocks(..) => {
+                ("RegistrationCostDecayBlocks", 0, u32::MAX as u128, true)
+            }
+            NetworkParameter::RegistrationCostAlpha(..) => ("RegistrationCostAlpha", 0, one, false),
+            NetworkParameter::NewRegistrationCostMultiplier(..) => {
+                ("NewRegistrationCostMultiplier", one, 10 * one, true)
+            }
+            NetworkParameter::MaxMinDelegateStakeMultiplier(..) => {
+                ("MaxMinDelegateStakeMultiplier", one, 10 * one, true)
+            }
+            NetworkParameter::ChurnLimits(..) => ("ChurnLimits", 0, u32::MAX as u128, false),
+            NetworkParameter::ChurnLimitMultipliers(..) => {
+                ("ChurnLimitMultipliers", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::QueueEpochs(..) => ("QueueEpochs", 0, u32::MAX as u128, false),
+            NetworkParameter::MinIdleClassificationEpochs(..) => {
+                ("MinIdleClassificationEpochs", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::MaxIdleClassificationEpochs(..) => {
+                ("MaxIdleClassificationEpochs", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::SubnetEnactmentEpochs(..) => {
+                ("SubnetEnactmentEpochs", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::IncludedClassificationEpochs(..) => {
+                ("IncludedClassificationEpochs", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::SubnetStakes(..) => ("SubnetStakes", token, 10000000 * token, true),
+            NetworkParameter::DelegateStakePercentages(..) => {
+                ("DelegateStakePercentages", 0, one, true)
+            }
+            NetworkParameter::MinMaxRegisteredNodes(..) => {
+                ("MinMaxRegisteredNodes", 0, u32::MAX as u128, true)
+            }
+            NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(..) => (
+                "MaxSubnetDelegateStakeRewardsPercentageChange",
+                0,
+                one,
+                true,
+            ),
+            NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod(..) => (
+                "SubnetDelegateStakeRewardsUpdatePeriod",
+                0,
+                u32::MAX as u128,
+                false,
+            ),
+            NetworkParameter::MinAttestationPercentage(..) => {
+                ("MinAttestationPercentage", one / 2 + 1, one, true)
+            }
+            NetworkParameter::SuperMajorityAttestationRatio(..) => {
+                ("SuperMajorityAttestationRatio", one / 2 + 1, one, true)
+            }
+            NetworkParameter::BaseValidatorReward(..) => {
+                ("BaseValidatorReward", 0, 1000 * token, true)
+            }
+            NetworkParameter::BaseSlashPercentage(..) => ("BaseSlashPercentage", 0, one, true),
+            NetworkParameter::MaxSlashAmount(..) => ("MaxSlashAmount", 0, 10000000 * token, true),
+            NetworkParameter::ValidatorReputationIncreaseFactor(..) => {
+                ("ValidatorReputationIncreaseFactor", 0, one, false)
+            }
+            NetworkParameter::ValidatorReputationDecreaseFactor(..) => {
+                ("ValidatorReputationDecreaseFactor", 0, one, false)
+            }
+            NetworkParameter::NetworkMaxStakeBalance(..) => {
+                ("NetworkMaxStakeBalance", token, 10000000 * token, true)
+            }
+            NetworkParameter::MinDelegateStakeDeposit(..) => {
+                ("MinDelegateStakeDeposit", 1000, 1000000 * token, true)
+            }
+            NetworkParameter::NodeRewardRateUpdatePeriod(..) => {
+                ("NodeRewardRateUpdatePeriod", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::MaxRewardRateDecrease(..) => ("MaxRewardRateDecrease", 0, one, false),
+            NetworkParameter::SubnetDistributionPower(..) => {
+                ("SubnetDistributionPower", 0, 5 * one, true)
+            }
+            NetworkParameter::DelegateStakeWeightFactor(..) => {
+                ("DelegateStakeWeightFactor", 0, one, true)
+            }
+            NetworkParameter::InflationSigmoidSteepness(..) => {
+                ("InflationSigmoidSteepness", 1, 100, true)
+            }
+            NetworkParameter::MaxOverwatchNodes(..) => {
+                ("MaxOverwatchNodes", 0, u32::MAX as u128, true)
+            }
+            NetworkParameter::OverwatchEpochLengthMultiplier(..) => {
+                ("OverwatchEpochLengthMultiplier", 1, u32::MAX as u128, true)
+            }
+            NetworkParameter::OverwatchCommitCutoffPercent(..) => {
+                ("OverwatchCommitCutoffPercent", 0, 950000000000000000, true)
+            }
+            NetworkParameter::OverwatchRevealCutoffPercent(..) => {
+                ("OverwatchRevealCutoffPercent", 0, one, true)
+            }
+            NetworkParameter::OverwatchNonRevealReputationFactor(..) => {
+                ("OverwatchNonRevealReputationFactor", 0, one, true)
+            }
+            NetworkParameter::OverwatchNonRevealStakePenalty(..) => {
+                ("OverwatchNonRevealStakePenalty", 0, one, true)
+            }
+            NetworkParameter::OverwatchWeightClipBand(..) => {
+                ("OverwatchWeightClipBand", 0, one, true)
+            }
+            NetworkParameter::OverwatchClipReputationFactor(..) => {
+                ("OverwatchClipReputationFactor", 0, one, true)
+            }
+            NetworkParameter::OverwatchChallengeBond(..) => (
+                "OverwatchChallengeBond",
+                token / 1000,
+                1000000 * token,
+                true,
+            ),
+            NetworkParameter::OverwatchChallengeQuorum(..) => {
+                ("OverwatchChallengeQuorum", 1, one, true)
+            }
+            NetworkParameter::OverwatchChallengeReputationFactor(..) => {
+                ("OverwatchChallengeReputationFactor", 0, one, true)
+            }
+            NetworkParameter::OverwatchMinDiversificationRatio(..) => {
+                ("OverwatchMinDiversificationRatio", 0, one, true)
+            }
+            NetworkParameter::OverwatchMinRepScore(..) => ("OverwatchMinRepScore", 0, one, true),
+            NetworkParameter::OverwatchMinAvgAttestationRatio(..) => {
+                ("OverwatchMinAvgAttestationRatio", 0, one, true)
+            }
+            NetworkParameter::OverwatchMinAge(..) => ("OverwatchMinAge", 0, u32::MAX as u128, true),
+            NetworkParameter::OverwatchMinStakeBalance(..) => {
+                ("OverwatchMinStakeBalance", 0, 10000000 * token, true)
+            }
+            NetworkParameter::MinBeaconReveals(..) => {
+                ("MinBeaconReveals", 1, u32::MAX as u128, true)
+            }
+            NetworkParameter::RewardsLedgerRetentionEpochs(..) => (
+                "RewardsLedgerRetentionEpochs",
+                1,
+                MAX_REWARDS_LEDGER_RETENTION_EPOCHS as u128,
+                true,
+            ),
+            NetworkParameter::SlashAppealEpochs(..) => (
+                "SlashAppealEpochs",
+                0,
+                MAX_SLASH_APPEAL_EPOCHS as u128,
+                true,
+            ),
+            NetworkParameter::EarlyUnbondingPenalty(..) => ("EarlyUnbondingPenalty", 0, one, true),
+            NetworkParameter::MinMaxSubnetNodes(..) => {
+                ("MinMaxSubnetNodes", 1, u32::MAX as u128, true)
+            }
+            NetworkParameter::TxRateLimit(..) => ("TxRateLimit", 0, u32::MAX as u128, true),
+            NetworkParameter::MinSubnetDelegateStakeFactor(..) => {
+                ("MinSubnetDelegateStakeFactor", 0, one, true)
+            }
+            NetworkParameter::MinSubnetRegistrationEpochs(..) => {
+                ("MinSubnetRegistrationEpochs", 0, u32::MAX as u128, true)
+            }
+            NetworkParameter::SubnetRegistrationEpochs(..) => {
+                ("SubnetRegistrationEpochs", 0, u32::MAX as u128, true)
+            }
+            NetworkParameter::MinActiveNodeStakeEpochs(..) => {
+                ("MinActiveNodeStakeEpochs", 0, u32::MAX as u128, true)
+            }
+            NetworkParameter::DelegateStakeCooldownEpochs(..) => {
+                ("DelegateStakeCooldownEpochs", 1, cooldown_epochs, false)
+            }
+            NetworkParameter::NodeDelegateStakeCooldownEpochs(..) => {
+                ("NodeDelegateStakeCooldownEpochs", 1, cooldown_epochs, false)
+            }
+            NetworkParameter::StakeCooldownEpochs(..) => {
+                ("StakeCooldownEpochs", 1, cooldown_epochs, false)
+            }
+            NetworkParameter::MaxUnbondings(..) => ("MaxUnbondings", 0, 256, true),
+            NetworkParameter::InflationSigmoidMidpoint(..) => {
+                ("InflationSigmoidMidpoint", 0, one, false)
+            }
+            NetworkParameter::MaximumHooksWeight(..) => ("MaximumHooksWeight", 1, 100, false),
+            NetworkParameter::BaseNodeBurnAmount(..) => {
+                ("BaseNodeBurnAmount", 0, 1000 * token, false)
+            }
+            NetworkParameter::NodeBurnRates(..) => ("NodeBurnRates", 1, one, false),
+            NetworkParameter::DelegateStakeSubnetRemovalInterval(..) => (
+                "DelegateStakeSubnetRemovalInterval",
+                1,
+                u32::MAX as u128,
+                false,
+            ),
+            NetworkParameter::SubnetRemovalIntervals(..) => {
+                ("SubnetRemovalIntervals", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::SubnetPauseCooldownEpochs(..) => {
+                ("SubnetPauseCooldownEpochs", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::MaxSwapQueueCallsPerBlock(..) => (
+                "MaxSwapQueueCallsPerBlock",
+                0,
+                T::MaxSwapQueueLength::get() as u128,
+                true,
+            ),
+            NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold(..) => (
+                "MaxSubnetNodeMinWeightDecreaseReputationThreshold",
+                0,
+                one,
+                false,
+            ),
+            NetworkParameter::ValidatorRewardK(..) => {
+                ("ValidatorRewardK", 1, u64::MAX as u128, false)
+            }
+            NetworkParameter::ValidatorRewardMidpoint(..) => {
+                ("ValidatorRewardMidpoint", 0, one, false)
+            }
+            NetworkParameter::AttestorRewardExponent(..) => {
+                ("AttestorRewardExponent", 1, u64::MAX as u128, false)
+            }
+            NetworkParameter::AttestorMinRewardFactor(..) => {
+                ("AttestorMinRewardFactor", 0, one, false)
+            }
+            NetworkParameter::NodeReputationLimits(..) => ("NodeReputationLimits", 0, one, false),
+            NetworkParameter::NodeReputationFactors(..) => ("NodeReputationFactors", 0, one, false),
+            NetworkParameter::MinSubnetReputation(..) => ("MinSubnetReputation", 0, one, false),
+            NetworkParameter::NotInConsensusSubnetReputationFactor(..) => {
+                ("NotInConsensusSubnetReputationFactor", 0, one, false)
+            }
+            NetworkParameter::MaxPauseEpochsSubnetReputationFactor(..) => {
+                ("MaxPauseEpochsSubnetReputationFactor", 0, one, false)
+            }
+            NetworkParameter::LessThanMinNodesSubnetReputationFactor(..) => {
+                ("LessThanMinNodesSubnetReputationFactor", 0, one, false)
+            }
+            NetworkParameter::ValidatorAbsentSubnetReputationFactor(..) => {
+                ("ValidatorAbsentSubnetReputationFactor", 0, one, false)
+            }
+            NetworkParameter::InConsensusSubnetReputationFactor(..) => {
+                ("InConsensusSubnetReputationFactor", 0, one, false)
+            }
+            NetworkParameter::OverwatchWeightFactor(..) => ("OverwatchWeightFactor", 0, one, false),
+            NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(..) => (
+                "MaxEmergencyValidatorEpochsMultiplier",
+                one,
+                10 * one,
+                false,
+            ),
+            NetworkParameter::MaxEmergencySubnetNodes(..) => {
+                ("MaxEmergencySubnetNodes", 0, u32::MAX as u128, false)
+            }
+            NetworkParameter::OverwatchStakeWeightFactor(..) => {
+                ("OverwatchStakeWeightFactor", one, 2 * one, false)
+            }
+            NetworkParameter::SubnetWeightFactors(..) => ("SubnetWeightFactors", 0, one, false),
+            NetworkParameter::DefaultOverwatchSubnetWeight(..) => {
+                ("DefaultOverwatchSubnetWeight", 0, one, false)
+            }
+            NetworkParameter::RequireSubnetRegistrationWhitelist(..) => {
+                ("RequireSubnetRegistrationWhitelist", 0, 1, false)
+            }
+            NetworkParameter::ParameterChangeDelayEpochs(..) => {
+                ("ParameterChangeDelayEpochs", 1, u32::MAX as u128, true)
+            }
+            NetworkParameter::SwapQueueDeposit(..) => ("SwapQueueDeposit", 0, 1000 * token, false),
+            NetworkParameter::OverwatchWeightQuorum(..) => ("OverwatchWeightQuorum", 1, one, true),
+        };
+        NetworkParameterSpec {
+            name,
+            min,
+            max,
+            super_majority,
+        }
+    }
+
+    /// Values of a parameter as `u128`s, in declaration order
+    pub fn network_parameter_values(parameter: &NetworkParameter) -> Vec<u128> {
+        match parameter {
+            NetworkParameter::SubnetOwnerPercentage(value) => vec![*value],
+            NetworkParameter::MaxSubnets(value) => vec![*value as u128],
+            NetworkParameter::MaxBootnodes(value) => vec![*value as u128],
+            NetworkParameter::MaxSubnetBootnodeAccess(value) => vec![*value as u128],
+            NetworkParameter::MaxSubnetPauseEpochs(value) => vec![*value as u128],
+            NetworkParameter::MinRegistrationCost(value) => vec![*value],
+            NetworkParameter::RegistrationCostDecayBlocks(value) => vec![*value as u128],
+            NetworkParameter::RegistrationCostAlpha(value) => vec![*value],
+            NetworkParameter::NewRegistrationCostMultiplier(value) => vec![*value],
+            NetworkParameter::MaxMinDelegateStakeMultiplier(value) => vec![*value],
+            NetworkParameter::ChurnLimits(min, max) => vec![*min as u128, *max as u128],
+            NetworkParameter::ChurnLimitMultipliers(min, max) => vec![*min as u128, *max as u128],
+            NetworkParameter::QueueEpochs(min, max) => vec![*min as u128, *max as u128],
+            NetworkParameter::MinIdleClassificationEpochs(value) => vec![*value as u128],
+            NetworkParameter::MaxIdleClassificationEpochs(value) => vec![*value as u128],
+            NetworkParameter::SubnetEnactmentEpochs(value) => vec![*value as u128],
+            NetworkParameter::IncludedClassificationEpochs(min, max) => {
+                vec![*min as u128, *max as u128]
+            }
+            NetworkParameter::SubnetStakes(min, max) => vec![*min, *max],
+            NetworkParameter::DelegateStakePercentages(min, max) => vec![*min, *max],
+            NetworkParameter::MinMaxRegisteredNodes(min, max) => vec![*min as u128, *max as u128],
+            NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(value) => vec![*value],
+            NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod(value) => vec![*value as u128],
+            NetworkParameter::MinAttestationPercentage(value) => vec![*value],
+            NetworkParameter::SuperMajorityAttestationRatio(value) => vec![*value],
+            NetworkParameter::BaseValidatorReward(value) => vec![*value],
+            NetworkParameter::BaseSlashPercentage(value) => vec![*value],
+            NetworkParameter::MaxSlashAmount(value) => vec![*value],
+            NetworkParameter::ValidatorReputationIncreaseFactor(value) => vec![*value],
+            NetworkParameter::ValidatorReputationDecreaseFactor(value) => vec![*value],
+            NetworkParameter::NetworkMaxStakeBalance(value) => vec![*value],
+            NetworkParameter::MinDelegateStakeDeposit(value) => vec![*value],
+            NetworkParameter::NodeRewardRateUpdatePeriod(value) => vec![*value as u128],
+            NetworkParameter::MaxRewardRateDecrease(value) => vec![*value],
+            NetworkParameter::SubnetDistributionPower(value) => vec![*value],
+            NetworkParameter::DelegateStakeWeightFactor(value) => vec![*value],
+            NetworkParameter::InflationSigmoidSteepness(value) => vec![*value],
+            NetworkParameter::MaxOverwatchNodes(value) => vec![*value as u128],
+            NetworkParameter::OverwatchEpochLengthMultiplier(value) => vec![*value as u128],
+            NetworkParameter::OverwatchCommitCutoffPercent(value) => vec![*value],
+            NetworkParameter::OverwatchRevealCutoffPercent(value) => vec![*value],
+            NetworkParameter::OverwatchNonRevealReputationFactor(value) => vec![*value],
+            NetworkParameter::OverwatchNonRevealStakePenalty(value) => vec![*value],
+            NetworkParameter::OverwatchWeightClipBand(value) => vec![*value],
+            NetworkParameter::OverwatchClipReputationFactor(value) => vec![*value],
+            NetworkParameter::OverwatchChallengeBond(value) => vec![*value],
+            NetworkParameter::OverwatchChallengeQuorum(value) => vec![*value],
+            NetworkParameter::OverwatchChallengeReputationFactor(value) => vec![*value],
+            NetworkParameter::OverwatchMinDiversificationRatio(value) => vec![*value],
+            NetworkParameter::OverwatchMinRepScore(value) => vec![*value],
+            NetworkParameter::OverwatchMinAvgAttestationRatio(value) => vec![*value],
+            NetworkParameter::OverwatchMinAge(value) => vec![*value as u128],
+            NetworkParameter::OverwatchMinStakeBalance(value) => vec![*value],
+            NetworkParameter::MinBeaconReveals(value) => vec![*value as u128],
+            NetworkParameter::RewardsLedgerRetentionEpochs(value) => vec![*value as u128],
+            NetworkParameter::SlashAppealEpochs(value) => vec![*value as u128],
+            NetworkParameter::EarlyUnbondingPenalty(value) => vec![*value],
+            NetworkParameter::MinMaxSubnetNodes(min, max) => vec![*min as u128, *max as u128],
+            NetworkParameter::TxRateLimit(value) => vec![*value as u128],
+            NetworkParameter::MinSubnetDelegateStakeFactor(value) => vec![*value],
+            NetworkParameter::MinSubnetRegistrationEpochs(value) => vec![*value as u128],
+            NetworkParameter::SubnetRegistrationEpochs(value) => vec![*value as u128],
+            NetworkParameter::MinActiveNodeStakeEpochs(value) => vec![*value as u128],
+            NetworkParameter::DelegateStakeCooldownEpochs(value) => vec![*value as u128],
+            NetworkParameter::NodeDelegateStakeCooldownEpochs(value) => vec![*value as u128],
+            NetworkParameter::StakeCooldownEpochs(value) => vec![*value as u128],
+            NetworkParameter::MaxUnbondings(value) => vec![*value as u128],
+            NetworkParameter::InflationSigmoidMidpoint(value) => vec![*value],
+            NetworkParameter::MaximumHooksWeight(value) => vec![*value as u128],
+            NetworkParameter::BaseNodeBurnAmount(value) => vec![*value],
+            NetworkParameter::NodeBurnRates(min, max) => vec![*min, *max],
+            NetworkParameter::DelegateStakeSubnetRemovalInterval(value) => vec![*value as u128],
+            NetworkParameter::SubnetRemovalIntervals(min, max) => vec![*min as u128, *max as u128],
+            NetworkParameter::SubnetPauseCooldownEpochs(value) => vec![*value as u128],
+            NetworkParameter::MaxSwapQueueCallsPerBlock(value) => vec![*value as u128],
+            NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold(value) => {
+                vec![*value]
+            }
+            NetworkParameter::ValidatorRewardK(value) => vec![*value as u128],
+            NetworkParameter::ValidatorRewardMidpoint(value) => vec![*value],
+            NetworkParameter::AttestorRewardExponent(value) => vec![*value as u128],
+            NetworkParameter::AttestorMinRewardFactor(value) => vec![*value],
+            NetworkParameter::NodeReputationLimits(min, max) => vec![*min, *max],
+            NetworkParameter::NodeReputationFactors(min, max) => vec![*min, *max],
+            NetworkParameter::MinSubnetReputation(value) => vec![*value],
+            NetworkParameter::NotInConsensusSubnetReputationFactor(value) => vec![*value],
+            NetworkParameter::MaxPauseEpochsSubnetReputationFactor(value) => vec![*value],
+            NetworkParameter::LessThanMinNodesSubnetReputationFactor(value) => vec![*value],
+            NetworkParameter::ValidatorAbsentSubnetReputationFactor(value) => vec![*value],
+            NetworkParameter::InConsensusSubnetReputationFactor(value) => vec![*value],
+            NetworkParameter::OverwatchWeightFactor(value) => vec![*value],
+            NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(value) => vec![*value],
+            NetworkParameter::MaxEmergencySubnetNodes(value) => vec![*value as u128],
+            NetworkParameter::OverwatchStakeWeightFactor(value) => vec![*value],
+            NetworkParameter::SubnetWeightFactors(value) => {
+                vec![value.delegate_stake, value.node_count, value.net_flow]
+            }
+            NetworkParameter::DefaultOverwatchSubnetWeight(value) => vec![*value],
+            NetworkParameter::RequireSubnetRegistrationWhitelist(value) => vec![*value as u128],
+            NetworkParameter::ParameterChangeDelayEpochs(value) => vec![*value as u128],
+            NetworkParameter::SwapQueueDeposit(value) => vec![*value],
+            NetworkParameter::OverwatchWeightQuorum(value) => vec![*value],
+        }
+    }
+
+    /// Whether any of the parameters requires the super majority collective
+    pub fn network_parameters_require_super_majority(parameters: &[NetworkParameter]) -> bool {
+        parameters
+            .iter()
+            .any(|parameter| Self::network_parameter_spec(parameter).super_majority)
+    }
+
+    /// Current value of every parameter in the registry
+    pub fn current_network_parameters() -> Vec<NetworkParameter> {
+        let max_block_ref_time = T::BlockWeights::get().max_block.ref_time().max(1);
+        let hooks_weight_percent = MaximumHooksWeightV2::<T>::get()
+            .ref_time()
+            .saturating_mul(100)
+            .saturating_div(max_block_ref_time) as u32;
+
+        vec![
+            NetworkParameter::SubnetOwnerPercentage(SubnetOwnerPercentage::<T>::get()),
+            NetworkParameter::MaxSubnets(MaxSubnets::<T>::get()),
+            NetworkParameter::MaxBootnodes(MaxBootnodes::<T>::get()),
+            NetworkParameter::MaxSubnetBootnodeAccess(MaxSubnetBootnodeAccess::<T>::get()),
+            NetworkParameter::MaxSubnetPauseEpochs(MaxSubnetPauseEpochs::<T>::get()),
+            NetworkParameter::MinRegistrationCost(MinRegistrationCost::<T>::get()),
+            NetworkParameter::RegistrationCostDecayBlocks(RegistrationCostDecayBlocks::<T>::get()),
+            NetworkParameter::RegistrationCostAlpha(RegistrationCostAlpha::<T>::get()),
+            NetworkParameter::NewRegistrationCostMultiplier(
+                NewRegistrationCostMultiplier::<T>::get(),
+            ),
+            NetworkParameter::MaxMinDelegateStakeMultiplier(
+                MaxMinDelegateStakeMultiplier::<T>::get(),
+            ),
+            NetworkParameter::ChurnLimits(MinChurnLimit::<T>::get(), MaxChurnLimit::<T>::get()),
+            NetworkParameter::ChurnLimitMultipliers(
+                MinChurnLimitMultiplier::<T>::get(),
+                MaxChurnLimitMultiplier::<T>::get(),
+            ),
+            NetworkParameter::QueueEpochs(MinQueueEpochs::<T>::get(), MaxQueueEpochs::<T>::get()),
+            NetworkParameter::MinIdleClassificationEpochs(MinIdleClassificationEpochs::<T>::get()),
+            NetworkParameter::MaxIdleClassificationEpochs(MaxIdleClassificationEpochs::<T>::get()),
+            NetworkParameter::SubnetEnactmentEpochs(SubnetEnactmentEpochs::<T>::get()),
+            NetworkParameter::IncludedClassificationEpochs(
+                MinIncludedClassificationEpochs::<T>::get(),
+                MaxIncludedClassificationEpochs::<T>::get(),
+            ),
+            NetworkParameter::SubnetStakes(
+                MinSubnetMinStake::<T>::get(),
+                MaxSubnetMinStake::<T>::get(),
+            ),
+            NetworkParameter::DelegateStakePercentages(
+                MinDelegateStakePercentage::<T>::get(),
+                MaxDelegateStakePercentage::<T>::get(),
+            ),
+            NetworkParameter::MinMaxRegisteredNodes(
+                MinMaxRegisteredNodes::<T>::get(),
+                MaxMaxRegisteredNodes::<T>::get(),
+            ),
+            NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(
+                MaxSubnetDelegateStakeRewardsPercentageChange::<T>::get(),
+            ),
+            NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod(
+                SubnetDelegateStakeRewardsUpdatePeriod::<T>::get(),
+            ),
+            NetworkParameter::MinAttestationPercentage(MinAttestationPercentage::<T>::get()),
+            NetworkParameter::SuperMajorityAttestationRatio(
+                SuperMajorityAttestationRatio::<T>::get(),
+            ),
+            NetworkParameter::BaseValidatorReward(BaseValidatorReward::<T>::get()),
+            NetworkParameter::BaseSlashPercentage(BaseSlashPercentage::<T>::get()),
+            NetworkParameter::MaxSlashAmount(MaxSlashAmount::<T>::get()),
+            NetworkParameter::ValidatorReputationIncreaseFactor(
+                ValidatorReputationIncreaseFactor::<T>::get(),
+            ),
+            NetworkParameter::ValidatorReputationDecreaseFactor(
+                ValidatorReputationDecreaseFactor::<T>::get(),
+            ),
+            NetworkParameter::NetworkMaxStakeBalance(NetworkMaxStakeBalance::<T>::get()),
+            NetworkParameter::MinDelegateStakeDeposit(MinDelegateStakeDeposit::<T>::get()),
+            NetworkParameter::NodeRewardRateUpdatePeriod(NodeRewardRateUpdatePeriod::<T>::get()),
+            NetworkParameter::MaxRewardRateDecrease(MaxRewardRateDecrease::<T>::get()),
+            NetworkParameter::SubnetDistributionPower(SubnetDistributionPower::<T>::get()),
+            NetworkParameter::DelegateStakeWeightFactor(DelegateStakeWeightFactor::<T>::get()),
+            NetworkParameter::InflationSigmoidSteepness(InflationSigmoidSteepness::<T>::get()),
+            NetworkParameter::MaxOverwatchNodes(MaxOverwatchNodes::<T>::get()),
+            NetworkParameter::OverwatchEpochLengthMultiplier(
+                OverwatchEpochLengthMultiplier::<T>::get(),
+            ),
+            NetworkParameter::OverwatchCommitCutoffPercent(OverwatchCommitCutoffPercent::<T>::get()),
+            NetworkParameter::OverwatchRevealCutoffPercent(OverwatchRevealCutoffPercent::<T>::get()),
+            NetworkParameter::OverwatchNonRevealReputationFactor(
+                OverwatchNonRevealReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::OverwatchNonRevealStakePenalty(
+                OverwatchNonRevealStakePenalty::<T>::get(),
+            ),
+            NetworkParameter::OverwatchWeightClipBand(OverwatchWeightClipBand::<T>::get()),
+            NetworkParameter::OverwatchClipReputationFactor(
+                OverwatchClipReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::OverwatchChallengeBond(OverwatchChallengeBond::<T>::get()),
+            NetworkParameter::OverwatchChallengeQuorum(OverwatchChallengeQuorum::<T>::get()),
+            NetworkParameter::OverwatchChallengeReputationFactor(
+                OverwatchChallengeReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::OverwatchMinDiversificationRatio(
+                OverwatchMinDiversificationRatio::<T>::get(),
+            ),
+            NetworkParameter::OverwatchMinRepScore(OverwatchMinRepScore::<T>::get()),
+            NetworkParameter::OverwatchMinAvgAttestationRatio(
+                OverwatchMinAvgAttestationRatio::<T>::get(),
+            ),
+            NetworkParameter::OverwatchMinAge(OverwatchMinAge::<T>::get()),
+            NetworkParameter::OverwatchMinStakeBalance(OverwatchMinStakeBalance::<T>::get()),
+            NetworkParameter::MinBeaconReveals(MinBeaconReveals::<T>::get()),
+            NetworkParameter::RewardsLedgerRetentionEpochs(RewardsLedgerRetentionEpochs::<T>::get()),
+            NetworkParameter::SlashAppealEpochs(SlashAppealEpochs::<T>::get()),
+            NetworkParameter::EarlyUnbondingPenalty(EarlyUnbondingPenalty::<T>::get()),
+            NetworkParameter::MinMaxSubnetNodes(
+                MinSubnetNodes::<T>::get(),
+                MaxSubnetNodes::<T>::get(),
+            ),
+            NetworkParameter::TxRateLimit(TxRateLimit::<T>::get()),
+            NetworkParameter::MinSubnetDelegateStakeFactor(MinSubnetDelegateStakeFactor::<T>::get()),
+            NetworkParameter::MinSubnetRegistrationEpochs(MinSubnetRegistrationEpochs::<T>::get()),
+            NetworkParameter::SubnetRegistrationEpochs(SubnetRegistrationEpochs::<T>::get()),
+            NetworkParameter::MinActiveNodeStakeEpochs(MinActiveNodeStakeEpochs::<T>::get()),
+            NetworkParameter::DelegateStakeCooldownEpochs(DelegateStakeCooldownEpochs::<T>::get()),
+            NetworkParameter::NodeDelegateStakeCooldownEpochs(
+                NodeDelegateStakeCooldownEpochs::<T>::get(),
+            ),
+            NetworkParameter::StakeCooldownEpochs(StakeCooldownEpochs::<T>::get()),
+            NetworkParameter::MaxUnbondings(MaxUnbondings::<T>::get()),
+            NetworkParameter::InflationSigmoidMidpoint(InflationSigmoidMidpoint::<T>::get()),
+            NetworkParameter::MaximumHooksWeight(hooks_weight_percent),
+            NetworkParameter::BaseNodeBurnAmount(BaseNodeBurnAmount::<T>::get()),
+            NetworkParameter::NodeBurnRates(
+                MinNodeBurnRate::<T>::get(),
+                MaxNodeBurnRate::<T>::get(),
+            ),
+            NetworkParameter::DelegateStakeSubnetRemovalInterval(
+                DelegateStakeSubnetRemovalInterval::<T>::get(),
+            ),
+            NetworkParameter::SubnetRemovalIntervals(
+                MinSubnetRemovalInterval::<T>::get(),
+                MaxSubnetRemovalInterval::<T>::get(),
+            ),
+            NetworkParameter::SubnetPauseCooldownEpochs(SubnetPauseCooldownEpochs::<T>::get()),
+            NetworkParameter::MaxSwapQueueCallsPerBlock(MaxSwapQueueCallsPerBlock::<T>::get()),
+            NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold(
+                MaxSubnetNodeMinWeightDecreaseReputationThreshold::<T>::get(),
+            ),
+            NetworkParameter::ValidatorRewardK(ValidatorRewardK::<T>::get()),
+            NetworkParameter::ValidatorRewardMidpoint(ValidatorRewardMidpoint::<T>::get()),
+            NetworkParameter::AttestorRewardExponent(AttestorRewardExponent::<T>::get()),
+            NetworkParameter::AttestorMinRewardFactor(AttestorMinRewardFactor::<T>::get()),
+            NetworkParameter::NodeReputationLimits(
+                MinMinSubnetNodeReputation::<T>::get(),
+                MaxMinSubnetNodeReputation::<T>::get(),
+            ),
+            NetworkParameter::NodeReputationFactors(
+                MinNodeReputationFactor::<T>::get(),
+                MaxNodeReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::MinSubnetReputation(MinSubnetReputation::<T>::get()),
+            NetworkParameter::NotInConsensusSubnetReputationFactor(
+                NotInConsensusSubnetReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::MaxPauseEpochsSubnetReputationFactor(
+                MaxPauseEpochsSubnetReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::LessThanMinNodesSubnetReputationFactor(
+                LessThanMinNodesSubnetReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::ValidatorAbsentSubnetReputationFactor(
+                ValidatorAbsentSubnetReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::InConsensusSubnetReputationFactor(
+                InConsensusSubnetReputationFactor::<T>::get(),
+            ),
+            NetworkParameter::OverwatchWeightFactor(OverwatchWeightFactor::<T>::get()),
+            NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(
+                MaxEmergencyValidatorEpochsMultiplier::<T>::get(),
+            ),
+            NetworkParameter::MaxEmergencySubnetNodes(MaxEmergencySubnetNodes::<T>::get()),
+            NetworkParameter::OverwatchStakeWeightFactor(OverwatchStakeWeightFactor::<T>::get()),
+            NetworkParameter::SubnetWeightFactors(SubnetWeightFactors::<T>::get()),
+            NetworkParameter::DefaultOverwatchSubnetWeight(DefaultOverwatchSubnetWeight::<T>::get()),
+            NetworkParameter::RequireSubnetRegistrationWhitelist(
+                RequireSubnetRegistrationWhitelist::<T>::get(),
+            ),
+            NetworkParameter::ParameterChangeDelayEpochs(ParameterChangeDelayEpochs::<T>::get()),
+            NetworkParameter::SwapQueueDeposit(SwapQueueDeposit::<T>::get()),
+            NetworkParameter::OverwatchWeightQuorum(OverwatchWeightQuorum::<T>::get()),
+        ]
+    }
+
+    /// Dump of every parameter with its current values and bounds
+    pub fn get_network_parameters() -> Vec<NetworkParameterInfo> {
+        Self::current_network_parameters()
+            .iter()
+            .map(|parameter| Self::network_parameter_info(parameter))
+            .collect()
+    }
+
+    /// Values and bounds of a parameter
+    pub fn network_parameter_info(parameter: &NetworkParameter) -> NetworkParameterInfo {
+        let spec = Self::network_parameter_spec(parameter);
+        NetworkParameterInfo {
+            index: parameter.encode()[0],
+            name: spec.name.as_bytes().to_vec(),
+            values: Self::network_parameter_values(parameter),
+            min: spec.min,
+            max: spec.max,
+            super_majority: spec.super_majority,
+        }
+    }
+
+    /// Ensures every value of a parameter is within its bounds
+    pub fn ensure_network_parameter_in_bounds(parameter: &NetworkParameter) -> DispatchResult {
+        let spec = Self::network_parameter_spec(parameter);
+        ensure!(
+            Self::network_parameter_values(parameter)
+                .iter()
+                .all(|value| *value >= spec.min && *value <= spec.max),
+            Error::<T>::ParameterOutOfBounds
+        );
+        Ok(())
+    }
+
+    /// Validates a parameter against its bounds and applies it through its setter
+    ///
+    /// The origin must already be checked against `network_parameter_spec`
+    pub fn do_set_parameter(parameter: NetworkParameter) -> DispatchResult {
+        Self::ensure_network_parameter_in_bounds(&parameter)?;
+
+        match parameter {
+            NetworkParameter::SubnetOwnerPercentage(value) => {
+                Self::do_set_subnet_owner_percentage(value)
+            }
+            NetworkParameter::MaxSubnets(value) => Self::do_set_max_subnets(value),
+            NetworkParameter::MaxBootnodes(value) => Self::do_set_max_bootnodes(value),
+            NetworkParameter::MaxSubnetBootnodeAccess(value) => {
+                Self::do_set_max_subnet_bootnodes_access(value)
+            }
+            NetworkParameter::MaxSubnetPauseEpochs(value) => Self::do_set_max_pause_epochs(value),
+            NetworkParameter::MinRegistrationCost(value) => {
+                Self::do_set_min_registration_cost(value)
+            }
+            NetworkParameter::RegistrationCostDecayBlocks(value) => {
+                Self::do_set_registration_cost_delay_blocks(value)
+            }
+            NetworkParameter::RegistrationCostAlpha(value) => {
+                Self::do_set_registration_cost_alpha(value)
+            }
+            NetworkParameter::NewRegistrationCostMultiplier(value) => {
+                Self::do_set_new_registration_cost_multiplier(value)
+            }
+            NetworkParameter::MaxMinDelegateStakeMultiplier(value) => {
+                Self::do_set_max_min_delegate_stake_multiplier(value)
+            }
+            NetworkParameter::ChurnLimits(min, max) => Self::do_set_churn_limits(min, max),
+            NetworkParameter::ChurnLimitMultipliers(min, max) => {
+                Self::do_set_churn_limit_multipliers(min, max)
+            }
+            NetworkParameter::QueueEpochs(min, max) => Self::do_set_queue_epochs(min, max),
+            NetworkParameter::MinIdleClassificationEpochs(value) => {
+                Self::do_set_min_idle_classification_epochs(value)
+            }
+            NetworkParameter::MaxIdleClassificationEpochs(value) => {
+                Self::do_set_max_idle_classification_epochs(value)
+            }
+            NetworkParameter::SubnetEnactmentEpochs(value) => {
+                Self::do_set_subnet_activation_enactment_epochs(value)
+            }
+            NetworkParameter::IncludedClassificationEpochs(min, max) => {
+                Self::do_set_included_classification_epochs(min, max)
+            }
+            NetworkParameter::SubnetStakes(min, max) => Self::do_set_subnet_stakes(min, max),
+            NetworkParameter::DelegateStakePercentages(min, max) => {
+                Self::do_set_delegate_stake_percentages(min, max)
+            }
+            NetworkParameter::MinMaxRegisteredNodes(min, max) => {
+                Self::do_set_min_max_registered_nodes(min, max)
+            }
+            NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(value) => {
+                Self::do_set_max_subnet_delegate_stake_rewards_percentage_change(value)
+            }
+            NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod(value) => {
+                Self::do_set_subnet_delegate_stake_rewards_update_period(value)
+            }
+            NetworkParameter::MinAttestationPercentage(value) => {
+                Self::do_set_min_attestation_percentage(value)
+            }
+            NetworkParameter::SuperMajorityAttestationRatio(value) => {
+                Self::do_set_super_majority_attestation_ratio(value)
+            }
+            NetworkParameter::BaseValidatorReward(value) => {
+                Self::do_set_base_validator_reward(value)
+            }
+            NetworkParameter::BaseSlashPercentage(value) => {
+                Self::do_set_base_slash_percentage(value)
+            }
+            NetworkParameter::MaxSlashAmount(value) => Self::do_set_max_slash_amount(value),
+            NetworkParameter::ValidatorReputationIncreaseFactor(value) => {
+                Self::do_set_reputation_increase_factor(value)
+            }
+            NetworkParameter::ValidatorReputationDecreaseFactor(value) => {
+                Self::do_set_reputation_decrease_factor(value)
+            }
+            NetworkParameter::NetworkMaxStakeBalance(value) => {
+                Self::do_set_network_max_stake_balance(value)
+            }
+            NetworkParameter::MinDelegateStakeDeposit(value) => {
+                Self::do_set_min_delegate_stake_deposit(value)
+            }
+            NetworkParameter::NodeRewardRateUpdatePeriod(value) => {
+                Self::do_set_node_reward_rate_update_period(value)
+            }
+            NetworkParameter::MaxRewardRateDecrease(value) => {
+                Self::do_set_max_reward_rate_decrease(value)
+            }
+            NetworkParameter::SubnetDistributionPower(value) => {
+                Self::do_set_subnet_distribution_power(value)
+            }
+            NetworkParameter::DelegateStakeWeightFactor(value) => {
+                Self::do_set_delegate_stake_weight_factor(value)
+            }
+            NetworkParameter::InflationSigmoidSteepness(value) => {
+                Self::do_set_inflation_sigmoid_steepness(value)
+            }
+            NetworkParameter::MaxOverwatchNodes(value) => Self::do_set_max_overwatch_nodes(value),
+            NetworkParameter::OverwatchEpochLengthMultiplier(value) => {
+                Self::do_set_overwatch_epoch_length_multiplier(value)
+            }
+            NetworkParameter::OverwatchCommitCutoffPercent(value) => {
+                Self::do_set_overwatch_commit_cutoff_percent(value)
+            }
+            NetworkParameter::OverwatchRevealCutoffPercent(value) => {
+                Self::do_set_overwatch_reveal_cutoff_percent(value)
+            }
+            NetworkParameter::OverwatchNonRevealReputationFactor(value) => {
+                Self::do_set_overwatch_non_reveal_reputation_factor(value)
+            }
+            NetworkParameter::OverwatchNonRevealStakePenalty(value) => {
+                Self::do_set_overwatch_non_reveal_stake_penalty(value)
+            }
+            NetworkParameter::OverwatchWeightClipBand(value) => {
+                Self::do_set_overwatch_weight_clip_band(value)
+            }
+            NetworkParameter::OverwatchClipReputationFactor(value) => {
+                Self::do_set_overwatch_clip_reputation_factor(value)
+            }
+            NetworkParameter::OverwatchChallengeBond(value) => {
+                Self::do_set_overwatch_challenge_bond(value)
+            }
+            NetworkParameter::OverwatchChallengeQuorum(value) => {
+                Self::do_set_overwatch_challenge_quorum(value)
+            }
+            NetworkParameter::OverwatchChallengeReputationFactor(value) => {
+                Self::do_set_overwatch_challenge_reputation_factor(value)
+            }
+            NetworkParameter::OverwatchMinDiversificationRatio(value) => {
+                Self::do_set_overwatch_min_diversification_ratio(value)
+            }
+            NetworkParameter::OverwatchMinRepScore(value) => {
+                Self::do_set_overwatch_min_rep_score(value)
+            }
+            NetworkParameter::OverwatchMinAvgAttestationRatio(value) => {
+                Self::do_set_overwatch_min_avg_attestation_ratio(value)
+            }
+            NetworkParameter::OverwatchMinAge(value) => Self::do_set_overwatch_min_age(value),
+            NetworkParameter::OverwatchMinStakeBalance(value) => {
+                Self::do_set_overwatch_min_stake_balance(value)
+            }
+            NetworkParameter::MinBeaconReveals(value) => Self::do_set_min_beacon_reveals(value),
+            NetworkParameter::RewardsLedgerRetentionEpochs(value) => {
+                Self::do_set_rewards_ledger_retention_epochs(value)
+            }
+            NetworkParameter::SlashAppealEpochs(value) => Self::do_set_slash_appeal_epochs(value),
+            NetworkParameter::EarlyUnbondingPenalty(value) => {
+                Self::do_set_early_unbonding_penalty(value)
+            }
+            NetworkParameter::MinMaxSubnetNodes(min, max) => {
+                Self::do_set_min_max_subnet_node(min, max)
+            }
+            NetworkParameter::TxRateLimit(value) => Self::do_set_tx_rate_limit(value),
+            NetworkParameter::MinSubnetDelegateStakeFactor(value) => {
+                Self::do_set_min_subnet_delegate_stake_factor(value)
+            }
+            NetworkParameter::MinSubnetRegistrationEpochs(value) => {
+                Self::do_set_min_subnet_registration_epochs(value)
+            }
+            NetworkParameter::SubnetRegistrationEpochs(value) => {
+                Self::do_set_subnet_registration_epochs(value)
+            }
+            NetworkParameter::MinActiveNodeStakeEpochs(value) => {
+                Self::do_set_min_active_node_stake_epochs(value)
+            }
+            NetworkParameter::DelegateStakeCooldownEpochs(value) => {
+                Self::do_set_delegate_stake_cooldown_epochs(value)
+            }
+            NetworkParameter::NodeDelegateStakeCooldownEpochs(value) => {
+                Self::do_set_node_delegate_stake_cooldown_epochs(value)
+            }
+            NetworkParameter::StakeCooldownEpochs(value) => {
+                Self::do_set_min_stake_cooldown_epochs(value)
+            }
+            NetworkParameter::MaxUnbondings(value) => Self::do_set_max_unbondings(value),
+            NetworkParameter::InflationSigmoidMidpoint(value) => {
+                Self::do_set_sigmoid_midpoint(value)
+            }
+            NetworkParameter::MaximumHooksWeight(value) => Self::do_set_maximum_hooks_weight(value),
+            NetworkParameter::BaseNodeBurnAmount(value) => {
+                Self::do_set_base_node_burn_amount(value)
+            }
+            NetworkParameter::NodeBurnRates(min, max) => Self::do_set_node_burn_rates(min, max),
+            NetworkParameter::DelegateStakeSubnetRemovalInterval(value) => {
+                Self::do_set_delegate_stake_subnet_removal_interval(value)
+            }
+            NetworkParameter::SubnetRemovalIntervals(min, max) => {
+                Self::do_set_subnet_removal_intervals(min, max)
+            }
+            NetworkParameter::SubnetPauseCooldownEpochs(value) => {
+                Self::do_set_subnet_pause_cooldown_epochs(value)
+            }
+            NetworkParameter::MaxSwapQueueCallsPerBlock(value) => {
+                Self::do_set_max_swap_queue_calls_per_block(value)
+            }
+            NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold(value) => {
+                Self::do_set_max_subnet_node_min_weight_decrease_reputation_threshold(value)
+            }
+            NetworkParameter::ValidatorRewardK(value) => Self::do_set_validator_reward_k(value),
+            NetworkParameter::ValidatorRewardMidpoint(value) => {
+                Self::do_set_validator_reward_midpoint(value)
+            }
+            NetworkParameter::AttestorRewardExponent(value) => {
+                Self::do_set_attestor_reward_exponent(value)
+            }
+            NetworkParameter::AttestorMinRewardFactor(value) => {
+                Self::do_set_attestor_min_reward_factor(value)
+            }
+            NetworkParameter::NodeReputationLimits(min, max) => {
+                Self::do_set_min_max_node_reputation(min, max)
+            }
+            NetworkParameter::NodeReputationFactors(min, max) => {
+                Self::do_set_min_max_node_reputation_factor(min, max)
+            }
+            NetworkParameter::MinSubnetReputation(value) => {
+                Self::do_set_min_subnet_reputation(value)
+            }
+            NetworkParameter::NotInConsensusSubnetReputationFactor(value) => {
+                Self::do_set_not_in_consensus_subnet_reputation_factor(value)
+            }
+            NetworkParameter::MaxPauseEpochsSubnetReputationFactor(value) => {
+                Self::do_set_max_pause_epochs_subnet_reputation_factor(value)
+            }
+            NetworkParameter::LessThanMinNodesSubnetReputationFactor(value) => {
+                Self::do_set_less_than_min_nodes_subnet_reputation_factor(value)
+            }
+            NetworkParameter::ValidatorAbsentSubnetReputationFactor(value) => {
+                Self::do_set_validator_proposal_absent_subnet_reputation_factor(value)
+            }
+            NetworkParameter::InConsensusSubnetReputationFactor(value) => {
+                Self::do_set_in_consensus_subnet_reputation_factor(value)
+            }
+            NetworkParameter::OverwatchWeightFactor(value) => {
+                Self::do_set_overwatch_weight_factor(value)
+            }
+            NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(value) => {
+                Self::do_set_max_emergency_validator_epochs_multiplier(value)
+            }
+            NetworkParameter::MaxEmergencySubnetNodes(value) => {
+                Self::do_set_max_emergency_subnet_nodes(value)
+            }
+            NetworkParameter::OverwatchStakeWeightFactor(value) => {
+                Self::do_set_overwatch_stake_weight_factor(value)
+            }
+            NetworkParameter::SubnetWeightFactors(value) => {
+                Self::do_set_subnet_weight_factors(value)
+            }
+            NetworkParameter::DefaultOverwatchSubnetWeight(value) => {
+                Self::do_set_default_overwatch_subnet_weight(value)
+            }
+            NetworkParameter::RequireSubnetRegistrationWhitelist(value) => {
+                RequireSubnetRegistrationWhitelist::<T>::set(value);
+                Ok(())
+            }
+            NetworkParameter::ParameterChangeDelayEpochs(value) => {
+                Self::do_set_parameter_change_delay_epochs(value)
+            }
+            NetworkParameter::SwapQueueDeposit(value) => Self::do_set_swap_queue_deposit(value),
+            NetworkParameter::OverwatchWeightQuorum(value) => {
+                Self::do_set_overwatch_weight_quorum(value)
+            }
+        }
+    }
+
+    /// Applies a batch of parameters in order
+    ///
+    /// Called from a transactional extrinsic, so a failing parameter reverts the whole batch
+    pub fn do_set_parameters(parameters: Vec<NetworkParameter>) -> DispatchResult {
+        ensure!(
+            parameters.len() as u32 <= MAX_NETWORK_PARAMETER_UPDATES,
+            Error::<T>::TooManyParameters
+        );
+
+        for parameter in parameters {
+            Self::do_set_parameter(parameter)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Per-epoch rewards ledger, see `SubnetRewardsLedger`
+
+use super::*;
+
+/// Maximum value of `RewardsLedgerRetentionEpochs`
+pub const MAX_REWARDS_LEDGER_RETENTION_EPOCHS: u32 = 4096;
+
+impl<T: Config> Pallet<T> {
+    /// Record the rewards distributed to a subnet in `epoch`
+    ///
+    /// Each subnet keeps the last `RewardsLedgerRetentionEpochs` entries, older entries are
+    /// pruned as new ones are written.
+    pub fn record_subnet_rewards(
+        weight_meter: &mut WeightMeter,
+        subnet_id: u32,
+        epoch: u32,
+        entry: SubnetRewardsLedgerEntry<T::AccountId>,
+    ) {
+        let db_weight = T::DbWeight::get();
+
+        let retention = RewardsLedgerRetentionEpochs::<T>::get() as usize;
+
+        SubnetRewardsLedger::<T>::insert(subnet_id, epoch, entry);
+
+        let mut pruned: u64 = 0;
+        SubnetRewardsLedgerEpochs::<T>::mutate(subnet_id, |epochs| {
+            if epochs.last() != Some(&epoch) {
+                epochs.push(epoch);
+            }
+
+            // Retention can be lowered by governance, prune everything above it
+            let excess = epochs.len().saturating_sub(retention);
+            for stale_epoch in epochs.drain(..excess) {
+                SubnetRewardsLedger::<T>::remove(subnet_id, stale_epoch);
+                pruned = pruned.saturating_add(1);
+            }
+        });
+
+        // RewardsLedgerRetentionEpochs | SubnetRewardsLedgerEpochs
+        // SubnetRewardsLedger | SubnetRewardsLedgerEpochs | SubnetRewardsLedger (pruned)
+        weight_meter.consume(db_weight.reads_writes(2, 2u64.saturating_add(pruned)));
+    }
+}
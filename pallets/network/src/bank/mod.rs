@@ -1,2 +1,5 @@
 use super::*;
+pub mod ledger;
+pub mod reward_destination;
 pub mod rewards;
+pub mod slashing;
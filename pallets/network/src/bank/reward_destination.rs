@@ -0,0 +1,136 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Reward destinations, see `RewardDestinations`
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    pub fn do_set_reward_destination(
+        account_id: T::AccountId,
+        destination: RewardDestination,
+    ) -> DispatchResult {
+        match destination {
+            RewardDestination::SubnetDelegateStake(subnet_id) => ensure!(
+                SubnetsData::<T>::contains_key(subnet_id),
+                Error::<T>::InvalidSubnetId
+            ),
+            RewardDestination::ValidatorDelegateStake(validator_id) => ensure!(
+                ValidatorsData::<T>::contains_key(validator_id),
+                Error::<T>::InvalidValidatorId
+            ),
+            RewardDestination::Staked | RewardDestination::Free => (),
+        }
+
+        if destination == RewardDestination::Staked {
+            RewardDestinations::<T>::remove(&account_id);
+        } else {
+            RewardDestinations::<T>::insert(&account_id, destination);
+        }
+
+        Self::deposit_event(Event::RewardDestinationSet {
+            account_id,
+            destination,
+        });
+
+        Ok(())
+    }
+
+    /// Credit a reward to `account_id` following its `RewardDestinations` preference
+    ///
+    /// Returns the reward that is left to be compounded into the stake it was earned on. This is
+    /// the full reward when the destination is `Staked`, or when the destination subnet or
+    /// validator no longer exists.
+    pub fn credit_reward_to_destination(
+        weight_meter: &mut WeightMeter,
+        account_id: &T::AccountId,
+        reward: u128,
+    ) -> u128 {
+        let db_weight = T::DbWeight::get();
+
+        let destination = RewardDestinations::<T>::get(account_id);
+        // RewardDestinations
+        weight_meter.consume(db_weight.reads(1));
+
+        match destination {
+            RewardDestination::Staked => reward,
+            RewardDestination::SubnetDelegateStake(subnet_id) => {
+                // SubnetsData
+                weight_meter.consume(db_weight.reads(1));
+                if !SubnetsData::<T>::contains_key(subnet_id) {
+                    return reward;
+                }
+
+                let (result, _, _) =
+                    Self::handle_increase_account_delegate_stake(account_id, subnet_id, reward);
+                weight_meter.consume(T::WeightInfo::handle_increase_account_delegate_stake());
+                if result.is_err() {
+                    return reward;
+                }
+                0
+            }
+            RewardDestination::ValidatorDelegateStake(validator_id) => {
+                // ValidatorsData
+                weight_meter.consume(db_weight.reads(1));
+                if !ValidatorsData::<T>::contains_key(validator_id) {
+                    return reward;
+                }
+
+                let (result, _, _) = Self::handle_increase_account_validator_delegate_stake(
+                    account_id,
+                    validator_id,
+                    reward,
+                );
+                weight_meter
+                    .consume(T::WeightInfo::handle_increase_account_validator_delegate_stake());
+                if result.is_err() {
+                    return reward;
+                }
+                0
+            }
+            RewardDestination::Free => match Self::u128_to_balance(reward) {
+                Some(balance) => {
+                    Self::add_balance_to_coldkey_account(account_id, balance);
+                    weight_meter.consume(T::WeightInfo::add_balance_to_coldkey_account());
+                    0
+                }
+                None => reward,
+            },
+        }
+    }
+
+    /// Credit a subnet node reward, compounding into the nodes stake unless the validators
+    /// coldkey has set another `RewardDestination`
+    pub fn credit_node_reward(
+        weight_meter: &mut WeightMeter,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        validator_id: u32,
+        reward: u128,
+    ) {
+        // ValidatorColdkey
+        weight_meter.consume(T::DbWeight::get().reads(1));
+        let remaining_reward = match ValidatorColdkey::<T>::get(validator_id) {
+            Some(coldkey) => Self::credit_reward_to_destination(weight_meter, &coldkey, reward),
+            None => reward,
+        };
+
+        if remaining_reward != 0 {
+            Self::increase_node_stake(subnet_node_id, subnet_id, remaining_reward);
+            // NodeSubnetStake | TotalSubnetStake | TotalStake
+            weight_meter.consume(T::DbWeight::get().reads_writes(3, 3));
+        }
+    }
+}
@@ -58,6 +58,23 @@ impl<T: Config> Pallet<T> {
         let electable_nodes_count = SubnetNodeElectionSlots::<T>::get(subnet_id).len() as u32;
         weight_meter.consume(db_weight.reads(1));
 
+        let mut ledger_entry = SubnetRewardsLedgerEntry {
+            rewards_data: rewards_data.clone(),
+            attestation_ratio: consensus_submission_data.attestation_ratio,
+            in_consensus: false,
+            validator_subnet_node_id: consensus_submission_data.validator_subnet_node_id,
+            validator_id: None,
+            validator_reward: 0,
+            subnet_owner: None,
+            subnet_owner_reward: 0,
+            capacitor_reward: 0,
+            node_rewards: Vec::new(),
+            delegate_stake_reward: 0,
+            delegate_stake_total_shares: 0,
+            validator_delegate_stake_rewards: Vec::new(),
+            delegate_account_allocations: Vec::new(),
+        };
+
         // --- If under minimum attestation ratio, penalize validator, skip rewards
         if consensus_submission_data.attestation_ratio < min_attestation_percentage {
             Self::handle_non_consensus(
@@ -73,6 +90,7 @@ impl<T: Config> Pallet<T> {
                 percentage_factor,
                 weight_meter,
             );
+            Self::record_subnet_rewards(weight_meter, subnet_id, current_epoch, ledger_entry);
             return;
         } else if let Some(validator_id) = SubnetNodeValidatorId::<T>::get(
             subnet_id,
@@ -82,7 +100,8 @@ impl<T: Config> Pallet<T> {
             // In consensus: Increase validators stake
             //
 
-            Self::handle_validator_reward(
+            ledger_entry.validator_id = Some(validator_id);
+            ledger_entry.validator_reward = Self::handle_validator_reward(
                 weight_meter,
                 validator_id,
                 subnet_id,
@@ -105,6 +124,8 @@ impl<T: Config> Pallet<T> {
         // --- We are now in consensus (>=66% attestation ratio)
         //
 
+        ledger_entry.in_consensus = true;
+
         let idle_epochs = IdleClassificationEpochs::<T>::get(subnet_id);
         let included_epochs = IncludedClassificationEpochs::<T>::get(subnet_id);
         let weight_threshold = SubnetNodeMinWeightDecreaseReputationThreshold::<T>::get(subnet_id);
@@ -151,6 +172,9 @@ impl<T: Config> Pallet<T> {
             });
             weight_meter.consume(db_weight.reads_writes(1, 1));
 
+            ledger_entry.capacitor_reward = rewards_data.overall_subnet_reward;
+            Self::record_subnet_rewards(weight_meter, subnet_id, current_epoch, ledger_entry);
+
             // Return before any rewards are distributed
             // The only node that gets rewards when weight_sum is 0 is the validator
             // But we already handled the validator reward above
@@ -162,7 +186,14 @@ impl<T: Config> Pallet<T> {
         weight_meter.consume(db_weight.writes(1));
 
         // --- Reward owner
-        Self::handle_subnet_owner_reward(weight_meter, subnet_id, rewards_data.subnet_owner_reward);
+        ledger_entry.subnet_owner = Self::handle_subnet_owner_reward(
+            weight_meter,
+            subnet_id,
+            rewards_data.subnet_owner_reward,
+        );
+        if ledger_entry.subnet_owner.is_some() {
+            ledger_entry.subnet_owner_reward = rewards_data.subnet_owner_reward;
+        }
 
         // Loop iteration overhead
         weight_meter.consume(Weight::from_parts(
@@ -440,6 +471,17 @@ impl<T: Config> Pallet<T> {
                         // Add the node delegate reward to the list for event
                         validator_delegate_stake_rewards
                             .push((subnet_node.validator_id, node_delegate_reward));
+                        ledger_entry.validator_delegate_stake_rewards.push(
+                            ValidatorDelegateStakeRewardRecord {
+                                validator_id: subnet_node.validator_id,
+                                reward: node_delegate_reward,
+                                total_shares: ValidatorDelegateStakeShares::<T>::get(
+                                    subnet_node.validator_id,
+                                ),
+                            },
+                        );
+                        // ValidatorDelegateStakeShares
+                        weight_meter.consume(db_weight.reads(1));
                     }
                 }
 
@@ -465,11 +507,20 @@ impl<T: Config> Pallet<T> {
                 }
             }
 
-            Self::increase_node_stake(subnet_node.id, subnet_id, account_reward);
-            // NodeSubnetStake | TotalSubnetStake | TotalStake
-            weight_meter.consume(db_weight.reads_writes(3, 3));
+            Self::credit_node_reward(
+                weight_meter,
+                subnet_id,
+                subnet_node.id,
+                subnet_node.validator_id,
+                account_reward,
+            );
 
             node_rewards.push((subnet_node.id, account_reward));
+            ledger_entry.node_rewards.push(SubnetNodeRewardRecord {
+                subnet_node_id: subnet_node.id,
+                validator_id: subnet_node.validator_id,
+                reward: account_reward,
+            });
         }
 
         // --- Increase the delegate stake pool balance
@@ -482,8 +533,17 @@ impl<T: Config> Pallet<T> {
             // TotalSubnetDelegateStakeBalance | | TotalSubnetDelegateStakeShares|
             // TotalSubnetDelegateStakeShares| TotalSubnetDelegateStakeBalance| TotalDelegateStake
             weight_meter.consume(db_weight.reads_writes(3, 5));
+
+            ledger_entry.delegate_stake_reward = rewards_data.delegate_stake_rewards;
+            ledger_entry.delegate_stake_total_shares =
+                TotalSubnetDelegateStakeShares::<T>::get(subnet_id);
+            // TotalSubnetDelegateStakeShares
+            weight_meter.consume(db_weight.reads(1));
         }
 
+        ledger_entry.delegate_account_allocations = node_delegate_account_allocations.clone();
+        Self::record_subnet_rewards(weight_meter, subnet_id, current_epoch, ledger_entry);
+
         Self::deposit_event(Event::SubnetRewards {
             subnet_id,
             node_rewards,
@@ -513,7 +573,7 @@ impl<T: Config> Pallet<T> {
         weight_meter.consume(db_weight.reads(1));
 
         // --- Slash validator
-        // Slashes stake balance
+        // Queues a slash of the stake balance
         // Decreases reputation
         // Possibly removes node if under min reputation
         let slash_validator_weight = Self::slash_validator(
@@ -525,6 +585,7 @@ impl<T: Config> Pallet<T> {
             min_validator_reputation,
             electable_nodes_count,
             current_epoch,
+            evaluated_subnet_epoch,
             reputation_factors.validator_non_consensus_decrease,
         );
         weight_meter.consume(slash_validator_weight);
@@ -608,7 +669,7 @@ impl<T: Config> Pallet<T> {
         min_attestation_percentage: u128,
         coldkey_reputation_increase_factor: u128,
         current_epoch: u32,
-    ) {
+    ) -> u128 {
         let db_weight = T::DbWeight::get();
 
         weight_meter.consume(db_weight.reads(1));
@@ -635,23 +696,34 @@ impl<T: Config> Pallet<T> {
         //
         weight_meter.consume(db_weight.reads(1));
 
-        // Give validator rewards to their stake
-        Self::increase_node_stake(subnet_node_id, subnet_id, validator_reward);
+        // Give validator rewards to their stake, or their reward destination
+        Self::credit_node_reward(
+            weight_meter,
+            subnet_id,
+            subnet_node_id,
+            validator_id,
+            validator_reward,
+        );
+
+        validator_reward
     }
 
+    /// Pay the subnet owner, returns the owner if paid
     pub fn handle_subnet_owner_reward(
         weight_meter: &mut WeightMeter,
         subnet_id: u32,
         amount: u128,
-    ) {
+    ) -> Option<T::AccountId> {
         // SubnetOwner
         weight_meter.consume(T::DbWeight::get().reads(1));
         if let Ok(owner) = SubnetOwner::<T>::try_get(subnet_id) {
             if let Some(balance) = Self::u128_to_balance(amount) {
                 Self::add_balance_to_coldkey_account(&owner, balance);
                 weight_meter.consume(T::WeightInfo::add_balance_to_coldkey_account());
+                return Some(owner);
             }
         }
+        None
     }
 
     /// Handles node queue operations based on consensus data.
@@ -921,7 +993,17 @@ impl<T: Config> Pallet<T> {
     ) -> (u128, u128) {
         let delegate_account_deposit = Self::percent_mul(account_reward, rate);
         let updated_account_reward = account_reward.saturating_sub(delegate_account_deposit);
-        Self::increase_delegate_account_balance(delegate_account_id, delegate_account_deposit);
+
+        let remaining_deposit = Self::credit_reward_to_destination(
+            weight_meter,
+            delegate_account_id,
+            delegate_account_deposit,
+        );
+        if remaining_deposit != 0 {
+            Self::increase_delegate_account_balance(delegate_account_id, remaining_deposit);
+            // DelegateAccountStake | TotalAccountDelegateStake
+            weight_meter.consume(T::DbWeight::get().reads_writes(2, 2));
+        }
 
         (updated_account_reward, delegate_account_deposit)
     }
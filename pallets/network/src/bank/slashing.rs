@@ -0,0 +1,207 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Pending validator slashes, see `PendingSlashes`
+
+use super::*;
+
+/// Maximum value of `SlashAppealEpochs`
+pub const MAX_SLASH_APPEAL_EPOCHS: u32 = 1024;
+
+impl<T: Config> Pallet<T> {
+    /// Queue a stake slash of a subnet node
+    ///
+    /// The slash is applied in `epoch + SlashAppealEpochs` by `apply_pending_slashes` unless the
+    /// council cancels it with `cancel_pending_slash`. If `SlashAppealEpochs` is zero the stake is
+    /// burned immediately.
+    ///
+    /// The slash is capped by the node's stake not locked by other pending slashes, the amount is
+    /// locked in `NodeSubnetPendingSlash` so it can't be removed during the appeal window.
+    ///
+    /// # Arguments
+    ///
+    /// * `subnet_id` - Subnet ID
+    /// * `subnet_node_id` - Subnet node ID
+    /// * `amount` - Stake to burn
+    /// * `epoch` - The blockchains general epoch
+    /// * `subnet_epoch` - The subnet epoch of the consensus submission that caused the slash
+    pub fn queue_slash(
+        subnet_id: u32,
+        subnet_node_id: u32,
+        amount: u128,
+        epoch: u32,
+        subnet_epoch: u32,
+    ) -> Weight {
+        let mut weight = Weight::zero();
+        let db_weight = T::DbWeight::get();
+
+        let appeal_epochs = SlashAppealEpochs::<T>::get();
+        // SlashAppealEpochs
+        weight = weight.saturating_add(db_weight.reads(1));
+
+        if appeal_epochs == 0 {
+            Self::slash_node_stake(subnet_id, subnet_node_id, amount);
+            // NodeSubnetStake | TotalSubnetStake | TotalStake
+            // SubnetNodeValidatorId | ValidatorColdkey | Holds
+            return weight.saturating_add(db_weight.reads_writes(6, 4));
+        }
+
+        // --- Lock the slashable stake so it can't be removed during the appeal window
+        let stake = NodeSubnetStake::<T>::get(subnet_node_id, subnet_id);
+        let locked = NodeSubnetPendingSlash::<T>::get(subnet_node_id, subnet_id);
+        let amount = amount.min(stake.saturating_sub(locked));
+
+        // NodeSubnetStake | NodeSubnetPendingSlash
+        weight = weight.saturating_add(db_weight.reads(2));
+
+        if amount == 0 {
+            return weight;
+        }
+
+        let id = NextPendingSlashId::<T>::get();
+        let execute_epoch = epoch.saturating_add(appeal_epochs);
+
+        PendingSlashes::<T>::insert(
+            execute_epoch,
+            id,
+            PendingSlash {
+                subnet_id,
+                subnet_node_id,
+                validator_id: SubnetNodeValidatorId::<T>::get(subnet_id, subnet_node_id),
+                amount,
+                epoch,
+                subnet_epoch,
+                execute_epoch,
+            },
+        );
+        PendingSlashExecuteEpoch::<T>::insert(id, execute_epoch);
+        NodeSubnetPendingSlash::<T>::insert(
+            subnet_node_id,
+            subnet_id,
+            locked.saturating_add(amount),
+        );
+
+        NextPendingSlashId::<T>::put(id.saturating_add(1));
+
+        // NextPendingSlashId | SubnetNodeValidatorId
+        // PendingSlashes | PendingSlashExecuteEpoch | NodeSubnetPendingSlash | NextPendingSlashId
+        weight = weight.saturating_add(db_weight.reads_writes(2, 4));
+
+        Self::deposit_event(Event::SlashQueued {
+            id,
+            subnet_id,
+            subnet_node_id,
+            amount,
+            execute_epoch,
+        });
+
+        weight
+    }
+
+    /// Burn the stake of each pending slash whose appeal window ended by `epoch`
+    ///
+    /// Slashes are read by execute epoch, from the epoch after `PendingSlashesAppliedEpoch` up to
+    /// `epoch` so epochs skipped while the network was paused are applied. Epochs that don't fit
+    /// in `weight_meter` are applied next time.
+    pub fn apply_pending_slashes(weight_meter: &mut WeightMeter, epoch: u32) {
+        let db_weight = T::DbWeight::get();
+
+        // PendingSlashesAppliedEpoch
+        weight_meter.consume(db_weight.reads(1));
+
+        let from = PendingSlashesAppliedEpoch::<T>::get()
+            .map_or(epoch, |applied| applied.saturating_add(1));
+
+        for execute_epoch in from..=epoch {
+            // PendingSlashes prefix | PendingSlashesAppliedEpoch
+            if !weight_meter.can_consume(db_weight.reads_writes(1, 1)) {
+                return;
+            }
+            weight_meter.consume(db_weight.reads_writes(1, 1));
+
+            for (id, pending_slash) in PendingSlashes::<T>::drain_prefix(execute_epoch) {
+                Self::apply_pending_slash(id, pending_slash);
+
+                // PendingSlashes | PendingSlashExecuteEpoch | NodeSubnetPendingSlash
+                // NodeSubnetStake | TotalSubnetStake | TotalStake
+                // SubnetNodeValidatorId | ValidatorColdkey | Holds
+                weight_meter.consume(db_weight.reads_writes(8, 7));
+            }
+
+            PendingSlashesAppliedEpoch::<T>::put(execute_epoch);
+        }
+    }
+
+    fn apply_pending_slash(id: u32, pending_slash: PendingSlash) {
+        let subnet_id = pending_slash.subnet_id;
+        let subnet_node_id = pending_slash.subnet_node_id;
+
+        PendingSlashExecuteEpoch::<T>::remove(id);
+        Self::unlock_pending_slash_stake(subnet_node_id, subnet_id, pending_slash.amount);
+
+        // --- The stake was locked when the slash was queued
+        let stake = NodeSubnetStake::<T>::get(subnet_node_id, subnet_id);
+        let amount = pending_slash.amount.min(stake);
+
+        if amount > 0 {
+            Self::slash_node_stake(subnet_id, subnet_node_id, amount);
+        }
+
+        Self::deposit_event(Event::PendingSlashApplied {
+            id,
+            subnet_id,
+            subnet_node_id,
+            amount,
+        });
+    }
+
+    /// Release `amount` of the stake locked by pending slashes of a subnet node
+    pub fn unlock_pending_slash_stake(subnet_node_id: u32, subnet_id: u32, amount: u128) {
+        NodeSubnetPendingSlash::<T>::mutate_exists(subnet_node_id, subnet_id, |locked| {
+            let remaining = locked.unwrap_or(0).saturating_sub(amount);
+            *locked = if remaining == 0 {
+                None
+            } else {
+                Some(remaining)
+            };
+        });
+    }
+
+    /// Burn `amount` of a subnet node's stake and the matching hold on its validator coldkey
+    pub fn slash_node_stake(subnet_id: u32, subnet_node_id: u32, amount: u128) {
+        Self::decrease_node_stake(subnet_node_id, subnet_id, amount);
+
+        let coldkey = SubnetNodeValidatorId::<T>::try_get(subnet_id, subnet_node_id)
+            .ok()
+            .and_then(|validator_id| ValidatorColdkey::<T>::try_get(validator_id).ok());
+
+        if let Some(coldkey) = coldkey {
+            Self::burn_stake_hold(&coldkey, HoldReason::NodeStake, amount);
+        }
+    }
+
+    /// Burn `amount` of an overwatch node's stake and the matching hold on its validator coldkey
+    pub fn slash_overwatch_node_stake(overwatch_node_id: u32, amount: u128) {
+        Self::decrease_overwatch_node_stake(overwatch_node_id, amount);
+
+        let coldkey = OverwatchNodeValidatorId::<T>::try_get(overwatch_node_id)
+            .ok()
+            .and_then(|validator_id| ValidatorColdkey::<T>::try_get(validator_id).ok());
+
+        if let Some(coldkey) = coldkey {
+            Self::burn_stake_hold(&coldkey, HoldReason::OverwatchStake, amount);
+        }
+    }
+}
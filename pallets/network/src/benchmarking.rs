@@ -20,6 +20,8 @@
 // frame-omni-bencher v1 benchmark pallet --runtime target/release/wbuild/hypertensor-runtime/hypertensor_runtime.compact.compressed.wasm --extrinsic "" --pallet "pallet_network"
 
 #![cfg(feature = "runtime-benchmarks")]
+// The deprecated `set_*` extrinsics are benchmarked until they are removed
+#![allow(deprecated)]
 use super::*;
 
 #[allow(unused)]
@@ -1145,6 +1147,7 @@ pub fn get_simulated_consensus_data<T: Config>(subnet_id: u32, node_count: u32)
                 block: block_number,
                 attestor_progress: 0,
                 reward_factor: Network::<T>::percentage_factor_as_u128(),
+                attestation_weight: 1,
                 data: None,
             },
         );
@@ -1171,6 +1174,12 @@ pub fn get_simulated_consensus_data<T: Config>(subnet_id: u32, node_count: u32)
         SubnetNodeElectionSlots::<T>::get(subnet_id)
     };
 
+    let total_attestation_weight = Network::<T>::get_total_attestation_weight(
+        subnet_id,
+        &validator_ids,
+        AttestationWeighting::Count,
+    );
+
     ConsensusData::<T> {
         validator_id: subnet_id * max_subnet_nodes,
         block: block_number,
@@ -1178,6 +1187,8 @@ pub fn get_simulated_consensus_data<T: Config>(subnet_id: u32, node_count: u32)
         validator_reward_factor: Network::<T>::percentage_factor_as_u128(),
         validator_ids,
         attests,
+        attestation_weighting: AttestationWeighting::Count,
+        total_attestation_weight,
         data,
         prioritize_queue_node_id: None,
         remove_queue_node_id: None,
@@ -3117,7 +3128,7 @@ mod benchmarks {
                 assert_eq!(*to_subnet_id, starting_to_subnet_id);
                 assert_ne!(*balance, 0);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         let next_id = NextSwapQueueId::<T>::get();
@@ -3392,7 +3403,6 @@ mod benchmarks {
         let call_queue = SwapCallQueue::<T>::get(prev_next_id).unwrap();
         assert_eq!(call_queue.id, prev_next_id);
         match &call_queue.call {
-            QueuedSwapCall::SwapToSubnetDelegateStake { .. } => assert!(false),
             QueuedSwapCall::SwapToValidatorDelegateStake {
                 account_id,
                 to_validator_id: queued_to_validator_id,
@@ -3402,6 +3412,7 @@ mod benchmarks {
                 assert_eq!(*queued_to_validator_id, to_validator_id);
                 assert_ne!(*balance, 0);
             }
+            _ => assert!(false),
         };
 
         assert_eq!(NextSwapQueueId::<T>::get(), prev_next_id + 1);
@@ -3563,7 +3574,7 @@ mod benchmarks {
                 assert_eq!(*queued_to_subnet_id, to_subnet_id);
                 assert_ne!(*balance, 0);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         assert_eq!(NextSwapQueueId::<T>::get(), prev_next_id + 1);
@@ -3621,7 +3632,6 @@ mod benchmarks {
         let call_queue = SwapCallQueue::<T>::get(prev_next_id).unwrap();
         assert_eq!(call_queue.id, prev_next_id);
         match &call_queue.call {
-            QueuedSwapCall::SwapToSubnetDelegateStake { .. } => assert!(false),
             QueuedSwapCall::SwapToValidatorDelegateStake {
                 account_id,
                 to_validator_id: queued_to_validator_id,
@@ -3631,6 +3641,7 @@ mod benchmarks {
                 assert_eq!(*queued_to_validator_id, to_validator_id);
                 assert_ne!(*balance, 0);
             }
+            _ => assert!(false),
         };
 
         assert_eq!(NextSwapQueueId::<T>::get(), prev_next_id + 1);
@@ -3657,6 +3668,30 @@ mod benchmarks {
         assert_eq!(unbondings.len(), 1);
     }
 
+    #[benchmark]
+    fn set_reward_destination() {
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            0,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+        let coldkey: T::AccountId = funded_account::<T>("coldkey", 0);
+
+        #[extrinsic_call]
+        set_reward_destination(
+            RawOrigin::Signed(coldkey.clone()),
+            RewardDestination::SubnetDelegateStake(subnet_id),
+        );
+
+        assert_eq!(
+            RewardDestinations::<T>::get(&coldkey),
+            RewardDestination::SubnetDelegateStake(subnet_id)
+        );
+    }
+
     #[benchmark]
     fn propose_attestation() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
@@ -4433,7 +4468,7 @@ mod benchmarks {
     fn set_min_subnet_delegate_stake_factor() {
         let new_value = Network::<T>::percentage_factor_as_u128();
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4447,7 +4482,7 @@ mod benchmarks {
         let value = SubnetOwnerPercentage::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4476,7 +4511,7 @@ mod benchmarks {
         let value = MaxBootnodes::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4490,7 +4525,7 @@ mod benchmarks {
         let value = MaxSubnetBootnodeAccess::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4504,7 +4539,7 @@ mod benchmarks {
         let value = MaxSubnetPauseEpochs::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4517,7 +4552,7 @@ mod benchmarks {
     fn set_delegate_stake_subnet_removal_interval() {
         let new_value = 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4531,7 +4566,7 @@ mod benchmarks {
         let min = 1;
         let max = 2;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4545,7 +4580,7 @@ mod benchmarks {
     fn set_subnet_pause_cooldown_epochs() {
         let new_value = 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4559,7 +4594,7 @@ mod benchmarks {
         let value = MinRegistrationCost::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4573,7 +4608,7 @@ mod benchmarks {
         let value = RegistrationCostDecayBlocks::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4587,7 +4622,7 @@ mod benchmarks {
         let value = RegistrationCostAlpha::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4601,7 +4636,7 @@ mod benchmarks {
         let value = NewRegistrationCostMultiplier::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4628,7 +4663,7 @@ mod benchmarks {
         let min = 1;
         let max = 2;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4643,7 +4678,7 @@ mod benchmarks {
         let min = 1;
         let max = 2;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4658,7 +4693,7 @@ mod benchmarks {
         let value = MaxSwapQueueCallsPerBlock::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4672,7 +4707,7 @@ mod benchmarks {
         let value = MinIdleClassificationEpochs::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4686,7 +4721,7 @@ mod benchmarks {
         let value = MaxIdleClassificationEpochs::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4700,7 +4735,7 @@ mod benchmarks {
         let value = SubnetEnactmentEpochs::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4714,7 +4749,7 @@ mod benchmarks {
         let min = 1;
         let max = 2;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4726,10 +4761,10 @@ mod benchmarks {
 
     #[benchmark]
     fn set_subnet_stakes() {
-        let min = 5;
-        let max = 6;
+        let min = 1000000000000000000;
+        let max = 2000000000000000000;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4744,7 +4779,7 @@ mod benchmarks {
         let min = 5;
         let max = 6;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4759,7 +4794,7 @@ mod benchmarks {
         let min = 5;
         let max = 6;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4774,7 +4809,7 @@ mod benchmarks {
         let value = MaxSubnetDelegateStakeRewardsPercentageChange::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4794,7 +4829,7 @@ mod benchmarks {
         let value = SubnetDelegateStakeRewardsUpdatePeriod::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4881,7 +4916,7 @@ mod benchmarks {
         let value = ValidatorReputationIncreaseFactor::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4895,7 +4930,7 @@ mod benchmarks {
         let value = ValidatorReputationDecreaseFactor::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4937,7 +4972,7 @@ mod benchmarks {
         let value = NodeRewardRateUpdatePeriod::<T>::get();
         let new_value = value + 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -4951,7 +4986,7 @@ mod benchmarks {
         let value = MaxRewardRateDecrease::<T>::get();
         let new_value = value - 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5210,7 +5245,7 @@ mod benchmarks {
         let value = DelegateStakeCooldownEpochs::<T>::get();
         let new_value = value + 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5224,7 +5259,7 @@ mod benchmarks {
         let value = NodeDelegateStakeCooldownEpochs::<T>::get();
         let new_value = value + 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5238,7 +5273,7 @@ mod benchmarks {
         let value = StakeCooldownEpochs::<T>::get();
         let new_value = value + 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5266,7 +5301,7 @@ mod benchmarks {
         let value = InflationSigmoidMidpoint::<T>::get();
         let new_value = value + 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5281,7 +5316,7 @@ mod benchmarks {
         let expected_value =
             sp_runtime::Perbill::from_percent(new_value) * T::BlockWeights::get().max_block;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5294,7 +5329,7 @@ mod benchmarks {
     fn set_base_node_burn_amount() {
         let new_value = 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5308,7 +5343,7 @@ mod benchmarks {
         let min = 1;
         let max = 2;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5322,7 +5357,7 @@ mod benchmarks {
     fn set_max_subnet_node_min_weight_decrease_reputation_threshold() {
         let new_value = 1;
 
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5340,7 +5375,7 @@ mod benchmarks {
     #[benchmark]
     fn set_validator_reward_k() {
         let new_value = ValidatorRewardK::<T>::get() + 1;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5352,7 +5387,7 @@ mod benchmarks {
     #[benchmark]
     fn set_validator_reward_midpoint() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5364,7 +5399,7 @@ mod benchmarks {
     #[benchmark]
     fn set_attestor_reward_exponent() {
         let new_value = AttestorRewardExponent::<T>::get() + 1;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5376,7 +5411,7 @@ mod benchmarks {
     #[benchmark]
     fn set_attestor_min_reward_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5389,7 +5424,7 @@ mod benchmarks {
     fn set_min_max_node_reputation() {
         let min = 1;
         let max = 2;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5403,7 +5438,7 @@ mod benchmarks {
     fn set_min_max_node_reputation_factor() {
         let min = 1;
         let max = 2;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5416,7 +5451,7 @@ mod benchmarks {
     #[benchmark]
     fn set_min_subnet_reputation() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5428,7 +5463,7 @@ mod benchmarks {
     #[benchmark]
     fn set_not_in_consensus_subnet_reputation_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5440,7 +5475,7 @@ mod benchmarks {
     #[benchmark]
     fn set_max_pause_epochs_subnet_reputation_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5452,7 +5487,7 @@ mod benchmarks {
     #[benchmark]
     fn set_less_than_min_nodes_subnet_reputation_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5467,7 +5502,7 @@ mod benchmarks {
     #[benchmark]
     fn set_validator_proposal_absent_subnet_reputation_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5482,7 +5517,7 @@ mod benchmarks {
     #[benchmark]
     fn set_in_consensus_subnet_reputation_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5494,7 +5529,7 @@ mod benchmarks {
     #[benchmark]
     fn set_overwatch_weight_factor() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5506,7 +5541,7 @@ mod benchmarks {
     #[benchmark]
     fn set_max_emergency_validator_epochs_multiplier() {
         let new_value = 1_000_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5518,7 +5553,7 @@ mod benchmarks {
     #[benchmark]
     fn set_max_emergency_subnet_nodes() {
         let new_value = MinSubnetNodes::<T>::get();
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5530,7 +5565,7 @@ mod benchmarks {
     #[benchmark]
     fn set_overwatch_stake_weight_factor() {
         let new_value = 1_000_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5546,7 +5581,7 @@ mod benchmarks {
             node_count: 300_000_000_000_000_000u128,
             net_flow: 300_000_000_000_000_000u128,
         };
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5559,7 +5594,7 @@ mod benchmarks {
     fn set_churn_limit_multipliers() {
         let min = 1;
         let max = 2;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5572,7 +5607,7 @@ mod benchmarks {
     #[benchmark]
     fn set_default_overwatch_subnet_weight() {
         let new_value = 500_000_000_000_000_000u128;
-        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
@@ -5748,7 +5783,7 @@ mod benchmarks {
                 assert_eq!(*to_subnet_id, starting_to_subnet_id);
                 assert_ne!(*balance, 0);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         let next_id = NextSwapQueueId::<T>::get();
@@ -5787,7 +5822,7 @@ mod benchmarks {
                 assert_ne!(*balance, 0);
                 assert_ne!(*balance, u128::MAX);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
     }
 
@@ -1,2 +1,3 @@
 use super::*;
+pub mod simulation;
 pub mod subnet_validator;
@@ -0,0 +1,237 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Dry-run of consensus proposals, see `simulate_consensus`
+
+use super::*;
+use frame_support::pallet_prelude::DispatchError;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+
+impl<T: Config> Pallet<T> {
+    /// Project the outcome of a consensus proposal without writing to storage
+    ///
+    /// Builds the submission `do_propose_attestation` would store for the elected validator of
+    /// the current subnet epoch, with an attestation from each of `attestor_subnet_node_ids` at
+    /// the current epoch progression. The submission then goes through
+    /// `precheck_subnet_consensus_submission`, `calculate_rewards` and `distribute_rewards`
+    /// inside a storage transaction that is always rolled back.
+    ///
+    /// Rewards are calculated from the emissions of the current epoch, the real distribution uses
+    /// the emissions of the epoch it runs in.
+    ///
+    /// Returns `None` if no validator is elected or the scores overflow.
+    pub fn simulate_consensus(
+        subnet_id: u32,
+        data: Vec<SubnetNodeConsensusData>,
+        attestor_subnet_node_ids: Vec<u32>,
+    ) -> Option<ConsensusSimulation<T::AccountId>> {
+        let subnet_epoch_data = Self::get_current_subnet_epoch_data(subnet_id)?;
+        let subnet_epoch = subnet_epoch_data.subnet_epoch;
+        let subnet_epoch_progression = subnet_epoch_data.subnet_epoch_progression;
+        let current_epoch = Self::get_current_epoch_as_u32();
+        let block = Self::get_current_block_as_u32();
+
+        let validator_subnet_node_id = SubnetElectedValidator::<T>::get(subnet_id, subnet_epoch)?;
+
+        let (data, _) =
+            Self::canonicalize_consensus_data_for_submission(subnet_id, subnet_epoch, data).ok()?;
+
+        let validator_ids = Self::get_consensus_validator_ids(subnet_id);
+        let attestation_weighting = Self::get_attestation_weighting(subnet_id, subnet_epoch);
+        let total_attestation_weight =
+            Self::get_total_attestation_weight(subnet_id, &validator_ids, attestation_weighting);
+
+        // --- Validator auto-attests, attestors outside of the validator set are ignored like
+        // in `do_attest`
+        let mut attests: BTreeMap<u32, AttestEntry<T>> = BTreeMap::from([(
+            validator_subnet_node_id,
+            AttestEntry::<T> {
+                block,
+                attestor_progress: 0,
+                reward_factor: Self::percentage_factor_as_u128(),
+                attestation_weight: Self::get_attestation_weight(
+                    subnet_id,
+                    validator_subnet_node_id,
+                    attestation_weighting,
+                ),
+                data: None,
+            },
+        )]);
+        let attestor_reward_factor = Self::get_attestor_reward_multiplier(subnet_epoch_progression);
+        for subnet_node_id in attestor_subnet_node_ids {
+            if validator_ids.binary_search(&subnet_node_id).is_err() {
+                continue;
+            }
+            attests
+                .entry(subnet_node_id)
+                .or_insert_with(|| AttestEntry::<T> {
+                    block,
+                    attestor_progress: subnet_epoch_progression,
+                    reward_factor: attestor_reward_factor,
+                    attestation_weight: Self::get_attestation_weight(
+                        subnet_id,
+                        subnet_node_id,
+                        attestation_weighting,
+                    ),
+                    data: None,
+                });
+        }
+
+        let subnet_nodes: Vec<SubnetNode<T>> = Self::get_active_classified_subnet_nodes(
+            subnet_id,
+            &SubnetNodeClass::Idle,
+            subnet_epoch,
+        );
+
+        let consensus_data = ConsensusData::<T> {
+            validator_id: validator_subnet_node_id,
+            block,
+            validator_epoch_progress: subnet_epoch_progression,
+            validator_reward_factor: Self::get_validator_reward_multiplier(
+                subnet_epoch_progression,
+            ),
+            attests,
+            attestation_weighting,
+            total_attestation_weight,
+            validator_ids,
+            subnet_nodes: subnet_nodes.clone(),
+            prioritize_queue_node_id: None,
+            remove_queue_node_id: None,
+            data,
+            args: None,
+        };
+
+        let node_state = |subnet_node_id: u32| {
+            (
+                SubnetNodeReputation::<T>::get(subnet_id, subnet_node_id),
+                SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+                    .ok()
+                    .map(|subnet_node| subnet_node.classification.node_class),
+                NodeSubnetStake::<T>::get(subnet_node_id, subnet_id),
+            )
+        };
+
+        let prev_subnet_reputation = SubnetReputation::<T>::get(subnet_id);
+        let prev_nodes: Vec<(u32, u128, SubnetNodeClass, u128)> = subnet_nodes
+            .iter()
+            .map(|subnet_node| {
+                let (reputation, _, stake) = node_state(subnet_node.id);
+                (
+                    subnet_node.id,
+                    reputation.unwrap_or(0),
+                    subnet_node.classification.node_class.clone(),
+                    stake,
+                )
+            })
+            .collect();
+
+        let next_pending_slash_id = NextPendingSlashId::<T>::get();
+
+        with_transaction(|| {
+            SubnetConsensusSubmission::<T>::insert(subnet_id, subnet_epoch, consensus_data);
+
+            let (Some(consensus_submission_data), _) =
+                Self::precheck_subnet_consensus_submission(subnet_id, subnet_epoch, current_epoch)
+            else {
+                return TransactionOutcome::Rollback(Ok(None));
+            };
+
+            let (subnets_emissions, subnet_weight) =
+                match FinalSubnetEmissionWeights::<T>::try_get(current_epoch) {
+                    Ok(weights) => (
+                        weights.subnets_emissions,
+                        weights.subnet_weights.get(&subnet_id).copied().unwrap_or(0),
+                    ),
+                    Err(()) => (0, 0),
+                };
+            let (rewards_data, _) =
+                Self::calculate_rewards(subnet_id, subnets_emissions, subnet_weight);
+
+            let attestation_ratio = consensus_submission_data.attestation_ratio;
+            let weight_sum = consensus_submission_data.weight_sum;
+
+            // Rewards are distributed in the subnet epoch after the proposal
+            Self::distribute_rewards(
+                &mut WeightMeter::new(),
+                subnet_id,
+                block,
+                current_epoch,
+                subnet_epoch.saturating_add(1),
+                consensus_submission_data,
+                rewards_data.clone(),
+                MinAttestationPercentage::<T>::get(),
+                ValidatorReputationIncreaseFactor::<T>::get(),
+                ValidatorReputationDecreaseFactor::<T>::get(),
+                SuperMajorityAttestationRatio::<T>::get(),
+            );
+
+            let Some(rewards) = SubnetRewardsLedger::<T>::get(subnet_id, current_epoch) else {
+                return TransactionOutcome::Rollback(Ok(None));
+            };
+
+            // Slashes queued by the distribution
+            let mut pending_slashes: BTreeMap<u32, u128> = BTreeMap::new();
+            for id in next_pending_slash_id..NextPendingSlashId::<T>::get() {
+                let pending_slash = PendingSlashExecuteEpoch::<T>::get(id)
+                    .and_then(|execute_epoch| PendingSlashes::<T>::get(execute_epoch, id));
+                if let Some(pending_slash) = pending_slash {
+                    let amount = pending_slashes
+                        .entry(pending_slash.subnet_node_id)
+                        .or_insert(0);
+                    *amount = amount.saturating_add(pending_slash.amount);
+                }
+            }
+
+            let nodes = prev_nodes
+                .into_iter()
+                .map(
+                    |(subnet_node_id, prev_reputation, prev_class, prev_stake)| {
+                        let (reputation, class, stake) = node_state(subnet_node_id);
+                        SimulatedNodeOutcome {
+                            subnet_node_id,
+                            prev_reputation,
+                            reputation,
+                            prev_class,
+                            class,
+                            prev_stake,
+                            stake,
+                            pending_slash: pending_slashes
+                                .get(&subnet_node_id)
+                                .copied()
+                                .unwrap_or(0),
+                        }
+                    },
+                )
+                .collect();
+
+            let simulation = ConsensusSimulation {
+                subnet_epoch,
+                validator_subnet_node_id,
+                attestation_ratio,
+                weight_sum,
+                rewards_data,
+                reputation_factors: Self::get_reputation_factors_for_epoch(subnet_id, subnet_epoch),
+                rewards,
+                prev_subnet_reputation,
+                subnet_reputation: SubnetReputation::<T>::get(subnet_id),
+                nodes,
+            };
+
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(Some(simulation)))
+        })
+        .ok()
+        .flatten()
+    }
+}
@@ -17,7 +17,9 @@ use super::*;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::Pays;
 use frame_support::pallet_prelude::Weight;
-use libm::{exp, fmax, fmin};
+
+/// Maximum fallback proposers per subnet epoch, see `FallbackProposerConfig`
+pub const MAX_FALLBACK_PROPOSERS: u32 = 8;
 
 impl<T: Config> Pallet<T> {
     pub(crate) fn canonicalize_consensus_data_entries(
@@ -52,7 +54,7 @@ impl<T: Config> Pallet<T> {
         Ok((canonical_data, weight_sum))
     }
 
-    fn canonicalize_consensus_data_for_submission(
+    pub(crate) fn canonicalize_consensus_data_for_submission(
         subnet_id: u32,
         subnet_epoch: u32,
         data: Vec<SubnetNodeConsensusData>,
@@ -71,17 +73,193 @@ impl<T: Config> Pallet<T> {
         Self::canonicalize_consensus_data_entries(filtered_data)
     }
 
+    /// The consensus data policies of a subnet epoch, the pending policies once their effective
+    /// epoch is reached, otherwise `SubnetConsensusDataPolicies`
+    pub fn get_consensus_data_policies(
+        subnet_id: u32,
+        subnet_epoch: u32,
+    ) -> Vec<ConsensusDataPolicy> {
+        match PendingSubnetConsensusDataPolicies::<T>::get(subnet_id) {
+            Some((effective_subnet_epoch, policies)) if effective_subnet_epoch <= subnet_epoch => {
+                policies
+            }
+            _ => SubnetConsensusDataPolicies::<T>::get(subnet_id),
+        }
+    }
+
+    /// Ensure canonical consensus data satisfies every policy of the subnet epoch, see
+    /// `get_consensus_data_policies`
+    pub(crate) fn validate_consensus_data_policies(
+        subnet_id: u32,
+        subnet_epoch: u32,
+        data: &[SubnetNodeConsensusData],
+        weight_sum: u128,
+    ) -> Result<(), Error<T>> {
+        let scored_nodes = data.iter().filter(|entry| entry.score > 0).count() as u32;
+
+        for policy in Self::get_consensus_data_policies(subnet_id, subnet_epoch) {
+            match policy {
+                ConsensusDataPolicy::MaxScore(max) => ensure!(
+                    data.iter().all(|entry| entry.score <= max),
+                    Error::<T>::ConsensusDataScoreAboveMax
+                ),
+                ConsensusDataPolicy::MinScoredNodes(min) => ensure!(
+                    scored_nodes >= min,
+                    Error::<T>::ConsensusDataTooFewScoredNodes
+                ),
+                ConsensusDataPolicy::NormalizedScores => ensure!(
+                    weight_sum == Self::percentage_factor_as_u128(),
+                    Error::<T>::ConsensusDataScoresNotNormalized
+                ),
+                ConsensusDataPolicy::TopK(k) => ensure!(
+                    scored_nodes <= k,
+                    Error::<T>::ConsensusDataTooManyScoredNodes
+                ),
+                ConsensusDataPolicy::MinIncludedScore(min) => ensure!(
+                    data.iter().all(|entry| entry.score >= min),
+                    Error::<T>::ConsensusDataScoreBelowMin
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn canonicalize_consensus_validator_ids(mut validator_ids: Vec<u32>) -> Vec<u32> {
         validator_ids.sort_unstable();
         validator_ids.dedup();
         validator_ids
     }
 
+    /// The nodes that can attest to a proposal submitted now, the emergency validator set if the
+    /// subnet is temporarily forked
+    pub(crate) fn get_consensus_validator_ids(subnet_id: u32) -> Vec<u32> {
+        let validator_ids: Vec<u32> = if let Some(emergency_validator_data) =
+            EmergencySubnetNodeElectionData::<T>::get(subnet_id)
+        {
+            emergency_validator_data
+                .subnet_node_ids
+                .into_iter()
+                .collect()
+        } else {
+            SubnetNodeElectionSlots::<T>::get(subnet_id)
+        };
+        Self::canonicalize_consensus_validator_ids(validator_ids)
+    }
+
+    /// Ensure a node other than the elected validator can submit a fallback proposal
+    fn ensure_fallback_proposer(
+        subnet_id: u32,
+        subnet_epoch: u32,
+        subnet_epoch_progression: u128,
+        subnet_node_id: u32,
+    ) -> DispatchResult {
+        // --- Only the elected validator can propose if fallback proposers are disabled
+        let config =
+            SubnetFallbackProposers::<T>::get(subnet_id).ok_or(Error::<T>::InvalidSubnetNodeId)?;
+
+        ensure!(
+            subnet_epoch_progression >= config.start_progression,
+            Error::<T>::FallbackProposalsNotOpen
+        );
+
+        ensure!(
+            SubnetFallbackProposerIds::<T>::get(subnet_id, subnet_epoch)
+                .iter()
+                .take(config.max_proposers as usize)
+                .any(|id| *id == subnet_node_id),
+            Error::<T>::NotFallbackProposer
+        );
+
+        ensure!(
+            !SubnetFallbackConsensusSubmissions::<T>::get(subnet_id, subnet_epoch)
+                .iter()
+                .any(|proposal| proposal.validator_id == subnet_node_id),
+            Error::<T>::FallbackAlreadyProposed
+        );
+
+        Ok(())
+    }
+
+    /// Whether the node attested to the elected validator's proposal or any fallback proposal
+    pub(crate) fn has_attested_subnet_epoch(
+        subnet_id: u32,
+        subnet_epoch: u32,
+        subnet_node_id: u32,
+    ) -> bool {
+        SubnetConsensusSubmission::<T>::get(subnet_id, subnet_epoch)
+            .is_some_and(|submission| submission.attests.contains_key(&subnet_node_id))
+            || SubnetFallbackConsensusSubmissions::<T>::get(subnet_id, subnet_epoch)
+                .iter()
+                .any(|proposal| proposal.attests.contains_key(&subnet_node_id))
+    }
+
+    /// Attestation weight of a node under the subnet's `AttestationWeighting`
+    pub(crate) fn get_attestation_weight(
+        subnet_id: u32,
+        subnet_node_id: u32,
+        weighting: AttestationWeighting,
+    ) -> u128 {
+        match weighting {
+            AttestationWeighting::Count => 1,
+            AttestationWeighting::Stake => NodeSubnetStake::<T>::get(subnet_node_id, subnet_id),
+            AttestationWeighting::Reputation => {
+                SubnetNodeReputation::<T>::get(subnet_id, subnet_node_id).unwrap_or(0)
+            }
+        }
+    }
+
+    /// The attestation weighting of a subnet epoch, the pending weighting once its effective
+    /// epoch is reached, otherwise `SubnetAttestationWeighting`
+    pub fn get_attestation_weighting(subnet_id: u32, subnet_epoch: u32) -> AttestationWeighting {
+        match PendingSubnetAttestationWeighting::<T>::get(subnet_id) {
+            Some((effective_subnet_epoch, weighting)) if effective_subnet_epoch <= subnet_epoch => {
+                weighting
+            }
+            _ => SubnetAttestationWeighting::<T>::get(subnet_id),
+        }
+    }
+
+    /// Total attestation weight of a validator set under `weighting`
+    pub(crate) fn get_total_attestation_weight(
+        subnet_id: u32,
+        validator_ids: &[u32],
+        weighting: AttestationWeighting,
+    ) -> u128 {
+        Self::canonicalize_consensus_validator_ids(validator_ids.to_vec())
+            .iter()
+            .fold(0u128, |total_weight, subnet_node_id| {
+                total_weight.saturating_add(Self::get_attestation_weight(
+                    subnet_id,
+                    *subnet_node_id,
+                    weighting,
+                ))
+            })
+    }
+
+    /// Attestation ratio of a proposal over the canonical validator set it was proposed to
+    ///
+    /// Under `AttestationWeighting::Count` this is the share of attestors, otherwise the
+    /// attestors' share of the validator set's stake or reputation. Both sides are taken when
+    /// the proposal is made and attested, so later stake or reputation changes don't move it.
+    pub(crate) fn get_consensus_attestation_ratio(submission: &ConsensusData<T>) -> u128 {
+        let attested_weight = submission.attests.values().fold(0u128, |acc, attest| {
+            acc.saturating_add(attest.attestation_weight)
+        });
+
+        Self::percent_div(attested_weight, submission.total_attestation_weight)
+            .clamp(0, Self::percentage_factor_as_u128())
+    }
+
     /// Proposes attestation and submits consensus data for a subnet epoch.
     ///
     /// This function allows an elected validator to submit consensus data for their subnet,
     /// including peer scores, queue management decisions, and optional attestation data.
     ///
+    /// If the subnet enabled `SubnetFallbackProposers` and the elected validator has not
+    /// proposed by the configured epoch progression, the fallback proposers can each submit a
+    /// competing proposal to `SubnetFallbackConsensusSubmissions`.
+    ///
     /// The validator automatically attests to their own submission.
     ///
     /// # Parameters
@@ -108,12 +286,14 @@ impl<T: Config> Pallet<T> {
     ///
     /// The function performs the following steps:
     /// 1. Determines the current subnet epoch
-    /// 2. Verifies the caller is the elected validator for this epoch
-    /// 3. Ensures consensus has not already been submitted for this epoch
+    /// 2. Verifies the caller is the elected validator, or a fallback proposer, for this epoch
+    /// 3. Ensures consensus has not already been submitted for this epoch and the caller
+    ///    hasn't attested to another proposal
     /// 4. Qualifies the consensus data by:
     ///    - Filtering out non-Included peers
     ///    - Collapsing duplicate subnet node IDs to the lowest submitted score
     ///    - Validating scores don't overflow when summed
+    ///    - Validating the data against the subnet's consensus data policies
     /// 5. Validates queue operations (prioritize/remove) if specified
     /// 6. Stores the consensus submission with the validator's auto-attestation
     ///
@@ -121,8 +301,14 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `NoElectedValidator` - No validator is elected for the current subnet epoch
     /// * `InvalidValidator` - The caller's hotkey doesn't match the elected validator
+    /// * `InvalidSubnetNodeId` - Not the elected validator and fallback proposers are disabled
+    /// * `FallbackProposalsNotOpen` - The fallback start progression has not been reached
+    /// * `NotFallbackProposer` - Not ranked as a fallback proposer this epoch
+    /// * `FallbackAlreadyProposed` - The fallback proposer already proposed this epoch
     /// * `SubnetRewardsAlreadySubmitted` - Consensus has already been submitted for this epoch
+    /// * `AlreadyAttested` - The caller already attested to another proposal this epoch
     /// * `ScoreOverflow` - The sum of all scores would overflow u128
+    /// * `ConsensusData*` - The data violates one of the subnet's `SubnetConsensusDataPolicies`
     ///
     /// # Returns
     ///
@@ -164,10 +350,16 @@ impl<T: Config> Pallet<T> {
         let validator_subnet_node_id = SubnetElectedValidator::<T>::get(subnet_id, subnet_epoch)
             .ok_or(Error::<T>::NoElectedValidator)?;
 
-        ensure!(
-            subnet_node_id == validator_subnet_node_id,
-            Error::<T>::InvalidSubnetNodeId
-        );
+        // --- Nodes other than the elected validator can only propose as a fallback proposer
+        let is_fallback = subnet_node_id != validator_subnet_node_id;
+        if is_fallback {
+            Self::ensure_fallback_proposer(
+                subnet_id,
+                subnet_epoch,
+                subnet_epoch_progression,
+                subnet_node_id,
+            )?;
+        }
 
         // // The elected validator can act with its node-specific override hotkey, or with the
         // // validator hotkey when no node-specific override exists.
@@ -191,26 +383,43 @@ impl<T: Config> Pallet<T> {
             Error::<T>::SubnetRewardsAlreadySubmitted
         );
 
+        // --- Ensure the proposer's auto-attestation is its only attestation this epoch
+        ensure!(
+            !Self::has_attested_subnet_epoch(subnet_id, subnet_epoch, subnet_node_id),
+            Error::<T>::AlreadyAttested
+        );
+
         //
         // --- Qualify the data
         //
 
         // Remove queue classified entries, collapse duplicate node IDs to the lowest score,
         // and ensure the canonical score sum does not overflow.
-        let (data, _) =
+        let (data, weight_sum) =
             Self::canonicalize_consensus_data_for_submission(subnet_id, subnet_epoch, data)?;
 
+        // --- Ensure the data satisfies the subnet owner's validation policies
+        Self::validate_consensus_data_policies(subnet_id, subnet_epoch, &data, weight_sum)?;
+
         let block: u32 = Self::get_current_block_as_u32();
 
+        // --- The weighting attestations of this proposal count under
+        let attestation_weighting = Self::get_attestation_weighting(subnet_id, subnet_epoch);
+
         // --- Validator auto-attests the epoch
         // let attests: BTreeMap<u32, (u32, Option<ValidatorArgs<T>>)> =
         //     BTreeMap::from([(validator_subnet_node_id, (block, attest_data))]);
         let attests: BTreeMap<u32, AttestEntry<T>> = BTreeMap::from([(
-            validator_subnet_node_id,
+            subnet_node_id,
             AttestEntry::<T> {
                 block: block,
                 attestor_progress: 0,
                 reward_factor: Self::percentage_factor_as_u128(),
+                attestation_weight: Self::get_attestation_weight(
+                    subnet_id,
+                    subnet_node_id,
+                    attestation_weighting,
+                ),
                 data: attest_data,
             },
         )]);
@@ -238,17 +447,9 @@ impl<T: Config> Pallet<T> {
         // call of this function as the official point of time of which nodes can attest on this epoch.
         //
         // This is in case the owner "suedo-forks" or pauses the subnet after the validator has submitted their data.
-        let validator_ids: Vec<u32> = if let Some(emergency_validator_data) =
-            EmergencySubnetNodeElectionData::<T>::get(subnet_id)
-        {
-            emergency_validator_data
-                .subnet_node_ids
-                .into_iter()
-                .collect()
-        } else {
-            SubnetNodeElectionSlots::<T>::get(subnet_id)
-        };
-        let validator_ids = Self::canonicalize_consensus_validator_ids(validator_ids);
+        let validator_ids = Self::get_consensus_validator_ids(subnet_id);
+        let total_attestation_weight =
+            Self::get_total_attestation_weight(subnet_id, &validator_ids, attestation_weighting);
 
         // Check if validator sent through queue priority or removal node IDs
         if prioritize_queue_node_id.is_some() || remove_queue_node_id.is_some() {
@@ -291,13 +492,15 @@ impl<T: Config> Pallet<T> {
 
         // Organize all of the data into a ConsensusData<T> struct to be used later for emissions business logic.
         let consensus_data: ConsensusData<T> = ConsensusData::<T> {
-            validator_id: validator_subnet_node_id,
+            validator_id: subnet_node_id,
             block,
             validator_epoch_progress: subnet_epoch_progression,
             validator_reward_factor: Self::get_validator_reward_multiplier(
                 subnet_epoch_progression,
             ),
             attests: attests,
+            attestation_weighting,
+            total_attestation_weight,
             validator_ids,
             subnet_nodes: subnet_nodes,
             prioritize_queue_node_id: prioritize_queue_node_id,
@@ -307,11 +510,69 @@ impl<T: Config> Pallet<T> {
         };
 
         // --- Store the data
-        SubnetConsensusSubmission::<T>::insert(subnet_id, subnet_epoch, consensus_data);
+        if is_fallback {
+            SubnetFallbackConsensusSubmissions::<T>::append(
+                subnet_id,
+                subnet_epoch,
+                consensus_data,
+            );
+
+            Self::deposit_event(Event::FallbackValidatorSubmission {
+                subnet_id: subnet_id,
+                subnet_node_id: subnet_node_id,
+                account_id: hotkey,
+                epoch: subnet_epoch,
+            });
+        } else {
+            SubnetConsensusSubmission::<T>::insert(subnet_id, subnet_epoch, consensus_data);
+
+            Self::deposit_event(Event::ValidatorSubmission {
+                subnet_id: subnet_id,
+                account_id: hotkey,
+                epoch: subnet_epoch,
+            });
+        }
+
+        // If we make it this far, the extrinsic call is free.
+        Ok(Pays::No.into())
+    }
 
-        Self::deposit_event(Event::ValidatorSubmission {
+    pub fn do_attest(
+        hotkey: T::AccountId,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        data: Option<ValidatorArgs<T>>,
+    ) -> DispatchResultWithPostInfo {
+        let subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+
+        Self::ensure_attestor(hotkey, subnet_id, subnet_node_id, subnet_epoch)?;
+
+        // --- Ensure they haven't attested to a fallback proposal
+        ensure!(
+            !SubnetFallbackConsensusSubmissions::<T>::get(subnet_id, subnet_epoch)
+                .iter()
+                .any(|proposal| proposal.attests.contains_key(&subnet_node_id)),
+            Error::<T>::AlreadyAttested
+        );
+
+        let block: u32 = Self::get_current_block_as_u32();
+
+        // We make sure the submission exists in order to attest to it
+        SubnetConsensusSubmission::<T>::try_mutate_exists(
+            subnet_id,
+            subnet_epoch,
+            |maybe_params| -> DispatchResult {
+                let params = maybe_params
+                    .as_mut()
+                    .ok_or(Error::<T>::InvalidSubnetConsensusSubmission)?;
+
+                Self::insert_attestation(subnet_id, subnet_node_id, block, data, params)
+            },
+        )?;
+
+        Self::deposit_event(Event::Attestation {
             subnet_id: subnet_id,
-            account_id: hotkey,
+            subnet_node_id: subnet_node_id,
             epoch: subnet_epoch,
         });
 
@@ -319,14 +580,59 @@ impl<T: Config> Pallet<T> {
         Ok(Pays::No.into())
     }
 
-    pub fn do_attest(
+    /// Attest to a fallback proposal of the current subnet epoch
+    ///
+    /// A node attests to only one proposal per subnet epoch, the elected validator's or one
+    /// fallback proposal.
+    pub fn do_attest_fallback(
         hotkey: T::AccountId,
         subnet_id: u32,
         subnet_node_id: u32,
+        proposer_subnet_node_id: u32,
         data: Option<ValidatorArgs<T>>,
     ) -> DispatchResultWithPostInfo {
         let subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
 
+        Self::ensure_attestor(hotkey, subnet_id, subnet_node_id, subnet_epoch)?;
+
+        ensure!(
+            !Self::has_attested_subnet_epoch(subnet_id, subnet_epoch, subnet_node_id),
+            Error::<T>::AlreadyAttested
+        );
+
+        let block: u32 = Self::get_current_block_as_u32();
+
+        SubnetFallbackConsensusSubmissions::<T>::try_mutate(
+            subnet_id,
+            subnet_epoch,
+            |proposals| -> DispatchResult {
+                let params = proposals
+                    .iter_mut()
+                    .find(|proposal| proposal.validator_id == proposer_subnet_node_id)
+                    .ok_or(Error::<T>::InvalidFallbackProposal)?;
+
+                Self::insert_attestation(subnet_id, subnet_node_id, block, data, params)
+            },
+        )?;
+
+        Self::deposit_event(Event::FallbackAttestation {
+            subnet_id: subnet_id,
+            subnet_node_id: subnet_node_id,
+            proposer_subnet_node_id: proposer_subnet_node_id,
+            epoch: subnet_epoch,
+        });
+
+        // If we make it this far, the extrinsic call is free.
+        Ok(Pays::No.into())
+    }
+
+    /// Ensure the hotkey can attest for the subnet node on the subnet epoch
+    fn ensure_attestor(
+        hotkey: T::AccountId,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        subnet_epoch: u32,
+    ) -> DispatchResult {
         // --- Ensure subnet node is authorized under either its override hotkey or the
         //     validator hotkey when no override exists.
         ensure!(
@@ -355,99 +661,94 @@ impl<T: Config> Pallet<T> {
 
         // - Note: we don't check stake balance here
 
-        let block: u32 = Self::get_current_block_as_u32();
-
-        // We make sure the submission exists in order to attest to it
-        SubnetConsensusSubmission::<T>::try_mutate_exists(
-            subnet_id,
-            subnet_epoch,
-            |maybe_params| -> DispatchResult {
-                let params = maybe_params
-                    .as_mut()
-                    .ok_or(Error::<T>::InvalidSubnetConsensusSubmission)?;
-
-                // Ensure they are in the validator list and are eligible to attest
-                // Only validator classified nodes can attest
-                //
-                // See `do_propose_attestation` for the logic of how the validator set is determined as the
-                // official point of truth.
-                let validator_ids = &mut params.validator_ids;
-                ensure!(
-                    validator_ids
-                        .iter()
-                        .any(|validator_id| *validator_id == subnet_node_id),
-                    Error::<T>::InvalidValidatorId
-                );
-
-                // Get the epoch progression used to determine the reward factor.
-                let proposal_block = params.block;
-                let subnet_epoch_data = Self::attestor_subnet_epoch_data(subnet_id, proposal_block)
-                    .ok_or(Error::<T>::SubnetEpochDataIsNone)?;
-                let subnet_epoch_progression = subnet_epoch_data.subnet_epoch_progression;
-
-                // Get the reward factor.
-                // The longer a node takes to attest, the lower its emissions will be.
-                let reward_factor = Self::get_attestor_reward_multiplier(subnet_epoch_progression);
+        Ok(())
+    }
 
-                let mut attests = &mut params.attests;
+    /// Insert the node's attestation into a proposal
+    fn insert_attestation(
+        subnet_id: u32,
+        subnet_node_id: u32,
+        block: u32,
+        data: Option<ValidatorArgs<T>>,
+        params: &mut ConsensusData<T>,
+    ) -> DispatchResult {
+        // Ensure they are in the validator list and are eligible to attest
+        // Only validator classified nodes can attest
+        //
+        // See `do_propose_attestation` for the logic of how the validator set is determined as the
+        // official point of truth.
+        ensure!(
+            params
+                .validator_ids
+                .iter()
+                .any(|validator_id| *validator_id == subnet_node_id),
+            Error::<T>::InvalidValidatorId
+        );
 
-                // Ensure they haven't attested already
-                ensure!(
-                    attests.insert(
-                        subnet_node_id,
-                        AttestEntry::<T> {
-                            block,
-                            attestor_progress: subnet_epoch_progression,
-                            reward_factor,
-                            data
-                        }
-                    ) == None,
-                    Error::<T>::AlreadyAttested
-                );
+        // Get the epoch progression used to determine the reward factor.
+        let proposal_block = params.block;
+        let subnet_epoch_data = Self::attestor_subnet_epoch_data(subnet_id, proposal_block)
+            .ok_or(Error::<T>::SubnetEpochDataIsNone)?;
+        let subnet_epoch_progression = subnet_epoch_data.subnet_epoch_progression;
 
-                params.attests = attests.clone();
-                Ok(())
-            },
-        )?;
+        // Get the reward factor.
+        // The longer a node takes to attest, the lower its emissions will be.
+        let reward_factor = Self::get_attestor_reward_multiplier(subnet_epoch_progression);
 
-        Self::deposit_event(Event::Attestation {
-            subnet_id: subnet_id,
-            subnet_node_id: subnet_node_id,
-            epoch: subnet_epoch,
-        });
+        // Ensure they haven't attested already
+        ensure!(
+            params
+                .attests
+                .insert(
+                    subnet_node_id,
+                    AttestEntry::<T> {
+                        block,
+                        attestor_progress: subnet_epoch_progression,
+                        reward_factor,
+                        attestation_weight: Self::get_attestation_weight(
+                            subnet_id,
+                            subnet_node_id,
+                            params.attestation_weighting,
+                        ),
+                        data
+                    }
+                )
+                .is_none(),
+            Error::<T>::AlreadyAttested
+        );
 
-        // If we make it this far, the extrinsic call is free.
-        Ok(Pays::No.into())
+        Ok(())
     }
 
     pub fn get_validator_reward_multiplier(progress: u128) -> u128 {
-        Self::get_f64_as_percentage(Self::sigmoid_decreasing(
-            Self::get_percent_as_f64(progress),
-            Self::get_percent_as_f64(ValidatorRewardMidpoint::<T>::get()),
-            ValidatorRewardK::<T>::get() as f64,
-            0.0,
-            1.0,
-        ))
-        .clamp(0, Self::percentage_factor_as_u128())
-
-        // Self::get_f64_as_percentage(Self::sigmoid_decreasing_start_offset(
-        //     Self::get_percent_as_f64(progress),
-        //     Self::get_percent_as_f64(ValidatorRewardMidpoint::<T>::get()),
-        //     ValidatorRewardK::<T>::get() as f64,
-        //     0.05, // x offset (gives leeway for submission so it doesn't need to be on block step 0 to get 100%)
-        //     4.0,
-        // ))
-        // .clamp(0, Self::percentage_factor_as_u128())
+        let percentage_factor = Self::percentage_factor_as_u128();
+
+        Self::sigmoid_decreasing(
+            progress,
+            ValidatorRewardMidpoint::<T>::get(),
+            (ValidatorRewardK::<T>::get() as u128).saturating_mul(percentage_factor),
+            0,
+            percentage_factor,
+        )
+
+        // Self::sigmoid_decreasing_start_offset(
+        //     progress,
+        //     ValidatorRewardMidpoint::<T>::get(),
+        //     (ValidatorRewardK::<T>::get() as u128).saturating_mul(percentage_factor),
+        //     50_000_000_000_000_000, // x offset (gives leeway for submission so it doesn't need to be on block step 0 to get 100%)
+        //     4,
+        // )
     }
 
     pub fn get_attestor_reward_multiplier(progress: u128) -> u128 {
-        Self::get_f64_as_percentage(Self::concave_down_decreasing(
-            Self::get_percent_as_f64(progress),
-            Self::get_percent_as_f64(AttestorMinRewardFactor::<T>::get()),
-            1.0,
-            AttestorRewardExponent::<T>::get() as f64,
-        ))
-        .clamp(0, Self::percentage_factor_as_u128())
+        let percentage_factor = Self::percentage_factor_as_u128();
+
+        Self::concave_down_decreasing(
+            progress,
+            AttestorMinRewardFactor::<T>::get(),
+            percentage_factor,
+            (AttestorRewardExponent::<T>::get() as u128).saturating_mul(percentage_factor),
+        )
     }
 
     /// Return the validators reward that submitted data on the previous epoch
@@ -469,7 +770,10 @@ impl<T: Config> Pallet<T> {
     /// * `min_attestation_percentage` - Blockchains minimum attestation percentage (66%)
     /// * `coldkey_reputation_decrease_factor`: `ValidatorReputationDecreaseFactor`
     /// * `epoch`: The blockchains general epoch
+    /// * `subnet_epoch`: The subnet epoch of the consensus submission
     /// * `validator_non_consensus_reputation_factor`: Resolved subnet node factor for this epoch
+    ///
+    /// The stake slash is queued with `queue_slash`, reputation is decreased immediately.
     pub fn slash_validator(
         subnet_id: u32,
         subnet_node_id: u32,
@@ -479,6 +783,7 @@ impl<T: Config> Pallet<T> {
         min_validator_reputation: u128,
         electable_nodes: u32,
         epoch: u32,
+        subnet_epoch: u32,
         validator_non_consensus_reputation_factor: u128,
     ) -> Weight {
         let mut weight = Weight::zero();
@@ -504,12 +809,14 @@ impl<T: Config> Pallet<T> {
         );
 
         if slash_amount > 0 {
-            // --- Decrease account stake
-            Self::decrease_node_stake(subnet_node_id, subnet_id, slash_amount);
-
-            // NodeSubnetStake | TotalSubnetStake | TotalStake
-            weight = weight.saturating_add(db_weight.writes(3));
-            weight = weight.saturating_add(db_weight.reads(3));
+            // --- Queue the stake slash, the council can cancel it during `SlashAppealEpochs`
+            weight = weight.saturating_add(Self::queue_slash(
+                subnet_id,
+                subnet_node_id,
+                slash_amount,
+                epoch,
+                subnet_epoch,
+            ));
         }
 
         let reputation = SubnetNodeReputation::<T>::get(subnet_id, subnet_node_id).map(|rep| {
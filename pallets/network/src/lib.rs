@@ -49,8 +49,8 @@ use frame_support::{
     ensure,
     storage::bounded_vec::BoundedVec,
     traits::{
-        tokens::WithdrawReasons, Currency, EnsureOrigin, ExistenceRequirement, Get, Randomness,
-        ReservableCurrency,
+        fungible, tokens::WithdrawReasons, Currency, EnsureOrigin, ExistenceRequirement, Get,
+        Randomness, ReservableCurrency,
     },
     weights::WeightMeter,
     PalletId,
@@ -110,6 +110,8 @@ pub mod migrations {
 
     pub struct CleanupStaleValidatorColdkeys<T>(PhantomData<T>);
     pub struct CleanupStaleValidatorHotkeys<T>(PhantomData<T>);
+    pub struct MigrateStakeToHolds<T>(PhantomData<T>);
+    pub struct MigrateAttestationWeights<T>(PhantomData<T>);
 
     pub fn cleanup_stale_validator_coldkeys<T: pallet::Config>() -> Weight {
         let on_chain_version = pallet::Pallet::<T>::on_chain_storage_version();
@@ -202,6 +204,336 @@ pub mod migrations {
             cleanup_stale_validator_hotkeys::<T>()
         }
     }
+
+    /// Maximum positions `step_stake_to_holds` moves to holds in a single block
+    pub const STAKE_HOLDS_MIGRATION_ITEMS_PER_BLOCK: u32 = 256;
+
+    /// Stake used to be withdrawn from the coldkey, every staked, unbonding and queued balance is
+    /// minted back to its owner and placed on hold under the matching reason
+    ///
+    /// The upgrade only starts the migration, the positions are moved over multiple blocks by
+    /// `step_stake_to_holds` from `on_initialize`, see `StakeHoldsMigration`.
+    pub fn migrate_stake_to_holds<T: pallet::Config>() -> Weight {
+        let on_chain_version = pallet::Pallet::<T>::on_chain_storage_version();
+        if on_chain_version >= StorageVersion::new(3) || on_chain_version < StorageVersion::new(2) {
+            return T::DbWeight::get().reads(1);
+        }
+
+        pallet::StakeHoldsMigration::<T>::put(pallet::StakeHoldsMigrationCursor::default());
+
+        StorageVersion::new(3).put::<pallet::Pallet<T>>();
+
+        T::DbWeight::get().reads_writes(1, 2)
+    }
+
+    /// Move the next positions of the running stake to holds migration to holds
+    ///
+    /// Positions are read from the cursor in `StakeHoldsMigration`, one storage map per stage. A
+    /// position that can't be held is counted in `failed` and reported with
+    /// `StakeHoldMigrationFailed`, its counters are left as they are.
+    pub fn step_stake_to_holds<T: pallet::Config>(weight_meter: &mut WeightMeter) {
+        let db_weight = T::DbWeight::get();
+
+        // StakeHoldsMigration
+        weight_meter.consume(db_weight.reads(1));
+
+        let Some(mut cursor) = pallet::StakeHoldsMigration::<T>::get() else {
+            return;
+        };
+
+        // Key lookups, the account and its holds
+        let item_weight = db_weight.reads_writes(5, 2);
+        let limit = (weight_meter.remaining().ref_time() / item_weight.ref_time().max(1))
+            .min(STAKE_HOLDS_MIGRATION_ITEMS_PER_BLOCK as u64) as u32;
+
+        if limit == 0 {
+            return;
+        }
+
+        let mut holds = 0u32;
+        let mut failed = 0u32;
+
+        let mut hold = |who: &T::AccountId, reason: pallet::HoldReason, amount: u128| {
+            if amount == 0 {
+                return;
+            }
+            match pallet::Pallet::<T>::move_stake_hold(who, None, reason, amount) {
+                Ok(()) => holds = holds.saturating_add(1),
+                Err(error) => {
+                    failed = failed.saturating_add(1);
+                    log::error!(
+                        target: "runtime::network",
+                        "stake to holds migration failed for {:?} {:?} {}: {:?}",
+                        who,
+                        reason,
+                        amount,
+                        error
+                    );
+                    pallet::Pallet::<T>::deposit_event(pallet::Event::StakeHoldMigrationFailed {
+                        account_id: who.clone(),
+                        reason,
+                        amount,
+                        error,
+                    });
+                }
+            }
+        };
+
+        let validator_coldkey = |validator_id: Option<u32>| -> Option<T::AccountId> {
+            validator_id.and_then(|validator_id| pallet::ValidatorColdkey::<T>::get(validator_id))
+        };
+
+        let last_key = cursor.last_key.take();
+
+        let (processed, next_key) = match cursor.stage {
+            pallet::StakeHoldsMigrationStage::NodeStake => {
+                let iter = match last_key {
+                    Some(key) => pallet::NodeSubnetStake::<T>::iter_from(key),
+                    None => pallet::NodeSubnetStake::<T>::iter(),
+                };
+                step_prefix(iter, limit, |(subnet_node_id, subnet_id, stake)| {
+                    let validator_id =
+                        pallet::SubnetNodeValidatorId::<T>::get(subnet_id, subnet_node_id);
+                    if let Some(coldkey) = validator_coldkey(validator_id) {
+                        hold(&coldkey, pallet::HoldReason::NodeStake, stake);
+                    }
+                })
+            }
+            pallet::StakeHoldsMigrationStage::DelegateStake => {
+                let iter = match last_key {
+                    Some(key) => pallet::AccountSubnetDelegateStakeShares::<T>::iter_from(key),
+                    None => pallet::AccountSubnetDelegateStakeShares::<T>::iter(),
+                };
+                step_prefix(iter, limit, |(account_id, subnet_id, shares)| {
+                    let balance = pallet::Pallet::<T>::convert_to_balance(
+                        shares,
+                        pallet::TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+                        pallet::TotalSubnetDelegateStakeBalance::<T>::get(subnet_id),
+                    );
+                    hold(&account_id, pallet::HoldReason::DelegateStake, balance);
+                })
+            }
+            pallet::StakeHoldsMigrationStage::ValidatorDelegateStake => {
+                let iter = match last_key {
+                    Some(key) => pallet::AccountValidatorDelegateStakeShares::<T>::iter_from(key),
+                    None => pallet::AccountValidatorDelegateStakeShares::<T>::iter(),
+                };
+                step_prefix(iter, limit, |(account_id, validator_id, shares)| {
+                    let balance = pallet::Pallet::<T>::convert_to_balance(
+                        shares,
+                        pallet::ValidatorDelegateStakeShares::<T>::get(validator_id),
+                        pallet::ValidatorDelegateStakeBalance::<T>::get(validator_id),
+                    );
+                    hold(
+                        &account_id,
+                        pallet::HoldReason::ValidatorDelegateStake,
+                        balance,
+                    );
+                })
+            }
+            pallet::StakeHoldsMigrationStage::OverwatchStake => {
+                let iter = match last_key {
+                    Some(key) => pallet::OverwatchNodeStakeBalance::<T>::iter_from(key),
+                    None => pallet::OverwatchNodeStakeBalance::<T>::iter(),
+                };
+                step_prefix(iter, limit, |(overwatch_node_id, stake)| {
+                    let validator_id =
+                        pallet::OverwatchNodeValidatorId::<T>::get(overwatch_node_id);
+                    if let Some(coldkey) = validator_coldkey(validator_id) {
+                        hold(&coldkey, pallet::HoldReason::OverwatchStake, stake);
+                    }
+                })
+            }
+            pallet::StakeHoldsMigrationStage::Unbonding => {
+                let iter = match last_key {
+                    Some(key) => pallet::StakeUnbondingLedger::<T>::iter_from(key),
+                    None => pallet::StakeUnbondingLedger::<T>::iter(),
+                };
+                step_prefix(iter, limit, |(coldkey, ledger)| {
+                    let total = ledger
+                        .values()
+                        .fold(0u128, |acc, amount| acc.saturating_add(*amount));
+                    hold(&coldkey, pallet::HoldReason::Unbonding, total);
+                })
+            }
+            pallet::StakeHoldsMigrationStage::SwapQueue => {
+                let iter = match last_key {
+                    Some(key) => pallet::SwapCallQueue::<T>::iter_from(key),
+                    None => pallet::SwapCallQueue::<T>::iter(),
+                };
+                step_prefix(iter, limit, |(_, item)| {
+                    if let Some(reason) = item.call.get_hold_reason() {
+                        hold(item.call.get_owner(), reason, item.call.get_queue_balance());
+                    }
+                })
+            }
+        };
+
+        weight_meter.consume(item_weight.saturating_mul(processed as u64));
+
+        cursor.holds = cursor.holds.saturating_add(holds);
+        cursor.failed = cursor.failed.saturating_add(failed);
+
+        match next_key {
+            Some(key) => {
+                cursor.last_key = Some(key);
+                pallet::StakeHoldsMigration::<T>::put(cursor);
+            }
+            None => match cursor.stage.next() {
+                Some(stage) => {
+                    cursor.stage = stage;
+                    pallet::StakeHoldsMigration::<T>::put(cursor);
+                }
+                None => {
+                    pallet::StakeHoldsMigration::<T>::kill();
+                    log::info!(
+                        target: "runtime::network",
+                        "stake to holds migration completed, {} holds, {} failed",
+                        cursor.holds,
+                        cursor.failed
+                    );
+                    pallet::Pallet::<T>::deposit_event(pallet::Event::StakeHoldsMigrated {
+                        holds: cursor.holds,
+                        failed: cursor.failed,
+                    });
+                }
+            },
+        }
+
+        // StakeHoldsMigration
+        weight_meter.consume(db_weight.writes(1));
+    }
+
+    /// Apply `migrate` to up to `limit` items of `iter`
+    ///
+    /// Returns the items processed and the raw key to continue from, `None` once `iter` is done.
+    fn step_prefix<Item>(
+        mut iter: frame_support::storage::PrefixIterator<Item>,
+        limit: u32,
+        mut migrate: impl FnMut(Item),
+    ) -> (u32, Option<Vec<u8>>) {
+        let mut processed = 0u32;
+        while processed < limit {
+            match iter.next() {
+                Some(item) => {
+                    migrate(item);
+                    processed = processed.saturating_add(1);
+                }
+                None => return (processed, None),
+            }
+        }
+        (processed, Some(iter.last_raw_key().to_vec()))
+    }
+
+    impl<T: pallet::Config> OnRuntimeUpgrade for MigrateStakeToHolds<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migrate_stake_to_holds::<T>()
+        }
+    }
+
+    /// `AttestEntry` before `attestation_weight`
+    #[derive(Encode, Decode)]
+    struct OldAttestEntry<T: pallet::Config> {
+        block: u32,
+        attestor_progress: u128,
+        reward_factor: u128,
+        data: Option<pallet::ValidatorArgs<T>>,
+    }
+
+    /// `ConsensusData` before `attestation_weighting` and `total_attestation_weight`
+    #[derive(Encode, Decode)]
+    struct OldConsensusData<T: pallet::Config> {
+        validator_id: u32,
+        block: u32,
+        validator_epoch_progress: u128,
+        validator_reward_factor: u128,
+        validator_ids: Vec<u32>,
+        attests: BTreeMap<u32, OldAttestEntry<T>>,
+        subnet_nodes: Vec<pallet::SubnetNode<T>>,
+        prioritize_queue_node_id: Option<u32>,
+        remove_queue_node_id: Option<u32>,
+        data: Vec<pallet::SubnetNodeConsensusData>,
+        args: Option<pallet::ValidatorArgs<T>>,
+    }
+
+    /// Consensus submissions stored before attestations were weighted get the weights of
+    /// `AttestationWeighting::Count`, so their attestation ratio stays the share of attestors
+    /// they were proposed and attested under
+    pub fn migrate_attestation_weights<T: pallet::Config>() -> Weight {
+        let on_chain_version = pallet::Pallet::<T>::on_chain_storage_version();
+        if on_chain_version != StorageVersion::new(3) {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let weighting = pallet::AttestationWeighting::Count;
+        let mut translated = 0u64;
+
+        pallet::SubnetConsensusSubmission::<T>::translate::<OldConsensusData<T>, _>(
+            |subnet_id, _, old| {
+                translated = translated.saturating_add(1);
+
+                let attests = old
+                    .attests
+                    .into_iter()
+                    .map(|(subnet_node_id, attest)| {
+                        let attestation_weight = pallet::Pallet::<T>::get_attestation_weight(
+                            subnet_id,
+                            subnet_node_id,
+                            weighting,
+                        );
+                        (
+                            subnet_node_id,
+                            pallet::AttestEntry {
+                                block: attest.block,
+                                attestor_progress: attest.attestor_progress,
+                                reward_factor: attest.reward_factor,
+                                attestation_weight,
+                                data: attest.data,
+                            },
+                        )
+                    })
+                    .collect();
+
+                let total_attestation_weight = pallet::Pallet::<T>::get_total_attestation_weight(
+                    subnet_id,
+                    &old.validator_ids,
+                    weighting,
+                );
+
+                Some(pallet::ConsensusData {
+                    validator_id: old.validator_id,
+                    block: old.block,
+                    validator_epoch_progress: old.validator_epoch_progress,
+                    validator_reward_factor: old.validator_reward_factor,
+                    validator_ids: old.validator_ids,
+                    attests,
+                    attestation_weighting: weighting,
+                    total_attestation_weight,
+                    subnet_nodes: old.subnet_nodes,
+                    prioritize_queue_node_id: old.prioritize_queue_node_id,
+                    remove_queue_node_id: old.remove_queue_node_id,
+                    data: old.data,
+                    args: old.args,
+                })
+            },
+        );
+
+        StorageVersion::new(4).put::<pallet::Pallet<T>>();
+
+        log::info!(
+            target: "runtime::network",
+            "attestation weights migration translated {} consensus submissions",
+            translated
+        );
+
+        T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+    }
+
+    impl<T: pallet::Config> OnRuntimeUpgrade for MigrateAttestationWeights<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migrate_attestation_weights::<T>()
+        }
+    }
 }
 
 // mod rewards;
@@ -213,6 +545,7 @@ pub mod pallet {
     // Import various useful types required by all FRAME pallets.
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_support::storage::{with_transaction, TransactionOutcome};
     use frame_support::RuntimeDebugNoBound;
     use frame_system::pallet_prelude::*;
     use sp_std::vec;
@@ -220,7 +553,7 @@ pub mod pallet {
 
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
     // (`Call`s) in this pallet.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -240,7 +573,16 @@ pub mod pallet {
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
 
-        type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId> + Send + Sync;
+        /// Staked and unbonding balances stay on the coldkey through `fungible::MutateHold`
+        type Currency: Currency<Self::AccountId>
+            + ReservableCurrency<Self::AccountId>
+            + fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Send
+            + Sync;
+
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
 
         /// Majority council 2/3s
         type MajorityCollectiveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -266,6 +608,9 @@ pub mod pallet {
 
         type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+        /// Beacon used for validator election, falls back to `Randomness` when it has no seed
+        type RandomnessBeacon: randomness::RandomnessBeacon<Self::Hash>;
+
         #[pallet::constant]
         type TreasuryAccount: Get<Self::AccountId>;
 
@@ -308,6 +653,25 @@ pub mod pallet {
     pub type ValidatorArgs<T> = BoundedVec<u8, <T as Config>::ValidatorArgsLimit>;
     pub type SwapQueueIds<T> = BoundedVec<u32, <T as Config>::MaxSwapQueueLength>;
 
+    /// Reasons for the pallet holding funds on an account
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Subnet node stake of a validator coldkey
+        NodeStake,
+        /// Subnet delegate stake
+        DelegateStake,
+        /// Validator delegate stake
+        ValidatorDelegateStake,
+        /// Overwatch node stake of a validator coldkey
+        OverwatchStake,
+        /// Stake in the unbonding ledger
+        Unbonding,
+        /// Overwatch stake bonded to an open subnet node challenge
+        ChallengeBond,
+        /// Deposit of a scheduled swap queue call, see `SwapQueueDeposit`
+        SwapQueueDeposit,
+    }
+
     /// Events that functions in this pallet can emit.
     ///
     /// Events are a simple means of indicating to the outside world (such as dApps, chain explorers
@@ -462,6 +826,20 @@ pub mod pallet {
             account_id: T::AccountId,
             amount: u128,
         },
+        RewardDestinationSet {
+            account_id: T::AccountId,
+            destination: RewardDestination,
+        },
+        UnbondingRestaked {
+            account_id: T::AccountId,
+            target: RestakeTarget,
+            amount: u128,
+        },
+        UnbondingWithdrawnEarly {
+            account_id: T::AccountId,
+            amount: u128,
+            penalty: u128,
+        },
         // Admin
         SetMaxSubnets(u32),
         SetMaxBootnodes(u32),
@@ -538,11 +916,26 @@ pub mod pallet {
         SetMaxOverwatchNodes(u32),
         SetOverwatchEpochLengthMultiplier(u32),
         SetOverwatchCommitCutoffPercent(u128),
+        SetOverwatchRevealCutoffPercent(u128),
+        SetOverwatchNonRevealReputationFactor(u128),
+        SetOverwatchNonRevealStakePenalty(u128),
+        SetOverwatchWeightClipBand(u128),
+        SetOverwatchClipReputationFactor(u128),
+        SetOverwatchChallengeBond(u128),
+        SetOverwatchChallengeQuorum(u128),
+        SetOverwatchChallengeReputationFactor(u128),
+        SetParameterChangeDelayEpochs(u32),
+        SetSwapQueueDeposit(u128),
+        SetOverwatchWeightQuorum(u128),
         SetOverwatchMinDiversificationRatio(u128),
         SetOverwatchMinRepScore(u128),
         SetOverwatchMinAvgAttestationRatio(u128),
         SetOverwatchMinAge(u32),
         SetOverwatchMinStakeBalance(u128),
+        SetMinBeaconReveals(u32),
+        SetRewardsLedgerRetentionEpochs(u32),
+        SetSlashAppealEpochs(u32),
+        SetEarlyUnbondingPenalty(u128),
         SetTxPause(),
         SetTxUnpause(),
         SetSubnetOwnerPercentage(u128),
@@ -565,11 +958,36 @@ pub mod pallet {
             account_id: T::AccountId,
             epoch: u32,
         },
+        ValidatorElected {
+            subnet_id: u32,
+            subnet_epoch: u32,
+            subnet_node_id: u32,
+        },
         Attestation {
             subnet_id: u32,
             subnet_node_id: u32,
             epoch: u32,
         },
+        /// A fallback proposer submitted a competing proposal
+        FallbackValidatorSubmission {
+            subnet_id: u32,
+            subnet_node_id: u32,
+            account_id: T::AccountId,
+            epoch: u32,
+        },
+        /// A node attested to a fallback proposal
+        FallbackAttestation {
+            subnet_id: u32,
+            subnet_node_id: u32,
+            proposer_subnet_node_id: u32,
+            epoch: u32,
+        },
+        /// A fallback proposal reached super-majority and replaced the elected validator's
+        FallbackProposalSelected {
+            subnet_id: u32,
+            subnet_epoch: u32,
+            subnet_node_id: u32,
+        },
         Slashing {
             subnet_id: u32,
             account_id: T::AccountId,
@@ -587,6 +1005,68 @@ pub mod pallet {
         OverwatchRewards {
             node_rewards: Vec<(u32, u128)>,
         },
+        /// An overwatch node revealed subnet weights outside `OverwatchWeightClipBand` of the
+        /// stake-weighted median
+        OverwatchSubmissionsClipped {
+            overwatch_node_id: u32,
+            overwatch_epoch: u32,
+            subnet_ids: Vec<u32>,
+            ow_score: u128,
+        },
+        /// An overwatch node committed subnet weights it didn't reveal
+        OverwatchNonRevealPenalty {
+            overwatch_node_id: u32,
+            overwatch_epoch: u32,
+            unrevealed_subnet_ids: Vec<u32>,
+            ow_score: u128,
+            stake_penalty: u128,
+        },
+        /// An overwatch node bonded a challenge against a subnet node
+        SubnetNodeChallenged {
+            challenge_id: u32,
+            overwatch_node_id: u32,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            evidence_hash: T::Hash,
+            bond: u128,
+        },
+        OverwatchChallengeVote {
+            challenge_id: u32,
+            overwatch_node_id: u32,
+            approve: bool,
+            stake: u128,
+        },
+        /// A subnet node challenge was resolved, `slashed_bond` is the challenger's burned bond
+        /// if the challenge failed
+        SubnetNodeChallengeResolved {
+            challenge_id: u32,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            passed: bool,
+            approve_stake: u128,
+            reject_stake: u128,
+            slashed_bond: u128,
+        },
+        /// The subnet node of a passed challenge was removed at the epoch boundary
+        ChallengedSubnetNodeRemoved {
+            subnet_id: u32,
+            subnet_node_id: u32,
+        },
+        BeaconRoundFinalized {
+            round: u32,
+            reveals: u32,
+        },
+        BeaconRoundMissed {
+            round: u32,
+            reveals: u32,
+        },
+        /// An overwatch node committed beacon entropy it didn't reveal
+        BeaconNonRevealPenalty {
+            overwatch_node_id: u32,
+            round: u32,
+            ow_score: u128,
+            stake_penalty: u128,
+        },
         SubnetReputationUpdate {
             subnet_id: u32,
             prev_reputation: u128,
@@ -731,6 +1211,40 @@ pub mod pallet {
             owner: T::AccountId,
             value: u32,
         },
+        ConsensusDataPoliciesUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            policies: Vec<ConsensusDataPolicy>,
+            effective_subnet_epoch: u32,
+        },
+        ValidatorElectionModeUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            mode: ValidatorElectionMode,
+        },
+        FallbackProposersUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            config: Option<FallbackProposerConfig>,
+        },
+        AttestationWeightingUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            weighting: AttestationWeighting,
+            effective_subnet_epoch: u32,
+        },
+        NodeConcentrationCapsUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            caps: NodeConcentrationCaps,
+        },
+        /// A node was removed because its validator ID was over the subnet's node concentration
+        /// caps
+        NodeConcentrationCapRemoval {
+            subnet_id: u32,
+            subnet_node_id: u32,
+            validator_id: u32,
+        },
         SubnetNodeMinWeightDecreaseReputationThresholdUpdate {
             subnet_id: u32,
             owner: T::AccountId,
@@ -766,6 +1280,63 @@ pub mod pallet {
             account_id: T::AccountId,
             call: QueuedSwapCall<T::AccountId>,
         },
+        SwapCallCancelled {
+            id: u32,
+            account_id: T::AccountId,
+            call: QueuedSwapCall<T::AccountId>,
+        },
+        SwapCallFailed {
+            id: u32,
+            account_id: T::AccountId,
+            error: DispatchError,
+        },
+        SlashQueued {
+            id: u32,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            amount: u128,
+            execute_epoch: u32,
+        },
+        PendingSlashCancelled {
+            id: u32,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            amount: u128,
+        },
+        PendingSlashApplied {
+            id: u32,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            amount: u128,
+        },
+        ParameterChangeScheduled {
+            id: u32,
+            parameters: Vec<NetworkParameter>,
+            enactment_epoch: u32,
+        },
+        ParameterChangeEnacted {
+            id: u32,
+        },
+        ParameterChangeFailed {
+            id: u32,
+            error: DispatchError,
+        },
+        ParameterChangeFastTracked {
+            id: u32,
+        },
+        ParameterChangeVetoed {
+            id: u32,
+        },
+        StakeHoldMigrationFailed {
+            account_id: T::AccountId,
+            reason: HoldReason,
+            amount: u128,
+            error: DispatchError,
+        },
+        StakeHoldsMigrated {
+            holds: u32,
+            failed: u32,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -850,6 +1421,12 @@ pub mod pallet {
         NotInitialValidatorRegistrationWhitelist,
         MaxRegisteredNodes,
         MaxQueuedNodes,
+        /// The coldkey holds the subnet's maximum nodes per coldkey
+        MaxNodesPerColdkeyReached,
+        /// The validator ID holds the subnet's maximum nodes per validator ID
+        MaxNodesPerValidatorReached,
+        /// Node concentration cap of zero
+        InvalidNodeConcentrationCaps,
         /// Wallet doesn't have enough balance to register subnet
         NotEnoughBalanceToRegisterSubnet,
         UniqueParameterTaken,
@@ -944,6 +1521,8 @@ pub mod pallet {
         MaxUnlockingsReached,
         /// Maximum queued swap calls reached.
         SwapQueueFull,
+        /// Account has `MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT` queued swap calls
+        TooManyQueuedSwapCalls,
         NoStakeUnbondingsOrCooldownNotMet,
         MinDelegateStake,
         /// Elected validator on current epoch cannot unstake to ensure they are able to be rewarded or penalized
@@ -974,6 +1553,36 @@ pub mod pallet {
         AlreadyAttested,
         /// Score overflow
         ScoreOverflow,
+        /// Consensus data policy parameters are invalid, e.g. `TopK(0)`
+        InvalidConsensusDataPolicy,
+        /// The same consensus data policy kind was entered more than once
+        DuplicateConsensusDataPolicy,
+        /// No proposal could satisfy the policies, e.g. `MinScoredNodes` greater than `TopK`
+        ConflictingConsensusDataPolicies,
+        /// A score is above the subnet's `MaxScore` policy
+        ConsensusDataScoreAboveMax,
+        /// Fewer nodes are scored than the subnet's `MinScoredNodes` policy
+        ConsensusDataTooFewScoredNodes,
+        /// Scores do not sum to 1e18 under the subnet's `NormalizedScores` policy
+        ConsensusDataScoresNotNormalized,
+        /// More nodes are scored than the subnet's `TopK` policy
+        ConsensusDataTooManyScoredNodes,
+        /// An `Included` node is scored below the subnet's `MinIncludedScore` policy
+        ConsensusDataScoreBelowMin,
+        /// Weighted election exponent above `MAX_VALIDATOR_ELECTION_EXPONENT` or validator
+        /// probability cap of zero or above 100%
+        InvalidValidatorElectionMode,
+        /// Fallback proposer start progression above 100% or proposer count of zero or above
+        /// `MAX_FALLBACK_PROPOSERS`
+        InvalidFallbackProposerConfig,
+        /// Fallback proposals are not enabled or the epoch progression is before the start
+        FallbackProposalsNotOpen,
+        /// Subnet node is not a fallback proposer of the subnet epoch
+        NotFallbackProposer,
+        /// Fallback proposer already submitted a proposal this subnet epoch
+        FallbackAlreadyProposed,
+        /// No fallback proposal by the proposer on this epoch
+        InvalidFallbackProposal,
         ElectionSlotInsertFail,
         /// Not the key owner
         NotKeyOwner,
@@ -1049,12 +1658,55 @@ pub mod pallet {
         MaxSubnetBootnodeAccess,
         /// Swap call not found under ID
         SwapCallNotFound,
+        /// Only swap calls holding a balance can be updated, scheduled calls must be cancelled
+        SwapCallNotUpdatable,
+        /// Rebalance targets must be unique subnets, not exceed `MAX_REBALANCE_TARGETS`, and
+        /// sum to 100%
+        InvalidRebalanceTargets,
+        /// Rebalance interval must be greater than zero
+        InvalidRebalanceInterval,
         /// Coldkey is blacklisted from being an Overwatch Node
         ColdkeyBlacklisted,
         MultiaddrInvalidVarint,
         MultiaddrInvalidProtocol,
         MultiaddrInvalidAddress,
         MultiaddrTruncated,
+        /// Minimum beacon reveals must be greater than zero
+        InvalidMinBeaconReveals,
+        /// Rewards ledger retention must be greater than zero and not exceed the maximum
+        InvalidRewardsLedgerRetentionEpochs,
+        /// Pending slash not found under ID, it was already applied or cancelled
+        PendingSlashNotFound,
+        /// Stake is locked by a pending slash of the subnet node
+        StakeLockedByPendingSlash,
+        /// Slash appeal window exceeds the maximum
+        InvalidSlashAppealEpochs,
+        /// Unbonding ledger balance is less than the amount
+        NotEnoughUnbondingBalance,
+        /// Overwatch node stake balance is less than `OverwatchChallengeBond`
+        NotEnoughStakeForChallengeBond,
+        /// Subnet node already has an open challenge
+        SubnetNodeAlreadyChallenged,
+        /// Overwatch node already has an open challenge
+        OverwatchChallengeAlreadyOpen,
+        /// Challenge not found under ID, it was already resolved
+        InvalidChallengeId,
+        /// Challenges can only be voted on in the overwatch epoch after they were posted
+        NotChallengeVotingPeriod,
+        /// Challenger cannot vote on its own challenge
+        ChallengerCannotVote,
+        /// Already voted on this challenge
+        AlreadyVoted,
+        /// Parameter value is outside of the bounds of the parameter
+        ParameterOutOfBounds,
+        /// More parameters than `MAX_NETWORK_PARAMETER_UPDATES`
+        TooManyParameters,
+        /// Scheduled parameter change not found under ID, it was already enacted or vetoed
+        ScheduledParameterChangeNotFound,
+        /// The origin already scheduled `MAX_SCHEDULED_PARAMETER_CHANGES` parameter changes
+        TooManyScheduledParameterChanges,
+        /// Stake can't be changed until the stake to holds migration completes
+        StakeHoldsMigrationInProgress,
     }
 
     /// Subnet data
@@ -1489,6 +2141,205 @@ pub mod pallet {
         }
     }
 
+    /// Where an accounts rewards are credited, see `RewardDestinations`
+    ///
+    /// Applies to the node rewards of a validators coldkey and to the rewards allocated to a
+    /// delegate account.
+    #[derive(
+        Default, Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo,
+    )]
+    pub enum RewardDestination {
+        /// Compound into the stake the reward was earned on, node stake for node rewards and
+        /// `DelegateAccountStake` for delegate accounts
+        #[default]
+        Staked,
+        /// Compound into the subnet delegate stake of the subnet ID
+        SubnetDelegateStake(u32),
+        /// Compound into the validator delegate stake of the validator ID
+        ValidatorDelegateStake(u32),
+        /// Pay out to the free balance
+        Free,
+    }
+
+    /// Stake an unbonding balance can be re-staked into, see `restake_unbonding`
+    #[derive(Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum RestakeTarget {
+        /// Node stake of a subnet node owned by the caller
+        SubnetNode { subnet_id: u32, subnet_node_id: u32 },
+        /// Subnet delegate stake of the subnet ID
+        SubnetDelegateStake(u32),
+        /// Validator delegate stake of the validator ID
+        ValidatorDelegateStake(u32),
+    }
+
+    /// Owner-selected rule the consensus data of a `propose_attestation` must satisfy, see
+    /// `owner_update_consensus_data_policies`
+    ///
+    /// Policies are checked against the canonical data, after non-`Included` nodes are filtered
+    /// out and duplicate node IDs are collapsed
+    #[derive(Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum ConsensusDataPolicy {
+        /// No score may be above this value
+        MaxScore(u128),
+        /// At least this many nodes must have a non-zero score
+        MinScoredNodes(u32),
+        /// Scores must sum to exactly 1e18
+        NormalizedScores,
+        /// At most this many nodes may have a non-zero score
+        TopK(u32),
+        /// Every `Included` node in the data must be scored at least this value
+        MinIncludedScore(u128),
+    }
+
+    /// How the validator of a subnet epoch is drawn from the election slots, see
+    /// `owner_update_validator_election_mode`
+    #[derive(
+        Default, Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo,
+    )]
+    pub enum ValidatorElectionMode {
+        /// Every node in the election slots is equally likely
+        #[default]
+        Uniform,
+        /// Nodes are weighted by stake and reputation, see `WeightedValidatorElection`
+        Weighted(WeightedValidatorElection),
+    }
+
+    /// Parameters of `ValidatorElectionMode::Weighted`
+    ///
+    /// A node's weight is `stake^stake_exponent * node_reputation^node_reputation_exponent *
+    /// validator_reputation^validator_reputation_exponent`, where stake is the node's share of
+    /// `TotalSubnetStake` from `NodeSubnetStake`, node reputation is `SubnetNodeReputation` and validator reputation is the coldkey's
+    /// `ValidatorReputation` score. Exponents are in 1e18 precision (1e18 = 1.0), an exponent of
+    /// zero ignores the factor.
+    #[derive(Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct WeightedValidatorElection {
+        pub stake_exponent: u128,
+        pub node_reputation_exponent: u128,
+        pub validator_reputation_exponent: u128,
+        /// Maximum election probability of all nodes of one validator (coldkey) combined, 1e18 =
+        /// 100%. The excess is redistributed to the other validators pro rata.
+        pub max_validator_probability: u128,
+    }
+
+    /// Fallback proposers of a subnet, see `owner_update_fallback_proposers`
+    ///
+    /// If the elected validator has not proposed by `start_progression` of the subnet epoch, the
+    /// next `max_proposers` nodes drawn in the election after it may each propose.
+    #[derive(Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct FallbackProposerConfig {
+        /// Subnet epoch progression fallback proposals open at, 1e18 = 100%
+        pub start_progression: u128,
+        pub max_proposers: u32,
+    }
+
+    /// How attestations are weighted in the attestation ratio of a proposal, see
+    /// `owner_update_attestation_weighting`
+    ///
+    /// The ratio is the attesting nodes' share of the total weight of the proposal's validator
+    /// set, evaluated against `MinAttestationPercentage` and `SuperMajorityAttestationRatio`.
+    #[derive(
+        Default, Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo,
+    )]
+    pub enum AttestationWeighting {
+        /// Every attestation counts the same
+        #[default]
+        Count,
+        /// Attestations are weighted by `NodeSubnetStake`
+        Stake,
+        /// Attestations are weighted by `SubnetNodeReputation`
+        Reputation,
+    }
+
+    /// Per-subnet caps on the nodes a coldkey or validator ID can hold, see
+    /// `owner_update_node_concentration_caps`
+    ///
+    /// Caps count both queued and active nodes on registration, and active nodes on activation
+    /// from the queue. Active nodes over a lowered cap are removed one per subnet epoch.
+    ///
+    /// Each coldkey owns a single validator ID (see `ColdkeyValidatorId`), so the nodes of a
+    /// coldkey are the nodes of its validator ID.
+    #[derive(
+        Default, Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo,
+    )]
+    pub struct NodeConcentrationCaps {
+        pub max_nodes_per_coldkey: Option<u32>,
+        pub max_nodes_per_validator: Option<u32>,
+    }
+
+    /// An overwatch node's revealed subnet weight and its deviation from the subnet's
+    /// stake-weighted median
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct OverwatchNodeWeightDeviation {
+        pub overwatch_node_id: u32,
+        pub weight: u128,
+        pub deviation: u128,
+        pub clipped: bool,
+    }
+
+    /// RPC helper for the overwatch aggregation of a subnet's weight, see
+    /// `get_overwatch_subnet_weight_breakdown`
+    ///
+    /// * `weight` - The stake-weighted median of the revealed weights, `OverwatchSubnetWeights`
+    /// * `clip_band` - The current `OverwatchWeightClipBand` used for `clipped`
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct OverwatchSubnetWeightBreakdown {
+        pub overwatch_epoch: u32,
+        pub subnet_id: u32,
+        pub weight: u128,
+        pub clip_band: u128,
+        pub nodes: Vec<OverwatchNodeWeightDeviation>,
+    }
+
+    /// Active nodes and stake of a coldkey in a subnet
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct ColdkeyConcentration<AccountId> {
+        pub coldkey: AccountId,
+        pub nodes: u32,
+        pub stake: u128,
+    }
+
+    /// RPC helper for the coldkey concentration of a subnet's active nodes, see
+    /// `get_subnet_coldkey_concentration`
+    ///
+    /// * `top_node_share` - Share of the active nodes held by the top-N coldkeys by nodes,
+    ///   1e18 = 100%.
+    /// * `top_stake_share` - Share of the node stake held by the top-N coldkeys by stake.
+    /// * `coldkeys` - The top-N coldkeys by nodes, then stake.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SubnetColdkeyConcentration<AccountId> {
+        pub subnet_id: u32,
+        pub total_nodes: u32,
+        pub total_stake: u128,
+        pub top_node_share: u128,
+        pub top_stake_share: u128,
+        pub coldkeys: Vec<ColdkeyConcentration<AccountId>>,
+    }
+
+    /// RPC helper for the validator set of a subnet
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, scale_info::TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ValidatorsAndAttestors<T: Config> {
+        /// Nodes in the current election slots, or the emergency validator set
+        pub validators: Vec<SubnetNodeInfo<T>>,
+        pub attestation_weighting: AttestationWeighting,
+        /// Share of the attestation weight of each validator, 1e18 = 100%
+        pub attestation_weights: BTreeMap<u32, u128>,
+        /// Effective attestation ratio of the current subnet epoch's proposal, `None` if not
+        /// proposed yet
+        pub attestation_ratio: Option<u128>,
+    }
+
+    /// RPC helper for the elected validator of a subnet epoch
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, scale_info::TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ElectedValidatorInfo<T: Config> {
+        /// The elected validator, `None` if not elected yet
+        pub validator: Option<SubnetNodeInfo<T>>,
+        pub election_mode: ValidatorElectionMode,
+        /// Probability of each node in the current election slots being elected, 1e18 = 100%
+        pub election_probabilities: BTreeMap<u32, u128>,
+    }
+
     #[derive(
         Default,
         Encode,
@@ -1698,6 +2549,40 @@ pub mod pallet {
         pub balance: u128,
     }
 
+    /// RPC helper for paginated queries
+    ///
+    /// # Fields
+    ///
+    /// * `items` - Items of this page.
+    /// * `next_cursor` - Cursor to pass to the next query, `None` if the last page was reached.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct Page<Item, Cursor> {
+        pub items: Vec<Item>,
+        pub next_cursor: Option<Cursor>,
+    }
+
+    /// RPC helper for filtering subnet nodes
+    ///
+    /// Every `Some` field must match for a node to be returned.
+    ///
+    /// # Fields
+    ///
+    /// * `subnet_id` - Only nodes of this subnet.
+    /// * `min_class` - Minimum classification at the current subnet epoch, see `has_classification`.
+    /// * `min_stake` - Minimum node stake balance.
+    /// * `validator_id` - Only nodes owned by this validator.
+    /// * `min_reputation` - Minimum node reputation, inclusive.
+    /// * `max_reputation` - Maximum node reputation, inclusive.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SubnetNodeInfoFilter {
+        pub subnet_id: Option<u32>,
+        pub min_class: Option<SubnetNodeClass>,
+        pub min_stake: Option<u128>,
+        pub validator_id: Option<u32>,
+        pub min_reputation: Option<u128>,
+        pub max_reputation: Option<u128>,
+    }
+
     /// Classification levels for subnet nodes, representing their participation status.
     ///
     /// This enum defines the lifecycle stages of a subnet node, from initial registration
@@ -1882,6 +2767,8 @@ pub mod pallet {
     ///   timing relative to the consensus submission.
     /// * `attestor_progress` - The percentage progress from the validator proposal to when attestor attests
     /// * `reward_factor` - The percentage factor against the reward the attestor receives
+    /// * `attestation_weight` - The attestor's weight under the proposal's
+    ///   `attestation_weighting`, taken when it attests.
     /// * `data` - Optional arbitrary attestation data that the validator can include.
     ///   This data is not used in any onchain logic but allows validators to attach
     ///   metadata, signatures, or other information for off-chain verification or
@@ -1901,6 +2788,7 @@ pub mod pallet {
         pub block: u32,
         pub attestor_progress: u128,
         pub reward_factor: u128,
+        pub attestation_weight: u128,
         pub data: Option<ValidatorArgs<T>>,
     }
 
@@ -2018,6 +2906,10 @@ pub mod pallet {
     ///   validators have attested to this consensus submission. Each entry contains the block
     ///   number when the attestation was made and optional attestation data. The proposing
     ///   validator automatically attests to their own submission upon proposal.
+    /// * `attestation_weighting` - The subnet's `AttestationWeighting` for the epoch, taken at
+    ///   proposal.
+    /// * `total_attestation_weight` - The total attestation weight of `validator_ids`, taken at
+    ///   proposal. The attestation ratio is the attestors' `attestation_weight` over this.
     /// * `subnet_nodes` - A vector of all active subnet nodes that are eligible for consensus
     ///   and rewards at the time of submission. This includes nodes with `Idle` classification
     ///   and above, captured during proposal for efficient processing during reward distribution.
@@ -2053,6 +2945,8 @@ pub mod pallet {
         pub validator_reward_factor: u128,
         pub validator_ids: Vec<u32>, // All validators of the epoch
         pub attests: BTreeMap<u32, AttestEntry<T>>, // Count of attestations of the submitted data (node ID, (block, data))
+        pub attestation_weighting: AttestationWeighting,
+        pub total_attestation_weight: u128,
         pub subnet_nodes: Vec<SubnetNode<T>>,
         pub prioritize_queue_node_id: Option<u32>,
         pub remove_queue_node_id: Option<u32>,
@@ -2121,7 +3015,380 @@ pub mod pallet {
         pub subnet_node_rewards: u128,
     }
 
-    // Overwatch nodes
+    /// A subnet node payout recorded in the rewards ledger
+    ///
+    /// * `subnet_node_id` - The subnet node rewarded.
+    /// * `validator_id` - The validator that owns the subnet node.
+    /// * `reward` - Stake added to the subnet node after delegate cuts.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SubnetNodeRewardRecord {
+        pub subnet_node_id: u32,
+        pub validator_id: u32,
+        pub reward: u128,
+    }
+
+    /// A validator delegate stake pool payout recorded in the rewards ledger
+    ///
+    /// * `validator_id` - The validator whose delegate stake pool was rewarded.
+    /// * `reward` - Balance added to the pool.
+    /// * `total_shares` - Pool shares at the time of the reward.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct ValidatorDelegateStakeRewardRecord {
+        pub validator_id: u32,
+        pub reward: u128,
+        pub total_shares: u128,
+    }
+
+    /// Rewards distributed to a subnet in one epoch, see `SubnetRewardsLedger`
+    ///
+    /// * `rewards_data` - The calculated rewards for the epoch.
+    /// * `attestation_ratio` - Attestation ratio of the consensus submission.
+    /// * `in_consensus` - Whether the submission reached `MinAttestationPercentage`. When false
+    ///   no rewards were paid.
+    /// * `validator_subnet_node_id` - The elected validator subnet node.
+    /// * `validator_id` - The validator that owns the elected subnet node.
+    /// * `validator_reward` - Stake added to the elected validator subnet node.
+    /// * `subnet_owner` - The subnet owner at the time of distribution.
+    /// * `subnet_owner_reward` - Balance paid to the subnet owner.
+    /// * `capacitor_reward` - Rewards held in the `RewardsCapacitor` instead of being paid.
+    /// * `node_rewards` - Subnet node payouts.
+    /// * `delegate_stake_reward` - Balance added to the subnet delegate stake pool.
+    /// * `delegate_stake_total_shares` - Subnet delegate stake pool shares at the time of the
+    ///   reward.
+    /// * `validator_delegate_stake_rewards` - Validator delegate stake pool payouts.
+    /// * `delegate_account_allocations` - Subnet node ID => (delegate account, balance paid).
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SubnetRewardsLedgerEntry<AccountId> {
+        pub rewards_data: RewardsData,
+        pub attestation_ratio: u128,
+        pub in_consensus: bool,
+        pub validator_subnet_node_id: u32,
+        pub validator_id: Option<u32>,
+        pub validator_reward: u128,
+        pub subnet_owner: Option<AccountId>,
+        pub subnet_owner_reward: u128,
+        pub capacitor_reward: u128,
+        pub node_rewards: Vec<SubnetNodeRewardRecord>,
+        pub delegate_stake_reward: u128,
+        pub delegate_stake_total_shares: u128,
+        pub validator_delegate_stake_rewards: Vec<ValidatorDelegateStakeRewardRecord>,
+        pub delegate_account_allocations: Vec<(u32, (AccountId, u128))>,
+    }
+
+    /// Where an account's reward in the rewards ledger came from
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum RewardSource {
+        /// Reward for being the elected validator
+        Validator,
+        /// Reward for being scored in consensus data
+        SubnetNode,
+        /// Subnet owner cut
+        SubnetOwner,
+        /// Delegate account cut of a subnet node reward
+        DelegateAccount,
+        /// Estimated share of the subnet delegate stake pool reward, see `RewardRecord`
+        SubnetDelegateStake,
+        /// Estimated share of a validator delegate stake pool reward, see `RewardRecord`
+        ValidatorDelegateStake,
+    }
+
+    /// A single reward read from the rewards ledger
+    ///
+    /// * `epoch` - The epoch the reward was distributed in.
+    /// * `subnet_id` - The subnet the reward was distributed from.
+    /// * `subnet_node_id` - The subnet node the reward is tied to, if any.
+    /// * `source` - Where the reward came from.
+    /// * `amount` - The reward. Delegate stake rewards are estimated from the account's current
+    ///   shares against the pool shares at the time of the reward, per-account shares aren't
+    ///   recorded in the ledger.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct RewardRecord {
+        pub epoch: u32,
+        pub subnet_id: u32,
+        pub subnet_node_id: Option<u32>,
+        pub source: RewardSource,
+        pub amount: u128,
+    }
+
+    /// Projected effect of a consensus proposal on a subnet node, see `simulate_consensus`
+    ///
+    /// * `subnet_node_id` - The subnet node.
+    /// * `prev_reputation` - Reputation before the rewards distribution.
+    /// * `reputation` - Reputation after, `None` if the node is removed.
+    /// * `prev_class` - Classification before the rewards distribution.
+    /// * `class` - Classification after, `None` if the node is removed.
+    /// * `prev_stake` - Stake before the rewards distribution.
+    /// * `stake` - Stake after, includes rewards and slashes applied immediately.
+    /// * `pending_slash` - Stake slash queued in `PendingSlashes`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SimulatedNodeOutcome {
+        pub subnet_node_id: u32,
+        pub prev_reputation: u128,
+        pub reputation: Option<u128>,
+        pub prev_class: SubnetNodeClass,
+        pub class: Option<SubnetNodeClass>,
+        pub prev_stake: u128,
+        pub stake: u128,
+        pub pending_slash: u128,
+    }
+
+    /// Projected outcome of a consensus proposal, see `simulate_consensus`
+    ///
+    /// * `subnet_epoch` - The subnet epoch the proposal is simulated in.
+    /// * `validator_subnet_node_id` - The elected validator proposing.
+    /// * `attestation_ratio` - Attestation ratio of the hypothetical attestor set.
+    /// * `weight_sum` - Sum of the canonicalized scores.
+    /// * `rewards_data` - Output of `calculate_rewards`.
+    /// * `reputation_factors` - Node reputation factors applied to the subnet epoch.
+    /// * `rewards` - Payouts of the rewards distribution, as recorded in the rewards ledger.
+    /// * `prev_subnet_reputation` - Subnet reputation before the rewards distribution.
+    /// * `subnet_reputation` - Subnet reputation after.
+    /// * `nodes` - Outcome of each subnet node eligible for the proposal.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct ConsensusSimulation<AccountId> {
+        pub subnet_epoch: u32,
+        pub validator_subnet_node_id: u32,
+        pub attestation_ratio: u128,
+        pub weight_sum: u128,
+        pub rewards_data: RewardsData,
+        pub reputation_factors: SubnetReputationFactors,
+        pub rewards: SubnetRewardsLedgerEntry<AccountId>,
+        pub prev_subnet_reputation: u128,
+        pub subnet_reputation: u128,
+        pub nodes: Vec<SimulatedNodeOutcome>,
+    }
+
+    /// Projected yield of a delegate stake, see `get_subnet_delegate_stake_projection` and
+    /// `get_validator_delegate_stake_projection`
+    ///
+    /// Assumes the current emissions and subnet weights hold and the subnets stay in consensus.
+    ///
+    /// * `amount` - The stake the projection is for.
+    /// * `epoch_emissions` - Emissions to all subnets per epoch.
+    /// * `pool_balance` - Balance of the delegate stake pool including `amount`.
+    /// * `pool_epoch_reward` - Reward to the pool per epoch.
+    /// * `epoch_reward` - The share of `amount` in `pool_epoch_reward`.
+    /// * `annual_reward` - `epoch_reward` over a year without compounding.
+    /// * `apr` - `annual_reward` over `amount`, 1e18 is 100%.
+    /// * `apy` - `apr` compounded every epoch, rewards are added to the pool balance.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct DelegateStakeProjection {
+        pub amount: u128,
+        pub epoch_emissions: u128,
+        pub pool_balance: u128,
+        pub pool_epoch_reward: u128,
+        pub epoch_reward: u128,
+        pub annual_reward: u128,
+        pub apr: u128,
+        pub apy: u128,
+    }
+
+    /// Subnet and subnet node lifecycle changes in a block, see `get_subnet_lifecycle_notifications`
+    ///
+    /// Pushed to subnet operators over the RPC pub-sub so node clients can react without polling.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum SubnetLifecycleNotification {
+        /// The subnet was registered, activated, paused or unpaused
+        SubnetStateChanged { subnet_id: u32, state: SubnetState },
+        /// The subnet was removed
+        SubnetRemoved {
+            subnet_id: u32,
+            reason: SubnetRemovalReason,
+        },
+        /// The subnet node entered the active subnet nodes
+        SubnetNodeActivated { subnet_id: u32, subnet_node_id: u32 },
+        /// The subnet node was removed from the subnet
+        SubnetNodeRemoved { subnet_id: u32, subnet_node_id: u32 },
+        /// The subnet node graduated to a new class
+        SubnetNodeClassGraduated {
+            subnet_id: u32,
+            subnet_node_id: u32,
+            classification: SubnetNodeClassification,
+        },
+        /// The subnet node is the elected validator of the subnet epoch
+        ValidatorElected {
+            subnet_id: u32,
+            subnet_epoch: u32,
+            subnet_node_id: u32,
+        },
+        /// The elected validator submitted its proposal, attestations are open for the subnet epoch
+        AttestationWindowOpen { subnet_id: u32, subnet_epoch: u32 },
+    }
+
+    impl SubnetLifecycleNotification {
+        pub fn subnet_id(&self) -> u32 {
+            match self {
+                SubnetLifecycleNotification::SubnetStateChanged { subnet_id, .. }
+                | SubnetLifecycleNotification::SubnetRemoved { subnet_id, .. }
+                | SubnetLifecycleNotification::SubnetNodeActivated { subnet_id, .. }
+                | SubnetLifecycleNotification::SubnetNodeRemoved { subnet_id, .. }
+                | SubnetLifecycleNotification::SubnetNodeClassGraduated { subnet_id, .. }
+                | SubnetLifecycleNotification::ValidatorElected { subnet_id, .. }
+                | SubnetLifecycleNotification::AttestationWindowOpen { subnet_id, .. } => {
+                    *subnet_id
+                }
+            }
+        }
+
+        /// Subnet node the notification is about, `None` if it applies to the whole subnet
+        pub fn subnet_node_id(&self) -> Option<u32> {
+            match self {
+                SubnetLifecycleNotification::SubnetNodeActivated { subnet_node_id, .. }
+                | SubnetLifecycleNotification::SubnetNodeRemoved { subnet_node_id, .. }
+                | SubnetLifecycleNotification::SubnetNodeClassGraduated {
+                    subnet_node_id, ..
+                }
+                | SubnetLifecycleNotification::ValidatorElected { subnet_node_id, .. } => {
+                    Some(*subnet_node_id)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// Tunable network parameter with its new value, see `set_parameter`
+    ///
+    /// Two value variants are `(min, max)`. Variants are SCALE encoded by index, only append new
+    /// variants to the end.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum NetworkParameter {
+        SubnetOwnerPercentage(u128),
+        MaxSubnets(u32),
+        MaxBootnodes(u32),
+        MaxSubnetBootnodeAccess(u32),
+        MaxSubnetPauseEpochs(u32),
+        MinRegistrationCost(u128),
+        RegistrationCostDecayBlocks(u32),
+        RegistrationCostAlpha(u128),
+        NewRegistrationCostMultiplier(u128),
+        MaxMinDelegateStakeMultiplier(u128),
+        ChurnLimits(u32, u32),
+        ChurnLimitMultipliers(u32, u32),
+        QueueEpochs(u32, u32),
+        MinIdleClassificationEpochs(u32),
+        MaxIdleClassificationEpochs(u32),
+        SubnetEnactmentEpochs(u32),
+        IncludedClassificationEpochs(u32, u32),
+        SubnetStakes(u128, u128),
+        DelegateStakePercentages(u128, u128),
+        MinMaxRegisteredNodes(u32, u32),
+        MaxSubnetDelegateStakeRewardsPercentageChange(u128),
+        SubnetDelegateStakeRewardsUpdatePeriod(u32),
+        MinAttestationPercentage(u128),
+        SuperMajorityAttestationRatio(u128),
+        BaseValidatorReward(u128),
+        BaseSlashPercentage(u128),
+        MaxSlashAmount(u128),
+        ValidatorReputationIncreaseFactor(u128),
+        ValidatorReputationDecreaseFactor(u128),
+        NetworkMaxStakeBalance(u128),
+        MinDelegateStakeDeposit(u128),
+        NodeRewardRateUpdatePeriod(u32),
+        MaxRewardRateDecrease(u128),
+        SubnetDistributionPower(u128),
+        DelegateStakeWeightFactor(u128),
+        InflationSigmoidSteepness(u128),
+        MaxOverwatchNodes(u32),
+        OverwatchEpochLengthMultiplier(u32),
+        OverwatchCommitCutoffPercent(u128),
+        OverwatchRevealCutoffPercent(u128),
+        OverwatchNonRevealReputationFactor(u128),
+        OverwatchNonRevealStakePenalty(u128),
+        OverwatchWeightClipBand(u128),
+        OverwatchClipReputationFactor(u128),
+        OverwatchChallengeBond(u128),
+        OverwatchChallengeQuorum(u128),
+        OverwatchChallengeReputationFactor(u128),
+        OverwatchMinDiversificationRatio(u128),
+        OverwatchMinRepScore(u128),
+        OverwatchMinAvgAttestationRatio(u128),
+        OverwatchMinAge(u32),
+        OverwatchMinStakeBalance(u128),
+        MinBeaconReveals(u32),
+        RewardsLedgerRetentionEpochs(u32),
+        SlashAppealEpochs(u32),
+        EarlyUnbondingPenalty(u128),
+        MinMaxSubnetNodes(u32, u32),
+        TxRateLimit(u32),
+        MinSubnetDelegateStakeFactor(u128),
+        MinSubnetRegistrationEpochs(u32),
+        SubnetRegistrationEpochs(u32),
+        MinActiveNodeStakeEpochs(u32),
+        DelegateStakeCooldownEpochs(u32),
+        NodeDelegateStakeCooldownEpochs(u32),
+        StakeCooldownEpochs(u32),
+        MaxUnbondings(u32),
+        InflationSigmoidMidpoint(u128),
+        /// Percentage of the max block weight, 1-100
+        MaximumHooksWeight(u32),
+        BaseNodeBurnAmount(u128),
+        NodeBurnRates(u128, u128),
+        DelegateStakeSubnetRemovalInterval(u32),
+        SubnetRemovalIntervals(u32, u32),
+        SubnetPauseCooldownEpochs(u32),
+        MaxSwapQueueCallsPerBlock(u32),
+        MaxSubnetNodeMinWeightDecreaseReputationThreshold(u128),
+        ValidatorRewardK(u64),
+        ValidatorRewardMidpoint(u128),
+        AttestorRewardExponent(u64),
+        AttestorMinRewardFactor(u128),
+        NodeReputationLimits(u128, u128),
+        NodeReputationFactors(u128, u128),
+        MinSubnetReputation(u128),
+        NotInConsensusSubnetReputationFactor(u128),
+        MaxPauseEpochsSubnetReputationFactor(u128),
+        LessThanMinNodesSubnetReputationFactor(u128),
+        ValidatorAbsentSubnetReputationFactor(u128),
+        InConsensusSubnetReputationFactor(u128),
+        OverwatchWeightFactor(u128),
+        MaxEmergencyValidatorEpochsMultiplier(u128),
+        MaxEmergencySubnetNodes(u32),
+        OverwatchStakeWeightFactor(u128),
+        SubnetWeightFactors(SubnetWeightFactorsData),
+        DefaultOverwatchSubnetWeight(u128),
+        RequireSubnetRegistrationWhitelist(bool),
+        ParameterChangeDelayEpochs(u32),
+        SwapQueueDeposit(u128),
+        OverwatchWeightQuorum(u128),
+    }
+
+    /// Current value and bounds of a `NetworkParameter`, see `get_network_parameters`
+    ///
+    /// # Fields
+    ///
+    /// * `index` - SCALE index of the `NetworkParameter` variant.
+    /// * `name` - Name of the `NetworkParameter` variant.
+    /// * `values` - Current values, `(min, max)` for two value variants.
+    /// * `min` - Inclusive lower bound of each value.
+    /// * `max` - Inclusive upper bound of each value.
+    /// * `super_majority` - Whether updating requires the super majority collective.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct NetworkParameterInfo {
+        pub index: u8,
+        pub name: Vec<u8>,
+        pub values: Vec<u128>,
+        pub min: u128,
+        pub max: u128,
+        pub super_majority: bool,
+    }
+
+    /// Parameter changes waiting for their time lock, see `get_scheduled_parameter_changes`
+    ///
+    /// # Fields
+    ///
+    /// * `id` - Scheduled parameter change ID, see `ScheduledParameterChanges`.
+    /// * `epoch` - The epoch the changes were scheduled in.
+    /// * `enactment_epoch` - The epoch the changes are enacted in unless vetoed.
+    /// * `parameters` - New values and bounds of each parameter.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct ScheduledParameterChangeInfo {
+        pub id: u32,
+        pub epoch: u32,
+        pub enactment_epoch: u32,
+        pub parameters: Vec<NetworkParameterInfo>,
+    }
+
+    // Overwatch nodes
 
     #[derive(
         Default,
@@ -2974,6 +4241,92 @@ pub mod pallet {
         800000000000000000
     }
     /// This type value is referenced in:
+    /// - OverwatchRevealCutoffPercent
+    #[pallet::type_value]
+    pub fn DefaultOverwatchRevealCutoffPercent() -> u128 {
+        // 100%
+        1000000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchNonRevealReputationFactor
+    #[pallet::type_value]
+    pub fn DefaultOverwatchNonRevealReputationFactor() -> u128 {
+        // 10%
+        100000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchNonRevealStakePenalty
+    #[pallet::type_value]
+    pub fn DefaultOverwatchNonRevealStakePenalty() -> u128 {
+        // 1%
+        10000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchWeightClipBand
+    #[pallet::type_value]
+    pub fn DefaultOverwatchWeightClipBand() -> u128 {
+        // 25%
+        250000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchWeightQuorum
+    #[pallet::type_value]
+    pub fn DefaultOverwatchWeightQuorum() -> u128 {
+        // 50%
+        500000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchClipReputationFactor
+    #[pallet::type_value]
+    pub fn DefaultOverwatchClipReputationFactor() -> u128 {
+        // 10%
+        100000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchChallengeBond
+    #[pallet::type_value]
+    pub fn DefaultOverwatchChallengeBond() -> u128 {
+        10e+18 as u128
+    }
+    /// This type value is referenced in:
+    /// - OverwatchChallengeQuorum
+    #[pallet::type_value]
+    pub fn DefaultOverwatchChallengeQuorum() -> u128 {
+        // 50%
+        500000000000000000
+    }
+    /// This type value is referenced in:
+    /// - OverwatchChallengeReputationFactor
+    #[pallet::type_value]
+    pub fn DefaultOverwatchChallengeReputationFactor() -> u128 {
+        // 10%
+        100000000000000000
+    }
+    /// This type value is referenced in:
+    /// - MinBeaconReveals
+    #[pallet::type_value]
+    pub fn DefaultMinBeaconReveals() -> u32 {
+        3
+    }
+    /// This type value is referenced in:
+    /// - RewardsLedgerRetentionEpochs
+    #[pallet::type_value]
+    pub fn DefaultRewardsLedgerRetentionEpochs() -> u32 {
+        256
+    }
+    /// This type value is referenced in:
+    /// - SlashAppealEpochs
+    #[pallet::type_value]
+    pub fn DefaultSlashAppealEpochs() -> u32 {
+        3
+    }
+    /// This type value is referenced in:
+    /// - ParameterChangeDelayEpochs
+    #[pallet::type_value]
+    pub fn DefaultParameterChangeDelayEpochs() -> u32 {
+        48
+    }
+    /// This type value is referenced in:
     /// - OverwatchStakeWeightFactor
     #[pallet::type_value]
     pub fn DefaultOverwatchStakeWeightFactor() -> u128 {
@@ -3052,6 +4405,13 @@ pub mod pallet {
         32
     }
     /// This type value is referenced in:
+    /// - EarlyUnbondingPenalty
+    #[pallet::type_value]
+    pub fn DefaultEarlyUnbondingPenalty() -> u128 {
+        // 10%
+        100000000000000000
+    }
+    /// This type value is referenced in:
     /// - CurrentNodeBurnRate
     /// - MinNodeBurnRate
     #[pallet::type_value]
@@ -3132,6 +4492,12 @@ pub mod pallet {
         16
     }
     /// This type value is referenced in:
+    /// - SwapQueueDeposit
+    #[pallet::type_value]
+    pub fn DefaultSwapQueueDeposit() -> u128 {
+        1000000000000000000
+    }
+    /// This type value is referenced in:
     /// - MaximumHooksWeightV2
     #[pallet::type_value]
     pub fn DefaultMaximumHooksWeightV2<T: Config>() -> Weight {
@@ -3766,6 +5132,48 @@ pub mod pallet {
     pub type QueueImmunityEpochs<T: Config> =
         StorageMap<_, Identity, u32, u32, ValueQuery, DefaultMinRegistrationQueueEpochs>;
 
+    /// Validation policies the consensus data of `propose_attestation` must satisfy for a subnet
+    /// See `owner_update_consensus_data_policies`
+    #[pallet::storage]
+    pub type SubnetConsensusDataPolicies<T: Config> =
+        StorageMap<_, Identity, u32, Vec<ConsensusDataPolicy>, ValueQuery>;
+
+    /// Consensus data policies that replace `SubnetConsensusDataPolicies` from a subnet epoch
+    /// Subnet ID => (Effective subnet epoch, Policies)
+    #[pallet::storage]
+    pub type PendingSubnetConsensusDataPolicies<T: Config> =
+        StorageMap<_, Identity, u32, (u32, Vec<ConsensusDataPolicy>), OptionQuery>;
+
+    /// How the validator is elected each subnet epoch
+    /// See `owner_update_validator_election_mode`
+    #[pallet::storage]
+    pub type SubnetValidatorElectionMode<T: Config> =
+        StorageMap<_, Identity, u32, ValidatorElectionMode, ValueQuery>;
+
+    /// How attestations are weighted in the attestation ratio
+    /// See `owner_update_attestation_weighting`
+    #[pallet::storage]
+    pub type SubnetAttestationWeighting<T: Config> =
+        StorageMap<_, Identity, u32, AttestationWeighting, ValueQuery>;
+
+    /// Attestation weighting that replaces `SubnetAttestationWeighting` from a subnet epoch
+    /// Subnet ID => (Effective subnet epoch, Weighting)
+    #[pallet::storage]
+    pub type PendingSubnetAttestationWeighting<T: Config> =
+        StorageMap<_, Identity, u32, (u32, AttestationWeighting), OptionQuery>;
+
+    /// Caps on the nodes of one coldkey or validator ID
+    /// See `owner_update_node_concentration_caps`
+    #[pallet::storage]
+    pub type SubnetNodeConcentrationCaps<T: Config> =
+        StorageMap<_, Identity, u32, NodeConcentrationCaps, ValueQuery>;
+
+    /// Fallback proposer mode of a subnet, disabled if `None`
+    /// See `owner_update_fallback_proposers`
+    #[pallet::storage]
+    pub type SubnetFallbackProposers<T: Config> =
+        StorageMap<_, Identity, u32, FallbackProposerConfig, OptionQuery>;
+
     /// Min required stake balance for a Subnet Node in a specified subnet
     #[pallet::storage]
     pub type SubnetMinStakeBalance<T> =
@@ -4174,6 +5582,16 @@ pub mod pallet {
     pub type SubnetConsensusSubmission<T: Config> =
         StorageDoubleMap<_, Identity, u32, Identity, u32, ConsensusData<T>>;
 
+    /// Competing proposals of fallback proposers in submission order
+    #[pallet::storage] // subnet ID => epoch  => data
+    pub type SubnetFallbackConsensusSubmissions<T: Config> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, Vec<ConsensusData<T>>, ValueQuery>;
+
+    /// Fallback proposers in the order they were drawn after the elected validator
+    #[pallet::storage] // subnet ID => epoch  => subnet node IDs
+    pub type SubnetFallbackProposerIds<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, Vec<u32>, ValueQuery>;
+
     /// Minimum attestation ratio to form consensus
     #[pallet::storage]
     pub type MinAttestationPercentage<T> =
@@ -4317,6 +5735,11 @@ pub mod pallet {
     pub type DelegateAccountStake<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery, DefaultZeroU128>;
 
+    /// Reward destination of coldkeys and delegate accounts, see `RewardDestination`
+    #[pallet::storage] // account --> destination
+    pub type RewardDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RewardDestination, ValueQuery>;
+
     /// account => { block: balance }
     #[pallet::storage]
     pub type StakeUnbondingLedger<T: Config> = StorageMap<
@@ -4332,6 +5755,11 @@ pub mod pallet {
     #[pallet::storage]
     pub type TotalUnbondingBalance<T> = StorageValue<_, u128, ValueQuery>;
 
+    /// Percentage of an unbonding balance sent to the treasury when withdrawn before its cooldown
+    #[pallet::storage]
+    pub type EarlyUnbondingPenalty<T> =
+        StorageValue<_, u128, ValueQuery, DefaultEarlyUnbondingPenalty>;
+
     /// The number of epochs a node must stay staked as a node from its start_epoch
     /// This only applies to activated nodes
     /// If a node never activates, they can unstake under the standard cooldown period
@@ -4463,6 +5891,57 @@ pub mod pallet {
     pub type OverwatchCommitCutoffPercent<T> =
         StorageValue<_, u128, ValueQuery, DefaultOverwatchCommitCutoffPercent>;
 
+    /// The percent progress of the overwatch interval where the node can no longer reveal
+    /// i.e. Node can reveal from `OverwatchCommitCutoffPercent` up to this percent of the period
+    #[pallet::storage]
+    pub type OverwatchRevealCutoffPercent<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchRevealCutoffPercent>;
+
+    /// The `ow_score` decrease factor of an overwatch node that commits weights it doesn't reveal,
+    /// scaled by the share of its commits left unrevealed
+    #[pallet::storage]
+    pub type OverwatchNonRevealReputationFactor<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchNonRevealReputationFactor>;
+
+    /// The percent of an overwatch node's stake removed when it commits weights it doesn't reveal,
+    /// scaled by the share of its commits left unrevealed
+    #[pallet::storage]
+    pub type OverwatchNonRevealStakePenalty<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchNonRevealStakePenalty>;
+
+    /// The max deviation of an overwatch node's subnet weight from the stake-weighted median
+    /// before the submission is clipped, i.e. excluded from the node's score
+    #[pallet::storage]
+    pub type OverwatchWeightClipBand<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchWeightClipBand>;
+
+    /// The share of the total overwatch stake that must reveal a subnet's weight before the
+    /// stake-weighted median replaces `DefaultOverwatchSubnetWeight` for the subnet
+    #[pallet::storage]
+    pub type OverwatchWeightQuorum<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchWeightQuorum>;
+
+    /// The `ow_score` decrease factor of an overwatch node with clipped submissions, scaled by
+    /// the share of its reveals clipped
+    #[pallet::storage]
+    pub type OverwatchClipReputationFactor<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchClipReputationFactor>;
+
+    /// The overwatch stake bonded to challenge a subnet node, burned if the challenge fails
+    #[pallet::storage]
+    pub type OverwatchChallengeBond<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchChallengeBond>;
+
+    /// The percent of the total overwatch stake that must vote on a challenge for it to pass
+    #[pallet::storage]
+    pub type OverwatchChallengeQuorum<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchChallengeQuorum>;
+
+    /// The validator reputation decrease factor when a challenge against its subnet node passes
+    #[pallet::storage]
+    pub type OverwatchChallengeReputationFactor<T> =
+        StorageValue<_, u128, ValueQuery, DefaultOverwatchChallengeReputationFactor>;
+
     // Overwatch Node ID => OverwatchNode
     #[pallet::storage]
     pub type OverwatchNodes<T: Config> =
@@ -4522,6 +6001,104 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// An overwatch node's bonded challenge against a subnet node, see `challenge_subnet_node`
+    ///
+    /// * `overwatch_node_id` - The challenger.
+    /// * `coldkey` - The challenger's validator coldkey holding the bond.
+    /// * `evidence_hash` - Hash of the off-chain evidence of the subnet node faking work.
+    /// * `bond` - Overwatch stake held until the challenge is resolved.
+    /// * `overwatch_epoch` - The overwatch epoch the challenge was posted in, votes are accepted
+    ///   in the following overwatch epoch.
+    /// * `total_stake` - Total overwatch stake of the `overwatch_epoch` snapshot, the quorum is
+    ///   measured against it.
+    /// * `approve_stake` - Overwatch stake that voted for removing the subnet node.
+    /// * `reject_stake` - Overwatch stake that voted against removing the subnet node.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, scale_info::TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OverwatchChallenge<T: Config> {
+        pub overwatch_node_id: u32,
+        pub coldkey: T::AccountId,
+        pub subnet_id: u32,
+        pub subnet_node_id: u32,
+        pub evidence_hash: T::Hash,
+        pub bond: u128,
+        pub overwatch_epoch: u32,
+        pub total_stake: u128,
+        pub approve_stake: u128,
+        pub reject_stake: u128,
+    }
+
+    /// Open subnet node challenges
+    /// Challenge ID => OverwatchChallenge
+    #[pallet::storage]
+    pub type OverwatchChallenges<T: Config> =
+        StorageMap<_, Identity, u32, OverwatchChallenge<T>, OptionQuery>;
+
+    /// Tracks overwatch challenge IDs
+    #[pallet::storage]
+    pub type NextOverwatchChallengeId<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Overwatch node votes on open challenges
+    /// Challenge ID => Overwatch ID => Approve
+    #[pallet::storage]
+    pub type OverwatchChallengeVotes<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, bool, OptionQuery>;
+
+    /// The open challenge of an overwatch node, limited to one at a time
+    /// Overwatch ID => Challenge ID
+    #[pallet::storage]
+    pub type OverwatchNodeChallengeId<T> = StorageMap<_, Identity, u32, u32, OptionQuery>;
+
+    /// The open challenge against a subnet node
+    /// Subnet ID => Subnet Node ID => Challenge ID
+    #[pallet::storage]
+    pub type SubnetNodeChallengeId<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, u32, OptionQuery>;
+
+    /// Overwatch node stake challenge votes are weighted by, see `snapshot_overwatch_stake`
+    /// Overwatch epoch => Overwatch ID => Stake
+    #[pallet::storage]
+    pub type OverwatchStakeSnapshot<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, u128, ValueQuery>;
+
+    /// Total overwatch stake of each snapshot
+    /// Overwatch epoch => Stake
+    #[pallet::storage]
+    pub type TotalOverwatchStakeSnapshot<T> = StorageMap<_, Identity, u32, u128, ValueQuery>;
+
+    /// Subnet nodes of passed challenges, removed at the next epoch boundary
+    /// Vec<(Subnet ID, Subnet Node ID)>
+    #[pallet::storage]
+    pub type PendingChallengeRemovals<T> = StorageValue<_, Vec<(u32, u32)>, ValueQuery>;
+
+    /// Randomness beacon entropy commits
+    /// Overwatch epoch (round) => Overwatch ID => hash(entropy, overwatch_node_id)
+    #[pallet::storage]
+    pub type BeaconEntropyCommits<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u32, // Round
+        Identity,
+        u32,     // Overwatch ID
+        T::Hash, // Commit
+        OptionQuery,
+    >;
+
+    /// XOR accumulator of revealed beacon entropy and the number of reveals
+    /// Overwatch epoch (round) => (accumulator, reveals)
+    #[pallet::storage]
+    pub type BeaconRoundEntropy<T: Config> =
+        StorageMap<_, Identity, u32, (T::Hash, u32), OptionQuery>;
+
+    /// Finalized beacon seeds, only the last `MAX_BEACON_LOOKBACK_ROUNDS + 1` rounds are kept
+    /// Overwatch epoch (round) => Seed
+    #[pallet::storage]
+    pub type BeaconRandomness<T: Config> = StorageMap<_, Identity, u32, T::Hash, OptionQuery>;
+
+    /// Minimum reveals required to finalize a beacon round
+    #[pallet::storage]
+    pub type MinBeaconReveals<T> = StorageValue<_, u32, ValueQuery, DefaultMinBeaconReveals>;
+
     /// The percentage factor applied to the final overwatch weights for its impact on economic weights (dstake weight and node weight)
     /// Example: `weight = overwatch_weight * factor`
     #[pallet::storage]
@@ -4532,7 +6109,8 @@ pub mod pallet {
     pub type OverwatchStakeWeightFactor<T> =
         StorageValue<_, u128, ValueQuery, DefaultOverwatchStakeWeightFactor>;
 
-    /// Finalized calculated subnet weights from overwatch nodes
+    /// Finalized calculated subnet weights from overwatch nodes, only set once the revealing stake
+    /// reaches `OverwatchWeightQuorum`
     /// Epoch => Subnet ID => Weight
     #[pallet::storage]
     pub type OverwatchSubnetWeights<T> = StorageDoubleMap<
@@ -4628,6 +6206,26 @@ pub mod pallet {
             to_validator_id: u32,
             balance: u128,
         },
+        // schedule_remove_delegate_stake
+        RemoveSubnetDelegateStake {
+            account_id: AccountId,
+            subnet_id: u32,
+            shares: u128,
+        },
+        // schedule_transfer_delegate_stake
+        TransferSubnetDelegateStake {
+            account_id: AccountId,
+            subnet_id: u32,
+            to_account_id: AccountId,
+            shares: u128,
+        },
+        // schedule_rebalance_delegate_stake
+        // Re-queued every `interval_blocks` after each execution
+        RebalanceSubnetDelegateStake {
+            account_id: AccountId,
+            targets: Vec<(u32, u128)>, // (subnet_id, percentage)
+            interval_blocks: u32,
+        },
     }
 
     impl<AccountId> core::fmt::Debug for QueuedSwapCall<AccountId> {
@@ -4653,6 +6251,33 @@ pub mod pallet {
                     .field("to_validator_id", to_validator_id)
                     .field("balance", balance)
                     .finish(),
+                Self::RemoveSubnetDelegateStake {
+                    subnet_id, shares, ..
+                } => fmt
+                    .debug_struct("QueuedSwapCall::RemoveSubnetDelegateStake")
+                    .field("account_id", &"<opaque>")
+                    .field("subnet_id", subnet_id)
+                    .field("shares", shares)
+                    .finish(),
+                Self::TransferSubnetDelegateStake {
+                    subnet_id, shares, ..
+                } => fmt
+                    .debug_struct("QueuedSwapCall::TransferSubnetDelegateStake")
+                    .field("account_id", &"<opaque>")
+                    .field("subnet_id", subnet_id)
+                    .field("to_account_id", &"<opaque>")
+                    .field("shares", shares)
+                    .finish(),
+                Self::RebalanceSubnetDelegateStake {
+                    targets,
+                    interval_blocks,
+                    ..
+                } => fmt
+                    .debug_struct("QueuedSwapCall::RebalanceSubnetDelegateStake")
+                    .field("account_id", &"<opaque>")
+                    .field("targets", targets)
+                    .field("interval_blocks", interval_blocks)
+                    .finish(),
             }
         }
     }
@@ -4681,15 +6306,48 @@ pub mod pallet {
             match self {
                 QueuedSwapCall::SwapToSubnetDelegateStake { balance, .. } => *balance,
                 QueuedSwapCall::SwapToValidatorDelegateStake { balance, .. } => *balance,
+                // Scheduled calls hold shares in the pool until they are executed
+                _ => 0,
             }
         }
-    }
 
-    /// List of current swaps in order
-    #[pallet::storage]
-    pub type SwapQueueOrder<T: Config> = StorageValue<_, SwapQueueIds<T>, ValueQuery>;
+        pub fn get_owner(&self) -> &AccountId {
+            match self {
+                QueuedSwapCall::SwapToSubnetDelegateStake { account_id, .. } => account_id,
+                QueuedSwapCall::SwapToValidatorDelegateStake { account_id, .. } => account_id,
+                QueuedSwapCall::RemoveSubnetDelegateStake { account_id, .. } => account_id,
+                QueuedSwapCall::TransferSubnetDelegateStake { account_id, .. } => account_id,
+                QueuedSwapCall::RebalanceSubnetDelegateStake { account_id, .. } => account_id,
+            }
+        }
 
-    /// Queue to swap between nodes and subnet delegate staking
+        /// Blocks until the call is executed again, `None` if the call is removed once executed
+        pub fn get_recurring_interval(&self) -> Option<u32> {
+            match self {
+                QueuedSwapCall::RebalanceSubnetDelegateStake {
+                    interval_blocks, ..
+                } => Some(*interval_blocks),
+                _ => None,
+            }
+        }
+
+        /// Hold reason of the balance in the queue, see `get_queue_balance`
+        pub fn get_hold_reason(&self) -> Option<HoldReason> {
+            match self {
+                QueuedSwapCall::SwapToSubnetDelegateStake { .. } => Some(HoldReason::DelegateStake),
+                QueuedSwapCall::SwapToValidatorDelegateStake { .. } => {
+                    Some(HoldReason::ValidatorDelegateStake)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// List of current swaps in order
+    #[pallet::storage]
+    pub type SwapQueueOrder<T: Config> = StorageValue<_, SwapQueueIds<T>, ValueQuery>;
+
+    /// Queue to swap between nodes and subnet delegate staking
     #[pallet::storage]
     pub type SwapCallQueue<T: Config> = StorageMap<
         _,
@@ -4708,6 +6366,25 @@ pub mod pallet {
     pub type MaxSwapQueueCallsPerBlock<T> =
         StorageValue<_, u32, ValueQuery, DefaultMaxSwapQueueCallsPerBlock>;
 
+    /// Position in `SwapQueueOrder` the next block starts reading from
+    #[pallet::storage]
+    pub type SwapQueueCursor<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of queued swap calls of an account, see `MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT`
+    /// Account ID => Queued swap calls
+    #[pallet::storage]
+    pub type AccountSwapQueueCalls<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Balance held from the owner of a scheduled swap queue call until it leaves the queue
+    #[pallet::storage]
+    pub type SwapQueueDeposit<T> = StorageValue<_, u128, ValueQuery, DefaultSwapQueueDeposit>;
+
+    /// Deposit held for a queued swap call, see `SwapQueueDeposit`
+    /// Swap queue ID => Deposit
+    #[pallet::storage]
+    pub type SwapCallDeposits<T> = StorageMap<_, Identity, u32, u128, OptionQuery>;
+
     #[pallet::storage]
     pub type MaximumHooksWeightV2<T> =
         StorageValue<_, Weight, ValueQuery, DefaultMaximumHooksWeightV2<T>>;
@@ -4715,6 +6392,174 @@ pub mod pallet {
     #[pallet::storage]
     pub type RewardsCapacitor<T> = StorageMap<_, Identity, u32, u128, ValueQuery, DefaultZeroU128>;
 
+    /// Rewards distributed per subnet per epoch
+    /// Subnet ID => Epoch => Ledger entry
+    #[pallet::storage]
+    pub type SubnetRewardsLedger<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u32, // Subnet ID
+        Identity,
+        u32, // Epoch
+        SubnetRewardsLedgerEntry<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Epochs held in `SubnetRewardsLedger` for a subnet, oldest first
+    #[pallet::storage]
+    pub type SubnetRewardsLedgerEpochs<T> = StorageMap<_, Identity, u32, Vec<u32>, ValueQuery>;
+
+    /// Number of epochs each subnet keeps in `SubnetRewardsLedger`
+    #[pallet::storage]
+    pub type RewardsLedgerRetentionEpochs<T> =
+        StorageValue<_, u32, ValueQuery, DefaultRewardsLedgerRetentionEpochs>;
+
+    //
+    // Pending slashes
+    //
+
+    /// A validator slash waiting for its appeal window to pass, see `queue_slash`
+    ///
+    /// * `subnet_id` - The subnet the validator failed consensus in.
+    /// * `subnet_node_id` - The slashed subnet node.
+    /// * `validator_id` - The validator that owns the subnet node.
+    /// * `amount` - Stake to burn, locked in `NodeSubnetPendingSlash` until applied or cancelled.
+    /// * `epoch` - The epoch the slash was issued in.
+    /// * `subnet_epoch` - The subnet epoch of the consensus submission that caused the slash.
+    /// * `execute_epoch` - The epoch the slash is applied in unless cancelled.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct PendingSlash {
+        pub subnet_id: u32,
+        pub subnet_node_id: u32,
+        pub validator_id: Option<u32>,
+        pub amount: u128,
+        pub epoch: u32,
+        pub subnet_epoch: u32,
+        pub execute_epoch: u32,
+    }
+
+    /// Slashes that can still be cancelled by the council
+    /// Execute epoch => Pending slash ID => Pending slash
+    #[pallet::storage]
+    pub type PendingSlashes<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, PendingSlash, OptionQuery>;
+
+    /// Execute epoch of each pending slash
+    /// Pending slash ID => Execute epoch
+    #[pallet::storage]
+    pub type PendingSlashExecuteEpoch<T> = StorageMap<_, Identity, u32, u32, OptionQuery>;
+
+    /// Last epoch `apply_pending_slashes` applied the pending slashes of
+    #[pallet::storage]
+    pub type PendingSlashesAppliedEpoch<T> = StorageValue<_, u32, OptionQuery>;
+
+    /// Stake of a subnet node locked by its pending slashes, it can't be removed
+    /// Subnet node ID => Subnet ID => Locked stake
+    #[pallet::storage]
+    pub type NodeSubnetPendingSlash<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, u128, ValueQuery>;
+
+    /// Tracks pending slash IDs
+    #[pallet::storage]
+    pub type NextPendingSlashId<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of epochs a validator slash stays pending before its stake is burned
+    ///
+    /// Zero applies slashes immediately.
+    #[pallet::storage]
+    pub type SlashAppealEpochs<T> = StorageValue<_, u32, ValueQuery, DefaultSlashAppealEpochs>;
+
+    //
+    // Scheduled parameter changes
+    //
+
+    /// Parameter changes from the collectives waiting for their time lock, see
+    /// `schedule_parameter_change`
+    ///
+    /// * `parameters` - Parameters and their new values, applied in order.
+    /// * `epoch` - The epoch the changes were scheduled in.
+    /// * `enactment_epoch` - The epoch the changes are enacted in unless vetoed.
+    /// * `super_majority` - Whether the super majority collective scheduled the changes.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct ScheduledParameterChange {
+        pub parameters: Vec<NetworkParameter>,
+        pub epoch: u32,
+        pub enactment_epoch: u32,
+        pub super_majority: bool,
+    }
+
+    /// Parameter changes that can still be fast-tracked or vetoed by the super majority collective
+    /// Scheduled parameter change ID => Scheduled parameter change
+    #[pallet::storage]
+    pub type ScheduledParameterChanges<T> =
+        StorageMap<_, Identity, u32, ScheduledParameterChange, OptionQuery>;
+
+    /// Tracks scheduled parameter change IDs
+    #[pallet::storage]
+    pub type NextScheduledParameterChangeId<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of epochs scheduled parameter changes wait before they are enacted
+    #[pallet::storage]
+    pub type ParameterChangeDelayEpochs<T> =
+        StorageValue<_, u32, ValueQuery, DefaultParameterChangeDelayEpochs>;
+
+    //
+    // Migrations
+    //
+
+    /// Storage map `step_stake_to_holds` is moving to holds, in order
+    #[derive(
+        Default, Copy, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo,
+    )]
+    pub enum StakeHoldsMigrationStage {
+        #[default]
+        NodeStake,
+        DelegateStake,
+        ValidatorDelegateStake,
+        OverwatchStake,
+        Unbonding,
+        SwapQueue,
+    }
+
+    impl StakeHoldsMigrationStage {
+        pub fn next(&self) -> Option<Self> {
+            match self {
+                StakeHoldsMigrationStage::NodeStake => {
+                    Some(StakeHoldsMigrationStage::DelegateStake)
+                }
+                StakeHoldsMigrationStage::DelegateStake => {
+                    Some(StakeHoldsMigrationStage::ValidatorDelegateStake)
+                }
+                StakeHoldsMigrationStage::ValidatorDelegateStake => {
+                    Some(StakeHoldsMigrationStage::OverwatchStake)
+                }
+                StakeHoldsMigrationStage::OverwatchStake => {
+                    Some(StakeHoldsMigrationStage::Unbonding)
+                }
+                StakeHoldsMigrationStage::Unbonding => Some(StakeHoldsMigrationStage::SwapQueue),
+                StakeHoldsMigrationStage::SwapQueue => None,
+            }
+        }
+    }
+
+    /// Progress of the stake to holds migration
+    ///
+    /// * `stage` - Storage map being migrated.
+    /// * `last_key` - Raw key of the last migrated item in `stage`, `None` to start from the first.
+    /// * `holds` - Positions placed on hold so far.
+    /// * `failed` - Positions that could not be placed on hold so far.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct StakeHoldsMigrationCursor {
+        pub stage: StakeHoldsMigrationStage,
+        pub last_key: Option<Vec<u8>>,
+        pub holds: u32,
+        pub failed: u32,
+    }
+
+    /// Running stake to holds migration, see `migrations::step_stake_to_holds`
+    #[pallet::storage]
+    pub type StakeHoldsMigration<T> = StorageValue<_, StakeHoldsMigrationCursor, OptionQuery>;
+
     impl<T: Config> Pallet<T> {
         pub fn ensure_canonical_validator_coldkey(
             coldkey: &T::AccountId,
@@ -5368,6 +7213,7 @@ pub mod pallet {
             max_burn_amount: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
             Self::do_register_subnet_node(
                 origin,
                 validator_id,
@@ -5585,6 +7431,7 @@ pub mod pallet {
             ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_add_node_stake(origin.clone(), subnet_id, subnet_node_id, stake_to_be_added)
         }
@@ -5600,6 +7447,7 @@ pub mod pallet {
             let coldkey: T::AccountId = ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_remove_node_stake(
                 origin.clone(),
@@ -5634,6 +7482,7 @@ pub mod pallet {
             let account_id: T::AccountId = ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             // --- Ensure subnet exists
             ensure!(
@@ -5667,6 +7516,7 @@ pub mod pallet {
             delegate_stake_shares_to_swap: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             // --- Ensure ``to`` subnet exists
             ensure!(
@@ -5704,6 +7554,7 @@ pub mod pallet {
             delegate_stake_shares_to_transfer: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             // Handles ``ensure_signed``
             Self::do_transfer_delegate_stake(
@@ -5734,6 +7585,7 @@ pub mod pallet {
             shares_to_be_removed: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_remove_delegate_stake(origin, subnet_id, shares_to_be_removed)
         }
@@ -5764,6 +7616,7 @@ pub mod pallet {
             let account_id: T::AccountId = ensure_signed(origin)?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             // --- Ensure subnet exists, otherwise at risk of burning tokens
             ensure!(
@@ -5810,6 +7663,7 @@ pub mod pallet {
             delegate_stake_to_be_added: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             ensure!(
                 ValidatorsData::<T>::contains_key(validator_id),
@@ -5828,6 +7682,7 @@ pub mod pallet {
             validator_delegate_stake_shares_to_transfer: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_transfer_validator_delegate_stake(
                 origin,
@@ -5845,6 +7700,7 @@ pub mod pallet {
             validator_delegate_stake_shares_to_be_removed: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_remove_validator_delegate_stake(
                 origin,
@@ -5864,6 +7720,7 @@ pub mod pallet {
             let coldkey: T::AccountId = ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_swap_from_validator_to_validator(
                 origin.clone(),
@@ -5883,6 +7740,7 @@ pub mod pallet {
             let account_id: T::AccountId = ensure_signed(origin)?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             // --- Ensure Subnet Node exists, otherwise at risk of burning tokens
             ensure!(
@@ -5930,6 +7788,7 @@ pub mod pallet {
             node_delegate_stake_shares_to_swap: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             ensure!(
                 SubnetsData::<T>::contains_key(to_subnet_id),
@@ -5953,6 +7812,7 @@ pub mod pallet {
             subnet_delegate_stake_shares_to_swap: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             ensure!(
                 ValidatorsData::<T>::contains_key(to_validator_id),
@@ -5975,6 +7835,7 @@ pub mod pallet {
             new_call: QueuedSwapCall<T::AccountId>,
         ) -> DispatchResult {
             let account_id: T::AccountId = ensure_signed(origin)?;
+            Self::ensure_stake_holds_migrated()?;
             Self::do_update_swap_queue(account_id, id, new_call)
         }
 
@@ -5987,6 +7848,7 @@ pub mod pallet {
             let account_id: T::AccountId = ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_remove_delegate_account_balance(origin, amount_to_remove)
         }
@@ -6004,6 +7866,7 @@ pub mod pallet {
             let coldkey: T::AccountId = ensure_signed(origin)?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             let successful_unbondings: u32 = Self::do_claim_unbondings(&coldkey);
 
@@ -6084,6 +7947,7 @@ pub mod pallet {
             stake_to_be_added: u128,
         ) -> DispatchResult {
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
             Self::do_register_overwatch_node(origin, stake_to_be_added)
         }
 
@@ -6222,6 +8086,7 @@ pub mod pallet {
             let coldkey: T::AccountId = ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             Self::do_add_overwatch_node_stake(origin, overwatch_node_id, stake_to_be_added)
         }
@@ -6236,6 +8101,7 @@ pub mod pallet {
             let coldkey: T::AccountId = ensure_signed(origin.clone())?;
 
             Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
 
             let is_overwatch_node: bool = match OverwatchNodes::<T>::try_get(overwatch_node_id) {
                 Ok(_) => true,
@@ -6320,12 +8186,15 @@ pub mod pallet {
         ///
         #[pallet::call_index(84)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinSubnetDelegateStakeFactor`, removed in spec_version 3"
+        )]
         pub fn set_min_subnet_delegate_stake_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_subnet_delegate_stake_factor(value)
+            Self::do_set_parameter(NetworkParameter::MinSubnetDelegateStakeFactor(value))
         }
 
         /// Set new subnet owner percentage of emissions
@@ -6336,421 +8205,572 @@ pub mod pallet {
         ///
         #[pallet::call_index(85)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetOwnerPercentage`, removed in spec_version 3"
+        )]
         pub fn set_subnet_owner_percentage(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_owner_percentage(value)
+            Self::do_set_parameter(NetworkParameter::SubnetOwnerPercentage(value))
         }
 
         #[pallet::call_index(86)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSubnets`, removed in spec_version 3"
+        )]
         pub fn set_max_subnets(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_subnets(value)
+            Self::do_set_parameter(NetworkParameter::MaxSubnets(value))
         }
 
         #[pallet::call_index(87)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxBootnodes`, removed in spec_version 3"
+        )]
         pub fn set_max_bootnodes(origin: OriginFor<T>, value: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_bootnodes(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxBootnodes(value))
         }
 
         #[pallet::call_index(88)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSubnetBootnodeAccess`, removed in spec_version 3"
+        )]
         pub fn set_max_subnet_bootnodes_access(origin: OriginFor<T>, value: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_subnet_bootnodes_access(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxSubnetBootnodeAccess(value))
         }
 
         #[pallet::call_index(89)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSubnetPauseEpochs`, removed in spec_version 3"
+        )]
         pub fn set_max_pause_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_pause_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxSubnetPauseEpochs(value))
         }
 
         #[pallet::call_index(90)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::DelegateStakeSubnetRemovalInterval`, removed in spec_version 3"
+        )]
         pub fn set_delegate_stake_subnet_removal_interval(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_delegate_stake_subnet_removal_interval(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::DelegateStakeSubnetRemovalInterval(value))
         }
 
         #[pallet::call_index(91)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetRemovalIntervals`, removed in spec_version 3"
+        )]
         pub fn set_subnet_removal_intervals(
             origin: OriginFor<T>,
             min: u32,
             max: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_removal_intervals(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::SubnetRemovalIntervals(min, max))
         }
 
         #[pallet::call_index(92)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetPauseCooldownEpochs`, removed in spec_version 3"
+        )]
         pub fn set_subnet_pause_cooldown_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_pause_cooldown_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::SubnetPauseCooldownEpochs(value))
         }
 
         #[pallet::call_index(93)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinRegistrationCost`, removed in spec_version 3"
+        )]
         pub fn set_min_registration_cost(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_registration_cost(value)
+            Self::do_set_parameter(NetworkParameter::MinRegistrationCost(value))
         }
 
         #[pallet::call_index(94)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::RegistrationCostDecayBlocks`, removed in spec_version 3"
+        )]
         pub fn set_registration_cost_delay_blocks(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_registration_cost_delay_blocks(value)
+            Self::do_set_parameter(NetworkParameter::RegistrationCostDecayBlocks(value))
         }
 
         #[pallet::call_index(95)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::RegistrationCostAlpha`, removed in spec_version 3"
+        )]
         pub fn set_registration_cost_alpha(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_registration_cost_alpha(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::RegistrationCostAlpha(value))
         }
 
         #[pallet::call_index(96)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NewRegistrationCostMultiplier`, removed in spec_version 3"
+        )]
         pub fn set_new_registration_cost_multiplier(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_new_registration_cost_multiplier(value)
+            Self::do_set_parameter(NetworkParameter::NewRegistrationCostMultiplier(value))
         }
 
         #[pallet::call_index(97)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxMinDelegateStakeMultiplier`, removed in spec_version 3"
+        )]
         pub fn set_max_min_delegate_stake_multiplier(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_min_delegate_stake_multiplier(value)
+            Self::do_set_parameter(NetworkParameter::MaxMinDelegateStakeMultiplier(value))
         }
 
         #[pallet::call_index(98)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ChurnLimits`, removed in spec_version 3"
+        )]
         pub fn set_churn_limits(origin: OriginFor<T>, min: u32, max: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_churn_limits(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ChurnLimits(min, max))
         }
 
         #[pallet::call_index(99)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::QueueEpochs`, removed in spec_version 3"
+        )]
         pub fn set_queue_epochs(origin: OriginFor<T>, min: u32, max: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_queue_epochs(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::QueueEpochs(min, max))
         }
 
         #[pallet::call_index(100)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSwapQueueCallsPerBlock`, removed in spec_version 3"
+        )]
         pub fn set_max_swap_queue_calls_per_block(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_swap_queue_calls_per_block(value)
+            Self::do_set_parameter(NetworkParameter::MaxSwapQueueCallsPerBlock(value))
         }
 
         #[pallet::call_index(101)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinIdleClassificationEpochs`, removed in spec_version 3"
+        )]
         pub fn set_min_idle_classification_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_idle_classification_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MinIdleClassificationEpochs(value))
         }
 
         #[pallet::call_index(102)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxIdleClassificationEpochs`, removed in spec_version 3"
+        )]
         pub fn set_max_idle_classification_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_idle_classification_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxIdleClassificationEpochs(value))
         }
 
         #[pallet::call_index(103)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetEnactmentEpochs`, removed in spec_version 3"
+        )]
         pub fn set_subnet_activation_enactment_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_activation_enactment_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::SubnetEnactmentEpochs(value))
         }
 
         #[pallet::call_index(104)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::IncludedClassificationEpochs`, removed in spec_version 3"
+        )]
         pub fn set_included_classification_epochs(
             origin: OriginFor<T>,
             min: u32,
             max: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_included_classification_epochs(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::IncludedClassificationEpochs(min, max))
         }
 
         #[pallet::call_index(105)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetStakes`, removed in spec_version 3"
+        )]
         pub fn set_subnet_stakes(origin: OriginFor<T>, min: u128, max: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_stakes(min, max)
+            Self::do_set_parameter(NetworkParameter::SubnetStakes(min, max))
         }
 
         #[pallet::call_index(106)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::DelegateStakePercentages`, removed in spec_version 3"
+        )]
         pub fn set_delegate_stake_percentages(
             origin: OriginFor<T>,
             min: u128,
             max: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_delegate_stake_percentages(min, max)
+            Self::do_set_parameter(NetworkParameter::DelegateStakePercentages(min, max))
         }
 
         #[pallet::call_index(107)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinMaxRegisteredNodes`, removed in spec_version 3"
+        )]
         pub fn set_min_max_registered_nodes(
             origin: OriginFor<T>,
             min: u32,
             max: u32,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_max_registered_nodes(min, max)
+            Self::do_set_parameter(NetworkParameter::MinMaxRegisteredNodes(min, max))
         }
 
         #[pallet::call_index(108)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange`, removed in spec_version 3"
+        )]
         pub fn set_max_subnet_delegate_stake_rewards_percentage_change(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_subnet_delegate_stake_rewards_percentage_change(value)
+            Self::do_set_parameter(
+                NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(value),
+            )
         }
 
         #[pallet::call_index(109)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod`, removed in spec_version 3"
+        )]
         pub fn set_subnet_delegate_stake_rewards_update_period(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_delegate_stake_rewards_update_period(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod(
+                value,
+            ))
         }
 
         #[pallet::call_index(110)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinAttestationPercentage`, removed in spec_version 3"
+        )]
         pub fn set_min_attestation_percentage(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_attestation_percentage(value)
+            Self::do_set_parameter(NetworkParameter::MinAttestationPercentage(value))
         }
 
         #[pallet::call_index(111)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SuperMajorityAttestationRatio`, removed in spec_version 3"
+        )]
         pub fn set_super_majority_attestation_ratio(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_super_majority_attestation_ratio(value)
+            Self::do_set_parameter(NetworkParameter::SuperMajorityAttestationRatio(value))
         }
 
         #[pallet::call_index(112)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::BaseValidatorReward`, removed in spec_version 3"
+        )]
         pub fn set_base_validator_reward(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_base_validator_reward(value)
+            Self::do_set_parameter(NetworkParameter::BaseValidatorReward(value))
         }
 
         #[pallet::call_index(113)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::BaseSlashPercentage`, removed in spec_version 3"
+        )]
         pub fn set_base_slash_percentage(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_base_slash_percentage(value)
+            Self::do_set_parameter(NetworkParameter::BaseSlashPercentage(value))
         }
 
         #[pallet::call_index(114)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSlashAmount`, removed in spec_version 3"
+        )]
         pub fn set_max_slash_amount(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_slash_amount(value)
+            Self::do_set_parameter(NetworkParameter::MaxSlashAmount(value))
         }
 
         #[pallet::call_index(115)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ValidatorReputationIncreaseFactor`, removed in spec_version 3"
+        )]
         pub fn set_reputation_increase_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_reputation_increase_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ValidatorReputationIncreaseFactor(value))
         }
 
         #[pallet::call_index(116)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ValidatorReputationDecreaseFactor`, removed in spec_version 3"
+        )]
         pub fn set_reputation_decrease_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_reputation_decrease_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ValidatorReputationDecreaseFactor(value))
         }
 
         #[pallet::call_index(117)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NetworkMaxStakeBalance`, removed in spec_version 3"
+        )]
         pub fn set_network_max_stake_balance(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_network_max_stake_balance(value)
+            Self::do_set_parameter(NetworkParameter::NetworkMaxStakeBalance(value))
         }
 
         #[pallet::call_index(118)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinDelegateStakeDeposit`, removed in spec_version 3"
+        )]
         pub fn set_min_delegate_stake_deposit(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_delegate_stake_deposit(value)
+            Self::do_set_parameter(NetworkParameter::MinDelegateStakeDeposit(value))
         }
 
         #[pallet::call_index(119)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NodeRewardRateUpdatePeriod`, removed in spec_version 3"
+        )]
         pub fn set_node_reward_rate_update_period(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_node_reward_rate_update_period(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::NodeRewardRateUpdatePeriod(value))
         }
 
         #[pallet::call_index(120)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxRewardRateDecrease`, removed in spec_version 3"
+        )]
         pub fn set_max_reward_rate_decrease(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_reward_rate_decrease(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxRewardRateDecrease(value))
         }
 
         #[pallet::call_index(121)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetDistributionPower`, removed in spec_version 3"
+        )]
         pub fn set_subnet_distribution_power(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_distribution_power(value)
+            Self::do_set_parameter(NetworkParameter::SubnetDistributionPower(value))
         }
 
         #[pallet::call_index(122)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::DelegateStakeWeightFactor`, removed in spec_version 3"
+        )]
         pub fn set_delegate_stake_weight_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_delegate_stake_weight_factor(value)
+            Self::do_set_parameter(NetworkParameter::DelegateStakeWeightFactor(value))
         }
 
         #[pallet::call_index(123)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::InflationSigmoidSteepness`, removed in spec_version 3"
+        )]
         pub fn set_inflation_sigmoid_steepness(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_inflation_sigmoid_steepness(value)
+            Self::do_set_parameter(NetworkParameter::InflationSigmoidSteepness(value))
         }
 
         #[pallet::call_index(124)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxOverwatchNodes`, removed in spec_version 3"
+        )]
         pub fn set_max_overwatch_nodes(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_overwatch_nodes(value)
+            Self::do_set_parameter(NetworkParameter::MaxOverwatchNodes(value))
         }
 
         #[pallet::call_index(125)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchEpochLengthMultiplier`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_epoch_length_multiplier(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_epoch_length_multiplier(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchEpochLengthMultiplier(value))
         }
 
         #[pallet::call_index(126)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchCommitCutoffPercent`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_commit_cutoff_percent(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_commit_cutoff_percent(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchCommitCutoffPercent(value))
         }
 
         #[pallet::call_index(127)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchMinDiversificationRatio`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_min_diversification_ratio(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_min_diversification_ratio(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchMinDiversificationRatio(value))
         }
 
         #[pallet::call_index(128)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchMinRepScore`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_min_rep_score(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_min_rep_score(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchMinRepScore(value))
         }
 
         #[pallet::call_index(129)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchMinAvgAttestationRatio`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_min_avg_attestation_ratio(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_min_avg_attestation_ratio(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchMinAvgAttestationRatio(value))
         }
 
         #[pallet::call_index(130)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchMinAge`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_min_age(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_min_age(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchMinAge(value))
         }
 
         #[pallet::call_index(131)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchMinStakeBalance`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_min_stake_balance(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_min_stake_balance(value)
+            Self::do_set_parameter(NetworkParameter::OverwatchMinStakeBalance(value))
         }
 
         #[pallet::call_index(132)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinMaxSubnetNodes`, removed in spec_version 3"
+        )]
         pub fn set_min_max_subnet_node(origin: OriginFor<T>, min: u32, max: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_max_subnet_node(min, max)
+            Self::do_set_parameter(NetworkParameter::MinMaxSubnetNodes(min, max))
         }
 
         #[pallet::call_index(133)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::TxRateLimit`, removed in spec_version 3"
+        )]
         pub fn set_tx_rate_limit(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_tx_rate_limit(value)
+            Self::do_set_parameter(NetworkParameter::TxRateLimit(value))
         }
 
         #[pallet::call_index(134)]
@@ -6766,274 +8786,379 @@ pub mod pallet {
 
         #[pallet::call_index(135)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinSubnetRegistrationEpochs`, removed in spec_version 3"
+        )]
         pub fn set_min_subnet_registration_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_subnet_registration_epochs(value)
+            Self::do_set_parameter(NetworkParameter::MinSubnetRegistrationEpochs(value))
         }
 
         #[pallet::call_index(136)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetRegistrationEpochs`, removed in spec_version 3"
+        )]
         pub fn set_subnet_registration_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_registration_epochs(value)
+            Self::do_set_parameter(NetworkParameter::SubnetRegistrationEpochs(value))
         }
 
         #[pallet::call_index(137)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinActiveNodeStakeEpochs`, removed in spec_version 3"
+        )]
         pub fn set_min_active_node_stake_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_active_node_stake_epochs(value)
+            Self::do_set_parameter(NetworkParameter::MinActiveNodeStakeEpochs(value))
         }
 
         #[pallet::call_index(138)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::DelegateStakeCooldownEpochs`, removed in spec_version 3"
+        )]
         pub fn set_delegate_stake_cooldown_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_delegate_stake_cooldown_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::DelegateStakeCooldownEpochs(value))
         }
 
         #[pallet::call_index(139)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NodeDelegateStakeCooldownEpochs`, removed in spec_version 3"
+        )]
         pub fn set_node_delegate_stake_cooldown_epochs(
             origin: OriginFor<T>,
             value: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_node_delegate_stake_cooldown_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::NodeDelegateStakeCooldownEpochs(value))
         }
 
         #[pallet::call_index(140)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::StakeCooldownEpochs`, removed in spec_version 3"
+        )]
         pub fn set_min_stake_cooldown_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_stake_cooldown_epochs(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::StakeCooldownEpochs(value))
         }
 
         #[pallet::call_index(141)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxUnbondings`, removed in spec_version 3"
+        )]
         pub fn set_max_unbondings(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_unbondings(value)
+            Self::do_set_parameter(NetworkParameter::MaxUnbondings(value))
         }
 
         /// Set midpoint on sigmoid for inflation mech
         #[pallet::call_index(142)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::InflationSigmoidMidpoint`, removed in spec_version 3"
+        )]
         pub fn set_sigmoid_midpoint(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_sigmoid_midpoint(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::InflationSigmoidMidpoint(value))
         }
 
         #[pallet::call_index(143)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaximumHooksWeight`, removed in spec_version 3"
+        )]
         pub fn set_maximum_hooks_weight(origin: OriginFor<T>, value: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_maximum_hooks_weight(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaximumHooksWeight(value))
         }
 
         #[pallet::call_index(144)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::BaseNodeBurnAmount`, removed in spec_version 3"
+        )]
         pub fn set_base_node_burn_amount(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_base_node_burn_amount(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::BaseNodeBurnAmount(value))
         }
 
         #[pallet::call_index(145)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NodeBurnRates`, removed in spec_version 3"
+        )]
         pub fn set_node_burn_rates(origin: OriginFor<T>, min: u128, max: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_node_burn_rates(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::NodeBurnRates(min, max))
         }
 
         #[pallet::call_index(146)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold`, removed in spec_version 3"
+        )]
         pub fn set_max_subnet_node_min_weight_decrease_reputation_threshold(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_subnet_node_min_weight_decrease_reputation_threshold(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(
+                NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold(value),
+            )
         }
 
         #[pallet::call_index(147)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ValidatorRewardK`, removed in spec_version 3"
+        )]
         pub fn set_validator_reward_k(origin: OriginFor<T>, value: u64) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_validator_reward_k(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ValidatorRewardK(value))
         }
 
         #[pallet::call_index(148)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ValidatorRewardMidpoint`, removed in spec_version 3"
+        )]
         pub fn set_validator_reward_midpoint(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_validator_reward_midpoint(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ValidatorRewardMidpoint(value))
         }
 
         #[pallet::call_index(149)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::AttestorRewardExponent`, removed in spec_version 3"
+        )]
         pub fn set_attestor_reward_exponent(origin: OriginFor<T>, value: u64) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_attestor_reward_exponent(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::AttestorRewardExponent(value))
         }
 
         #[pallet::call_index(150)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::AttestorMinRewardFactor`, removed in spec_version 3"
+        )]
         pub fn set_attestor_min_reward_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_attestor_min_reward_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::AttestorMinRewardFactor(value))
         }
 
         #[pallet::call_index(151)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NodeReputationLimits`, removed in spec_version 3"
+        )]
         pub fn set_min_max_node_reputation(
             origin: OriginFor<T>,
             min: u128,
             max: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_max_node_reputation(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::NodeReputationLimits(min, max))
         }
 
         #[pallet::call_index(152)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NodeReputationFactors`, removed in spec_version 3"
+        )]
         pub fn set_min_max_node_reputation_factor(
             origin: OriginFor<T>,
             min: u128,
             max: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_max_node_reputation_factor(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::NodeReputationFactors(min, max))
         }
 
         #[pallet::call_index(153)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MinSubnetReputation`, removed in spec_version 3"
+        )]
         pub fn set_min_subnet_reputation(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_min_subnet_reputation(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MinSubnetReputation(value))
         }
 
         #[pallet::call_index(154)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::NotInConsensusSubnetReputationFactor`, removed in spec_version 3"
+        )]
         pub fn set_not_in_consensus_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_not_in_consensus_subnet_reputation_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::NotInConsensusSubnetReputationFactor(
+                value,
+            ))
         }
 
         #[pallet::call_index(155)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxPauseEpochsSubnetReputationFactor`, removed in spec_version 3"
+        )]
         pub fn set_max_pause_epochs_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_pause_epochs_subnet_reputation_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxPauseEpochsSubnetReputationFactor(
+                value,
+            ))
         }
 
         #[pallet::call_index(156)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::LessThanMinNodesSubnetReputationFactor`, removed in spec_version 3"
+        )]
         pub fn set_less_than_min_nodes_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_less_than_min_nodes_subnet_reputation_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::LessThanMinNodesSubnetReputationFactor(
+                value,
+            ))
         }
 
         #[pallet::call_index(157)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ValidatorAbsentSubnetReputationFactor`, removed in spec_version 3"
+        )]
         pub fn set_validator_proposal_absent_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_validator_proposal_absent_subnet_reputation_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ValidatorAbsentSubnetReputationFactor(
+                value,
+            ))
         }
 
         #[pallet::call_index(158)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::InConsensusSubnetReputationFactor`, removed in spec_version 3"
+        )]
         pub fn set_in_consensus_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_in_consensus_subnet_reputation_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::InConsensusSubnetReputationFactor(value))
         }
 
         #[pallet::call_index(159)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchWeightFactor`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_weight_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_weight_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::OverwatchWeightFactor(value))
         }
 
         #[pallet::call_index(160)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxEmergencyValidatorEpochsMultiplier`, removed in spec_version 3"
+        )]
         pub fn set_max_emergency_validator_epochs_multiplier(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_emergency_validator_epochs_multiplier(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(
+                value,
+            ))
         }
 
         #[pallet::call_index(161)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::MaxEmergencySubnetNodes`, removed in spec_version 3"
+        )]
         pub fn set_max_emergency_subnet_nodes(origin: OriginFor<T>, value: u32) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_max_emergency_subnet_nodes(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::MaxEmergencySubnetNodes(value))
         }
 
         #[pallet::call_index(162)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::OverwatchStakeWeightFactor`, removed in spec_version 3"
+        )]
         pub fn set_overwatch_stake_weight_factor(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_overwatch_stake_weight_factor(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::OverwatchStakeWeightFactor(value))
         }
 
         #[pallet::call_index(163)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::SubnetWeightFactors`, removed in spec_version 3"
+        )]
         pub fn set_subnet_weight_factors(
             origin: OriginFor<T>,
             value: SubnetWeightFactorsData,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_subnet_weight_factors(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::SubnetWeightFactors(value))
         }
 
         #[pallet::call_index(164)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::ChurnLimitMultipliers`, removed in spec_version 3"
+        )]
         pub fn set_churn_limit_multipliers(
             origin: OriginFor<T>,
             min: u32,
             max: u32,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_churn_limit_multipliers(min, max)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::ChurnLimitMultipliers(min, max))
         }
 
         #[pallet::call_index(165)]
         #[pallet::weight({0})]
+        #[deprecated(
+            note = "Use `set_parameter` with `NetworkParameter::DefaultOverwatchSubnetWeight`, removed in spec_version 3"
+        )]
         pub fn set_default_overwatch_subnet_weight(
             origin: OriginFor<T>,
             value: u128,
         ) -> DispatchResult {
-            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
-            Self::do_set_default_overwatch_subnet_weight(value)
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(NetworkParameter::DefaultOverwatchSubnetWeight(value))
         }
 
         #[pallet::call_index(166)]
@@ -7081,67 +9206,642 @@ pub mod pallet {
             Self::is_paused()?;
             Self::do_owner_update_reputation_factors(origin, subnet_id, updates)
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Register a new subnet on the network
-        ///
-        /// This function creates a new subnet and enters it into the registration phase.
-        /// After registration, the subnet must accumulate sufficient nodes and delegate stake
-        /// before it can be activated (see `do_activate_subnet`).
+        /// Commit randomness beacon entropy
         ///
         /// # Arguments
         ///
-        /// * `owner` - The account that owns and controls the subnet
-        /// * `max_cost` - Maximum registration cost the owner is willing to pay (slippage protection)
-        /// * `subnet_registration_data` - Subnet configuration data (see `RegistrationSubnetData`)
-        ///
-        /// # Registration Requirements
-        ///
-        /// ## 1. Uniqueness Constraints
+        /// * `overwatch_node_id` - Caller Overwatch Node ID.
+        /// * `commitment` - `hash(entropy, overwatch_node_id)`.
         ///
-        /// - **Unique Name**: Subnet name must not already exist in `SubnetName` storage
-        /// - **Unique Repository**: Repository URL must not already exist in `SubnetRepo` storage
+        /// Returns Ok(Pays::No.into()) on success
         ///
-        /// ## 2. Network Capacity
+        #[pallet::call_index(170)]
+        #[pallet::weight({0})]
+        pub fn commit_beacon_entropy(
+            origin: OriginFor<T>,
+            overwatch_node_id: u32,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Self::is_paused()?;
+            Self::do_commit_beacon_entropy(origin, overwatch_node_id, commitment)
+        }
+
+        /// Reveal randomness beacon entropy
         ///
-        /// - **Max Subnets**: Total registered subnets must be less than `MaxSubnets + 1`
-        ///   - Network allows one extra subnet (n+1) to facilitate subnet rotation
-        ///   - If exceeded, subnets are removed during epoch preliminaries (see `do_epoch_preliminaries`)
+        /// # Arguments
         ///
-        /// ## 3. Bootnode Configuration
+        /// * `overwatch_node_id` - Caller Overwatch Node ID.
+        /// * `entropy` - Entropy committed to in the commit phase of the overwatch epoch.
         ///
-        /// - **Non-Empty**: At least one bootnode must be provided
-        /// - **Count Limit**: Number of bootnodes must not exceed `MaxBootnodes`
-        /// - Bootnodes are P2P network entry points for subnet nodes and overwatchers
+        /// Returns Ok(Pays::No.into()) on success
         ///
-        /// ## 4. Stake Balance Configuration
+        #[pallet::call_index(171)]
+        #[pallet::weight({0})]
+        pub fn reveal_beacon_entropy(
+            origin: OriginFor<T>,
+            overwatch_node_id: u32,
+            entropy: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Self::is_paused()?;
+            Self::do_reveal_beacon_entropy(origin, overwatch_node_id, entropy)
+        }
+
+        /// Cancel a pending validator slash before it is applied
         ///
-        /// ### Minimum Stake Per Node
-        /// - Must be >= `MinSubnetMinStake` (network-wide minimum)
-        /// - Must be <= `MaxSubnetMinStake` (network-wide maximum)
-        /// - This is the minimum stake required for each subnet node
+        /// # Arguments
         ///
-        /// ### Maximum Stake Per Node
-        /// - Must be <= `NetworkMaxStakeBalance` (prevents stake concentration)
-        /// - Must be >= minimum stake (logical consistency)
+        /// * `id` - Pending slash ID, see `PendingSlashes`.
         ///
-        /// ## 5. Delegate Stake Rewards Configuration
+        #[pallet::call_index(174)]
+        #[pallet::weight({0})]
+        pub fn cancel_pending_slash(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_cancel_pending_slash(id)
+        }
+
+        /// Schedule a subnet delegate stake removal through the swap queue
         ///
-        /// - **Percentage Range**: Must be between `MinDelegateStakePercentage` and `MaxDelegateStakePercentage`
-        /// - **Percentage Cap**: Must not exceed 100% (`percentage_factor_as_u128()`)
-        /// - Determines what percentage of subnet emissions go to delegate stakers vs node operators
+        /// # Arguments
         ///
-        /// ## 6. Initial Node Operators (Whitelist)
+        /// * `subnet_id` - Subnet ID to remove stake from.
+        /// * `shares` - Shares of the subnet delegate stake pool to remove.
+        /// * `execute_after_blocks` - Blocks to wait before the removal is executed.
         ///
-        /// - **Minimum Count**: At least `MinSubnetNodes` coldkeys must be whitelisted
-        /// - **Registration Slots**: Each coldkey must be allocated at least 1 registration slot
-        /// - During registration period, only these coldkeys can register nodes
-        /// - Removed upon activation (see `do_activate_subnet`)
+        /// # Requirements
         ///
-        /// ## 7. Registration Cost (Dynamic Pricing)
+        /// * Caller must own at least `shares` when the call is queued and when it is executed.
+        /// * Caller must have `SwapQueueDeposit` free, it's held until the call leaves the queue.
+        /// * Caller must have less than `MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT` queued calls.
         ///
-        /// - **Cost Calculation**: Uses `get_current_registration_cost(block)` with:
+        #[pallet::call_index(176)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(8, 7))]
+        pub fn schedule_remove_delegate_stake(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            shares: u128,
+            execute_after_blocks: u32,
+        ) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
+
+            Self::do_schedule_remove_delegate_stake(
+                account_id,
+                subnet_id,
+                shares,
+                execute_after_blocks,
+            )
+        }
+
+        /// Schedule a subnet delegate stake transfer to another account through the swap queue
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID of the shares.
+        /// * `to_account_id` - Account receiving the shares.
+        /// * `shares` - Shares of the subnet delegate stake pool to transfer.
+        /// * `execute_after_blocks` - Blocks to wait before the transfer is executed.
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must have `SwapQueueDeposit` free, it's held until the call leaves the queue.
+        /// * Caller must have less than `MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT` queued calls.
+        ///
+        #[pallet::call_index(177)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(8, 7))]
+        pub fn schedule_transfer_delegate_stake(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            to_account_id: T::AccountId,
+            shares: u128,
+            execute_after_blocks: u32,
+        ) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
+
+            Self::do_schedule_transfer_delegate_stake(
+                account_id,
+                subnet_id,
+                to_account_id,
+                shares,
+                execute_after_blocks,
+            )
+        }
+
+        /// Schedule a recurring rebalance of the callers subnet delegate stake
+        ///
+        /// Every `interval_blocks` the callers delegate stake balance across the `targets`
+        /// subnets is split by the target percentages.
+        ///
+        /// # Arguments
+        ///
+        /// * `targets` - (Subnet ID, percentage) pairs, percentages must sum to 1e18 (100%).
+        /// * `interval_blocks` - Blocks between each rebalance.
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must have `SwapQueueDeposit` free, it's held until the rebalance is cancelled
+        ///   or fails.
+        /// * Caller must have less than `MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT` queued calls.
+        ///
+        #[pallet::call_index(178)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(6 + targets.len() as u64, 7))]
+        pub fn schedule_rebalance_delegate_stake(
+            origin: OriginFor<T>,
+            targets: Vec<(u32, u128)>,
+            interval_blocks: u32,
+        ) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
+
+            Self::do_schedule_rebalance_delegate_stake(account_id, targets, interval_blocks)
+        }
+
+        /// Cancel a queued swap call
+        ///
+        /// Balance held by a queued swap is added to the callers unbonding ledger.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - Swap queue ID, see `SwapCallQueue`.
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must be the owner of the queued call.
+        ///
+        #[pallet::call_index(179)]
+        #[pallet::weight({0})]
+        pub fn cancel_swap_queue(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+            Self::ensure_stake_holds_migrated()?;
+            Self::do_cancel_swap_queue(account_id, id)
+        }
+
+        /// Set where the callers rewards are credited
+        ///
+        /// Applies to the node rewards of the callers validators if the caller is a validator
+        /// coldkey, and to the rewards allocated to the caller as a delegate account.
+        ///
+        /// # Arguments
+        ///
+        /// * `destination` - See `RewardDestination`.
+        ///
+        /// # Requirements
+        ///
+        /// * The subnet or validator of a compounding destination must exist.
+        ///
+        #[pallet::call_index(180)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            destination: RewardDestination,
+        ) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+            Self::do_set_reward_destination(account_id, destination)
+        }
+
+        /// Re-stake balance from the callers unbonding ledger
+        ///
+        /// The balance is taken from matured unbondings first, then oldest to newest, and skips the
+        /// cooldown.
+        ///
+        /// # Arguments
+        ///
+        /// * `target` - Stake to add the balance to, see `RestakeTarget`.
+        /// * `amount` - Balance to re-stake.
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must have at least `amount` in the unbonding ledger.
+        /// * `target` must accept `amount` as if it were added from the free balance.
+        ///
+        #[pallet::call_index(181)]
+        #[pallet::weight({0})]
+        pub fn restake_unbonding(
+            origin: OriginFor<T>,
+            target: RestakeTarget,
+            amount: u128,
+        ) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
+
+            Self::do_restake_unbonding(account_id, target, amount)
+        }
+
+        /// Withdraw balance from the callers unbonding ledger before its cooldown ends
+        ///
+        /// The `EarlyUnbondingPenalty` of the balance still in cooldown is sent to the treasury.
+        ///
+        /// # Arguments
+        ///
+        /// * `amount` - Balance to withdraw, taken from matured unbondings first, then oldest to
+        ///   newest.
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must have at least `amount` in the unbonding ledger.
+        ///
+        #[pallet::call_index(182)]
+        #[pallet::weight({0})]
+        pub fn withdraw_unbonding_early(origin: OriginFor<T>, amount: u128) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+            Self::ensure_stake_holds_migrated()?;
+
+            Self::do_withdraw_unbonding_early(account_id, amount)
+        }
+
+        /// Owner update the validation policies applied to consensus data proposals
+        ///
+        /// Replaces the subnet's policies from the next subnet epoch. An empty vector removes all
+        /// policies.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID.
+        /// * `policies` - One of each `ConsensusDataPolicy` kind at most. `MinScoredNodes` and
+        ///   `TopK` can't be above `MaxSubnetNodes`.
+        ///
+        #[pallet::call_index(184)]
+        #[pallet::weight({0})]
+        pub fn owner_update_consensus_data_policies(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            policies: Vec<ConsensusDataPolicy>,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_consensus_data_policies(origin, subnet_id, policies)
+        }
+
+        /// Owner update how the subnet's validator is elected each epoch
+        ///
+        /// Takes effect on the next election.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID.
+        /// * `mode` - `Uniform` or `Weighted` by stake and reputation.
+        ///
+        #[pallet::call_index(185)]
+        #[pallet::weight({0})]
+        pub fn owner_update_validator_election_mode(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            mode: ValidatorElectionMode,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_validator_election_mode(origin, subnet_id, mode)
+        }
+
+        /// Owner enable, update or disable (`None`) fallback proposers
+        ///
+        /// If the elected validator has not proposed by `start_progression` of the subnet epoch,
+        /// the next `max_proposers` nodes drawn in the election after it can each submit a
+        /// competing proposal with `propose_attestation`. Nodes attest to one proposal with
+        /// `attest_fallback`, and the proposal with a super-majority of attestations is used
+        /// for rewards.
+        ///
+        /// Fallback proposers are drawn with the validator, enabling takes effect on the next
+        /// election.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID.
+        /// * `config` - Fallback proposer config, `None` to disable.
+        ///
+        #[pallet::call_index(186)]
+        #[pallet::weight({0})]
+        pub fn owner_update_fallback_proposers(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            config: Option<FallbackProposerConfig>,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_fallback_proposers(origin, subnet_id, config)
+        }
+
+        /// Attest to the proposal of a fallback proposer
+        ///
+        /// A node can attest to only one proposal per subnet epoch, either the elected
+        /// validator's or one fallback proposal.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID.
+        /// * `subnet_node_id` - Attesting subnet node ID.
+        /// * `proposer_subnet_node_id` - Subnet node ID of the fallback proposer.
+        /// * `data` - Optional arbitrary attestation data.
+        ///
+        #[pallet::call_index(187)]
+        #[pallet::weight({0})]
+        pub fn attest_fallback(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            proposer_subnet_node_id: u32,
+            data: Option<ValidatorArgs<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let hotkey: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+
+            Self::do_attest_fallback(
+                hotkey,
+                subnet_id,
+                subnet_node_id,
+                proposer_subnet_node_id,
+                data,
+            )
+        }
+
+        /// Owner update how attestations are weighted in the attestation ratio
+        ///
+        /// Applies to proposals from the next subnet epoch, proposals keep the weighting they were
+        /// proposed under.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID.
+        /// * `weighting` - `Count`, `Stake` or `Reputation`.
+        ///
+        #[pallet::call_index(188)]
+        #[pallet::weight({0})]
+        pub fn owner_update_attestation_weighting(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            weighting: AttestationWeighting,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_attestation_weighting(origin, subnet_id, weighting)
+        }
+
+        /// Owner update the caps on the nodes one coldkey or validator ID can hold
+        ///
+        /// New registrations and queue activations over a cap fail, active nodes over a lowered
+        /// cap are removed one per subnet epoch, lowest reputation first.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID.
+        /// * `caps` - Max nodes per coldkey and per validator ID, `None` for no cap.
+        ///
+        #[pallet::call_index(189)]
+        #[pallet::weight({0})]
+        pub fn owner_update_node_concentration_caps(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            caps: NodeConcentrationCaps,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_node_concentration_caps(origin, subnet_id, caps)
+        }
+
+        /// Challenge a subnet node faking work
+        ///
+        /// # Requirements
+        ///
+        /// * Must be an overwatch node
+        /// * Overwatch node stake must cover `OverwatchChallengeBond`
+        /// * One open challenge per overwatch node and per subnet node
+        ///
+        /// # Note
+        ///
+        /// The bond is held from the overwatch stake. Overwatch nodes vote on the challenge in the
+        /// next overwatch epoch. A passing challenge removes the subnet node at the next epoch
+        /// boundary and releases the bond, a failing challenge burns the bond.
+        ///
+        /// # Arguments
+        ///
+        /// * `overwatch_node_id` - Caller Overwatch Node ID.
+        /// * `subnet_id` - Subnet ID of the challenged subnet node.
+        /// * `subnet_node_id` - Challenged subnet node ID.
+        /// * `evidence_hash` - Hash of the off-chain evidence.
+        ///
+        /// Returns Ok(Pays::No.into()) on success
+        ///
+        #[pallet::call_index(195)]
+        #[pallet::weight({0})]
+        pub fn challenge_subnet_node(
+            origin: OriginFor<T>,
+            overwatch_node_id: u32,
+            subnet_id: u32,
+            subnet_node_id: u32,
+            evidence_hash: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Self::is_paused()?;
+            Self::do_challenge_subnet_node(
+                origin,
+                overwatch_node_id,
+                subnet_id,
+                subnet_node_id,
+                evidence_hash,
+            )
+        }
+
+        /// Vote on a subnet node challenge, weighted by the overwatch node's stake in the
+        /// challenge epoch's snapshot, see `OverwatchStakeSnapshot`
+        ///
+        /// # Requirements
+        ///
+        /// * Must be an overwatch node other than the challenger
+        /// * Must be in the overwatch epoch after the challenge was posted
+        ///
+        /// # Arguments
+        ///
+        /// * `overwatch_node_id` - Caller Overwatch Node ID.
+        /// * `challenge_id` - Challenge ID, see `OverwatchChallenges`.
+        /// * `approve` - Vote to remove the subnet node.
+        ///
+        /// Returns Ok(Pays::No.into()) on success
+        ///
+        #[pallet::call_index(196)]
+        #[pallet::weight({0})]
+        pub fn vote_subnet_node_challenge(
+            origin: OriginFor<T>,
+            overwatch_node_id: u32,
+            challenge_id: u32,
+            approve: bool,
+        ) -> DispatchResultWithPostInfo {
+            Self::is_paused()?;
+            Self::do_vote_subnet_node_challenge(origin, overwatch_node_id, challenge_id, approve)
+        }
+
+        /// Set a network parameter through the parameter registry, effective immediately
+        ///
+        /// The majority collective changes parameters through `schedule_parameter_change`.
+        ///
+        /// Replaces the single-parameter `set_*` extrinsics. They are deprecated and removed in
+        /// spec_version 3 along with their precompile selectors. Until then they require the super
+        /// majority collective and, like the selectors, are applied through `set_parameter`.
+        ///
+        /// # Requirements
+        ///
+        /// * Super majority collective
+        /// * Values must be within the bounds of the parameter, see `get_network_parameters`
+        ///
+        /// # Arguments
+        ///
+        /// * `parameter` - Parameter and its new value.
+        ///
+        #[pallet::call_index(200)]
+        #[pallet::weight({0})]
+        pub fn set_parameter(origin: OriginFor<T>, parameter: NetworkParameter) -> DispatchResult {
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameter(parameter)
+        }
+
+        /// Set multiple network parameters at once, all or nothing, effective immediately
+        ///
+        /// # Requirements
+        ///
+        /// * Super majority collective
+        /// * At most `MAX_NETWORK_PARAMETER_UPDATES` parameters
+        ///
+        /// # Arguments
+        ///
+        /// * `parameters` - Parameters and their new values, applied in order.
+        ///
+        #[pallet::call_index(201)]
+        #[pallet::weight({0})]
+        pub fn set_parameters(
+            origin: OriginFor<T>,
+            parameters: Vec<NetworkParameter>,
+        ) -> DispatchResult {
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_parameters(parameters)
+        }
+
+        /// Schedule network parameter changes to be enacted after `ParameterChangeDelayEpochs`
+        ///
+        /// Gives node operators and delegators notice before the network economics change. The
+        /// super majority collective can enact the changes early with `fast_track_parameter_change`
+        /// or cancel them with `veto_parameter_change`.
+        ///
+        /// # Requirements
+        ///
+        /// * Super majority collective if any parameter requires it, otherwise majority collective
+        /// * Values must be within the bounds of each parameter, see `get_network_parameters`
+        /// * At most `MAX_NETWORK_PARAMETER_UPDATES` parameters
+        /// * At most `MAX_SCHEDULED_PARAMETER_CHANGES` changes scheduled by each collective, so
+        ///   the majority collective can't fill the queue of the super majority collective
+        ///
+        /// # Arguments
+        ///
+        /// * `parameters` - Parameters and their new values, applied in order.
+        ///
+        #[pallet::call_index(202)]
+        #[pallet::weight({0})]
+        pub fn schedule_parameter_change(
+            origin: OriginFor<T>,
+            parameters: Vec<NetworkParameter>,
+        ) -> DispatchResult {
+            let super_majority =
+                T::SuperMajorityCollectiveOrigin::ensure_origin(origin.clone()).is_ok();
+            if !super_majority {
+                ensure!(
+                    !Self::network_parameters_require_super_majority(&parameters),
+                    DispatchError::BadOrigin
+                );
+                T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+            }
+            Self::do_schedule_parameter_change(parameters, super_majority)
+        }
+
+        /// Enact scheduled parameter changes before their enactment epoch
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - Scheduled parameter change ID, see `ScheduledParameterChanges`.
+        ///
+        #[pallet::call_index(203)]
+        #[pallet::weight({0})]
+        pub fn fast_track_parameter_change(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_fast_track_parameter_change(id)
+        }
+
+        /// Cancel scheduled parameter changes before they are enacted
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - Scheduled parameter change ID, see `ScheduledParameterChanges`.
+        ///
+        #[pallet::call_index(204)]
+        #[pallet::weight({0})]
+        pub fn veto_parameter_change(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_veto_parameter_change(id)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Register a new subnet on the network
+        ///
+        /// This function creates a new subnet and enters it into the registration phase.
+        /// After registration, the subnet must accumulate sufficient nodes and delegate stake
+        /// before it can be activated (see `do_activate_subnet`).
+        ///
+        /// # Arguments
+        ///
+        /// * `owner` - The account that owns and controls the subnet
+        /// * `max_cost` - Maximum registration cost the owner is willing to pay (slippage protection)
+        /// * `subnet_registration_data` - Subnet configuration data (see `RegistrationSubnetData`)
+        ///
+        /// # Registration Requirements
+        ///
+        /// ## 1. Uniqueness Constraints
+        ///
+        /// - **Unique Name**: Subnet name must not already exist in `SubnetName` storage
+        /// - **Unique Repository**: Repository URL must not already exist in `SubnetRepo` storage
+        ///
+        /// ## 2. Network Capacity
+        ///
+        /// - **Max Subnets**: Total registered subnets must be less than `MaxSubnets + 1`
+        ///   - Network allows one extra subnet (n+1) to facilitate subnet rotation
+        ///   - If exceeded, subnets are removed during epoch preliminaries (see `do_epoch_preliminaries`)
+        ///
+        /// ## 3. Bootnode Configuration
+        ///
+        /// - **Non-Empty**: At least one bootnode must be provided
+        /// - **Count Limit**: Number of bootnodes must not exceed `MaxBootnodes`
+        /// - Bootnodes are P2P network entry points for subnet nodes and overwatchers
+        ///
+        /// ## 4. Stake Balance Configuration
+        ///
+        /// ### Minimum Stake Per Node
+        /// - Must be >= `MinSubnetMinStake` (network-wide minimum)
+        /// - Must be <= `MaxSubnetMinStake` (network-wide maximum)
+        /// - This is the minimum stake required for each subnet node
+        ///
+        /// ### Maximum Stake Per Node
+        /// - Must be <= `NetworkMaxStakeBalance` (prevents stake concentration)
+        /// - Must be >= minimum stake (logical consistency)
+        ///
+        /// ## 5. Delegate Stake Rewards Configuration
+        ///
+        /// - **Percentage Range**: Must be between `MinDelegateStakePercentage` and `MaxDelegateStakePercentage`
+        /// - **Percentage Cap**: Must not exceed 100% (`percentage_factor_as_u128()`)
+        /// - Determines what percentage of subnet emissions go to delegate stakers vs node operators
+        ///
+        /// ## 6. Initial Node Operators (Whitelist)
+        ///
+        /// - **Minimum Count**: At least `MinSubnetNodes` coldkeys must be whitelisted
+        /// - **Registration Slots**: Each coldkey must be allocated at least 1 registration slot
+        /// - During registration period, only these coldkeys can register nodes
+        /// - Removed upon activation (see `do_activate_subnet`)
+        ///
+        /// ## 7. Registration Cost (Dynamic Pricing)
+        ///
+        /// - **Cost Calculation**: Uses `get_current_registration_cost(block)` with:
         ///   - Exponential decay based on time since last registration
         ///   - Alpha parameter for concave decay curve
         ///   - Minimum floor price `MinRegistrationCost`
@@ -7686,6 +10386,8 @@ pub mod pallet {
         /// - `SubnetNodeReputation` - Individual node reputations (cleared via prefix)
         /// - `SubnetNodeConsecutiveIncludedEpochs` - Inclusion streaks (cleared via prefix)
         /// - `SubnetElectedValidator` - Validator election results (cleared via prefix)
+        /// - `SubnetFallbackProposerIds` - Fallback proposer rankings (cleared via prefix)
+        /// - `SubnetFallbackConsensusSubmissions` - Fallback proposals (cleared via prefix)
         /// - `NodeSlotIndex` - Slot index mappings (cleared via prefix)
         /// - `SubnetNodeElectionSlots` - Election slot arrays
         /// - `SubnetNodeQueue` - Node queue
@@ -7919,6 +10621,13 @@ pub mod pallet {
             NodeBurnRateAlpha::<T>::remove(subnet_id);
             CurrentNodeBurnRate::<T>::remove(subnet_id);
             QueueImmunityEpochs::<T>::remove(subnet_id);
+            SubnetConsensusDataPolicies::<T>::remove(subnet_id);
+            PendingSubnetConsensusDataPolicies::<T>::remove(subnet_id);
+            SubnetValidatorElectionMode::<T>::remove(subnet_id);
+            SubnetFallbackProposers::<T>::remove(subnet_id);
+            SubnetAttestationWeighting::<T>::remove(subnet_id);
+            PendingSubnetAttestationWeighting::<T>::remove(subnet_id);
+            SubnetNodeConcentrationCaps::<T>::remove(subnet_id);
             SubnetBootnodeAccess::<T>::remove(subnet_id);
             SubnetBootnodes::<T>::remove(subnet_id);
             EmergencySubnetNodeElectionData::<T>::remove(subnet_id);
@@ -7933,7 +10642,7 @@ pub mod pallet {
                 weight = weight.saturating_add(T::DbWeight::get().writes(1));
             }
 
-            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 26));
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 33));
 
             // Remove from slot
             Self::free_slot_of_subnet(subnet_id);
@@ -8043,10 +10752,25 @@ pub mod pallet {
                 SubnetElectedValidator::<T>::clear_prefix(subnet_id, u32::MAX, None);
             weight_acc.add_clear_prefix(subnet_elected_validator.unique);
 
+            let subnet_fallback_proposer_ids =
+                SubnetFallbackProposerIds::<T>::clear_prefix(subnet_id, u32::MAX, None);
+            weight_acc.add_clear_prefix(subnet_fallback_proposer_ids.unique);
+
+            let subnet_fallback_submissions =
+                SubnetFallbackConsensusSubmissions::<T>::clear_prefix(subnet_id, u32::MAX, None);
+            weight_acc.add_clear_prefix(subnet_fallback_submissions.unique);
+
             let node_slot_index_removed =
                 NodeSlotIndex::<T>::clear_prefix(subnet_id, u32::MAX, None);
             weight_acc.add_clear_prefix(node_slot_index_removed.unique);
 
+            let rewards_ledger_removed =
+                SubnetRewardsLedger::<T>::clear_prefix(subnet_id, u32::MAX, None);
+            weight_acc.add_clear_prefix(rewards_ledger_removed.unique);
+
+            SubnetRewardsLedgerEpochs::<T>::remove(subnet_id);
+            weight_acc.add_remove();
+
             let electable_nodes = SubnetNodeElectionSlots::<T>::take(subnet_id).len() as u32;
             weight_acc.add_take();
 
@@ -8214,6 +10938,12 @@ pub mod pallet {
                 }
             }
 
+            // --- Ensure the coldkey and validator ID are under the subnet's node caps
+            Self::ensure_node_concentration_caps(
+                subnet_id,
+                Self::get_validator_subnet_node_count(subnet_id, validator_id),
+            )?;
+
             // Ensure there are registered node slots available
             ensure!(
                 SubnetNodeQueue::<T>::get(subnet_id).len() as u32
@@ -8251,7 +10981,8 @@ pub mod pallet {
 
             ensure!(stake_to_be_added != 0, Error::<T>::InvalidAmount);
 
-            let stake_as_balance = match Self::u128_to_balance(stake_to_be_added) {
+            // --- Ensure that we can convert this u128 to a balance.
+            match Self::u128_to_balance(stake_to_be_added) {
                 Some(b) => b,
                 None => return Err(Error::<T>::CouldNotConvertToBalance.into()),
             };
@@ -8323,7 +11054,7 @@ pub mod pallet {
             }
 
             ensure!(
-                Self::remove_balance_from_coldkey_account(&coldkey, stake_as_balance) == true,
+                Self::hold_stake(&coldkey, HoldReason::NodeStake, stake_to_be_added),
                 Error::<T>::BalanceWithdrawalError
             );
 
@@ -8492,7 +11223,8 @@ pub mod pallet {
         ///
         /// 1. **Validation**: Checks if the subnet state and queue flag combination is valid.
         /// 2. **Weight Check**: Verifies if there is enough weight remaining to perform the operation.
-        /// 3. **Queue Handling**: If called from the queue, ensures the node is in the `Registered` class.
+        /// 3. **Queue Handling**: If called from the queue, ensures the node is in the `Registered` class,
+        ///    and removes it instead if its validator ID is at the subnet's `NodeConcentrationCaps`.
         /// 4. **State Transition**:
         ///    - Moves the node data from `RegisteredSubnetNodesData` to `SubnetNodesData`.
         ///    - Sets the node class to `Idle` initially.
@@ -8537,11 +11269,11 @@ pub mod pallet {
                 return false;
             }
 
-            // Total reads: 5, Total writes: 5
+            // Total reads: 7, Total writes: 5
             // Reads/Writes: RegisteredSubnetNodesData(take), TotalActiveSubnetNodes(mutate), TotalActiveNodes(mutate), ColdkeyReputation(mutate)
             // Writes: SubnetNodesData(insert)
-            // Reads: (get)
-            if !weight_meter.can_consume(db_weight.reads_writes(5, 5)) {
+            // Reads: (get), SubnetNodeConcentrationCaps, ValidatorSubnetNodes
+            if !weight_meter.can_consume(db_weight.reads_writes(7, 5)) {
                 return false;
             }
 
@@ -8552,7 +11284,35 @@ pub mod pallet {
             }
 
             // Consume the necessary weight
-            weight_meter.consume(db_weight.reads_writes(5, 5));
+            weight_meter.consume(db_weight.reads_writes(7, 5));
+
+            // --- Drop queued nodes of validator IDs at the subnet's node concentration caps
+            if queue && Self::is_over_node_concentration_caps(subnet_id, validator_id) {
+                let validator_subnet_nodes = ValidatorSubnetNodes::<T>::get(validator_id);
+                let remove_weight = T::WeightInfo::remove_registered_subnet_node(
+                    validator_subnet_nodes.len() as u32,
+                    SubnetNodeQueue::<T>::decode_len(subnet_id).unwrap_or(0) as u32,
+                    validator_subnet_nodes
+                        .get(&subnet_id)
+                        .map_or(0, |nodes| nodes.len() as u32),
+                );
+                if !weight_meter.can_consume(remove_weight) {
+                    return false;
+                }
+                weight_meter.consume(remove_weight);
+
+                // The caller drains the node from `SubnetNodeQueue`
+                RegisteredSubnetNodesData::<T>::remove(subnet_id, subnet_node.id);
+                Self::common_remove_subnet_node(subnet_id, subnet_node.id, subnet_node.clone());
+
+                Self::deposit_event(Event::NodeConcentrationCapRemoval {
+                    subnet_id,
+                    subnet_node_id: subnet_node.id,
+                    validator_id,
+                });
+
+                return true;
+            }
 
             // Try to take the RegisteredSubnetNodesData
             RegisteredSubnetNodesData::<T>::take(subnet_id, subnet_node.id);
@@ -8631,6 +11391,9 @@ pub mod pallet {
             // MaximumHooksWeightV2
             weight_meter.consume(db_weight.reads(1));
 
+            // Runs while paused so the migration can finish before the network is unpaused
+            migrations::step_stake_to_holds::<T>(&mut weight_meter);
+
             if Self::is_paused().is_err() {
                 return weight_meter.consumed();
             }
@@ -8664,6 +11427,28 @@ pub mod pallet {
                 let block_step_weight = Self::calculate_overwatch_rewards();
                 // `consume(..)` saturates at zero
                 weight_meter.consume(block_step_weight);
+
+                // Penalize commits of the previous overwatch epoch that were never revealed
+                let block_step_weight = Self::penalize_overwatch_non_reveals(
+                    Self::get_current_overwatch_epoch_as_u32().saturating_sub(1),
+                );
+                weight_meter.consume(block_step_weight);
+
+                // Resolve subnet node challenges voted on in the previous overwatch epoch
+                let block_step_weight =
+                    Self::resolve_overwatch_challenges(Self::get_current_overwatch_epoch_as_u32());
+                weight_meter.consume(block_step_weight);
+
+                // Snapshot the stake challenges of this overwatch epoch are voted with
+                let block_step_weight =
+                    Self::snapshot_overwatch_stake(Self::get_current_overwatch_epoch_as_u32());
+                weight_meter.consume(block_step_weight);
+
+                // Finalize the randomness beacon of the previous overwatch epoch
+                let block_step_weight = Self::finalize_beacon_round(
+                    Self::get_current_overwatch_epoch_as_u32().saturating_sub(1),
+                );
+                weight_meter.consume(block_step_weight);
             } else if block.saturating_sub(2) >= epoch_length
                 && block.saturating_sub(2) % epoch_length == 0
             {
@@ -8693,8 +11478,11 @@ pub mod pallet {
                 weight_meter.consume(db_weight.reads(1));
             }
 
-            // Attempt stake swap queue on every block
-            Self::execute_ready_swap_calls(block, &mut weight_meter);
+            // Attempt stake swap queue on every block, queued calls wait for the stake to holds
+            // migration
+            if Self::ensure_stake_holds_migrated().is_ok() {
+                Self::execute_ready_swap_calls(block, &mut weight_meter);
+            }
 
             // for EVM tests (Weights in on_initialize change the block weight/gas)
             // Weight::from_parts(0, 0)
@@ -8711,109 +11499,205 @@ pub mod pallet {
 
     impl<T: Config> Pallet<T> {
         // Execute multiple calls at once (for block hooks)
+        //
+        // Calls are executed in queue order. Calls that are not ready yet are skipped so they
+        // don't hold back calls queued after them with a shorter delay.
         pub fn execute_ready_swap_calls(block_number: u32, weight_meter: &mut WeightMeter) {
             let db_weight = T::DbWeight::get();
 
-            let max_executions = MaxSwapQueueCallsPerBlock::<T>::get();
-            weight_meter.consume(db_weight.reads(1));
+            let max_reads = MaxSwapQueueCallsPerBlock::<T>::get();
+            // MaxSwapQueueCallsPerBlock | SwapQueueOrder | SwapQueueCursor
+            weight_meter.consume(db_weight.reads(3));
 
-            let mut executed = 0;
+            let mut queue = SwapQueueOrder::<T>::get();
+            if queue.is_empty() {
+                return;
+            }
 
-            while executed < max_executions {
-                // Loop iteration overhead
-                weight_meter.consume(Weight::from_parts(1_000, 0));
+            // --- Every read counts toward `MaxSwapQueueCallsPerBlock`, calls that are not ready
+            // yet are skipped and the next block continues after them
+            let queue_len = queue.len() as u32;
+            let mut index = (SwapQueueCursor::<T>::get() % queue_len) as usize;
+            let mut reads = 0;
+            let mut recurring: Vec<u32> = Vec::new();
+            let mut queue_mutated = false;
+
+            while reads < max_reads.min(queue_len) && !queue.is_empty() {
+                if index >= queue.len() {
+                    index = 0;
+                }
 
-                // SwapQueueOrder
-                weight_meter.consume(db_weight.reads_writes(1, 1));
-                let should_continue = SwapQueueOrder::<T>::mutate(|queue| -> bool {
-                    // Check either stake functions can be called before we get there.
-                    // `weight_meter.can_consume` is called in `execute_swap_call_internal` again.
-                    // This is redundant but will save at least one DB read (which is what is expensive)
-                    if queue.is_empty() {
-                        return false;
-                    }
+                // Loop iteration overhead | SwapCallQueue
+                let read_weight = Weight::from_parts(1_000, 0).saturating_add(db_weight.reads(1));
+                if !weight_meter.can_consume(read_weight) {
+                    break;
+                }
+                weight_meter.consume(read_weight);
+                reads += 1;
 
-                    let first_id = queue[0];
-
-                    // SwapCallQueue
-                    weight_meter.consume(db_weight.reads(1));
-
-                    if let Some(item) = SwapCallQueue::<T>::get(&first_id) {
-                        let blocks_passed = block_number.saturating_sub(item.queued_at_block);
-                        if blocks_passed >= item.execute_after_blocks.into() {
-                            // If the function can't be called, it will return before calling and the loop will break
-                            let is_ok = Self::execute_swap_call_internal(&item.call, weight_meter);
-                            // If not `is_ok`, the function was not called
-                            if !is_ok {
-                                // break if no weight left in WeightMeter
-                                return false;
-                            }
-
-                            // The swap can only fail if the balance -> shares conversion fails
-                            // Therefore, we always remove from the queue.
-                            // If the conversion fails, the stake value is worthless or near worthless
-                            queue.remove(0);
-                            SwapCallQueue::<T>::remove(&first_id);
-                            // SwapCallQueue
-                            weight_meter.consume(db_weight.writes(1));
-                            return true;
-                        }
-                    }
+                let id = queue[index];
 
-                    // If no elements in `SwapCallQueue`
-                    false
-                });
+                let Some(mut item) = SwapCallQueue::<T>::get(&id) else {
+                    // Stale ID
+                    queue.remove(index);
+                    queue_mutated = true;
+                    continue;
+                };
 
-                if !should_continue {
+                let blocks_passed = block_number.saturating_sub(item.queued_at_block);
+                if blocks_passed < item.execute_after_blocks {
+                    index += 1;
+                    continue;
+                }
+
+                // If the function can't be called, it will return before calling and the loop will break
+                let Some(result) = Self::execute_swap_call_internal(&item.call, weight_meter)
+                else {
+                    // break if no weight left in WeightMeter
                     break;
+                };
+
+                queue.remove(index);
+                queue_mutated = true;
+
+                match (result, item.call.get_recurring_interval()) {
+                    (Ok(()), Some(interval_blocks)) => {
+                        item.queued_at_block = block_number;
+                        item.execute_after_blocks = interval_blocks;
+                        SwapCallQueue::<T>::insert(&id, &item);
+                        recurring.push(id);
+                    }
+                    (Ok(()), None) => {
+                        Self::remove_queued_swap_call(id, item.call.get_owner());
+                    }
+                    // Failed calls are always removed, the owner can queue the call again
+                    (Err(error), _) => {
+                        Self::remove_queued_swap_call(id, item.call.get_owner());
+                        Self::deposit_event(Event::SwapCallFailed {
+                            id,
+                            account_id: item.call.get_owner().clone(),
+                            error,
+                        });
+                    }
                 }
+                // SwapCallQueue | AccountSwapQueueCalls | SwapCallDeposits | Holds
+                weight_meter.consume(db_weight.reads_writes(3, 4));
+            }
 
-                executed += 1;
+            if queue_mutated {
+                for id in recurring {
+                    // Room was made when the call was removed above
+                    let _ = queue.try_push(id);
+                }
+                SwapQueueOrder::<T>::put(queue);
+                // SwapQueueOrder
+                weight_meter.consume(db_weight.writes(1));
             }
+
+            SwapQueueCursor::<T>::put(index as u32);
+            // SwapQueueCursor
+            weight_meter.consume(db_weight.writes(1));
         }
 
+        /// Execute a queued swap call in a storage transaction
+        ///
+        /// Returns `None` if there isn't enough weight to execute the call, otherwise the result
+        /// of the call. Storage changes of a failed call are rolled back.
         pub fn execute_swap_call_internal(
             queued_call: &QueuedSwapCall<T::AccountId>,
             weight_meter: &mut WeightMeter,
-        ) -> bool {
-            match queued_call {
-                QueuedSwapCall::SwapToSubnetDelegateStake {
-                    account_id,
-                    to_subnet_id,
-                    balance,
-                } => {
-                    if !weight_meter
-                        .can_consume(T::WeightInfo::handle_increase_account_delegate_stake())
-                    {
-                        return false;
+        ) -> Option<DispatchResult> {
+            let call_weight = match queued_call {
+                QueuedSwapCall::SwapToSubnetDelegateStake { .. }
+                | QueuedSwapCall::SwapToValidatorDelegateStake { .. } => {
+                    T::WeightInfo::handle_increase_account_delegate_stake()
+                }
+                QueuedSwapCall::RemoveSubnetDelegateStake { .. } => {
+                    T::WeightInfo::remove_delegate_stake()
+                }
+                QueuedSwapCall::TransferSubnetDelegateStake { .. } => {
+                    T::WeightInfo::transfer_delegate_stake()
+                }
+                QueuedSwapCall::RebalanceSubnetDelegateStake { targets, .. } => {
+                    // Each target is removed from or added to
+                    T::WeightInfo::remove_delegate_stake().saturating_mul(targets.len() as u64)
+                }
+            };
+
+            if !weight_meter.can_consume(call_weight) {
+                return None;
+            }
+            weight_meter.consume(call_weight);
+
+            let result = with_transaction(|| {
+                let result = match queued_call {
+                    // The swaps can only fail if the balance -> shares conversion fails
+                    // If the conversion fails, the stake value is worthless or near worthless
+                    QueuedSwapCall::SwapToSubnetDelegateStake {
+                        account_id,
+                        to_subnet_id,
+                        balance,
+                    } => {
+                        let (_, _, _) = Self::handle_increase_account_delegate_stake(
+                            account_id,
+                            *to_subnet_id,
+                            *balance,
+                        );
+                        Ok(())
                     }
-                    weight_meter.consume(T::WeightInfo::handle_increase_account_delegate_stake());
-                    let (_, _, _) = Self::handle_increase_account_delegate_stake(
+                    QueuedSwapCall::SwapToValidatorDelegateStake {
                         account_id,
-                        *to_subnet_id,
-                        *balance,
-                    );
-                }
-                QueuedSwapCall::SwapToValidatorDelegateStake {
-                    account_id,
-                    to_validator_id,
-                    balance,
-                } => {
-                    if !weight_meter
-                        .can_consume(T::WeightInfo::handle_increase_account_delegate_stake())
-                    {
-                        return false;
+                        to_validator_id,
+                        balance,
+                    } => {
+                        let (_, _, _) = Self::handle_increase_account_validator_delegate_stake(
+                            account_id,
+                            *to_validator_id,
+                            *balance,
+                        );
+                        Ok(())
                     }
-                    // weight_meter.consume(T::WeightInfo::handle_increase_account_validator_delegate_stake());
-                    let (_, _, _) = Self::handle_increase_account_validator_delegate_stake(
+                    QueuedSwapCall::RemoveSubnetDelegateStake {
                         account_id,
-                        *to_validator_id,
-                        *balance,
-                    );
+                        subnet_id,
+                        shares,
+                    } => {
+                        let (result, balance, _) = Self::perform_do_remove_subnet_delegate_stake(
+                            account_id, *subnet_id, *shares, true,
+                        );
+                        result.map(|_| {
+                            Self::deposit_event(Event::SubnetDelegateStakeRemoved(
+                                *subnet_id,
+                                account_id.clone(),
+                                balance,
+                            ))
+                        })
+                    }
+                    QueuedSwapCall::TransferSubnetDelegateStake {
+                        account_id,
+                        subnet_id,
+                        to_account_id,
+                        shares,
+                    } => Self::perform_transfer_delegate_stake(
+                        account_id,
+                        *subnet_id,
+                        to_account_id,
+                        *shares,
+                    ),
+                    QueuedSwapCall::RebalanceSubnetDelegateStake {
+                        account_id,
+                        targets,
+                        ..
+                    } => Self::perform_rebalance_subnet_delegate_stake(account_id, targets),
+                };
+
+                match result {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(error) => TransactionOutcome::Rollback(Err(error)),
                 }
-            }
+            });
 
-            true
+            Some(result)
         }
     }
 
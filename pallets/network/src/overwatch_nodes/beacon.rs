@@ -0,0 +1,196 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Commit-reveal randomness beacon fed by overwatch nodes, see `OverwatchRandomnessBeacon`
+
+use super::*;
+use crate::randomness::MAX_BEACON_LOOKBACK_ROUNDS;
+use frame_support::pallet_prelude::{DispatchResultWithPostInfo, Pays, Weight};
+use sp_runtime::traits::Hash;
+
+impl<T: Config> Pallet<T> {
+    pub fn do_commit_beacon_entropy(
+        origin: T::RuntimeOrigin,
+        overwatch_node_id: u32,
+        commitment: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        let hotkey: T::AccountId = ensure_signed(origin)?;
+
+        let overwatch_hotkey = Self::get_overwatch_node_associated_hotkey(overwatch_node_id)?;
+
+        ensure!(overwatch_hotkey == hotkey, Error::<T>::NotKeyOwner);
+
+        let validator_id = OverwatchNodeValidatorId::<T>::try_get(overwatch_node_id)
+            .map_err(|_| Error::<T>::InvalidValidatorId)?;
+
+        ensure!(
+            OverwatchValidatorWhitelist::<T>::get(validator_id),
+            Error::<T>::ColdkeyBlacklisted
+        );
+
+        // --- Check if we are in commit period
+        ensure!(
+            Self::in_overwatch_commit_period(),
+            Error::<T>::NotCommitPeriod
+        );
+
+        Self::perform_commit_beacon_entropy(overwatch_node_id, commitment)
+    }
+
+    pub fn perform_commit_beacon_entropy(
+        overwatch_node_id: u32,
+        commitment: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        let round = Self::get_current_overwatch_epoch_as_u32();
+
+        ensure!(
+            !BeaconEntropyCommits::<T>::contains_key(round, overwatch_node_id),
+            Error::<T>::AlreadyCommitted
+        );
+
+        BeaconEntropyCommits::<T>::insert(round, overwatch_node_id, commitment);
+
+        Ok(Pays::No.into())
+    }
+
+    pub fn do_reveal_beacon_entropy(
+        origin: T::RuntimeOrigin,
+        overwatch_node_id: u32,
+        entropy: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        let hotkey: T::AccountId = ensure_signed(origin)?;
+
+        let overwatch_hotkey = Self::get_overwatch_node_associated_hotkey(overwatch_node_id)?;
+
+        ensure!(overwatch_hotkey == hotkey, Error::<T>::NotKeyOwner);
+
+        let validator_id = OverwatchNodeValidatorId::<T>::try_get(overwatch_node_id)
+            .map_err(|_| Error::<T>::InvalidValidatorId)?;
+
+        ensure!(
+            OverwatchValidatorWhitelist::<T>::get(validator_id),
+            Error::<T>::ColdkeyBlacklisted
+        );
+
+        // --- Check if we are in reveal period
+        ensure!(
+            !Self::in_overwatch_commit_period(),
+            Error::<T>::NotRevealPeriod
+        );
+
+        Self::perform_reveal_beacon_entropy(overwatch_node_id, entropy)
+    }
+
+    /// Reveal beacon entropy and fold it into the round accumulator.
+    ///
+    /// The commit is `hash(entropy, overwatch_node_id)`. Binding the node ID prevents a node from
+    /// copying another node's commit and revealing the same entropy to cancel it out of the XOR.
+    pub fn perform_reveal_beacon_entropy(
+        overwatch_node_id: u32,
+        entropy: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        let round = Self::get_current_overwatch_epoch_as_u32();
+
+        let Some(commitment) = BeaconEntropyCommits::<T>::get(round, overwatch_node_id) else {
+            return Err(Error::<T>::NoCommitFound.into());
+        };
+
+        let actual_hash = T::Hashing::hash_of(&(entropy, overwatch_node_id));
+
+        ensure!(actual_hash == commitment, Error::<T>::RevealMismatch);
+
+        // Removing the commit makes a second reveal fail with `NoCommitFound`
+        BeaconEntropyCommits::<T>::remove(round, overwatch_node_id);
+
+        // XOR is order independent so the block author cannot bias the output by ordering reveals.
+        // The revealed entropy is folded in, not the commit, since commits are public before the
+        // reveal period.
+        BeaconRoundEntropy::<T>::mutate(round, |entry| {
+            let (accumulator, reveals) = entry.get_or_insert((T::Hash::default(), 0));
+            *accumulator = *accumulator ^ entropy;
+            *reveals = reveals.saturating_add(1);
+        });
+
+        Ok(Pays::No.into())
+    }
+
+    /// Finalize a beacon round into `BeaconRandomness`.
+    ///
+    /// Called at the start of the overwatch epoch after `round`. A round with fewer than
+    /// `MinBeaconReveals` reveals is missed and elections fall back, see `get_beacon_seed`.
+    ///
+    /// Commits that were never revealed are penalized like unrevealed weight commits, see
+    /// `penalize_overwatch_non_reveals`, since withholding a reveal is the last way left to
+    /// influence the round.
+    pub fn finalize_beacon_round(round: u32) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        let min_reveals = MinBeaconReveals::<T>::get();
+        let entry = BeaconRoundEntropy::<T>::take(round);
+        // MinBeaconReveals | BeaconRoundEntropy
+        let mut weight = db_weight.reads_writes(2, 1);
+
+        // OverwatchNonRevealReputationFactor, OverwatchNonRevealStakePenalty
+        weight = weight.saturating_add(db_weight.reads(2));
+        let reputation_factor = OverwatchNonRevealReputationFactor::<T>::get();
+        let stake_penalty = OverwatchNonRevealStakePenalty::<T>::get();
+
+        // Reveals remove their commit, so every commit left was never revealed
+        for (overwatch_node_id, _) in BeaconEntropyCommits::<T>::drain_prefix(round) {
+            // BeaconEntropyCommits
+            weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+
+            let (penalty_weight, penalty) = Self::penalize_overwatch_node(
+                overwatch_node_id,
+                reputation_factor,
+                stake_penalty,
+                Self::percentage_factor_as_u128(),
+            );
+            weight = weight.saturating_add(penalty_weight);
+            let Some((ow_score, stake_penalty)) = penalty else {
+                continue;
+            };
+
+            Self::deposit_event(Event::BeaconNonRevealPenalty {
+                overwatch_node_id,
+                round,
+                ow_score,
+                stake_penalty,
+            });
+        }
+
+        let reveals = entry.map(|(_, reveals)| reveals).unwrap_or(0);
+
+        match entry {
+            Some((accumulator, reveals)) if reveals >= min_reveals => {
+                let seed = T::Hashing::hash_of(&(accumulator, round));
+                BeaconRandomness::<T>::insert(round, seed);
+                weight = weight.saturating_add(db_weight.writes(1));
+                Self::deposit_event(Event::BeaconRoundFinalized { round, reveals });
+            }
+            _ => {
+                Self::deposit_event(Event::BeaconRoundMissed { round, reveals });
+            }
+        }
+
+        // Keep only the rounds `get_beacon_seed` can look back to
+        if round > MAX_BEACON_LOOKBACK_ROUNDS {
+            BeaconRandomness::<T>::remove(round - MAX_BEACON_LOOKBACK_ROUNDS - 1);
+            weight = weight.saturating_add(db_weight.writes(1));
+        }
+
+        weight
+    }
+}
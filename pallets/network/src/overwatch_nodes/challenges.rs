@@ -0,0 +1,383 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Bonded overwatch challenges against individual subnet nodes, see `OverwatchChallenges`
+
+use super::*;
+use frame_support::pallet_prelude::{DispatchResultWithPostInfo, Pays, Weight};
+
+impl<T: Config> Pallet<T> {
+    pub fn do_challenge_subnet_node(
+        origin: T::RuntimeOrigin,
+        overwatch_node_id: u32,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        evidence_hash: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        let hotkey: T::AccountId = ensure_signed(origin)?;
+
+        let overwatch_hotkey = Self::get_overwatch_node_associated_hotkey(overwatch_node_id)?;
+
+        ensure!(overwatch_hotkey == hotkey, Error::<T>::NotKeyOwner);
+
+        let validator_id = OverwatchNodeValidatorId::<T>::try_get(overwatch_node_id)
+            .map_err(|_| Error::<T>::InvalidValidatorId)?;
+
+        ensure!(
+            OverwatchValidatorWhitelist::<T>::get(validator_id),
+            Error::<T>::ColdkeyBlacklisted
+        );
+
+        ensure!(
+            SubnetNodesData::<T>::contains_key(subnet_id, subnet_node_id)
+                || RegisteredSubnetNodesData::<T>::contains_key(subnet_id, subnet_node_id),
+            Error::<T>::InvalidSubnetNodeId
+        );
+
+        ensure!(
+            !SubnetNodeChallengeId::<T>::contains_key(subnet_id, subnet_node_id),
+            Error::<T>::SubnetNodeAlreadyChallenged
+        );
+
+        ensure!(
+            !OverwatchNodeChallengeId::<T>::contains_key(overwatch_node_id),
+            Error::<T>::OverwatchChallengeAlreadyOpen
+        );
+
+        let coldkey = ValidatorColdkey::<T>::try_get(validator_id)
+            .map_err(|_| Error::<T>::InvalidValidatorId)?;
+
+        let bond = OverwatchChallengeBond::<T>::get();
+
+        ensure!(
+            OverwatchNodeStakeBalance::<T>::get(overwatch_node_id) >= bond
+                && Self::get_stake_hold(&coldkey, HoldReason::OverwatchStake) >= bond,
+            Error::<T>::NotEnoughStakeForChallengeBond
+        );
+
+        // --- The bond is held outside of the node's stake until the challenge is resolved
+        Self::move_stake_hold(
+            &coldkey,
+            Some(HoldReason::OverwatchStake),
+            HoldReason::ChallengeBond,
+            bond,
+        )?;
+        Self::decrease_overwatch_node_stake(overwatch_node_id, bond);
+
+        let overwatch_epoch = Self::get_current_overwatch_epoch_as_u32();
+
+        // --- Votes are weighted by the overwatch stake of this epoch's snapshot
+        Self::snapshot_overwatch_stake(overwatch_epoch);
+
+        let challenge_id = NextOverwatchChallengeId::<T>::get();
+
+        OverwatchChallenges::<T>::insert(
+            challenge_id,
+            OverwatchChallenge {
+                overwatch_node_id,
+                coldkey,
+                subnet_id,
+                subnet_node_id,
+                evidence_hash,
+                bond,
+                overwatch_epoch,
+                total_stake: TotalOverwatchStakeSnapshot::<T>::get(overwatch_epoch),
+                approve_stake: 0,
+                reject_stake: 0,
+            },
+        );
+        NextOverwatchChallengeId::<T>::put(challenge_id.saturating_add(1));
+        OverwatchNodeChallengeId::<T>::insert(overwatch_node_id, challenge_id);
+        SubnetNodeChallengeId::<T>::insert(subnet_id, subnet_node_id, challenge_id);
+
+        Self::deposit_event(Event::SubnetNodeChallenged {
+            challenge_id,
+            overwatch_node_id,
+            subnet_id,
+            subnet_node_id,
+            evidence_hash,
+            bond,
+        });
+
+        Ok(Pays::No.into())
+    }
+
+    pub fn do_vote_subnet_node_challenge(
+        origin: T::RuntimeOrigin,
+        overwatch_node_id: u32,
+        challenge_id: u32,
+        approve: bool,
+    ) -> DispatchResultWithPostInfo {
+        let hotkey: T::AccountId = ensure_signed(origin)?;
+
+        let overwatch_hotkey = Self::get_overwatch_node_associated_hotkey(overwatch_node_id)?;
+
+        ensure!(overwatch_hotkey == hotkey, Error::<T>::NotKeyOwner);
+
+        let validator_id = OverwatchNodeValidatorId::<T>::try_get(overwatch_node_id)
+            .map_err(|_| Error::<T>::InvalidValidatorId)?;
+
+        ensure!(
+            OverwatchValidatorWhitelist::<T>::get(validator_id),
+            Error::<T>::ColdkeyBlacklisted
+        );
+
+        let mut challenge =
+            OverwatchChallenges::<T>::get(challenge_id).ok_or(Error::<T>::InvalidChallengeId)?;
+
+        // --- Votes are only accepted in the overwatch epoch after the challenge was posted
+        ensure!(
+            Self::get_current_overwatch_epoch_as_u32()
+                == challenge.overwatch_epoch.saturating_add(1),
+            Error::<T>::NotChallengeVotingPeriod
+        );
+
+        ensure!(
+            challenge.overwatch_node_id != overwatch_node_id,
+            Error::<T>::ChallengerCannotVote
+        );
+
+        ensure!(
+            !OverwatchChallengeVotes::<T>::contains_key(challenge_id, overwatch_node_id),
+            Error::<T>::AlreadyVoted
+        );
+
+        // --- Stake added after the challenge's snapshot doesn't count
+        let stake = OverwatchStakeSnapshot::<T>::get(challenge.overwatch_epoch, overwatch_node_id);
+
+        if approve {
+            challenge.approve_stake = challenge.approve_stake.saturating_add(stake);
+        } else {
+            challenge.reject_stake = challenge.reject_stake.saturating_add(stake);
+        }
+
+        OverwatchChallengeVotes::<T>::insert(challenge_id, overwatch_node_id, approve);
+        OverwatchChallenges::<T>::insert(challenge_id, challenge);
+
+        Self::deposit_event(Event::OverwatchChallengeVote {
+            challenge_id,
+            overwatch_node_id,
+            approve,
+            stake,
+        });
+
+        Ok(Pays::No.into())
+    }
+
+    /// Resolve the subnet node challenges whose voting overwatch epoch has ended
+    ///
+    /// A challenge passes when the stake that voted on it reaches `OverwatchChallengeQuorum` of
+    /// the overwatch stake snapshot and the approving stake outweighs the rejecting stake. A
+    /// passing challenge decreases its validator's reputation by
+    /// `OverwatchChallengeReputationFactor`, queues the subnet node for removal at the next epoch
+    /// boundary and releases the bond to the challenger. A failing challenge burns the bond.
+    ///
+    /// # Arguments
+    ///
+    /// * `overwatch_epoch` - The current overwatch epoch.
+    pub fn resolve_overwatch_challenges(overwatch_epoch: u32) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // OverwatchChallengeQuorum, OverwatchChallengeReputationFactor
+        let mut weight = db_weight.reads(2);
+        let quorum_percentage = OverwatchChallengeQuorum::<T>::get();
+        let reputation_factor = OverwatchChallengeReputationFactor::<T>::get();
+
+        // Open challenges are bounded by the overwatch nodes, see `OverwatchNodeChallengeId`
+        let mut challenges: Vec<(u32, OverwatchChallenge<T>)> = Vec::new();
+        for (challenge_id, challenge) in OverwatchChallenges::<T>::iter() {
+            // OverwatchChallenges
+            weight = weight.saturating_add(db_weight.reads(1));
+            if challenge.overwatch_epoch.saturating_add(1) < overwatch_epoch {
+                challenges.push((challenge_id, challenge));
+            }
+        }
+
+        for (challenge_id, challenge) in challenges {
+            let subnet_id = challenge.subnet_id;
+            let subnet_node_id = challenge.subnet_node_id;
+            let voted_stake = challenge
+                .approve_stake
+                .saturating_add(challenge.reject_stake);
+            let quorum = Self::percent_mul(challenge.total_stake, quorum_percentage);
+            let passed = voted_stake > 0
+                && voted_stake >= quorum
+                && challenge.approve_stake > challenge.reject_stake;
+
+            let mut slashed_bond = 0;
+            if passed {
+                // SubnetNodesData, RegisteredSubnetNodesData
+                weight = weight.saturating_add(db_weight.reads(2));
+                let validator_id = if let Ok(subnet_node) =
+                    SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+                {
+                    Some(subnet_node.validator_id)
+                } else if let Ok(subnet_node) =
+                    RegisteredSubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+                {
+                    Some(subnet_node.validator_id)
+                } else {
+                    None
+                };
+
+                // The subnet node may have been removed while the challenge was open
+                if let Some(validator_id) = validator_id {
+                    // ValidatorReputation
+                    weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+                    ValidatorReputation::<T>::mutate(validator_id, |reputation| {
+                        reputation.score =
+                            Self::decrease_rep(reputation.score, reputation_factor, None);
+                        reputation.total_decreases = reputation.total_decreases.saturating_add(1);
+                    });
+
+                    // --- Removing the node mid-epoch could remove the elected validator
+                    // PendingChallengeRemovals
+                    weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+                    PendingChallengeRemovals::<T>::mutate(|removals| {
+                        removals.push((subnet_id, subnet_node_id))
+                    });
+                }
+
+                // Holds
+                weight = weight.saturating_add(db_weight.reads_writes(2, 1));
+                let bond = Self::get_stake_hold(&challenge.coldkey, HoldReason::ChallengeBond)
+                    .min(challenge.bond);
+                if let Err(error) =
+                    Self::release_stake_hold(&challenge.coldkey, HoldReason::ChallengeBond, bond)
+                {
+                    log::error!(
+                        target: "runtime::network",
+                        "failed to release the bond of challenge {}: {:?}",
+                        challenge_id,
+                        error
+                    );
+                }
+            } else {
+                // Holds
+                weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+                slashed_bond = Self::burn_stake_hold(
+                    &challenge.coldkey,
+                    HoldReason::ChallengeBond,
+                    challenge.bond,
+                );
+            }
+
+            // OverwatchChallenges, OverwatchNodeChallengeId, SubnetNodeChallengeId
+            weight = weight.saturating_add(db_weight.writes(3));
+            OverwatchChallenges::<T>::remove(challenge_id);
+            OverwatchNodeChallengeId::<T>::remove(challenge.overwatch_node_id);
+            SubnetNodeChallengeId::<T>::remove(subnet_id, subnet_node_id);
+
+            // OverwatchChallengeVotes
+            let votes = OverwatchChallengeVotes::<T>::clear_prefix(challenge_id, u32::MAX, None);
+            weight = weight.saturating_add(db_weight.writes(votes.unique as u64));
+
+            Self::deposit_event(Event::SubnetNodeChallengeResolved {
+                challenge_id,
+                subnet_id,
+                subnet_node_id,
+                passed,
+                approve_stake: challenge.approve_stake,
+                reject_stake: challenge.reject_stake,
+                slashed_bond,
+            });
+        }
+
+        weight
+    }
+
+    /// Snapshot the stake of every overwatch node for `overwatch_epoch`, challenge votes of the
+    /// epoch are weighted by it
+    ///
+    /// Taken once per overwatch epoch, at the overwatch epoch boundary or by the epoch's first
+    /// challenge, whichever comes first. Snapshots older than the previous overwatch epoch are
+    /// no longer voted with and are removed.
+    pub fn snapshot_overwatch_stake(overwatch_epoch: u32) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // TotalOverwatchStakeSnapshot
+        let mut weight = db_weight.reads(1);
+        if TotalOverwatchStakeSnapshot::<T>::contains_key(overwatch_epoch) {
+            return weight;
+        }
+
+        // Open challenges are voted on in the overwatch epoch after they were posted
+        let stale_epochs: Vec<u32> = TotalOverwatchStakeSnapshot::<T>::iter_keys()
+            .filter(|epoch| epoch.saturating_add(1) < overwatch_epoch)
+            .collect();
+        for epoch in stale_epochs {
+            TotalOverwatchStakeSnapshot::<T>::remove(epoch);
+            let removed = OverwatchStakeSnapshot::<T>::clear_prefix(epoch, u32::MAX, None);
+            weight = weight.saturating_add(db_weight.reads_writes(1, 1 + removed.unique as u64));
+        }
+
+        // Bounded by `MaxOverwatchNodes`
+        let mut total_stake: u128 = 0;
+        for (overwatch_node_id, stake) in OverwatchNodeStakeBalance::<T>::iter() {
+            // OverwatchNodeStakeBalance | OverwatchStakeSnapshot
+            weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+            if stake == 0 {
+                continue;
+            }
+            OverwatchStakeSnapshot::<T>::insert(overwatch_epoch, overwatch_node_id, stake);
+            total_stake = total_stake.saturating_add(stake);
+        }
+
+        // TotalOverwatchStakeSnapshot
+        weight = weight.saturating_add(db_weight.writes(1));
+        TotalOverwatchStakeSnapshot::<T>::insert(overwatch_epoch, total_stake);
+
+        weight
+    }
+
+    /// Remove the subnet nodes of passed challenges, see `resolve_overwatch_challenges`
+    ///
+    /// Called at the epoch boundary before validators are elected.
+    pub fn remove_challenged_subnet_nodes(weight_meter: &mut WeightMeter) {
+        let db_weight = T::DbWeight::get();
+
+        // PendingChallengeRemovals
+        weight_meter.consume(db_weight.reads_writes(1, 1));
+
+        for (subnet_id, subnet_node_id) in PendingChallengeRemovals::<T>::take() {
+            // SubnetNodesData, RegisteredSubnetNodesData
+            weight_meter.consume(db_weight.reads(2));
+            let validator_id =
+                if let Ok(subnet_node) = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id) {
+                    subnet_node.validator_id
+                } else if let Ok(subnet_node) =
+                    RegisteredSubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+                {
+                    subnet_node.validator_id
+                } else {
+                    // Removed since the challenge was resolved
+                    continue;
+                };
+
+            // ValidatorSubnetNodes
+            weight_meter.consume(db_weight.reads(1));
+            weight_meter.consume(T::WeightInfo::perform_remove_subnet_node(
+                ValidatorSubnetNodes::<T>::get(validator_id).len() as u32,
+                TotalSubnetNodes::<T>::get(subnet_id),
+            ));
+            Self::perform_remove_subnet_node(subnet_id, subnet_node_id);
+
+            Self::deposit_event(Event::ChallengedSubnetNodeRemoved {
+                subnet_id,
+                subnet_node_id,
+            });
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::Pays;
+use frame_support::pallet_prelude::Weight;
 use sp_runtime::traits::Hash;
 
 impl<T: Config> Pallet<T> {
@@ -100,7 +101,7 @@ impl<T: Config> Pallet<T> {
 
         // --- Check if we are in reveal period
         ensure!(
-            !Self::in_overwatch_commit_period(),
+            Self::in_overwatch_reveal_period(),
             Error::<T>::NotRevealPeriod
         );
 
@@ -135,4 +136,109 @@ impl<T: Config> Pallet<T> {
 
         Ok(Pays::No.into())
     }
+
+    /// Penalize overwatch nodes for the commits of an overwatch epoch they didn't reveal
+    ///
+    /// The node's `ow_score` is decreased by `OverwatchNonRevealReputationFactor` and its stake
+    /// by `OverwatchNonRevealStakePenalty`, both scaled by the share of its commits left
+    /// unrevealed so partial reveals are penalized partially.
+    pub fn penalize_overwatch_non_reveals(overwatch_epoch: u32) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // OverwatchNonRevealReputationFactor, OverwatchNonRevealStakePenalty
+        let mut weight = db_weight.reads(2);
+        let reputation_factor = OverwatchNonRevealReputationFactor::<T>::get();
+        let stake_penalty = OverwatchNonRevealStakePenalty::<T>::get();
+
+        // {node_id, (commits, unrevealed subnet IDs)}
+        let mut node_commits: BTreeMap<u32, (u32, Vec<u32>)> = BTreeMap::new();
+        for ((overwatch_node_id, subnet_id), _) in
+            OverwatchCommits::<T>::iter_prefix((overwatch_epoch,))
+        {
+            // OverwatchCommits, OverwatchReveals
+            weight = weight.saturating_add(db_weight.reads(2));
+            let entry = node_commits
+                .entry(overwatch_node_id)
+                .or_insert((0, Vec::new()));
+            entry.0.saturating_inc();
+            if !OverwatchReveals::<T>::contains_key((overwatch_epoch, subnet_id, overwatch_node_id))
+            {
+                entry.1.push(subnet_id);
+            }
+        }
+
+        for (overwatch_node_id, (commits, unrevealed_subnet_ids)) in node_commits {
+            if unrevealed_subnet_ids.is_empty() {
+                continue;
+            }
+
+            let unrevealed_share =
+                Self::percent_div(unrevealed_subnet_ids.len() as u128, commits as u128);
+
+            let (penalty_weight, penalty) = Self::penalize_overwatch_node(
+                overwatch_node_id,
+                reputation_factor,
+                stake_penalty,
+                unrevealed_share,
+            );
+            weight = weight.saturating_add(penalty_weight);
+            let Some((ow_score, stake_penalty)) = penalty else {
+                continue;
+            };
+
+            Self::deposit_event(Event::OverwatchNonRevealPenalty {
+                overwatch_node_id,
+                overwatch_epoch,
+                unrevealed_subnet_ids,
+                ow_score,
+                stake_penalty,
+            });
+        }
+
+        weight
+    }
+
+    /// Decrease an overwatch node's `ow_score` by `reputation_factor` and slash `stake_penalty`
+    /// of its stake, both scaled by `share`
+    ///
+    /// Returns the weight and the resulting `(ow_score, stake_penalty)`, `None` if the node no
+    /// longer exists.
+    pub fn penalize_overwatch_node(
+        overwatch_node_id: u32,
+        reputation_factor: u128,
+        stake_penalty: u128,
+        share: u128,
+    ) -> (Weight, Option<(u128, u128)>) {
+        let db_weight = T::DbWeight::get();
+
+        // OverwatchNodes
+        let mut weight = db_weight.reads(1);
+        if !OverwatchNodes::<T>::contains_key(overwatch_node_id) {
+            return (weight, None);
+        }
+
+        // OverwatchNodeValidatorId, ValidatorReputation
+        weight = weight.saturating_add(db_weight.reads_writes(2, 1));
+        let mut ow_score = 0;
+        if let Some(validator_id) = OverwatchNodeValidatorId::<T>::get(overwatch_node_id) {
+            ValidatorReputation::<T>::mutate(validator_id, |reputation| {
+                reputation.ow_score =
+                    Self::decrease_rep(reputation.ow_score, reputation_factor, Some(share));
+                ow_score = reputation.ow_score;
+            });
+        }
+
+        // OverwatchNodeStakeBalance, TotalOverwatchNodeStakeBalance
+        // OverwatchNodeValidatorId, ValidatorColdkey, Holds
+        weight = weight.saturating_add(db_weight.reads_writes(5, 3));
+        let stake_penalty = Self::percent_mul(
+            OverwatchNodeStakeBalance::<T>::get(overwatch_node_id),
+            Self::percent_mul(stake_penalty, share),
+        );
+        if stake_penalty > 0 {
+            Self::slash_overwatch_node_stake(overwatch_node_id, stake_penalty);
+        }
+
+        (weight, Some((ow_score, stake_penalty)))
+    }
 }
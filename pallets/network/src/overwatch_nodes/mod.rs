@@ -1,4 +1,6 @@
 use super::*;
+pub mod beacon;
+pub mod challenges;
 pub mod commit_reveal;
 pub mod registering;
 pub mod removing;
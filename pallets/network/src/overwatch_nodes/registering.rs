@@ -74,7 +74,7 @@ impl<T: Config> Pallet<T> {
 
         // ⸺ Stake
         ensure!(
-            Self::remove_balance_from_coldkey_account(&coldkey, balance) == true,
+            Self::hold_stake(&coldkey, HoldReason::OverwatchStake, stake_to_be_added),
             Error::<T>::BalanceWithdrawalError
         );
         Self::increase_overwatch_node_stake(current_uid, stake_to_be_added);
@@ -14,6 +14,15 @@
 // limitations under the License.
 
 use super::*;
+use sp_core::U256;
+
+/// Maximum items returned by a paginated query
+pub const MAX_PAGE_LIMIT: u32 = 256;
+
+/// Maximum storage entries scanned by a paginated query
+///
+/// A filtered query that hits this returns a partial page with a `next_cursor`.
+pub const MAX_PAGE_SCAN: u32 = 1024;
 
 impl<T: Config> Pallet<T> {
     pub fn get_subnet_info(subnet_id: u32) -> Option<SubnetInfo<T>> {
@@ -102,6 +111,47 @@ impl<T: Config> Pallet<T> {
         infos
     }
 
+    /// Get subnets info one page at a time
+    ///
+    /// Subnets are returned in storage order of `SubnetsData` starting after the `cursor` subnet ID
+    /// taken from a previous page's `next_cursor`.
+    pub fn get_all_subnets_info_paged(cursor: Option<u32>, limit: u32) -> Page<SubnetInfo<T>, u32> {
+        let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+        let mut items: Vec<SubnetInfo<T>> = Vec::new();
+        if limit == 0 {
+            return Page {
+                items,
+                next_cursor: cursor,
+            };
+        }
+
+        let mut iter = match cursor {
+            Some(subnet_id) => {
+                SubnetsData::<T>::iter_keys_from(SubnetsData::<T>::hashed_key_for(subnet_id))
+            }
+            None => SubnetsData::<T>::iter_keys(),
+        };
+
+        let mut last_key: Option<u32> = None;
+        for subnet_id in iter.by_ref() {
+            last_key = Some(subnet_id);
+            if let Some(subnet_info) = Self::get_subnet_info(subnet_id) {
+                items.push(subnet_info);
+            }
+            if items.len() >= limit {
+                break;
+            }
+        }
+
+        let next_cursor = if iter.next().is_some() {
+            last_key
+        } else {
+            None
+        };
+
+        Page { items, next_cursor }
+    }
+
     pub fn get_subnet_node_info(
         subnet_id: u32,
         subnet_node_id: u32,
@@ -177,6 +227,117 @@ impl<T: Config> Pallet<T> {
         infos
     }
 
+    /// Get subnet nodes info one page at a time
+    ///
+    /// Nodes are returned in storage order of `SubnetNodeReputation` starting after `cursor`, a
+    /// `(subnet_id, subnet_node_id)` pair taken from a previous page's `next_cursor`.
+    pub fn get_subnet_nodes_info_paged(
+        filter: SubnetNodeInfoFilter,
+        cursor: Option<(u32, u32)>,
+        limit: u32,
+    ) -> Page<SubnetNodeInfo<T>, (u32, u32)> {
+        let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+        let mut items: Vec<SubnetNodeInfo<T>> = Vec::new();
+        if limit == 0 {
+            return Page {
+                items,
+                next_cursor: cursor,
+            };
+        }
+
+        // One more than the scan limit to know if there is a next page
+        let scan = MAX_PAGE_SCAN as usize + 1;
+        let mut iter: Vec<(u32, u32, u128)> = match (filter.subnet_id, cursor) {
+            (Some(subnet_id), Some((cursor_subnet_id, subnet_node_id)))
+                if cursor_subnet_id == subnet_id =>
+            {
+                SubnetNodeReputation::<T>::iter_prefix_from(
+                    subnet_id,
+                    SubnetNodeReputation::<T>::hashed_key_for(subnet_id, subnet_node_id),
+                )
+                .take(scan)
+                .map(|(subnet_node_id, reputation)| (subnet_id, subnet_node_id, reputation))
+                .collect()
+            }
+            (Some(subnet_id), _) => SubnetNodeReputation::<T>::iter_prefix(subnet_id)
+                .take(scan)
+                .map(|(subnet_node_id, reputation)| (subnet_id, subnet_node_id, reputation))
+                .collect(),
+            (None, Some((subnet_id, subnet_node_id))) => SubnetNodeReputation::<T>::iter_from(
+                SubnetNodeReputation::<T>::hashed_key_for(subnet_id, subnet_node_id),
+            )
+            .take(scan)
+            .collect(),
+            (None, None) => SubnetNodeReputation::<T>::iter().take(scan).collect(),
+        }
+        .into_iter();
+
+        let mut scanned: u32 = 0;
+        let mut last_key: Option<(u32, u32)> = None;
+        for (subnet_id, subnet_node_id, reputation) in iter.by_ref() {
+            scanned = scanned.saturating_add(1);
+            last_key = Some((subnet_id, subnet_node_id));
+
+            if Self::subnet_node_reputation_in_range(&filter, reputation) {
+                if let Some(subnet_node_info) =
+                    Self::get_subnet_node_info(subnet_id, subnet_node_id)
+                {
+                    if Self::subnet_node_info_matches(&filter, &subnet_node_info) {
+                        items.push(subnet_node_info);
+                    }
+                }
+            }
+
+            if scanned >= MAX_PAGE_SCAN || items.len() >= limit {
+                break;
+            }
+        }
+
+        // Only point to the next page if something is left to scan
+        let next_cursor = if iter.next().is_some() {
+            last_key
+        } else {
+            None
+        };
+
+        Page { items, next_cursor }
+    }
+
+    fn subnet_node_reputation_in_range(filter: &SubnetNodeInfoFilter, reputation: u128) -> bool {
+        filter.min_reputation.map_or(true, |min| reputation >= min)
+            && filter.max_reputation.map_or(true, |max| reputation <= max)
+    }
+
+    fn subnet_node_info_matches(
+        filter: &SubnetNodeInfoFilter,
+        subnet_node_info: &SubnetNodeInfo<T>,
+    ) -> bool {
+        if let Some(min_class) = filter.min_class {
+            let current_subnet_epoch =
+                Self::get_current_subnet_epoch_as_u32(subnet_node_info.subnet_id);
+            let classification = &subnet_node_info.classification;
+            if classification.node_class < min_class
+                || classification.start_epoch > current_subnet_epoch
+            {
+                return false;
+            }
+        }
+
+        if let Some(min_stake) = filter.min_stake {
+            if subnet_node_info.stake_balance < min_stake {
+                return false;
+            }
+        }
+
+        if let Some(validator_id) = filter.validator_id {
+            if subnet_node_info.validator_id != Some(validator_id) {
+                return false;
+            }
+        }
+
+        true
+    }
+
     /// Get all subnet ID nodes info
     pub fn get_all_subnet_nodes_info() -> Vec<SubnetNodeInfo<T>> {
         let mut infos: Vec<SubnetNodeInfo<T>> = Vec::new();
@@ -194,18 +355,35 @@ impl<T: Config> Pallet<T> {
         infos
     }
 
-    /// Get the elected validators node info
+    /// Elected validator of a subnet epoch with the election probability of each node
+    ///
+    /// Probabilities are computed from the current election slots and state, not the state at the
+    /// time `subnet_epoch` was elected.
     pub fn get_elected_validator_info(
         subnet_id: u32,
         subnet_epoch: u32,
-    ) -> Option<SubnetNodeInfo<T>> {
-        match SubnetElectedValidator::<T>::try_get(subnet_id, subnet_epoch) {
+    ) -> ElectedValidatorInfo<T> {
+        let validator = match SubnetElectedValidator::<T>::try_get(subnet_id, subnet_epoch) {
             Ok(subnet_node_id) => Self::get_subnet_node_info(subnet_id, subnet_node_id),
             Err(()) => None,
+        };
+
+        ElectedValidatorInfo {
+            validator,
+            election_mode: SubnetValidatorElectionMode::<T>::get(subnet_id),
+            election_probabilities: Self::get_validator_election_probabilities(
+                subnet_id,
+                &Self::get_consensus_validator_ids(subnet_id),
+            ),
         }
     }
 
-    pub fn get_validators_and_attestors(subnet_id: u32) -> Vec<SubnetNodeInfo<T>> {
+    /// Validator set of a subnet with each node's share of the attestation weight
+    ///
+    /// `attestation_ratio` is the effective ratio of the current subnet epoch's proposal under
+    /// the subnet's `AttestationWeighting` and current stake and reputation, the ratio used for
+    /// rewards is recorded in the epoch's `SubnetRewardsLedgerEntry`.
+    pub fn get_validators_and_attestors(subnet_id: u32) -> ValidatorsAndAttestors<T> {
         let mut infos: Vec<SubnetNodeInfo<T>> = Vec::new();
         if let Some(emergency_validator_data) = EmergencySubnetNodeElectionData::<T>::get(subnet_id)
         {
@@ -226,7 +404,40 @@ impl<T: Config> Pallet<T> {
             }
         };
 
-        infos
+        let subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+        let attestation_weighting = Self::get_attestation_weighting(subnet_id, subnet_epoch);
+        let node_weights: Vec<(u32, u128)> = infos
+            .iter()
+            .map(|info| {
+                (
+                    info.subnet_node_id,
+                    Self::get_attestation_weight(
+                        subnet_id,
+                        info.subnet_node_id,
+                        attestation_weighting,
+                    ),
+                )
+            })
+            .collect();
+        let total_weight = node_weights
+            .iter()
+            .fold(0u128, |acc, (_, weight)| acc.saturating_add(*weight));
+        let attestation_weights = node_weights
+            .into_iter()
+            .map(|(subnet_node_id, weight)| {
+                (subnet_node_id, Self::percent_div(weight, total_weight))
+            })
+            .collect();
+
+        let attestation_ratio = SubnetConsensusSubmission::<T>::get(subnet_id, subnet_epoch)
+            .map(|submission| Self::get_consensus_attestation_ratio(&submission));
+
+        ValidatorsAndAttestors {
+            validators: infos,
+            attestation_weighting,
+            attestation_weights,
+            attestation_ratio,
+        }
     }
 
     /// Proof-of-stake
@@ -409,6 +620,59 @@ impl<T: Config> Pallet<T> {
         delegate_stake
     }
 
+    /// Get an accounts delegate stake one page at a time
+    ///
+    /// Stakes are returned in storage order starting after the `cursor` subnet ID taken from a
+    /// previous page's `next_cursor`.
+    pub fn get_delegate_stakes_paged(
+        account_id: T::AccountId,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Page<DelegateStakeInfo, u32> {
+        let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+        let mut items: Vec<DelegateStakeInfo> = Vec::new();
+        if limit == 0 {
+            return Page {
+                items,
+                next_cursor: cursor,
+            };
+        }
+
+        let mut iter = match cursor {
+            Some(subnet_id) => AccountSubnetDelegateStakeShares::<T>::iter_prefix_from(
+                &account_id,
+                AccountSubnetDelegateStakeShares::<T>::hashed_key_for(&account_id, subnet_id),
+            ),
+            None => AccountSubnetDelegateStakeShares::<T>::iter_prefix(&account_id),
+        };
+
+        for (subnet_id, shares) in iter.by_ref() {
+            let balance = Self::convert_to_balance(
+                shares,
+                TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+                TotalSubnetDelegateStakeBalance::<T>::get(subnet_id),
+            );
+
+            items.push(DelegateStakeInfo {
+                subnet_id,
+                shares,
+                balance,
+            });
+
+            if items.len() >= limit {
+                break;
+            }
+        }
+
+        let next_cursor = if iter.next().is_some() {
+            items.last().map(|info| info.subnet_id)
+        } else {
+            None
+        };
+
+        Page { items, next_cursor }
+    }
+
     /// Get an accounts node delegate stake across the entire network
     pub fn get_node_delegate_stakes(account_id: T::AccountId) -> Vec<NodeDelegateStakeInfo> {
         let mut node_delegate_stake: Vec<NodeDelegateStakeInfo> = Vec::new();
@@ -506,4 +770,255 @@ impl<T: Config> Pallet<T> {
             })
             .collect()
     }
+
+    /// Commits of an overwatch epoch that were never revealed
+    ///
+    /// Overwatch node ID => committed subnet IDs without a reveal
+    pub fn get_unrevealed_overwatch_commits(overwatch_epoch: u32) -> BTreeMap<u32, Vec<u32>> {
+        let mut unrevealed: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for ((overwatch_node_id, subnet_id), _) in
+            OverwatchCommits::<T>::iter_prefix((overwatch_epoch,))
+        {
+            if !OverwatchReveals::<T>::contains_key((overwatch_epoch, subnet_id, overwatch_node_id))
+            {
+                unrevealed
+                    .entry(overwatch_node_id)
+                    .or_default()
+                    .push(subnet_id);
+            }
+        }
+        unrevealed
+    }
+
+    /// Overwatch aggregation of a subnet's weight for an overwatch epoch
+    ///
+    /// Returns `None` until the overwatch epoch's weights are calculated
+    pub fn get_overwatch_subnet_weight_breakdown(
+        overwatch_epoch: u32,
+        subnet_id: u32,
+    ) -> Option<OverwatchSubnetWeightBreakdown> {
+        let weight = OverwatchSubnetWeights::<T>::get(overwatch_epoch, subnet_id)?;
+        let clip_band = OverwatchWeightClipBand::<T>::get();
+
+        let nodes = OverwatchReveals::<T>::iter_prefix((overwatch_epoch, subnet_id))
+            .map(|(overwatch_node_id, node_weight)| {
+                let deviation = node_weight.abs_diff(weight);
+                OverwatchNodeWeightDeviation {
+                    overwatch_node_id,
+                    weight: node_weight,
+                    deviation,
+                    clipped: deviation > clip_band,
+                }
+            })
+            .collect();
+
+        Some(OverwatchSubnetWeightBreakdown {
+            overwatch_epoch,
+            subnet_id,
+            weight,
+            clip_band,
+            nodes,
+        })
+    }
+
+    /// Get a subnet's rewards ledger entries between two epochs, inclusive, oldest first
+    pub fn get_subnet_rewards_ledger(
+        subnet_id: u32,
+        from_epoch: u32,
+        to_epoch: u32,
+    ) -> Vec<(u32, SubnetRewardsLedgerEntry<T::AccountId>)> {
+        SubnetRewardsLedgerEpochs::<T>::get(subnet_id)
+            .into_iter()
+            .filter(|epoch| *epoch >= from_epoch && *epoch <= to_epoch)
+            .filter_map(|epoch| {
+                SubnetRewardsLedger::<T>::get(subnet_id, epoch).map(|entry| (epoch, entry))
+            })
+            .collect()
+    }
+
+    /// Get the rewards a subnet node earned over the rewards ledger, oldest first
+    pub fn get_subnet_node_rewards_history(
+        subnet_id: u32,
+        subnet_node_id: u32,
+    ) -> Vec<RewardRecord> {
+        let mut records: Vec<RewardRecord> = Vec::new();
+
+        for (epoch, entry) in Self::get_subnet_rewards_ledger(subnet_id, 0, u32::MAX) {
+            if entry.validator_subnet_node_id == subnet_node_id && entry.validator_reward != 0 {
+                records.push(RewardRecord {
+                    epoch,
+                    subnet_id,
+                    subnet_node_id: Some(subnet_node_id),
+                    source: RewardSource::Validator,
+                    amount: entry.validator_reward,
+                });
+            }
+
+            if let Some(node_reward) = entry
+                .node_rewards
+                .iter()
+                .find(|node_reward| node_reward.subnet_node_id == subnet_node_id)
+            {
+                records.push(RewardRecord {
+                    epoch,
+                    subnet_id,
+                    subnet_node_id: Some(subnet_node_id),
+                    source: RewardSource::SubnetNode,
+                    amount: node_reward.reward,
+                });
+            }
+        }
+
+        records
+    }
+
+    /// Get the rewards an account earned over the rewards ledger one page of subnets at a time
+    ///
+    /// Covers the account as a validator coldkey, subnet owner, delegate account and delegate
+    /// staker. Up to `limit` subnets are read per page in storage order of
+    /// `SubnetRewardsLedgerEpochs`, starting after the `cursor` subnet ID taken from a previous
+    /// page's `next_cursor`. Records are ordered by subnet, then epoch.
+    ///
+    /// The ledger doesn't record per-account shares, so delegate stake rewards are estimates of
+    /// the account's current shares against the pool shares at the time of the reward. They are
+    /// inaccurate for any epoch the account's shares differed from today.
+    pub fn get_account_rewards_history(
+        account_id: T::AccountId,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Page<RewardRecord, u32> {
+        let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+        let mut items: Vec<RewardRecord> = Vec::new();
+        if limit == 0 {
+            return Page {
+                items,
+                next_cursor: cursor,
+            };
+        }
+
+        let mut iter = match cursor {
+            Some(subnet_id) => SubnetRewardsLedgerEpochs::<T>::iter_keys_from(
+                SubnetRewardsLedgerEpochs::<T>::hashed_key_for(subnet_id),
+            ),
+            None => SubnetRewardsLedgerEpochs::<T>::iter_keys(),
+        };
+
+        let validator_id = ColdkeyValidatorId::<T>::get(&account_id);
+        let mut last_key: Option<u32> = None;
+        let mut subnets: usize = 0;
+        for subnet_id in iter.by_ref() {
+            last_key = Some(subnet_id);
+            Self::append_account_subnet_rewards(&account_id, validator_id, subnet_id, &mut items);
+            subnets += 1;
+            if subnets >= limit {
+                break;
+            }
+        }
+
+        let next_cursor = if iter.next().is_some() {
+            last_key
+        } else {
+            None
+        };
+
+        Page { items, next_cursor }
+    }
+
+    /// Append the rewards an account earned over a subnet's rewards ledger, oldest first
+    fn append_account_subnet_rewards(
+        account_id: &T::AccountId,
+        validator_id: Option<u32>,
+        subnet_id: u32,
+        records: &mut Vec<RewardRecord>,
+    ) {
+        let subnet_shares = AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id);
+
+        for (epoch, entry) in Self::get_subnet_rewards_ledger(subnet_id, 0, u32::MAX) {
+            let mut push = |subnet_node_id: Option<u32>, source: RewardSource, amount: u128| {
+                if amount != 0 {
+                    records.push(RewardRecord {
+                        epoch,
+                        subnet_id,
+                        subnet_node_id,
+                        source,
+                        amount,
+                    });
+                }
+            };
+
+            if validator_id.is_some() && entry.validator_id == validator_id {
+                push(
+                    Some(entry.validator_subnet_node_id),
+                    RewardSource::Validator,
+                    entry.validator_reward,
+                );
+            }
+
+            for node_reward in &entry.node_rewards {
+                if Some(node_reward.validator_id) == validator_id {
+                    push(
+                        Some(node_reward.subnet_node_id),
+                        RewardSource::SubnetNode,
+                        node_reward.reward,
+                    );
+                }
+            }
+
+            if entry.subnet_owner.as_ref() == Some(account_id) {
+                push(None, RewardSource::SubnetOwner, entry.subnet_owner_reward);
+            }
+
+            for (subnet_node_id, (delegate_account, amount)) in &entry.delegate_account_allocations
+            {
+                if delegate_account == account_id {
+                    push(
+                        Some(*subnet_node_id),
+                        RewardSource::DelegateAccount,
+                        *amount,
+                    );
+                }
+            }
+
+            push(
+                None,
+                RewardSource::SubnetDelegateStake,
+                Self::rewards_ledger_share(
+                    entry.delegate_stake_reward,
+                    subnet_shares,
+                    entry.delegate_stake_total_shares,
+                ),
+            );
+
+            for validator_reward in &entry.validator_delegate_stake_rewards {
+                let shares = AccountValidatorDelegateStakeShares::<T>::get(
+                    account_id,
+                    validator_reward.validator_id,
+                );
+                push(
+                    None,
+                    RewardSource::ValidatorDelegateStake,
+                    Self::rewards_ledger_share(
+                        validator_reward.reward,
+                        shares,
+                        validator_reward.total_shares,
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Portion of a delegate stake pool reward owned by `shares`
+    fn rewards_ledger_share(reward: u128, shares: u128, total_shares: u128) -> u128 {
+        if shares == 0 {
+            return 0;
+        }
+
+        Self::checked_mul_div(
+            U256::from(reward),
+            U256::from(shares.min(total_shares)),
+            U256::from(total_shares),
+        )
+        .and_then(|res| res.try_into().ok())
+        .unwrap_or(0)
+    }
 }
@@ -0,0 +1,105 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Subnet lifecycle notifications for the RPC pub-sub, see `SubnetLifecycleNotification`
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Lifecycle notifications of the pallet `events`, optionally of one subnet
+    ///
+    /// The runtime API passes the events of the block it is called at.
+    pub fn get_subnet_lifecycle_notifications(
+        events: impl IntoIterator<Item = Event<T>>,
+        subnet_id: Option<u32>,
+    ) -> Vec<SubnetLifecycleNotification> {
+        events
+            .into_iter()
+            .filter_map(|event| Self::lifecycle_notification(event))
+            .filter(|notification| subnet_id.map_or(true, |id| notification.subnet_id() == id))
+            .collect()
+    }
+
+    fn lifecycle_notification(event: Event<T>) -> Option<SubnetLifecycleNotification> {
+        let notification = match event {
+            Event::SubnetRegistered { subnet_id, .. } => {
+                SubnetLifecycleNotification::SubnetStateChanged {
+                    subnet_id,
+                    state: SubnetState::Registered,
+                }
+            }
+            Event::SubnetActivated { subnet_id } | Event::SubnetUnpaused { subnet_id, .. } => {
+                SubnetLifecycleNotification::SubnetStateChanged {
+                    subnet_id,
+                    state: SubnetState::Active,
+                }
+            }
+            Event::SubnetPaused { subnet_id, .. } => {
+                SubnetLifecycleNotification::SubnetStateChanged {
+                    subnet_id,
+                    state: SubnetState::Paused,
+                }
+            }
+            Event::SubnetDeactivated { subnet_id, reason } => {
+                SubnetLifecycleNotification::SubnetRemoved { subnet_id, reason }
+            }
+            Event::SubnetNodeActivated {
+                subnet_id,
+                subnet_node_id,
+            } => SubnetLifecycleNotification::SubnetNodeActivated {
+                subnet_id,
+                subnet_node_id,
+            },
+            Event::SubnetNodeRemoved {
+                subnet_id,
+                subnet_node_id,
+            }
+            | Event::QueuedNodeRemoved {
+                subnet_id,
+                subnet_node_id,
+            } => SubnetLifecycleNotification::SubnetNodeRemoved {
+                subnet_id,
+                subnet_node_id,
+            },
+            Event::NodeClassGraduation {
+                subnet_id,
+                subnet_node_id,
+                classification,
+            } => SubnetLifecycleNotification::SubnetNodeClassGraduated {
+                subnet_id,
+                subnet_node_id,
+                classification,
+            },
+            Event::ValidatorElected {
+                subnet_id,
+                subnet_epoch,
+                subnet_node_id,
+            } => SubnetLifecycleNotification::ValidatorElected {
+                subnet_id,
+                subnet_epoch,
+                subnet_node_id,
+            },
+            Event::ValidatorSubmission {
+                subnet_id, epoch, ..
+            } => SubnetLifecycleNotification::AttestationWindowOpen {
+                subnet_id,
+                subnet_epoch: epoch,
+            },
+            _ => return None,
+        };
+
+        Some(notification)
+    }
+}
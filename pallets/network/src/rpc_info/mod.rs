@@ -1,2 +1,3 @@
 use super::*;
 pub mod info;
+pub mod lifecycle;
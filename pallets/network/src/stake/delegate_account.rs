@@ -52,6 +52,9 @@ impl<T: Config> Pallet<T> {
             block,
         )?;
 
+        // Delegate account balances are rewards and were never held
+        Self::move_stake_hold(&account_id, None, HoldReason::Unbonding, amount_to_remove)?;
+
         Self::decrease_delegate_account_balance(&account_id, amount_to_remove);
 
         // Add to ledger and always match the stake cooldown epochs (or greater cooldown)
@@ -58,7 +58,7 @@ impl<T: Config> Pallet<T> {
     /// * `delegate_stake_to_be_added` - Balance to add or swap.
     /// * `swap` - If we are swapping between subnets or nodes.
     ///              - True: Don't remove balance from users account
-    ///              - False: Check user balance is withdrawable and withdraw balance
+    ///              - False: Check user balance is withdrawable and hold balance
     ///
     pub fn perform_do_add_delegate_stake(
         account_id: &T::AccountId,
@@ -93,9 +93,13 @@ impl<T: Config> Pallet<T> {
             return (Err(Error::<T>::TxRateLimitExceeded.into()), 0, 0);
         }
 
-        // --- Ensure the remove operation from the account_id is a success.
+        // --- Ensure the hold on the account_id is a success.
         if !swap {
-            if Self::remove_balance_from_coldkey_account(&account_id, balance) == false {
+            if !Self::hold_stake(
+                &account_id,
+                HoldReason::DelegateStake,
+                delegate_stake_to_be_added,
+            ) {
                 return (Err(Error::<T>::BalanceWithdrawalError.into()), 0, 0);
             }
         }
@@ -249,6 +253,15 @@ impl<T: Config> Pallet<T> {
             ) {
                 return (Err(e), 0, 0);
             }
+
+            if let Err(e) = Self::move_stake_hold(
+                &account_id,
+                Some(HoldReason::DelegateStake),
+                HoldReason::Unbonding,
+                delegate_stake_to_be_removed,
+            ) {
+                return (Err(e), 0, 0);
+            }
         }
 
         // --- We remove the shares from the account and balance from the pool
@@ -314,6 +327,29 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let account_id: T::AccountId = ensure_signed(origin)?;
 
+        Self::perform_transfer_delegate_stake(
+            &account_id,
+            subnet_id,
+            &to_account_id,
+            delegate_stake_shares_to_transfer,
+        )
+    }
+
+    /// Transfer subnet delegate stake shares between accounts
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - Account transferring the shares.
+    /// * `subnet_id` - Subnet ID of the shares.
+    /// * `to_account_id` - Account receiving the shares.
+    /// * `delegate_stake_shares_to_transfer` - Shares of pool to transfer.
+    ///
+    pub fn perform_transfer_delegate_stake(
+        account_id: &T::AccountId,
+        subnet_id: u32,
+        to_account_id: &T::AccountId,
+        delegate_stake_shares_to_transfer: u128,
+    ) -> DispatchResult {
         ensure!(
             account_id != to_account_id,
             Error::<T>::TransferToSelfNotAllowed
@@ -324,6 +360,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStakeToWithdraw
         );
 
+        ensure!(
+            AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id)
+                >= delegate_stake_shares_to_transfer,
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
         let total_subnet_delegated_stake_shares =
             TotalSubnetDelegateStakeShares::<T>::get(subnet_id);
         let total_subnet_delegated_stake_balance =
@@ -342,9 +384,17 @@ impl<T: Config> Pallet<T> {
             Error::<T>::MinDelegateStakeDepositNotReached
         );
 
+        // --- Move the held principal with the shares
+        Self::transfer_stake_hold(
+            account_id,
+            to_account_id,
+            HoldReason::DelegateStake,
+            delegate_stake_to_be_transferred,
+        )?;
+
         // --- Remove shares from caller
         Self::decrease_account_delegate_stake(
-            &account_id,
+            account_id,
             subnet_id,
             0, // Do not mutate balance since we are transferring in the same subnet
             delegate_stake_shares_to_transfer,
@@ -352,7 +402,7 @@ impl<T: Config> Pallet<T> {
 
         // --- Increase shares to `to_account_id`
         Self::increase_account_delegate_stake(
-            &to_account_id,
+            to_account_id,
             subnet_id,
             0, // Do not mutate balance since we are transferring in the same subnet
             delegate_stake_shares_to_transfer,
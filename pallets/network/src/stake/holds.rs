@@ -0,0 +1,189 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Stake held on the coldkey through `fungible::MutateHold`
+//
+// Staked balances stay on the account as held balance under a `HoldReason` so wallets and block
+// explorers see them. The hold tracks the principal, rewards a position earns are minted when the
+// stake leaves the position.
+
+use super::*;
+use frame_support::pallet_prelude::DispatchError;
+use frame_support::traits::{
+    fungible::{Inspect, InspectHold, Mutate, MutateHold},
+    tokens::{Fortitude, Precision, Restriction},
+};
+
+pub type HoldBalance<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+impl<T: Config> Pallet<T> {
+    pub fn u128_to_hold_balance(input: u128) -> Option<HoldBalance<T>> {
+        input.try_into().ok()
+    }
+
+    /// Stake can't change while `migrations::step_stake_to_holds` is moving positions to holds
+    ///
+    /// A position that changes before it is migrated would be held twice, once by the call and once
+    /// by the migration.
+    pub fn ensure_stake_holds_migrated() -> DispatchResult {
+        ensure!(
+            !StakeHoldsMigration::<T>::exists(),
+            Error::<T>::StakeHoldsMigrationInProgress
+        );
+        Ok(())
+    }
+
+    /// Balance held on `coldkey` for `reason`
+    pub fn get_stake_hold(coldkey: &T::AccountId, reason: HoldReason) -> u128 {
+        T::Currency::balance_on_hold(&reason.into(), coldkey)
+            .try_into()
+            .unwrap_or(u128::MAX)
+    }
+
+    /// Hold `amount` of the free balance of `coldkey` as stake
+    ///
+    /// Replaces withdrawing the balance from the coldkey when staking
+    pub fn hold_stake(coldkey: &T::AccountId, reason: HoldReason, amount: u128) -> bool {
+        let Some(amount) = Self::u128_to_hold_balance(amount) else {
+            return false;
+        };
+
+        T::Currency::hold(&reason.into(), coldkey, amount).is_ok()
+    }
+
+    /// Move `amount` of stake from the `from` hold to the `to` hold of `coldkey`
+    ///
+    /// Up to the held principal is released from `from`, the remainder was earned by the position
+    /// and is minted. A `from` of `None` mints the full amount, e.g. for rewards.
+    pub fn move_stake_hold(
+        coldkey: &T::AccountId,
+        from: Option<HoldReason>,
+        to: HoldReason,
+        amount: u128,
+    ) -> DispatchResult {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let to_balance = |v: u128| -> Result<HoldBalance<T>, DispatchError> {
+            Self::u128_to_hold_balance(v).ok_or(Error::<T>::CouldNotConvertToBalance.into())
+        };
+
+        let released = match from {
+            Some(from) => {
+                let released = Self::get_stake_hold(coldkey, from).min(amount);
+                if released > 0 {
+                    T::Currency::release(
+                        &from.into(),
+                        coldkey,
+                        to_balance(released)?,
+                        Precision::Exact,
+                    )?;
+                }
+                released
+            }
+            None => 0,
+        };
+
+        // Rewards earned by the position were never held
+        let earned = amount.saturating_sub(released);
+        if earned > 0 {
+            T::Currency::mint_into(coldkey, to_balance(earned)?)?;
+        }
+
+        T::Currency::hold(&to.into(), coldkey, to_balance(amount)?)
+    }
+
+    /// Release `amount` of the `reason` hold of `coldkey` to its free balance
+    ///
+    /// Any amount above the held principal is minted, see `move_stake_hold`.
+    pub fn release_stake_hold(
+        coldkey: &T::AccountId,
+        reason: HoldReason,
+        amount: u128,
+    ) -> DispatchResult {
+        let to_balance = |v: u128| -> Result<HoldBalance<T>, DispatchError> {
+            Self::u128_to_hold_balance(v).ok_or(Error::<T>::CouldNotConvertToBalance.into())
+        };
+
+        let released = Self::get_stake_hold(coldkey, reason).min(amount);
+        let earned = amount.saturating_sub(released);
+
+        if released > 0 {
+            T::Currency::release(
+                &reason.into(),
+                coldkey,
+                to_balance(released)?,
+                Precision::Exact,
+            )?;
+        }
+
+        if earned > 0 {
+            T::Currency::mint_into(coldkey, to_balance(earned)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Burn up to `amount` of the `reason` hold of `coldkey`, returns the amount burned
+    pub fn burn_stake_hold(coldkey: &T::AccountId, reason: HoldReason, amount: u128) -> u128 {
+        let amount = Self::get_stake_hold(coldkey, reason).min(amount);
+        let Some(amount) = Self::u128_to_hold_balance(amount) else {
+            return 0;
+        };
+
+        T::Currency::burn_held(
+            &reason.into(),
+            coldkey,
+            amount,
+            Precision::BestEffort,
+            Fortitude::Force,
+        )
+        .ok()
+        .and_then(|burned| burned.try_into().ok())
+        .unwrap_or(0)
+    }
+
+    /// Transfer up to `amount` of the `reason` hold of `from` to the same hold of `to`
+    ///
+    /// Used when stake positions change owner, the receiver only takes over the held principal.
+    pub fn transfer_stake_hold(
+        from: &T::AccountId,
+        to: &T::AccountId,
+        reason: HoldReason,
+        amount: u128,
+    ) -> DispatchResult {
+        let amount = Self::get_stake_hold(from, reason).min(amount);
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let amount =
+            Self::u128_to_hold_balance(amount).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+
+        T::Currency::transfer_on_hold(
+            &reason.into(),
+            from,
+            to,
+            amount,
+            Precision::Exact,
+            Restriction::OnHold,
+            Fortitude::Polite,
+        )?;
+
+        Ok(())
+    }
+}
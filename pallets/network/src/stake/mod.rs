@@ -1,9 +1,11 @@
 use super::*;
 pub mod delegate_account;
 pub mod delegate_staking;
+pub mod holds;
 pub mod node_staking;
 pub mod overwatch_node_staking;
 pub mod staking_queue;
 pub mod staking_utils;
 pub mod transfer_utils;
+pub mod unbonding;
 pub mod validator_delegate_staking;
@@ -25,6 +25,34 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let coldkey: T::AccountId = ensure_signed(origin)?;
 
+        Self::perform_do_add_node_stake(
+            &coldkey,
+            subnet_id,
+            subnet_node_id,
+            stake_to_be_added,
+            false,
+        )
+    }
+
+    /// Add to the stake of a subnet node
+    ///
+    /// # Arguments
+    ///
+    /// * `coldkey` - Coldkey of the validator that owns the subnet node.
+    /// * `subnet_id` - Subnet ID of the node.
+    /// * `subnet_node_id` - Subnet node ID adding stake to.
+    /// * `stake_to_be_added` - Balance to add.
+    /// * `swap` - If the balance is already held as stake.
+    ///              - True: Don't hold balance from users account
+    ///              - False: Check user balance is withdrawable and hold balance
+    ///
+    pub fn perform_do_add_node_stake(
+        coldkey: &T::AccountId,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        stake_to_be_added: u128,
+        swap: bool,
+    ) -> DispatchResult {
         ensure!(
             SubnetsData::<T>::contains_key(subnet_id),
             Error::<T>::InvalidSubnetId
@@ -38,7 +66,7 @@ impl<T: Config> Pallet<T> {
         let validator_coldkey = ValidatorColdkey::<T>::try_get(validator_id)
             .map_err(|_| Error::<T>::InvalidValidatorId)?;
 
-        ensure!(coldkey == validator_coldkey, Error::<T>::NotKeyOwner);
+        ensure!(*coldkey == validator_coldkey, Error::<T>::NotKeyOwner);
 
         ensure!(stake_to_be_added != 0, Error::<T>::InvalidAmount);
 
@@ -65,7 +93,7 @@ impl<T: Config> Pallet<T> {
 
         // --- Ensure the callers coldkey has enough stake to perform the transaction.
         ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, balance),
+            swap || Self::can_remove_balance_from_coldkey_account(&coldkey, balance),
             Error::<T>::NotEnoughBalanceToStake
         );
 
@@ -77,9 +105,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::TxRateLimitExceeded
         );
 
-        // --- Ensure the remove operation from the coldkey is a success.
+        // --- Ensure the hold on the coldkey is a success.
         ensure!(
-            Self::remove_balance_from_coldkey_account(&coldkey, balance) == true,
+            swap || Self::hold_stake(&coldkey, HoldReason::NodeStake, stake_to_be_added),
             Error::<T>::BalanceWithdrawalError
         );
 
@@ -136,6 +164,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStakeToWithdraw
         );
 
+        // --- Stake locked by pending slashes can't be removed during the appeal window
+        ensure!(
+            node_stake_balance
+                .saturating_sub(NodeSubnetPendingSlash::<T>::get(subnet_node_id, subnet_id))
+                >= stake_to_be_removed,
+            Error::<T>::StakeLockedByPendingSlash
+        );
+
         // if user is still a subnet node they must keep the required minimum balance
         if is_subnet_node {
             ensure!(
@@ -168,6 +204,13 @@ impl<T: Config> Pallet<T> {
             block,
         )?;
 
+        Self::move_stake_hold(
+            &coldkey,
+            Some(HoldReason::NodeStake),
+            HoldReason::Unbonding,
+            stake_to_be_removed,
+        )?;
+
         // Clean up validator subnet nodes from `ValidatorSubnetNodes`
         if should_clean_validator_subnet_nodes {
             Self::clean_validator_subnet_nodes(validator_id);
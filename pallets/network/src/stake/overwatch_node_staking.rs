@@ -55,9 +55,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughBalanceToStake
         );
 
-        // --- Ensure the remove operation from the coldkey is a success.
+        // --- Ensure the hold on the coldkey is a success.
         ensure!(
-            Self::remove_balance_from_coldkey_account(&coldkey, balance) == true,
+            Self::hold_stake(&coldkey, HoldReason::OverwatchStake, stake_to_be_added),
             Error::<T>::BalanceWithdrawalError
         );
 
@@ -122,6 +122,13 @@ impl<T: Config> Pallet<T> {
             block,
         )?;
 
+        Self::move_stake_hold(
+            &coldkey,
+            Some(HoldReason::OverwatchStake),
+            HoldReason::Unbonding,
+            stake_to_be_removed,
+        )?;
+
         // --- 7. We remove the balance from the hotkey.
         Self::decrease_overwatch_node_stake(overwatch_node_id, stake_to_be_removed);
 
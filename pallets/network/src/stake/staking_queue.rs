@@ -16,6 +16,12 @@
 use super::*;
 use sp_core::U256;
 
+/// Maximum number of subnets in a `RebalanceSubnetDelegateStake` call
+pub const MAX_REBALANCE_TARGETS: u32 = 16;
+
+/// Maximum number of calls an account can have in the swap queue
+pub const MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT: u32 = 16;
+
 impl<T: Config> Pallet<T> {
     /// Queue a swap call
     ///
@@ -38,13 +44,34 @@ impl<T: Config> Pallet<T> {
         account_id: T::AccountId,
         call: QueuedSwapCall<T::AccountId>,
     ) -> DispatchResult {
+        Self::queue_swap_after(account_id, call, T::EpochLength::get())
+    }
+
+    /// Queue a swap call to be executed after `execute_after_blocks`
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - Account ID of the caller.
+    /// * `call` - Swap call to queue.
+    /// * `execute_after_blocks` - Blocks to wait before the call is executed.
+    ///
+    pub fn queue_swap_after(
+        account_id: T::AccountId,
+        call: QueuedSwapCall<T::AccountId>,
+        execute_after_blocks: u32,
+    ) -> DispatchResult {
+        ensure!(
+            AccountSwapQueueCalls::<T>::get(&account_id) < MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT,
+            Error::<T>::TooManyQueuedSwapCalls
+        );
+
         let id = NextSwapQueueId::<T>::get();
 
         let queued_item = QueuedSwapItem {
             id,
             call: call.clone(),
             queued_at_block: Self::get_current_block_as_u32(),
-            execute_after_blocks: T::EpochLength::get(),
+            execute_after_blocks,
         };
 
         SwapQueueOrder::<T>::try_mutate(|queue| -> DispatchResult {
@@ -55,6 +82,7 @@ impl<T: Config> Pallet<T> {
         })?;
 
         SwapCallQueue::<T>::insert(&id, &queued_item);
+        AccountSwapQueueCalls::<T>::mutate(&account_id, |n| n.saturating_inc());
 
         NextSwapQueueId::<T>::mutate(|next_id| *next_id = next_id.saturating_add(1));
 
@@ -67,6 +95,54 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Queue a call scheduled by the account, see `queue_swap_after`
+    ///
+    /// Scheduled calls hold `SwapQueueDeposit` from the account until they leave the queue.
+    pub fn queue_scheduled_swap(
+        account_id: T::AccountId,
+        call: QueuedSwapCall<T::AccountId>,
+        execute_after_blocks: u32,
+    ) -> DispatchResult {
+        let id = NextSwapQueueId::<T>::get();
+        Self::queue_swap_after(account_id.clone(), call, execute_after_blocks)?;
+
+        let deposit = SwapQueueDeposit::<T>::get();
+        if deposit > 0 {
+            ensure!(
+                Self::hold_stake(&account_id, HoldReason::SwapQueueDeposit, deposit),
+                Error::<T>::NotEnoughBalance
+            );
+            SwapCallDeposits::<T>::insert(id, deposit);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a call from `SwapCallQueue` and release its deposit
+    ///
+    /// The caller removes the ID from `SwapQueueOrder`
+    pub fn remove_queued_swap_call(id: u32, account_id: &T::AccountId) {
+        SwapCallQueue::<T>::remove(&id);
+        AccountSwapQueueCalls::<T>::mutate_exists(account_id, |n| {
+            *n = n.map(|n| n.saturating_sub(1)).filter(|n| *n > 0);
+        });
+
+        if let Some(deposit) = SwapCallDeposits::<T>::take(id) {
+            let deposit =
+                Self::get_stake_hold(account_id, HoldReason::SwapQueueDeposit).min(deposit);
+            if let Err(error) =
+                Self::release_stake_hold(account_id, HoldReason::SwapQueueDeposit, deposit)
+            {
+                log::error!(
+                    target: "runtime::network",
+                    "failed to release swap queue deposit of call {:?}: {:?}",
+                    id,
+                    error,
+                );
+            }
+        }
+    }
+
     pub fn do_update_swap_queue(
         key: T::AccountId,
         id: u32,
@@ -74,7 +150,17 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         SwapCallQueue::<T>::mutate(&id, |item_opt| -> DispatchResult {
             let item = item_opt.as_mut().ok_or(Error::<T>::SwapCallNotFound)?;
+            ensure!(item.call.get_owner() == &key, Error::<T>::NotKeyOwner);
+            ensure!(
+                matches!(
+                    item.call,
+                    QueuedSwapCall::SwapToSubnetDelegateStake { .. }
+                        | QueuedSwapCall::SwapToValidatorDelegateStake { .. }
+                ),
+                Error::<T>::SwapCallNotUpdatable
+            );
             let call_balance = item.call.get_queue_balance();
+            let call_hold_reason = item.call.get_hold_reason();
 
             match new_call {
                 QueuedSwapCall::SwapToSubnetDelegateStake {
@@ -125,10 +211,237 @@ impl<T: Config> Pallet<T> {
                         call: item.call.clone(),
                     });
                 }
+                _ => return Err(Error::<T>::SwapCallNotUpdatable.into()),
+            }
+
+            // --- Move the queued balance to the hold of the new stake type
+            if let (Some(from), Some(to)) = (call_hold_reason, item.call.get_hold_reason()) {
+                if from != to {
+                    Self::move_stake_hold(&key, Some(from), to, call_balance)?;
+                }
             }
+
             Ok(())
         })?;
 
         Ok(())
     }
+
+    /// Schedule a subnet delegate stake removal, see `schedule_remove_delegate_stake`
+    pub fn do_schedule_remove_delegate_stake(
+        account_id: T::AccountId,
+        subnet_id: u32,
+        shares: u128,
+        execute_after_blocks: u32,
+    ) -> DispatchResult {
+        ensure!(
+            SubnetsData::<T>::contains_key(subnet_id),
+            Error::<T>::InvalidSubnetId
+        );
+        ensure!(shares != 0, Error::<T>::SharesZero);
+        ensure!(
+            AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id) >= shares,
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        let call = QueuedSwapCall::RemoveSubnetDelegateStake {
+            account_id: account_id.clone(),
+            subnet_id,
+            shares,
+        };
+
+        Self::queue_scheduled_swap(account_id, call, execute_after_blocks)
+    }
+
+    /// Schedule a subnet delegate stake transfer, see `schedule_transfer_delegate_stake`
+    pub fn do_schedule_transfer_delegate_stake(
+        account_id: T::AccountId,
+        subnet_id: u32,
+        to_account_id: T::AccountId,
+        shares: u128,
+        execute_after_blocks: u32,
+    ) -> DispatchResult {
+        ensure!(
+            account_id != to_account_id,
+            Error::<T>::TransferToSelfNotAllowed
+        );
+        ensure!(
+            SubnetsData::<T>::contains_key(subnet_id),
+            Error::<T>::InvalidSubnetId
+        );
+        ensure!(shares != 0, Error::<T>::SharesZero);
+        ensure!(
+            AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id) >= shares,
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        let call = QueuedSwapCall::TransferSubnetDelegateStake {
+            account_id: account_id.clone(),
+            subnet_id,
+            to_account_id,
+            shares,
+        };
+
+        Self::queue_scheduled_swap(account_id, call, execute_after_blocks)
+    }
+
+    /// Schedule a recurring subnet delegate stake rebalance, see
+    /// `schedule_rebalance_delegate_stake`
+    ///
+    /// The first rebalance is executed after `interval_blocks`
+    pub fn do_schedule_rebalance_delegate_stake(
+        account_id: T::AccountId,
+        targets: Vec<(u32, u128)>,
+        interval_blocks: u32,
+    ) -> DispatchResult {
+        ensure!(interval_blocks > 0, Error::<T>::InvalidRebalanceInterval);
+        Self::validate_rebalance_targets(&targets)?;
+
+        let call = QueuedSwapCall::RebalanceSubnetDelegateStake {
+            account_id: account_id.clone(),
+            targets,
+            interval_blocks,
+        };
+
+        Self::queue_scheduled_swap(account_id, call, interval_blocks)
+    }
+
+    pub fn validate_rebalance_targets(targets: &Vec<(u32, u128)>) -> DispatchResult {
+        ensure!(
+            !targets.is_empty() && targets.len() as u32 <= MAX_REBALANCE_TARGETS,
+            Error::<T>::InvalidRebalanceTargets
+        );
+
+        let mut subnet_ids: BTreeSet<u32> = BTreeSet::new();
+        let mut total_percentage: u128 = 0;
+        for (subnet_id, percentage) in targets {
+            ensure!(
+                SubnetsData::<T>::contains_key(subnet_id),
+                Error::<T>::InvalidSubnetId
+            );
+            ensure!(
+                subnet_ids.insert(*subnet_id),
+                Error::<T>::InvalidRebalanceTargets
+            );
+            total_percentage = total_percentage.saturating_add(*percentage);
+        }
+
+        ensure!(
+            total_percentage == Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidRebalanceTargets
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a queued swap call, see `cancel_swap_queue`
+    ///
+    /// Balance held by a swap call was already removed from its pool so it is added to the
+    /// owners unbonding ledger with the delegate stake cooldown.
+    pub fn do_cancel_swap_queue(key: T::AccountId, id: u32) -> DispatchResult {
+        let item = SwapCallQueue::<T>::get(&id).ok_or(Error::<T>::SwapCallNotFound)?;
+        ensure!(item.call.get_owner() == &key, Error::<T>::NotKeyOwner);
+
+        let balance = item.call.get_queue_balance();
+        let block: u32 = Self::get_current_block_as_u32();
+        let cooldown_blocks = DelegateStakeCooldownEpochs::<T>::get() * T::EpochLength::get();
+        if balance > 0 {
+            Self::prepare_unbonding_ledger_entry(&key, balance, cooldown_blocks, block)?;
+            Self::move_stake_hold(
+                &key,
+                item.call.get_hold_reason(),
+                HoldReason::Unbonding,
+                balance,
+            )?;
+            Self::insert_balance_to_unbonding_ledger(&key, balance, cooldown_blocks, block);
+        }
+
+        Self::remove_queued_swap_call(id, &key);
+        SwapQueueOrder::<T>::mutate(|queue| queue.retain(|queue_id| *queue_id != id));
+
+        Self::deposit_event(Event::SwapCallCancelled {
+            id,
+            account_id: key,
+            call: item.call,
+        });
+
+        Ok(())
+    }
+
+    /// Split the accounts delegate stake balance across the `targets` subnets by percentage
+    ///
+    /// Only stake in the target subnets is rebalanced. Stake is moved between pools without the
+    /// unbonding ledger, like a swap. Each addition must reach `MinDelegateStakeDeposit`.
+    pub fn perform_rebalance_subnet_delegate_stake(
+        account_id: &T::AccountId,
+        targets: &Vec<(u32, u128)>,
+    ) -> DispatchResult {
+        // Subnets can be removed while the rebalance is queued
+        Self::validate_rebalance_targets(targets)?;
+
+        let balances: Vec<u128> = targets
+            .iter()
+            .map(|(subnet_id, _)| Self::convert_account_shares_to_balance(account_id, *subnet_id))
+            .collect();
+        let total_balance: u128 = balances.iter().fold(0, |acc, b| acc.saturating_add(*b));
+        if total_balance == 0 {
+            return Ok(());
+        }
+
+        // --- Remove the excess of each overweight subnet
+        let mut freed_balance: u128 = 0;
+        let mut deficits: Vec<(u32, u128)> = Vec::new();
+        for ((subnet_id, percentage), balance) in targets.iter().zip(balances.iter()) {
+            let target_balance = Self::percent_mul(total_balance, *percentage);
+            if *balance <= target_balance {
+                deficits.push((*subnet_id, target_balance - balance));
+                continue;
+            }
+
+            let total_subnet_delegated_stake_shares =
+                TotalSubnetDelegateStakeShares::<T>::get(subnet_id);
+            let total_subnet_delegated_stake_balance =
+                TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
+
+            let account_shares = AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id);
+            let shares = Self::convert_to_shares(
+                balance - target_balance,
+                total_subnet_delegated_stake_shares,
+                total_subnet_delegated_stake_balance,
+            )
+            .min(account_shares);
+
+            let removed_balance = Self::convert_to_balance(
+                shares,
+                total_subnet_delegated_stake_shares,
+                total_subnet_delegated_stake_balance,
+            );
+
+            Self::decrease_account_delegate_stake(account_id, *subnet_id, removed_balance, shares);
+            freed_balance = freed_balance.saturating_add(removed_balance);
+        }
+
+        // --- Add the freed balance to each underweight subnet, the last takes the remainder
+        let last_index = deficits.len().saturating_sub(1);
+        for (index, (subnet_id, deficit)) in deficits.into_iter().enumerate() {
+            if freed_balance == 0 {
+                break;
+            }
+            let amount = if index == last_index {
+                freed_balance
+            } else {
+                deficit.min(freed_balance)
+            };
+            if amount == 0 {
+                continue;
+            }
+            // The balance never left the pools, but the add stake checks still apply
+            let (result, _, _) =
+                Self::perform_do_add_delegate_stake(account_id, subnet_id, amount, true);
+            result?;
+            freed_balance = freed_balance.saturating_sub(amount);
+        }
+
+        Ok(())
+    }
 }
@@ -92,17 +92,12 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
-            let stake_to_be_added_as_currency = match Self::u128_to_balance(*amount) {
-                Some(b) => b,
-                None => {
-                    // Redundant, but attempt to remove the unbonding
-                    unbondings_copy.remove(&unbonding_block);
-                    continue;
-                }
-            };
+            // --- Entries that can't be released stay in the ledger to be claimed again
+            if Self::release_stake_hold(&coldkey, HoldReason::Unbonding, *amount).is_err() {
+                continue;
+            }
 
             unbondings_copy.remove(&unbonding_block);
-            Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_added_as_currency);
             TotalUnbondingBalance::<T>::mutate(|total| *total = total.saturating_sub(*amount));
             successful_unbondings += 1;
         }
@@ -226,33 +221,11 @@ impl<T: Config> Pallet<T> {
         balance: u128,
     ) -> DispatchResult {
         let call = QueuedSwapCall::SwapToSubnetDelegateStake {
-            account_id,
+            account_id: account_id.clone(),
             to_subnet_id,
             balance,
         };
 
-        let id = NextSwapQueueId::<T>::get();
-
-        let queued_item = QueuedSwapItem {
-            id,
-            call,
-            queued_at_block: Self::get_current_block_as_u32(),
-            execute_after_blocks: T::EpochLength::get(),
-        };
-
-        SwapQueueOrder::<T>::try_mutate(|queue| -> DispatchResult {
-            queue
-                .try_push(id)
-                .map_err(|_| Error::<T>::SwapQueueFull)?;
-            Ok(())
-        })?;
-
-        SwapCallQueue::<T>::insert(&id, &queued_item);
-
-        NextSwapQueueId::<T>::mutate(|next_id| *next_id = next_id.saturating_add(1));
-
-        // Self::deposit_event(Event::SwapCallQueued { id, who });
-
-        Ok(())
+        Self::queue_swap(account_id, call)
     }
 }
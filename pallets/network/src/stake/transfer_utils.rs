@@ -46,6 +46,14 @@ impl<T: Config> Pallet<T> {
 
         result?;
 
+        // --- The queued balance is held under the reason of the stake it swaps to
+        Self::move_stake_hold(
+            &account_id,
+            Some(HoldReason::ValidatorDelegateStake),
+            HoldReason::DelegateStake,
+            balance,
+        )?;
+
         let call = QueuedSwapCall::SwapToSubnetDelegateStake {
             account_id: account_id.clone(),
             to_subnet_id,
@@ -81,6 +89,14 @@ impl<T: Config> Pallet<T> {
 
         result?;
 
+        // --- The queued balance is held under the reason of the stake it swaps to
+        Self::move_stake_hold(
+            &account_id,
+            Some(HoldReason::DelegateStake),
+            HoldReason::ValidatorDelegateStake,
+            balance,
+        )?;
+
         let call = QueuedSwapCall::SwapToValidatorDelegateStake {
             account_id: account_id.clone(),
             to_validator_id,
@@ -0,0 +1,162 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Re-staking and early withdrawal of unbonding balances, see `StakeUnbondingLedger`
+
+use super::*;
+use frame_support::pallet_prelude::DispatchError;
+
+impl<T: Config> Pallet<T> {
+    pub fn do_restake_unbonding(
+        account_id: T::AccountId,
+        target: RestakeTarget,
+        amount: u128,
+    ) -> DispatchResult {
+        ensure!(amount != 0, Error::<T>::InvalidAmount);
+
+        Self::take_from_unbonding_ledger(&account_id, amount)?;
+
+        let (result, reason) = match target {
+            RestakeTarget::SubnetNode {
+                subnet_id,
+                subnet_node_id,
+            } => (
+                Self::perform_do_add_node_stake(
+                    &account_id,
+                    subnet_id,
+                    subnet_node_id,
+                    amount,
+                    true,
+                ),
+                HoldReason::NodeStake,
+            ),
+            RestakeTarget::SubnetDelegateStake(subnet_id) => {
+                ensure!(
+                    SubnetsData::<T>::contains_key(subnet_id),
+                    Error::<T>::InvalidSubnetId
+                );
+                let (result, _, _) =
+                    Self::perform_do_add_delegate_stake(&account_id, subnet_id, amount, true);
+                (result, HoldReason::DelegateStake)
+            }
+            RestakeTarget::ValidatorDelegateStake(validator_id) => {
+                ensure!(
+                    ValidatorsData::<T>::contains_key(validator_id),
+                    Error::<T>::InvalidValidatorId
+                );
+                let (result, _, _) = Self::perform_do_add_validator_delegate_stake(
+                    &account_id,
+                    validator_id,
+                    amount,
+                    true,
+                );
+                (result, HoldReason::ValidatorDelegateStake)
+            }
+        };
+
+        result?;
+
+        // --- The balance stays held, only under the reason of its new stake
+        Self::move_stake_hold(&account_id, Some(HoldReason::Unbonding), reason, amount)?;
+
+        // Set last block for rate limiting
+        Self::set_last_tx_block(&account_id, Self::get_current_block_as_u32());
+
+        Self::deposit_event(Event::UnbondingRestaked {
+            account_id,
+            target,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn do_withdraw_unbonding_early(account_id: T::AccountId, amount: u128) -> DispatchResult {
+        ensure!(amount != 0, Error::<T>::InvalidAmount);
+
+        let matured = Self::take_from_unbonding_ledger(&account_id, amount)?;
+
+        // --- Only the balance still in cooldown is penalized
+        let penalty = Self::percent_mul(
+            amount.saturating_sub(matured),
+            EarlyUnbondingPenalty::<T>::get(),
+        );
+
+        Self::release_stake_hold(&account_id, HoldReason::Unbonding, amount)?;
+
+        if penalty > 0 {
+            let penalty_as_balance =
+                Self::u128_to_balance(penalty).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+            Self::send_to_treasury(&account_id, penalty_as_balance)?;
+        }
+
+        Self::deposit_event(Event::UnbondingWithdrawnEarly {
+            account_id,
+            amount,
+            penalty,
+        });
+
+        Ok(())
+    }
+
+    /// Remove `amount` from the unbonding ledger of `coldkey`, matured unbondings first, then
+    /// oldest to newest
+    ///
+    /// Returns the part of `amount` that was already claimable.
+    pub fn take_from_unbonding_ledger(
+        coldkey: &T::AccountId,
+        amount: u128,
+    ) -> Result<u128, DispatchError> {
+        let block = Self::get_current_block_as_u32();
+        let mut unbondings = StakeUnbondingLedger::<T>::get(coldkey);
+
+        let total = unbondings
+            .values()
+            .fold(0u128, |acc, balance| acc.saturating_add(*balance));
+        ensure!(total >= amount, Error::<T>::NotEnoughUnbondingBalance);
+
+        let mut remaining = amount;
+        let mut matured = 0u128;
+        // Ordered by claim block, so matured entries come first
+        let claim_blocks: Vec<u32> = unbondings.keys().copied().collect();
+
+        for claim_block in claim_blocks {
+            if remaining == 0 {
+                break;
+            }
+
+            let Some(balance) = unbondings.get_mut(&claim_block) else {
+                continue;
+            };
+
+            let taken = (*balance).min(remaining);
+            balance.saturating_reduce(taken);
+            remaining.saturating_reduce(taken);
+
+            if claim_block <= block {
+                matured.saturating_accrue(taken);
+            }
+
+            if *balance == 0 {
+                unbondings.remove(&claim_block);
+            }
+        }
+
+        StakeUnbondingLedger::<T>::insert(coldkey, unbondings);
+        TotalUnbondingBalance::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+        Ok(matured)
+    }
+}
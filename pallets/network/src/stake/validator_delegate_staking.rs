@@ -58,7 +58,7 @@ impl<T: Config> Pallet<T> {
     /// * `delegate_stake_to_be_added` - Balance to add or swap.
     /// * `swap` - If we are swapping between validators or nodes.
     ///              - True: Don't remove balance from users account
-    ///              - False: Check user balance is withdrawable and withdraw balance
+    ///              - False: Check user balance is withdrawable and hold balance
     ///
     pub fn perform_do_add_validator_delegate_stake(
         account_id: &T::AccountId,
@@ -93,9 +93,13 @@ impl<T: Config> Pallet<T> {
             return (Err(Error::<T>::TxRateLimitExceeded.into()), 0, 0);
         }
 
-        // --- Ensure the remove operation from the account_id is a success.
+        // --- Ensure the hold on the account_id is a success.
         if !swap {
-            if Self::remove_balance_from_coldkey_account(&account_id, balance) == false {
+            if !Self::hold_stake(
+                &account_id,
+                HoldReason::ValidatorDelegateStake,
+                delegate_stake_to_be_added,
+            ) {
                 return (Err(Error::<T>::BalanceWithdrawalError.into()), 0, 0);
             }
         }
@@ -249,6 +253,15 @@ impl<T: Config> Pallet<T> {
             ) {
                 return (Err(e), 0, 0);
             }
+
+            if let Err(e) = Self::move_stake_hold(
+                &account_id,
+                Some(HoldReason::ValidatorDelegateStake),
+                HoldReason::Unbonding,
+                delegate_stake_to_be_removed,
+            ) {
+                return (Err(e), 0, 0);
+            }
         }
 
         // --- We remove the shares from the account and balance from the pool
@@ -312,6 +325,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::MinDelegateStakeDepositNotReached
         );
 
+        // --- Move the held principal with the shares
+        Self::transfer_stake_hold(
+            &account_id,
+            &to_account_id,
+            HoldReason::ValidatorDelegateStake,
+            delegate_stake_to_be_transferred,
+        )?;
+
         // --- Remove shares from caller
         Self::decrease_account_validator_delegate_stake(
             &account_id,
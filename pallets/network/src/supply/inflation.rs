@@ -16,25 +16,26 @@
 // Enables accounts to delegate stake to subnets for a portion of emissions
 
 use super::*;
+use sp_core::U256;
 use sp_runtime::traits::Saturating;
 
 pub struct Inflation {
-    /// Initial maximum inflation percentage, from time=0
-    pub initial_max: f64,
+    /// Initial maximum inflation, from time=0
+    pub initial_max: u128,
 
-    /// Initial minimum inflation percentage, from time=0
-    pub initial_min: f64,
+    /// Initial minimum inflation, from time=0
+    pub initial_min: u128,
 
-    /// Percentage of total inflation allocated to the foundation
-    pub foundation: f64,
+    /// Percentage of total inflation allocated to the foundation (1e18 = 100%)
+    pub foundation: u128,
     /// Duration of foundation pool inflation, in years
-    pub foundation_term: f64,
+    pub foundation_term: u32,
 }
 
-const DEFAULT_INITIAL_MAX: f64 = 100000000000000000000000.0; // 100,000 (initially 10% @ 10,000,000 units)
-const DEFAULT_INITIAL_MIN: f64 = 75000000000000000000000.0; // 75,000 (initially 7.5% @ 10,000,000 units)
-const DEFAULT_FOUNDATION: f64 = 0.2;
-const DEFAULT_FOUNDATION_TERM: f64 = 7.0;
+const DEFAULT_INITIAL_MAX: u128 = 100_000_000_000_000_000_000_000; // 100,000 (initially 10% @ 10,000,000 units)
+const DEFAULT_INITIAL_MIN: u128 = 75_000_000_000_000_000_000_000; // 75,000 (initially 7.5% @ 10,000,000 units)
+const DEFAULT_FOUNDATION: u128 = 200_000_000_000_000_000; // 20%
+const DEFAULT_FOUNDATION_TERM: u32 = 7;
 
 impl Default for Inflation {
     fn default() -> Self {
@@ -56,56 +57,73 @@ impl Inflation {
     /// `initial_max`: Max interest rate
     /// `initial_min`: Min interest rate
     ///
-    /// *x: Node utilization ratio
+    /// *x: Node utilization ratio (1e18 = 100%)
     /// *mid: Sigmoid midpoint
-    /// *f: Sigmoid steepness
+    /// *f: Sigmoid steepness (1e18 = 1.0)
     /// *sigmoid_fn: Sigmoid function
-    pub fn inflation<F>(&self, x: f64, mid: f64, k: f64, sigmoid_fn: F) -> f64
+    pub fn inflation<F>(&self, x: u128, mid: u128, k: u128, sigmoid_fn: F) -> u128
     where
-        F: Fn(f64, f64, f64) -> f64,
+        F: Fn(u128, u128, u128) -> u128,
     {
         let max = self.initial_max;
         let min = self.initial_min;
 
-        min + (max - min) * sigmoid_fn(x, mid, k)
+        let sigmoid = U256::from(sigmoid_fn(x, mid, k));
+        let range = U256::from(max.saturating_sub(min));
+        let scaled: u128 = (range * sigmoid / U256::from(1_000_000_000_000_000_000_u128))
+            .try_into()
+            .unwrap_or(u128::MAX);
+
+        min.saturating_add(scaled)
     }
 }
 
 impl<T: Config> Pallet<T> {
-    pub fn get_inflation(node_utilization: f64) -> f64 {
-        let mid = Self::get_percent_as_f64(InflationSigmoidMidpoint::<T>::get());
-        let k = InflationSigmoidSteepness::<T>::get() as f64;
+    pub fn get_inflation(node_utilization: u128) -> u128 {
+        let mid = InflationSigmoidMidpoint::<T>::get();
+        let k =
+            InflationSigmoidSteepness::<T>::get().saturating_mul(Self::percentage_factor_as_u128());
 
         let inflation = Inflation::default();
 
         inflation.inflation(node_utilization, mid, k, Self::sigmoid_decreasing_v2)
     }
 
-    fn get_subnet_node_utilization() -> f64 {
+    fn get_subnet_node_utilization() -> u128 {
         let max_subnets: u32 = MaxSubnets::<T>::get();
         let max_nodes: u32 = max_subnets.saturating_mul(MaxSubnetNodes::<T>::get());
         let total_active_nodes: u32 = TotalActiveNodes::<T>::get();
 
-        (total_active_nodes as f64 / max_nodes as f64).clamp(0.0, 1.0)
+        if max_nodes == 0 {
+            return Self::percentage_factor_as_u128();
+        }
+
+        Self::percent_div(total_active_nodes as u128, max_nodes as u128)
     }
 
     pub fn get_epoch_emissions() -> (u128, u128) {
-        let node_utilization = Self::get_subnet_node_utilization().min(1.0);
+        let node_utilization =
+            Self::get_subnet_node_utilization().min(Self::percentage_factor_as_u128());
         let emissions = Self::get_inflation(node_utilization);
 
         let (validator_emissions, foundation_emissions) = {
             let inflation = Inflation::default();
+            let foundation_emissions = Self::percent_mul(emissions, inflation.foundation);
             (
-                emissions - emissions * inflation.foundation,
-                emissions * inflation.foundation,
+                emissions.saturating_sub(foundation_emissions),
+                foundation_emissions,
             )
         };
 
-        let epochs_per_year: f64 = T::EpochsPerYear::get() as f64;
+        let epochs_per_year: u128 = T::EpochsPerYear::get() as u128;
 
         (
-            (validator_emissions / epochs_per_year) as u128,
-            (foundation_emissions / epochs_per_year) as u128,
+            validator_emissions
+                .checked_div(epochs_per_year)
+                .unwrap_or(0),
+            foundation_emissions
+                .checked_div(epochs_per_year)
+                .unwrap_or(0),
         )
     }
 }
@@ -1,2 +1,3 @@
 use super::*;
 pub mod inflation;
+pub mod projection;
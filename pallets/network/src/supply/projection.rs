@@ -0,0 +1,160 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Delegate stake yield projections, see `DelegateStakeProjection`
+
+use super::*;
+use frame_support::pallet_prelude::DispatchError;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use sp_core::U256;
+
+impl<T: Config> Pallet<T> {
+    /// Project the yield of `amount` delegated to a subnet with `add_delegate_stake`
+    ///
+    /// The subnet delegate stake pool receives `SubnetDelegateStakeRewardsPercentage` of the
+    /// subnet's rewards after the owner cut.
+    pub fn get_subnet_delegate_stake_projection(
+        subnet_id: u32,
+        amount: u128,
+    ) -> DelegateStakeProjection {
+        let (epoch_emissions, subnet_weights) = Self::get_projected_subnet_emissions();
+
+        let pool_epoch_reward = subnet_weights
+            .get(&subnet_id)
+            .map(|subnet_weight| {
+                let overall_subnet_reward = Self::percent_mul(epoch_emissions, *subnet_weight);
+                Self::split_subnet_rewards(subnet_id, overall_subnet_reward).delegate_stake_rewards
+            })
+            .unwrap_or(0);
+
+        let pool_balance =
+            TotalSubnetDelegateStakeBalance::<T>::get(subnet_id).saturating_add(amount);
+
+        Self::delegate_stake_projection(amount, epoch_emissions, pool_balance, pool_epoch_reward)
+    }
+
+    /// Project the yield of `amount` delegated to a validator with `add_validator_delegate_stake`
+    ///
+    /// The validator delegate stake pool receives `delegate_reward_rate` of the rewards of each
+    /// of the validator's `Validator` classified subnet nodes. Subnet node rewards are assumed to
+    /// be split evenly between the subnet's electable nodes.
+    pub fn get_validator_delegate_stake_projection(
+        validator_id: u32,
+        amount: u128,
+    ) -> DelegateStakeProjection {
+        let (epoch_emissions, subnet_weights) = Self::get_projected_subnet_emissions();
+
+        let delegate_reward_rate = ValidatorsData::<T>::get(validator_id).delegate_reward_rate;
+
+        let mut pool_epoch_reward: u128 = 0;
+        for (subnet_id, subnet_node_ids) in ValidatorSubnetNodes::<T>::get(validator_id) {
+            let Some(subnet_weight) = subnet_weights.get(&subnet_id) else {
+                continue;
+            };
+
+            let electable_nodes = TotalSubnetElectableNodes::<T>::get(subnet_id);
+            if electable_nodes == 0 {
+                continue;
+            }
+
+            let subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+            let validator_nodes = subnet_node_ids
+                .iter()
+                .filter(|subnet_node_id| {
+                    SubnetNodesData::<T>::try_get(subnet_id, **subnet_node_id)
+                        .map(|subnet_node| {
+                            subnet_node
+                                .has_classification(&SubnetNodeClass::Validator, subnet_epoch)
+                        })
+                        .unwrap_or(false)
+                })
+                .count() as u128;
+
+            let overall_subnet_reward = Self::percent_mul(epoch_emissions, *subnet_weight);
+            let subnet_node_rewards =
+                Self::split_subnet_rewards(subnet_id, overall_subnet_reward).subnet_node_rewards;
+            let node_rewards = subnet_node_rewards
+                .saturating_mul(validator_nodes)
+                .saturating_div(electable_nodes as u128);
+
+            pool_epoch_reward = pool_epoch_reward
+                .saturating_add(Self::percent_mul(node_rewards, delegate_reward_rate));
+        }
+
+        let pool_balance =
+            ValidatorDelegateStakeBalance::<T>::get(validator_id).saturating_add(amount);
+
+        Self::delegate_stake_projection(amount, epoch_emissions, pool_balance, pool_epoch_reward)
+    }
+
+    /// Emissions to all subnets per epoch from the current inflation, and the subnet weights of
+    /// the current epoch
+    fn get_projected_subnet_emissions() -> (u128, BTreeMap<u32, u128>) {
+        let (epoch_emissions, _) = Self::get_epoch_emissions();
+
+        let current_epoch = Self::get_current_epoch_as_u32();
+        let subnet_weights = match FinalSubnetEmissionWeights::<T>::try_get(current_epoch) {
+            Ok(distribution_data) => distribution_data.subnet_weights,
+            // Weights are not generated yet this epoch, `calculate_subnet_weights` takes the
+            // subnets net flow so it runs in a transaction that is rolled back
+            Err(()) => with_transaction(|| {
+                let (subnet_weights, _) = Self::calculate_subnet_weights(current_epoch);
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(subnet_weights))
+            })
+            .unwrap_or_default(),
+        };
+
+        (epoch_emissions, subnet_weights)
+    }
+
+    fn delegate_stake_projection(
+        amount: u128,
+        epoch_emissions: u128,
+        pool_balance: u128,
+        pool_epoch_reward: u128,
+    ) -> DelegateStakeProjection {
+        let epoch_reward: u128 = Self::checked_mul_div(
+            U256::from(pool_epoch_reward),
+            U256::from(amount),
+            U256::from(pool_balance),
+        )
+        .map(|reward| reward.min(U256::from(u128::MAX)).as_u128())
+        .unwrap_or(0);
+
+        let epochs_per_year = T::EpochsPerYear::get();
+        let annual_reward = epoch_reward.saturating_mul(epochs_per_year as u128);
+        let apr = Self::percent_div(annual_reward, amount);
+
+        // Rewards are added to the pool balance so each epoch compounds on the last
+        let percentage_factor = Self::percentage_factor_as_u128();
+        let epoch_yield = Self::percent_div(epoch_reward, amount);
+        let apy = Self::pow(
+            percentage_factor.saturating_add(epoch_yield),
+            (epochs_per_year as u128).saturating_mul(percentage_factor),
+        )
+        .saturating_sub(percentage_factor);
+
+        DelegateStakeProjection {
+            amount,
+            epoch_emissions,
+            pool_balance,
+            pool_epoch_reward,
+            epoch_reward,
+            annual_reward,
+            apr,
+            apy,
+        }
+    }
+}
@@ -1,10 +1,13 @@
+// The deprecated `set_*` extrinsics are tested until they are removed
+#![allow(deprecated)]
+
 use super::mock::*;
 use super::test_utils::*;
 use crate::Event;
 use crate::{
     AttestorMinRewardFactor, AttestorRewardExponent, BaseNodeBurnAmount, BaseSlashPercentage,
     BaseValidatorReward, DefaultOverwatchSubnetWeight, DelegateStakeCooldownEpochs,
-    DelegateStakeSubnetRemovalInterval, DelegateStakeWeightFactor, Error,
+    DelegateStakeSubnetRemovalInterval, DelegateStakeWeightFactor, EarlyUnbondingPenalty, Error,
     InConsensusSubnetReputationFactor, InflationSigmoidMidpoint, InflationSigmoidSteepness,
     LessThanMinNodesSubnetReputationFactor, MaxBootnodes, MaxChurnLimit, MaxChurnLimitMultiplier,
     MaxDelegateStakePercentage, MaxEmergencySubnetNodes, MaxEmergencyValidatorEpochsMultiplier,
@@ -15,25 +18,29 @@ use crate::{
     MaxSubnetDelegateStakeRewardsPercentageChange, MaxSubnetMinStake,
     MaxSubnetNodeMinWeightDecreaseReputationThreshold, MaxSubnetNodes, MaxSubnetPauseEpochs,
     MaxSubnetRemovalInterval, MaxSubnets, MaxSwapQueueCallsPerBlock, MaxUnbondings,
-    MaximumHooksWeightV2, MinActiveNodeStakeEpochs, MinAttestationPercentage, MinChurnLimit,
-    MinChurnLimitMultiplier, MinDelegateStakeDeposit, MinDelegateStakePercentage,
+    MaximumHooksWeightV2, MinActiveNodeStakeEpochs, MinAttestationPercentage, MinBeaconReveals,
+    MinChurnLimit, MinChurnLimitMultiplier, MinDelegateStakeDeposit, MinDelegateStakePercentage,
     MinIdleClassificationEpochs, MinIncludedClassificationEpochs, MinMaxRegisteredNodes,
     MinMinSubnetNodeReputation, MinNodeBurnRate, MinNodeReputationFactor, MinQueueEpochs,
     MinRegistrationCost, MinSubnetDelegateStakeFactor, MinSubnetMinStake, MinSubnetNodes,
     MinSubnetRegistrationEpochs, MinSubnetRemovalInterval, MinSubnetReputation,
-    NetworkMaxStakeBalance, NewRegistrationCostMultiplier, NodeDelegateStakeCooldownEpochs,
-    NodeRewardRateUpdatePeriod, NotInConsensusSubnetReputationFactor, OverwatchCommitCutoffPercent,
-    OverwatchEpochLengthMultiplier, OverwatchMinAge, OverwatchMinAvgAttestationRatio,
-    OverwatchMinDiversificationRatio, OverwatchMinRepScore, OverwatchMinStakeBalance,
-    OverwatchNodeBlacklist, OverwatchStakeWeightFactor, OverwatchValidatorWhitelist,
-    OverwatchWeightFactor, QueueImmunityEpochs, RegistrationCostAlpha, RegistrationCostDecayBlocks,
-    RequireSubnetRegistrationWhitelist, StakeCooldownEpochs,
-    SubnetDelegateStakeRewardsUpdatePeriod, SubnetDistributionPower, SubnetEnactmentEpochs,
-    SubnetName, SubnetOwnerPercentage, SubnetPauseCooldownEpochs, SubnetRegistrationEpochs,
-    SubnetRegistrationWhitelist, SubnetWeightFactors, SubnetWeightFactorsData,
-    SuperMajorityAttestationRatio, TxRateLimit, ValidatorAbsentSubnetReputationFactor,
-    ValidatorReputationDecreaseFactor, ValidatorReputationIncreaseFactor, ValidatorRewardK,
-    ValidatorRewardMidpoint,
+    NetworkMaxStakeBalance, NetworkParameter, NewRegistrationCostMultiplier,
+    NodeDelegateStakeCooldownEpochs, NodeRewardRateUpdatePeriod,
+    NotInConsensusSubnetReputationFactor, OverwatchChallengeBond, OverwatchChallengeQuorum,
+    OverwatchChallengeReputationFactor, OverwatchClipReputationFactor,
+    OverwatchCommitCutoffPercent, OverwatchEpochLengthMultiplier, OverwatchMinAge,
+    OverwatchMinAvgAttestationRatio, OverwatchMinDiversificationRatio, OverwatchMinRepScore,
+    OverwatchMinStakeBalance, OverwatchNodeBlacklist, OverwatchNonRevealReputationFactor,
+    OverwatchNonRevealStakePenalty, OverwatchRevealCutoffPercent, OverwatchStakeWeightFactor,
+    OverwatchValidatorWhitelist, OverwatchWeightClipBand, OverwatchWeightFactor,
+    ParameterChangeDelayEpochs, QueueImmunityEpochs, RegistrationCostAlpha,
+    RegistrationCostDecayBlocks, RequireSubnetRegistrationWhitelist, RewardsLedgerRetentionEpochs,
+    SlashAppealEpochs, StakeCooldownEpochs, SubnetDelegateStakeRewardsUpdatePeriod,
+    SubnetDistributionPower, SubnetEnactmentEpochs, SubnetName, SubnetOwnerPercentage,
+    SubnetPauseCooldownEpochs, SubnetRegistrationEpochs, SubnetRegistrationWhitelist,
+    SubnetWeightFactors, SubnetWeightFactorsData, SuperMajorityAttestationRatio, TxRateLimit,
+    ValidatorAbsentSubnetReputationFactor, ValidatorReputationDecreaseFactor,
+    ValidatorReputationIncreaseFactor, ValidatorRewardK, ValidatorRewardMidpoint,
 };
 use frame_support::traits::Get;
 use frame_support::{assert_err, assert_ok};
@@ -210,7 +217,7 @@ fn test_set_max_pause_epochs() {
         let new_value: u32 = 100;
 
         assert_ok!(Network::set_max_pause_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -250,7 +257,7 @@ fn test_set_registration_cost_alpha() {
         let new_value: u128 = 60000000000000000; // 6%
 
         assert_ok!(Network::set_registration_cost_alpha(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -291,7 +298,7 @@ fn test_set_churn_limits() {
         let max: u32 = 5;
 
         assert_ok!(Network::set_churn_limits(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -407,6 +414,202 @@ fn test_set_overwatch_commit_cutoff_percent() {
     });
 }
 
+#[test]
+fn test_set_overwatch_reveal_cutoff_percent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        // Must be after the commit cutoff
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::OverwatchRevealCutoffPercent(
+                    OverwatchCommitCutoffPercent::<Test>::get()
+                )
+            ),
+            Error::<Test>::InvalidPercent
+        );
+
+        let new_value: u128 = 900000000000000000; // 90%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchRevealCutoffPercent(new_value)
+        ));
+
+        assert_eq!(OverwatchRevealCutoffPercent::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchRevealCutoffPercent(new_value)
+        );
+
+        // The commit cutoff must stay before the reveal cutoff
+        assert_err!(
+            Network::set_overwatch_commit_cutoff_percent(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                new_value
+            ),
+            Error::<Test>::InvalidPercent
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_non_reveal_reputation_factor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 200000000000000000; // 20%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchNonRevealReputationFactor(new_value)
+        ));
+
+        assert_eq!(OverwatchNonRevealReputationFactor::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchNonRevealReputationFactor(new_value)
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_non_reveal_stake_penalty() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::OverwatchNonRevealStakePenalty(1000000000000000001)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+
+        let new_value: u128 = 50000000000000000; // 5%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchNonRevealStakePenalty(new_value)
+        ));
+
+        assert_eq!(OverwatchNonRevealStakePenalty::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchNonRevealStakePenalty(new_value)
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_weight_clip_band() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::OverwatchWeightClipBand(1000000000000000001)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+
+        let new_value: u128 = 100000000000000000; // 10%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchWeightClipBand(new_value)
+        ));
+
+        assert_eq!(OverwatchWeightClipBand::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchWeightClipBand(new_value)
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_clip_reputation_factor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 200000000000000000; // 20%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchClipReputationFactor(new_value)
+        ));
+
+        assert_eq!(OverwatchClipReputationFactor::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchClipReputationFactor(new_value)
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_challenge_bond() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 20000000000000000000; // 20e18
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchChallengeBond(new_value)
+        ));
+
+        assert_eq!(OverwatchChallengeBond::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchChallengeBond(new_value)
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_challenge_quorum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 660000000000000000; // 66%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchChallengeQuorum(new_value)
+        ));
+
+        assert_eq!(OverwatchChallengeQuorum::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchChallengeQuorum(new_value)
+        );
+    });
+}
+
+#[test]
+fn test_set_overwatch_challenge_reputation_factor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 200000000000000000; // 20%
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::OverwatchChallengeReputationFactor(new_value)
+        ));
+
+        assert_eq!(OverwatchChallengeReputationFactor::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetOverwatchChallengeReputationFactor(new_value)
+        );
+    });
+}
+
 #[test]
 fn test_set_min_subnet_delegate_stake_factor() {
     new_test_ext().execute_with(|| {
@@ -460,7 +663,7 @@ fn test_multiple_parameter_updates() {
         ));
 
         assert_ok!(Network::set_max_pause_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             120
         ));
 
@@ -486,7 +689,7 @@ fn test_set_max_bootnodes() {
         let new_value: u32 = 15;
 
         assert_ok!(Network::set_max_bootnodes(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -495,6 +698,25 @@ fn test_set_max_bootnodes() {
             *network_events().last().unwrap(),
             Event::SetMaxBootnodes(new_value)
         );
+
+        // The majority collective schedules changes, see `schedule_parameter_change`
+        assert_err!(
+            Network::set_max_bootnodes(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                new_value
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Bounded like `set_parameter`
+        assert_err!(
+            Network::set_max_bootnodes(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                257
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+        assert_eq!(MaxBootnodes::<Test>::get(), new_value);
     });
 }
 
@@ -506,7 +728,7 @@ fn test_set_max_subnet_bootnodes_access() {
         let new_value: u32 = 10;
 
         assert_ok!(Network::set_max_subnet_bootnodes_access(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -567,7 +789,7 @@ fn test_set_churn_limit_multipliers() {
         let max: u32 = 10;
 
         assert_ok!(Network::set_churn_limit_multipliers(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -590,7 +812,7 @@ fn test_set_queue_epochs() {
         let max: u32 = 10;
 
         assert_ok!(Network::set_queue_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -612,7 +834,7 @@ fn test_set_min_idle_classification_epochs() {
         let new_value: u32 = 5;
 
         assert_ok!(Network::set_min_idle_classification_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -632,7 +854,7 @@ fn test_set_max_idle_classification_epochs() {
         let new_value: u32 = 20;
 
         assert_ok!(Network::set_max_idle_classification_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -652,7 +874,7 @@ fn test_set_subnet_activation_enactment_epochs() {
         let new_value: u32 = 3;
 
         assert_ok!(Network::set_subnet_activation_enactment_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -673,7 +895,7 @@ fn test_set_included_classification_epochs() {
         let max: u32 = 15;
 
         assert_ok!(Network::set_included_classification_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -789,7 +1011,7 @@ fn test_set_subnet_delegate_stake_rewards_update_period() {
         let new_value: u32 = 100;
 
         assert_ok!(Network::set_subnet_delegate_stake_rewards_update_period(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -872,7 +1094,7 @@ fn test_set_reputation_increase_factor() {
         let new_value: u128 = 1000000000000000000;
 
         assert_ok!(Network::set_reputation_increase_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -892,7 +1114,7 @@ fn test_set_reputation_decrease_factor() {
         let new_value: u128 = 950000000000000000;
 
         assert_ok!(Network::set_reputation_decrease_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -932,7 +1154,7 @@ fn test_set_node_reward_rate_update_period() {
         let new_value: u32 = 50;
 
         assert_ok!(Network::set_node_reward_rate_update_period(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1009,7 +1231,7 @@ fn test_set_inflation_sigmoid_steepness() {
     new_test_ext().execute_with(|| {
         System::set_block_number(System::block_number() + 1);
 
-        let new_value: u128 = 5000000000000000000;
+        let new_value: u128 = 5;
 
         assert_ok!(Network::set_inflation_sigmoid_steepness(
             RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
@@ -1144,6 +1366,134 @@ fn test_set_overwatch_min_stake_balance() {
     });
 }
 
+#[test]
+fn test_set_min_beacon_reveals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u32 = 3;
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::MinBeaconReveals(new_value)
+        ));
+
+        assert_eq!(MinBeaconReveals::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetMinBeaconReveals(new_value)
+        );
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::MinBeaconReveals(0)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+    });
+}
+
+#[test]
+fn test_set_rewards_ledger_retention_epochs() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u32 = 64;
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::RewardsLedgerRetentionEpochs(new_value)
+        ));
+
+        assert_eq!(RewardsLedgerRetentionEpochs::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetRewardsLedgerRetentionEpochs(new_value)
+        );
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::RewardsLedgerRetentionEpochs(0)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::RewardsLedgerRetentionEpochs(
+                    crate::ledger::MAX_REWARDS_LEDGER_RETENTION_EPOCHS + 1
+                )
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+    });
+}
+
+#[test]
+fn test_set_slash_appeal_epochs() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u32 = 8;
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::SlashAppealEpochs(new_value)
+        ));
+
+        assert_eq!(SlashAppealEpochs::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetSlashAppealEpochs(new_value)
+        );
+
+        // Zero applies slashes immediately
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::SlashAppealEpochs(0)
+        ));
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::SlashAppealEpochs(crate::slashing::MAX_SLASH_APPEAL_EPOCHS + 1)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+    });
+}
+
+#[test]
+fn test_set_parameter_change_delay_epochs() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u32 = 96;
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::ParameterChangeDelayEpochs(new_value)
+        ));
+
+        assert_eq!(ParameterChangeDelayEpochs::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetParameterChangeDelayEpochs(new_value)
+        );
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::ParameterChangeDelayEpochs(0)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+    });
+}
+
 #[test]
 fn test_set_min_max_subnet_node() {
     new_test_ext().execute_with(|| {
@@ -1195,7 +1545,7 @@ fn test_set_delegate_stake_subnet_removal_interval() {
         let new_value: u32 = 50;
 
         assert_ok!(Network::set_delegate_stake_subnet_removal_interval(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1216,7 +1566,7 @@ fn test_set_subnet_removal_interval() {
         let max: u32 = 1000;
 
         assert_ok!(Network::set_subnet_removal_intervals(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -1238,7 +1588,7 @@ fn test_set_subnet_pause_cooldown_epochs() {
         let new_value: u32 = 10;
 
         assert_ok!(Network::set_subnet_pause_cooldown_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1258,7 +1608,7 @@ fn test_set_delegate_stake_cooldown_epochs() {
         let new_value: u32 = 5;
 
         assert_ok!(Network::set_delegate_stake_cooldown_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1278,7 +1628,7 @@ fn test_set_node_delegate_stake_cooldown_epochs() {
         let new_value: u32 = 5;
 
         assert_ok!(Network::set_node_delegate_stake_cooldown_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1298,7 +1648,7 @@ fn test_set_min_stake_cooldown_epochs() {
         let new_value: u32 = 3;
 
         assert_ok!(Network::set_min_stake_cooldown_epochs(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1354,7 +1704,7 @@ fn test_set_max_swap_queue_calls_per_block() {
                 RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
                 too_large
             ),
-            Error::<Test>::InvalidValues
+            Error::<Test>::ParameterOutOfBounds
         );
     });
 }
@@ -1541,7 +1891,7 @@ fn test_set_sigmoid_midpoint() {
         let new_value: u128 = 500000000000000000;
 
         assert_ok!(Network::set_sigmoid_midpoint(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1558,12 +1908,15 @@ fn test_set_maximum_hooks_weight() {
     new_test_ext().execute_with(|| {
         System::set_block_number(System::block_number() + 1);
 
-        assert_eq!(MaximumHooksWeightV2::<Test>::get(), MaximumHooksWeight::get());
+        assert_eq!(
+            MaximumHooksWeightV2::<Test>::get(),
+            MaximumHooksWeight::get()
+        );
 
         let new_value: u32 = 100;
 
         assert_ok!(Network::set_maximum_hooks_weight(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1583,7 +1936,7 @@ fn test_set_base_node_burn_amount() {
         let new_value: u128 = 1000000000000000000;
 
         assert_ok!(Network::set_base_node_burn_amount(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1604,7 +1957,7 @@ fn test_set_node_burn_rates() {
         let max: u128 = 1000000000000000000;
 
         assert_ok!(Network::set_node_burn_rates(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -1629,7 +1982,7 @@ fn test_set_max_subnet_node_min_weight_decrease_reputation_threshold() {
 
         assert_ok!(
             Network::set_max_subnet_node_min_weight_decrease_reputation_threshold(
-                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
                 new_value
             )
         );
@@ -1653,7 +2006,7 @@ fn test_set_validator_reward_k() {
         let new_value: u64 = 5;
 
         assert_ok!(Network::set_validator_reward_k(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1673,7 +2026,7 @@ fn test_set_validator_reward_midpoint() {
         let new_value: u128 = 600000000000000000;
 
         assert_ok!(Network::set_validator_reward_midpoint(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1693,7 +2046,7 @@ fn test_set_attestor_reward_exponent() {
         let new_value: u64 = 3;
 
         assert_ok!(Network::set_attestor_reward_exponent(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1713,7 +2066,7 @@ fn test_set_attestor_min_reward_factor() {
         let new_value: u128 = 200000000000000000;
 
         assert_ok!(Network::set_attestor_min_reward_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1736,7 +2089,7 @@ fn test_set_min_max_node_reputation() {
         let max: u128 = 2;
 
         assert_ok!(Network::set_min_max_node_reputation(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -1759,7 +2112,7 @@ fn test_set_min_max_node_reputation_factor() {
         let max: u128 = 2;
 
         assert_ok!(Network::set_min_max_node_reputation_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             min,
             max
         ));
@@ -1781,7 +2134,7 @@ fn test_set_min_subnet_reputation() {
         let new_value: u128 = 500000000000000000;
 
         assert_ok!(Network::set_min_subnet_reputation(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1801,7 +2154,7 @@ fn test_set_not_in_consensus_subnet_reputation_factor() {
         let new_value: u128 = 950000000000000000;
 
         assert_ok!(Network::set_not_in_consensus_subnet_reputation_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1824,7 +2177,7 @@ fn test_set_less_than_min_nodes_subnet_reputation_factor() {
         let new_value: u128 = 980000000000000000;
 
         assert_ok!(Network::set_less_than_min_nodes_subnet_reputation_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1848,7 +2201,7 @@ fn test_set_validator_proposal_absent_subnet_reputation_factor() {
 
         assert_ok!(
             Network::set_validator_proposal_absent_subnet_reputation_factor(
-                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
                 new_value
             )
         );
@@ -1872,7 +2225,7 @@ fn test_set_in_consensus_subnet_reputation_factor() {
         let new_value: u128 = 1;
 
         assert_ok!(Network::set_in_consensus_subnet_reputation_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1894,7 +2247,7 @@ fn test_set_overwatch_weight_factor() {
         let new_value: u128 = 200000000000000000;
 
         assert_ok!(Network::set_overwatch_weight_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1914,7 +2267,7 @@ fn test_set_max_emergency_validator_epochs_multiplier() {
         let new_value: u128 = 1000000000000000000;
 
         assert_ok!(Network::set_max_emergency_validator_epochs_multiplier(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1937,7 +2290,7 @@ fn test_set_max_emergency_subnet_nodes() {
         let new_value = MinSubnetNodes::<Test>::get() + 1;
 
         assert_ok!(Network::set_max_emergency_subnet_nodes(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1951,7 +2304,7 @@ fn test_set_max_emergency_subnet_nodes() {
 
         assert_err!(
             Network::set_max_emergency_subnet_nodes(
-                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
                 new_value
             ),
             Error::<Test>::InvalidMaxEmergencySubnetNodes
@@ -1967,7 +2320,7 @@ fn test_set_overwatch_stake_weight_factor() {
         let new_value: u128 = 1500000000000000000;
 
         assert_ok!(Network::set_overwatch_stake_weight_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             new_value
         ));
 
@@ -1991,7 +2344,7 @@ fn test_set_subnet_weight_factors() {
         };
 
         assert_ok!(Network::set_subnet_weight_factors(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             value.clone()
         ));
 
@@ -2011,7 +2364,7 @@ fn test_set_default_overwatch_subnet_weight() {
         let value = Network::percentage_factor_as_u128() / 2;
 
         assert_ok!(Network::set_default_overwatch_subnet_weight(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             value
         ));
 
@@ -2023,10 +2376,10 @@ fn test_set_default_overwatch_subnet_weight() {
 
         assert_err!(
             Network::set_default_overwatch_subnet_weight(
-                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
                 Network::percentage_factor_as_u128() + 1
             ),
-            Error::<Test>::InvalidPercent
+            Error::<Test>::ParameterOutOfBounds
         );
     });
 }
@@ -2072,7 +2425,7 @@ fn test_set_max_pause_epochs_subnet_reputation_factor() {
         let value: u128 = 5;
 
         assert_ok!(Network::set_max_pause_epochs_subnet_reputation_factor(
-            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
             value.clone()
         ));
 
@@ -2083,3 +2436,31 @@ fn test_set_max_pause_epochs_subnet_reputation_factor() {
         );
     });
 }
+
+#[test]
+fn test_set_early_unbonding_penalty() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 250000000000000000;
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::EarlyUnbondingPenalty(new_value)
+        ));
+
+        assert_eq!(EarlyUnbondingPenalty::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetEarlyUnbondingPenalty(new_value)
+        );
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::EarlyUnbondingPenalty(Network::percentage_factor_as_u128() + 1)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+    });
+}
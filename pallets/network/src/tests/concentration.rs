@@ -0,0 +1,239 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::Event;
+use crate::{
+    ColdkeyConcentration, ColdkeyValidatorId, Error, MaxSubnetNodes, MaxSubnets, MinSubnetMinStake,
+    NodeConcentrationCaps, NodeSubnetStake, PeerInfo, SubnetColdkeyConcentration,
+    SubnetElectedValidator, SubnetName, SubnetNodeClass, SubnetNodeConcentrationCaps,
+    SubnetNodeReputation, SubnetNodesData, SubnetState, TotalActiveSubnets, ValidatorSubnetNodes,
+};
+use frame_support::traits::Currency;
+use frame_support::weights::WeightMeter;
+use frame_support::{assert_err, assert_ok};
+use sp_std::collections::btree_set::BTreeSet;
+
+//
+//
+//
+//
+//
+//
+// Node concentration caps
+//
+//
+//
+//
+//
+//
+
+// Validator 1 (coldkey 1) owns nodes 1, 2 and 3, validator 2 (coldkey 2) owns node 4
+fn build_concentrated_subnet(subnet_id: u32) {
+    insert_subnet(subnet_id, SubnetState::Active, 0);
+    manual_insert_validator(1, 1, 101);
+    manual_insert_validator(2, 2, 102);
+
+    let nodes: [(u32, u32, u128, u128); 4] = [
+        // (subnet node ID, validator ID, stake, reputation)
+        (1, 1, 100, 500),
+        (2, 1, 100, 200),
+        (3, 1, 100, 200),
+        (4, 2, 500, 100),
+    ];
+
+    for (subnet_node_id, validator_id, stake, reputation) in nodes {
+        manual_insert_subnet_node_v2(
+            validator_id,
+            subnet_id,
+            subnet_node_id,
+            validator_id,
+            100 + validator_id,
+            subnet_node_id,
+            SubnetNodeClass::Validator,
+            0,
+        );
+        NodeSubnetStake::<Test>::insert(subnet_node_id, subnet_id, stake);
+        SubnetNodeReputation::<Test>::insert(subnet_id, subnet_node_id, reputation);
+        ValidatorSubnetNodes::<Test>::mutate(validator_id, |node_map| {
+            node_map
+                .entry(subnet_id)
+                .or_insert_with(BTreeSet::new)
+                .insert(subnet_node_id);
+        });
+    }
+}
+
+#[test]
+fn test_register_subnet_node_node_concentration_caps() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end + 1);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end + 1);
+
+        let burn_amount = Network::calculate_burn_amount(subnet_id);
+        let _ = Balances::deposit_creating(&coldkey.clone(), deposit_amount + burn_amount * 2);
+
+        assert_ok!(Network::register_validator(
+            RuntimeOrigin::signed(coldkey.clone()),
+            hotkey.clone(),
+            50000000000000000,
+            None,
+            None
+        ));
+        let validator_id = ColdkeyValidatorId::<Test>::get(coldkey.clone()).unwrap();
+
+        assert_ok!(Network::register_subnet_node(
+            RuntimeOrigin::signed(coldkey.clone()),
+            validator_id,
+            subnet_id,
+            None,
+            PeerInfo::<Test> {
+                peer_id: get_peer_id(subnets, max_subnet_nodes, max_subnets, end + 1),
+                multiaddr: None,
+            },
+            None,
+            None,
+            amount,
+            None,
+            None,
+            u128::MAX,
+        ));
+        assert_eq!(
+            Network::get_validator_subnet_node_count(subnet_id, validator_id),
+            1
+        );
+
+        let register_second_node = || {
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                validator_id,
+                subnet_id,
+                None,
+                PeerInfo::<Test> {
+                    peer_id: get_peer_id(subnets, max_subnet_nodes, max_subnets, end + 2),
+                    multiaddr: None,
+                },
+                None,
+                None,
+                amount,
+                None,
+                None,
+                u128::MAX,
+            )
+        };
+
+        SubnetNodeConcentrationCaps::<Test>::insert(
+            subnet_id,
+            NodeConcentrationCaps {
+                max_nodes_per_coldkey: None,
+                max_nodes_per_validator: Some(1),
+            },
+        );
+        assert_err!(
+            register_second_node(),
+            Error::<Test>::MaxNodesPerValidatorReached
+        );
+
+        SubnetNodeConcentrationCaps::<Test>::insert(
+            subnet_id,
+            NodeConcentrationCaps {
+                max_nodes_per_coldkey: Some(1),
+                max_nodes_per_validator: Some(2),
+            },
+        );
+        assert_err!(
+            register_second_node(),
+            Error::<Test>::MaxNodesPerColdkeyReached
+        );
+    })
+}
+
+#[test]
+fn test_drain_node_concentration_excess() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        build_concentrated_subnet(subnet_id);
+
+        // No caps, nothing is removed
+        Network::drain_node_concentration_excess(&mut WeightMeter::new(), subnet_id, 1);
+        assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 4);
+
+        SubnetNodeConcentrationCaps::<Test>::insert(
+            subnet_id,
+            NodeConcentrationCaps {
+                max_nodes_per_coldkey: None,
+                max_nodes_per_validator: Some(1),
+            },
+        );
+
+        // Node 3 is the newest of the lowest reputation nodes
+        Network::drain_node_concentration_excess(&mut WeightMeter::new(), subnet_id, 1);
+        assert!(!SubnetNodesData::<Test>::contains_key(subnet_id, 3));
+        assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 3);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::NodeConcentrationCapRemoval {
+                subnet_id,
+                subnet_node_id: 3,
+                validator_id: 1,
+            }
+        );
+
+        // The elected validator is never removed
+        SubnetElectedValidator::<Test>::insert(subnet_id, 2, 2);
+        Network::drain_node_concentration_excess(&mut WeightMeter::new(), subnet_id, 2);
+        assert!(SubnetNodesData::<Test>::contains_key(subnet_id, 2));
+        assert!(!SubnetNodesData::<Test>::contains_key(subnet_id, 1));
+
+        // Validator 1 is at the cap
+        Network::drain_node_concentration_excess(&mut WeightMeter::new(), subnet_id, 3);
+        assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 2);
+    })
+}
+
+#[test]
+fn test_get_subnet_coldkey_concentration() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        assert_eq!(
+            Network::get_subnet_coldkey_concentration(subnet_id, 1),
+            None
+        );
+
+        build_concentrated_subnet(subnet_id);
+
+        assert_eq!(
+            Network::get_subnet_coldkey_concentration(subnet_id, 1),
+            Some(SubnetColdkeyConcentration {
+                subnet_id,
+                total_nodes: 4,
+                total_stake: 800,
+                // 3 of 4 nodes
+                top_node_share: 750000000000000000,
+                // 500 of 800 stake
+                top_stake_share: 625000000000000000,
+                coldkeys: vec![ColdkeyConcentration {
+                    coldkey: account(1),
+                    nodes: 3,
+                    stake: 300,
+                }],
+            })
+        );
+
+        let concentration = Network::get_subnet_coldkey_concentration(subnet_id, 2).unwrap();
+        assert_eq!(concentration.top_node_share, 1000000000000000000);
+        assert_eq!(concentration.top_stake_share, 1000000000000000000);
+        assert_eq!(concentration.coldkeys.len(), 2);
+        assert_eq!(concentration.coldkeys[1].coldkey, account(2));
+    })
+}
@@ -1,15 +1,19 @@
+// The deprecated `set_*` extrinsics are tested until they are removed
+#![allow(deprecated)]
+
 use super::mock::*;
 use crate::tests::test_utils::*;
 use crate::Event;
 use crate::{
-    DelegateAccount,
-    DelegateAccountStake, Error, MaxSubnetNodes, MaxSubnets, MinActiveNodeStakeEpochs,
-    MinSubnetMinStake, OverwatchMinStakeBalance, OverwatchNodeIdHotkey, OverwatchNodes, PeerInfo,
+    AccountSubnetDelegateStakeShares, DelegateAccount, DelegateAccountStake, Error, MaxSubnetNodes,
+    MaxSubnets, MinActiveNodeStakeEpochs, MinSubnetMinStake, OverwatchMinStakeBalance,
+    OverwatchNodeIdHotkey, OverwatchNodes, PeerInfo, RewardDestination, RewardDestinations,
     StakeCooldownEpochs, StakeUnbondingLedger, SubnetName, SubnetNodeClass, SubnetState,
     TotalAccountDelegateStake, TotalActiveSubnets, TotalSubnetNodes, TotalValidatorIds,
     ValidatorsData,
 };
 use frame_support::traits::Currency;
+use frame_support::weights::WeightMeter;
 use frame_support::{assert_err, assert_ok};
 use sp_std::collections::btree_map::BTreeMap;
 
@@ -465,3 +469,120 @@ fn test_remove_delegate_account_balance_not_enough_stake_error() {
         assert_eq!(TotalAccountDelegateStake::<Test>::get(), 100);
     })
 }
+
+#[test]
+fn test_set_reward_destination() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let coldkey = account(100);
+
+        assert_err!(
+            Network::set_reward_destination(
+                RuntimeOrigin::signed(coldkey.clone()),
+                RewardDestination::SubnetDelegateStake(subnet_id + 1),
+            ),
+            Error::<Test>::InvalidSubnetId
+        );
+
+        assert_ok!(Network::set_reward_destination(
+            RuntimeOrigin::signed(coldkey.clone()),
+            RewardDestination::SubnetDelegateStake(subnet_id),
+        ));
+        assert_eq!(
+            RewardDestinations::<Test>::get(&coldkey),
+            RewardDestination::SubnetDelegateStake(subnet_id)
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::RewardDestinationSet {
+                account_id: coldkey.clone(),
+                destination: RewardDestination::SubnetDelegateStake(subnet_id),
+            }
+        );
+
+        // Setting the default removes the preference
+        assert_ok!(Network::set_reward_destination(
+            RuntimeOrigin::signed(coldkey.clone()),
+            RewardDestination::Staked,
+        ));
+        assert!(!RewardDestinations::<Test>::contains_key(&coldkey));
+    });
+}
+
+#[test]
+fn test_delegate_account_reward_destination() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let delegate_account_id = account(100);
+        let account_reward: u128 = 1000000000000000000000;
+        let delegate_rate = 400000000000000000; // 40%
+        let delegate_deposit = Network::percent_mul(account_reward, delegate_rate);
+
+        // Staked by default
+        let (updated_account_reward, deposit) = Network::handle_delegate_account(
+            &mut WeightMeter::new(),
+            account_reward,
+            &delegate_account_id,
+            delegate_rate,
+        );
+        assert_eq!(deposit, delegate_deposit);
+        assert_eq!(updated_account_reward, account_reward - delegate_deposit);
+        assert_eq!(
+            DelegateAccountStake::<Test>::get(&delegate_account_id),
+            delegate_deposit
+        );
+
+        // Paid out to the free balance
+        assert_ok!(Network::set_reward_destination(
+            RuntimeOrigin::signed(delegate_account_id.clone()),
+            RewardDestination::Free,
+        ));
+        let starting_balance = Balances::free_balance(&delegate_account_id);
+        Network::handle_delegate_account(
+            &mut WeightMeter::new(),
+            account_reward,
+            &delegate_account_id,
+            delegate_rate,
+        );
+        assert_eq!(
+            Balances::free_balance(&delegate_account_id),
+            starting_balance + delegate_deposit
+        );
+        assert_eq!(
+            DelegateAccountStake::<Test>::get(&delegate_account_id),
+            delegate_deposit
+        );
+
+        // Compounded into subnet delegate stake
+        assert_ok!(Network::set_reward_destination(
+            RuntimeOrigin::signed(delegate_account_id.clone()),
+            RewardDestination::SubnetDelegateStake(subnet_id),
+        ));
+        Network::handle_delegate_account(
+            &mut WeightMeter::new(),
+            account_reward,
+            &delegate_account_id,
+            delegate_rate,
+        );
+        assert_ne!(
+            AccountSubnetDelegateStakeShares::<Test>::get(&delegate_account_id, subnet_id),
+            0
+        );
+        assert_eq!(
+            DelegateAccountStake::<Test>::get(&delegate_account_id),
+            delegate_deposit
+        );
+    });
+}
@@ -1,12 +1,12 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
 use crate::{
-    AccountSubnetDelegateStakeShares, DelegateStakeCooldownEpochs, Error, MaxUnbondings,
-    MinDelegateStakeDeposit, MinSubnetMinStake, NextSwapQueueId, QueuedSwapCall,
-    StakeUnbondingLedger, SubnetName, SubnetRemovalReason, SubnetsData, SwapCallQueue,
-    SwapQueueOrder, TotalActiveSubnets, TotalDelegateStake, TotalNodeDelegateStakeBalance,
-    TotalNodeDelegateStakeShares, TotalSubnetDelegateStakeBalance, TotalSubnetDelegateStakeShares,
-    TotalSubnetNodes, TxRateLimit,
+    AccountSubnetDelegateStakeShares, DelegateStakeCooldownEpochs, Error,
+    FinalSubnetEmissionWeights, MaxUnbondings, MinDelegateStakeDeposit, MinSubnetMinStake,
+    NextSwapQueueId, QueuedSwapCall, StakeUnbondingLedger, SubnetName, SubnetRemovalReason,
+    SubnetsData, SwapCallQueue, SwapQueueOrder, TotalActiveSubnets, TotalDelegateStake,
+    TotalNodeDelegateStakeBalance, TotalNodeDelegateStakeShares, TotalSubnetDelegateStakeBalance,
+    TotalSubnetDelegateStakeShares, TotalSubnetNodes, TxRateLimit,
 };
 use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok};
@@ -1284,7 +1284,7 @@ fn test_swap_delegate_stake() {
                 assert_eq!(*to_subnet_id, starting_to_subnet_id);
                 assert_ne!(*balance, 0);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         let next_id = NextSwapQueueId::<Test>::get();
@@ -2252,3 +2252,49 @@ fn test_donate_delegate_stake() {
         assert_eq!(total_dstake, prev_total_dstake + amount);
     });
 }
+
+#[test]
+fn test_subnet_delegate_stake_projection() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 1000000000000000000000000;
+        let amount: u128 = 1000000000000000000000; // 1000
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let epoch = Network::get_current_epoch_as_u32();
+        let _ = Network::handle_subnet_emission_weights(epoch);
+        assert!(FinalSubnetEmissionWeights::<Test>::get(epoch)
+            .subnet_weights
+            .contains_key(&subnet_id));
+
+        let projection = Network::get_subnet_delegate_stake_projection(subnet_id, amount);
+
+        assert_eq!(projection.amount, amount);
+        assert_eq!(
+            projection.pool_balance,
+            TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id) + amount
+        );
+        assert!(projection.pool_epoch_reward > 0);
+        assert!(projection.epoch_reward > 0);
+        assert!(projection.epoch_reward <= projection.pool_epoch_reward);
+        assert_eq!(
+            projection.annual_reward,
+            projection.epoch_reward * EpochsPerYear::get() as u128
+        );
+        assert!(projection.apy >= projection.apr);
+
+        // A larger stake dilutes the pool
+        let larger_projection =
+            Network::get_subnet_delegate_stake_projection(subnet_id, amount * 1000);
+        assert!(larger_projection.apr < projection.apr);
+
+        // Unknown subnets have no yield
+        let unknown_projection = Network::get_subnet_delegate_stake_projection(999, amount);
+        assert_eq!(unknown_projection.epoch_reward, 0);
+        assert_eq!(unknown_projection.apr, 0);
+    });
+}
@@ -0,0 +1,227 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    MinSubnetMinStake, NodeSubnetStake, SubnetElectedValidator, SubnetName,
+    SubnetNodeElectionSlots, SubnetNodeReputation, SubnetValidatorElectionMode, TotalSubnetStake,
+    ValidatorElectionMode, WeightedValidatorElection,
+};
+use sp_core::U256;
+use sp_std::collections::btree_map::BTreeMap;
+
+///
+///
+///
+///
+///
+///
+///
+/// Validator election
+///
+///
+///
+///
+///
+///
+///
+
+const ONE: u128 = 1_000_000_000_000_000_000;
+
+fn weighted_election(
+    stake_exponent: u128,
+    node_reputation_exponent: u128,
+    max_validator_probability: u128,
+) -> ValidatorElectionMode {
+    ValidatorElectionMode::Weighted(WeightedValidatorElection {
+        stake_exponent,
+        node_reputation_exponent,
+        validator_reputation_exponent: 0,
+        max_validator_probability,
+    })
+}
+
+fn build_election_subnet(subnet_name: Vec<u8>, node_count: u32) -> (u32, Vec<u32>) {
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+    build_activated_subnet(
+        subnet_name.clone(),
+        0,
+        node_count,
+        deposit_amount,
+        stake_amount,
+    );
+
+    let subnet_id = SubnetName::<Test>::get(subnet_name).unwrap();
+    let slot_list = SubnetNodeElectionSlots::<Test>::get(subnet_id);
+
+    (subnet_id, slot_list)
+}
+
+#[test]
+fn test_cap_validator_election_probabilities() {
+    new_test_ext().execute_with(|| {
+        let weights: BTreeMap<u32, U256> =
+            BTreeMap::from([(1, U256::from(8)), (2, U256::from(1)), (3, U256::from(1))]);
+
+        // No cap, pro rata
+        let probabilities = Network::cap_validator_election_probabilities(&weights, ONE);
+        assert_eq!(probabilities.get(&1), Some(&(ONE * 8 / 10)));
+        assert_eq!(probabilities.get(&2), Some(&(ONE / 10)));
+        assert_eq!(probabilities.get(&3), Some(&(ONE / 10)));
+
+        // Excess over the cap is redistributed pro rata
+        let probabilities = Network::cap_validator_election_probabilities(&weights, ONE / 2);
+        assert_eq!(probabilities.get(&1), Some(&(ONE / 2)));
+        assert_eq!(probabilities.get(&2), Some(&(ONE / 4)));
+        assert_eq!(probabilities.get(&3), Some(&(ONE / 4)));
+
+        // The cap can't hold for 3 validators, share equally
+        let probabilities = Network::cap_validator_election_probabilities(&weights, ONE / 5);
+        assert!(probabilities.values().all(|p| *p == ONE / 3));
+    });
+}
+
+#[test]
+fn test_cap_validator_election_probabilities_cascades() {
+    new_test_ext().execute_with(|| {
+        // Capping 1 pushes 2 over the cap on the next pass
+        let weights: BTreeMap<u32, U256> = BTreeMap::from([
+            (1, U256::from(60)),
+            (2, U256::from(30)),
+            (3, U256::from(5)),
+            (4, U256::from(5)),
+        ]);
+
+        let cap = ONE * 35 / 100;
+        let probabilities = Network::cap_validator_election_probabilities(&weights, cap);
+        assert_eq!(probabilities.get(&1), Some(&cap));
+        assert_eq!(probabilities.get(&2), Some(&cap));
+        assert_eq!(probabilities.get(&3), Some(&(ONE * 15 / 100)));
+        assert_eq!(probabilities.get(&4), Some(&(ONE * 15 / 100)));
+    });
+}
+
+#[test]
+fn test_weighted_election_probabilities_follow_stake() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, slot_list) = build_election_subnet("subnet-name".into(), 4);
+        assert_eq!(slot_list.len(), 4);
+
+        let heavy_node_id = slot_list[0];
+        let stake = NodeSubnetStake::<Test>::get(heavy_node_id, subnet_id);
+        NodeSubnetStake::<Test>::insert(heavy_node_id, subnet_id, stake * 3);
+
+        // Uniform ignores stake
+        let probabilities = Network::get_validator_election_probabilities(subnet_id, &slot_list);
+        assert!(probabilities.values().all(|p| *p == ONE / 4));
+
+        SubnetValidatorElectionMode::<Test>::insert(subnet_id, weighted_election(ONE, 0, ONE));
+
+        let probabilities = Network::get_validator_election_probabilities(subnet_id, &slot_list);
+        assert_eq!(probabilities.len(), 4);
+        assert_eq!(probabilities.get(&heavy_node_id), Some(&(ONE / 2)));
+        for subnet_node_id in &slot_list[1..] {
+            assert_eq!(probabilities.get(subnet_node_id), Some(&(ONE / 6)));
+        }
+
+        // Cap the heavy node's validator at 40%, the rest is split evenly
+        SubnetValidatorElectionMode::<Test>::insert(
+            subnet_id,
+            weighted_election(ONE, 0, ONE * 4 / 10),
+        );
+
+        let probabilities = Network::get_validator_election_probabilities(subnet_id, &slot_list);
+        assert_eq!(probabilities.get(&heavy_node_id), Some(&(ONE * 4 / 10)));
+        for subnet_node_id in &slot_list[1..] {
+            assert_eq!(probabilities.get(subnet_node_id), Some(&(ONE * 2 / 10)));
+        }
+    });
+}
+
+#[test]
+fn test_weighted_election_stake_exponent_does_not_saturate() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, slot_list) = build_election_subnet("subnet-name".into(), 4);
+
+        // 10M and 20M tokens, raw 1e18 stake to the 4th power overflows u128
+        let stake: u128 = 10_000_000 * ONE;
+        let heavy_node_id = slot_list[0];
+        for subnet_node_id in &slot_list {
+            let node_stake = if *subnet_node_id == heavy_node_id {
+                stake * 2
+            } else {
+                stake
+            };
+            NodeSubnetStake::<Test>::insert(subnet_node_id, subnet_id, node_stake);
+        }
+        TotalSubnetStake::<Test>::insert(subnet_id, stake * 5);
+
+        SubnetValidatorElectionMode::<Test>::insert(subnet_id, weighted_election(ONE * 4, 0, ONE));
+
+        // 2^4 = 16 times the weight of each other node
+        let probabilities = Network::get_validator_election_probabilities(subnet_id, &slot_list);
+        let heavy_probability = *probabilities.get(&heavy_node_id).unwrap();
+        for subnet_node_id in &slot_list[1..] {
+            let probability = *probabilities.get(subnet_node_id).unwrap();
+            assert!(heavy_probability.abs_diff(probability * 16) < 1_000_000);
+        }
+    });
+}
+
+#[test]
+fn test_weighted_election_zero_weight_falls_back_to_uniform() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, slot_list) = build_election_subnet("subnet-name".into(), 4);
+
+        for subnet_node_id in &slot_list {
+            SubnetNodeReputation::<Test>::insert(subnet_id, subnet_node_id, 0);
+        }
+
+        SubnetValidatorElectionMode::<Test>::insert(subnet_id, weighted_election(0, ONE, ONE));
+
+        let probabilities = Network::get_validator_election_probabilities(subnet_id, &slot_list);
+        assert_eq!(probabilities.len(), 4);
+        assert!(probabilities.values().all(|p| *p == ONE / 4));
+    });
+}
+
+#[test]
+fn test_elect_validator_weighted_never_elects_zero_weight_nodes() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, slot_list) = build_election_subnet("subnet-name".into(), 4);
+
+        // Only one node has reputation
+        let reputable_node_id = slot_list[2];
+        for subnet_node_id in &slot_list {
+            let reputation = if *subnet_node_id == reputable_node_id {
+                ONE
+            } else {
+                0
+            };
+            SubnetNodeReputation::<Test>::insert(subnet_id, subnet_node_id, reputation);
+        }
+
+        SubnetValidatorElectionMode::<Test>::insert(subnet_id, weighted_election(0, ONE, ONE));
+
+        let start_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id) + 1;
+        for subnet_epoch in start_epoch..start_epoch + 10 {
+            Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+            assert_eq!(
+                SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch),
+                Some(reputable_node_id)
+            );
+        }
+
+        let validator_info = Network::get_elected_validator_info(subnet_id, start_epoch);
+        assert_eq!(
+            validator_info.validator.map(|info| info.subnet_node_id),
+            Some(reputable_node_id)
+        );
+        assert_eq!(
+            validator_info
+                .election_probabilities
+                .get(&reputable_node_id),
+            Some(&ONE)
+        );
+    });
+}
@@ -2,24 +2,30 @@ use super::mock::*;
 use super::test_utils::*;
 use crate::Event;
 use crate::{
-    AccountSubnetDelegateStakeShares, BaseValidatorReward, ColdkeyValidatorId,
-    DelegateAccountStake, EmergencySubnetNodeElectionData, Error, FinalSubnetEmissionWeights,
-    IdleClassificationEpochs, IncludedClassificationEpochs, MaxSubnetNodes, MaxSubnets,
-    MinAttestationPercentage, MinSubnetMinStake, MinSubnetNodeReputation, MinSubnetReputation,
-    NodeSubnetStake, PeerInfo, QueueImmunityEpochs, RegisteredSubnetNodesData, RewardsCapacitor,
-    SubnetConsensusSubmission, SubnetElectedValidator, SubnetName, SubnetNodeClass,
-    SubnetNodeConsecutiveIncludedEpochs, SubnetNodeConsensusData, SubnetNodeElectionSlots,
-    SubnetNodeIdleConsecutiveEpochs, SubnetNodeMinWeightDecreaseReputationThreshold,
-    SubnetNodeQueue, SubnetNodeQueueEpochs, SubnetNodeReputation, SubnetNodesData, SubnetOwner,
-    SubnetPauseCooldownEpochs, SubnetRemovalReason, SubnetReputation,
-    SubnetReputationFactorSchedules, SubnetState, SubnetsData, SuperMajorityAttestationRatio,
-    TotalActiveSubnets, TotalNodeDelegateStakeBalance, TotalNodeDelegateStakeShares,
-    TotalSubnetDelegateStakeBalance, TotalSubnetNodeUids, TotalSubnetNodes, TotalSubnetUids,
-    ValidatorAbsentSubnetReputationFactor, ValidatorColdkey, ValidatorDelegateStakeBalance,
-    ValidatorReputationDecreaseFactor, ValidatorReputationIncreaseFactor, ValidatorsData,
+    AccountSubnetDelegateStakeShares, AttestationWeighting, BaseValidatorReward,
+    ColdkeyValidatorId, ConsensusDataPolicy, DelegateAccountStake, EmergencySubnetNodeElectionData,
+    Error, FallbackProposerConfig, FinalSubnetEmissionWeights, IdleClassificationEpochs,
+    IncludedClassificationEpochs, MaxSubnetNodes, MaxSubnets, MinAttestationPercentage,
+    MinSubnetMinStake, MinSubnetNodeReputation, MinSubnetReputation, NodeSubnetPendingSlash,
+    NodeSubnetStake, PeerInfo, PendingSlashes, QueueImmunityEpochs, RegisteredSubnetNodesData,
+    RewardSource, RewardsCapacitor, RewardsData, RewardsLedgerRetentionEpochs,
+    SubnetAttestationWeighting, SubnetConsensusDataPolicies, SubnetConsensusSubmission,
+    SubnetElectedValidator, SubnetFallbackConsensusSubmissions, SubnetFallbackProposerIds,
+    SubnetFallbackProposers, SubnetName, SubnetNodeClass, SubnetNodeConsecutiveIncludedEpochs,
+    SubnetNodeConsensusData, SubnetNodeElectionSlots, SubnetNodeIdleConsecutiveEpochs,
+    SubnetNodeMinWeightDecreaseReputationThreshold, SubnetNodeQueue, SubnetNodeQueueEpochs,
+    SubnetNodeReputation, SubnetNodesData, SubnetOwner, SubnetPauseCooldownEpochs,
+    SubnetRemovalReason, SubnetReputation, SubnetReputationFactorSchedules, SubnetRewardsLedger,
+    SubnetRewardsLedgerEntry, SubnetRewardsLedgerEpochs, SubnetState, SubnetsData,
+    SuperMajorityAttestationRatio, TotalActiveSubnets, TotalNodeDelegateStakeBalance,
+    TotalNodeDelegateStakeShares, TotalSubnetDelegateStakeBalance, TotalSubnetNodeUids,
+    TotalSubnetNodes, TotalSubnetUids, ValidatorAbsentSubnetReputationFactor, ValidatorColdkey,
+    ValidatorDelegateStakeBalance, ValidatorReputationDecreaseFactor,
+    ValidatorReputationIncreaseFactor, ValidatorsData,
 };
+use codec::Encode;
 use frame_support::pallet_prelude::DispatchResult;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 use frame_support::weights::WeightMeter;
 use frame_support::{assert_err, assert_ok};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
@@ -121,6 +127,15 @@ fn assert_unique_sorted_consensus_data(data: &[SubnetNodeConsensusData]) {
 fn build_elected_subnet_for_consensus(
     subnet_name: Vec<u8>,
     node_count: u32,
+) -> (u32, u32, u32, AccountId, Vec<SubnetNodeConsensusData>) {
+    build_elected_subnet_for_fallback_consensus(subnet_name, node_count, None)
+}
+
+/// `build_elected_subnet_for_consensus` with fallback proposers enabled before the election
+fn build_elected_subnet_for_fallback_consensus(
+    subnet_name: Vec<u8>,
+    node_count: u32,
+    fallback_config: Option<FallbackProposerConfig>,
 ) -> (u32, u32, u32, AccountId, Vec<SubnetNodeConsensusData>) {
     increase_epochs(50);
 
@@ -144,6 +159,10 @@ fn build_elected_subnet_for_consensus(
     let epoch = System::block_number() / EpochLength::get();
     set_block_to_subnet_slot_epoch(epoch, subnet_id);
 
+    if let Some(config) = fallback_config {
+        SubnetFallbackProposers::<Test>::insert(subnet_id, config);
+    }
+
     let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
     Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
 
@@ -728,6 +747,651 @@ fn test_validator_absent_propose_attestation_decrease_reputation() {
 // //     });
 // // }
 
+#[test]
+fn test_propose_attestation_consensus_data_policy_errors() {
+    new_test_ext().execute_with(|| {
+        let node_count = 4;
+        let (subnet_id, subnet_epoch, elected_node_id, hotkey, consensus_data) =
+            build_elected_subnet_for_consensus("subnet-name".into(), node_count);
+        let scored_nodes = consensus_data.len() as u32;
+
+        let cases = vec![
+            (
+                ConsensusDataPolicy::MaxScore(DEFAULT_SCORE - 1),
+                Error::<Test>::ConsensusDataScoreAboveMax,
+            ),
+            (
+                ConsensusDataPolicy::MinScoredNodes(scored_nodes + 1),
+                Error::<Test>::ConsensusDataTooFewScoredNodes,
+            ),
+            (
+                ConsensusDataPolicy::NormalizedScores,
+                Error::<Test>::ConsensusDataScoresNotNormalized,
+            ),
+            (
+                ConsensusDataPolicy::TopK(scored_nodes - 1),
+                Error::<Test>::ConsensusDataTooManyScoredNodes,
+            ),
+            (
+                ConsensusDataPolicy::MinIncludedScore(DEFAULT_SCORE + 1),
+                Error::<Test>::ConsensusDataScoreBelowMin,
+            ),
+        ];
+
+        for (policy, error) in cases {
+            SubnetConsensusDataPolicies::<Test>::insert(subnet_id, vec![policy]);
+
+            assert_err!(
+                Network::propose_attestation(
+                    RuntimeOrigin::signed(hotkey.clone()),
+                    subnet_id,
+                    elected_node_id,
+                    consensus_data.clone(),
+                    None,
+                    None,
+                    None,
+                    None,
+                ),
+                error
+            );
+        }
+
+        assert!(SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).is_none());
+    });
+}
+
+#[test]
+fn test_propose_attestation_consensus_data_policies_satisfied() {
+    new_test_ext().execute_with(|| {
+        let node_count = 4;
+        let (subnet_id, subnet_epoch, elected_node_id, hotkey, consensus_data) =
+            build_elected_subnet_for_consensus("subnet-name".into(), node_count);
+        let scored_nodes = consensus_data.len() as u32;
+
+        // Split 1e18 evenly, giving the remainder to the first node
+        let percentage_factor = Network::percentage_factor_as_u128();
+        let share = percentage_factor / scored_nodes as u128;
+        let mut normalized_data: Vec<SubnetNodeConsensusData> = consensus_data
+            .iter()
+            .map(|entry| SubnetNodeConsensusData {
+                subnet_node_id: entry.subnet_node_id,
+                score: share,
+            })
+            .collect();
+        normalized_data[0].score += percentage_factor - share * scored_nodes as u128;
+
+        SubnetConsensusDataPolicies::<Test>::insert(
+            subnet_id,
+            vec![
+                ConsensusDataPolicy::MaxScore(percentage_factor),
+                ConsensusDataPolicy::MinScoredNodes(scored_nodes),
+                ConsensusDataPolicy::NormalizedScores,
+                ConsensusDataPolicy::TopK(scored_nodes),
+                ConsensusDataPolicy::MinIncludedScore(share),
+            ],
+        );
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(hotkey.clone()),
+            subnet_id,
+            elected_node_id,
+            normalized_data,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        let submission = SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        let sum = submission.data.iter().fold(0, |acc, x| acc + x.score);
+        assert_eq!(sum, percentage_factor);
+    });
+}
+
+#[test]
+fn test_propose_attestation_fallback_proposer() {
+    new_test_ext().execute_with(|| {
+        let node_count = 4;
+        let (subnet_id, subnet_epoch, elected_node_id, _, consensus_data) =
+            build_elected_subnet_for_fallback_consensus(
+                "subnet-name".into(),
+                node_count,
+                Some(FallbackProposerConfig {
+                    start_progression: 0,
+                    max_proposers: 1,
+                }),
+            );
+
+        let fallback_ids = SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch);
+        assert_eq!(fallback_ids.len(), 1);
+        let fallback_node_id = fallback_ids[0];
+        let fallback_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, fallback_node_id).unwrap();
+        let unranked_node_id = Network::get_consensus_validator_ids(subnet_id)
+            .into_iter()
+            .find(|id| *id != elected_node_id && *id != fallback_node_id)
+            .unwrap();
+        let unranked_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, unranked_node_id).unwrap();
+
+        // --- Only the elected validator can propose if fallback proposers are disabled
+        SubnetFallbackProposers::<Test>::remove(subnet_id);
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(fallback_hotkey.clone()),
+                subnet_id,
+                fallback_node_id,
+                consensus_data.clone(),
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::InvalidSubnetNodeId
+        );
+
+        SubnetFallbackProposers::<Test>::insert(
+            subnet_id,
+            FallbackProposerConfig {
+                start_progression: Network::percentage_factor_as_u128(),
+                max_proposers: 1,
+            },
+        );
+
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(fallback_hotkey.clone()),
+                subnet_id,
+                fallback_node_id,
+                consensus_data.clone(),
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::FallbackProposalsNotOpen
+        );
+
+        SubnetFallbackProposers::<Test>::insert(
+            subnet_id,
+            FallbackProposerConfig {
+                start_progression: 0,
+                max_proposers: 1,
+            },
+        );
+
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(unranked_hotkey),
+                subnet_id,
+                unranked_node_id,
+                consensus_data.clone(),
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::NotFallbackProposer
+        );
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(fallback_hotkey.clone()),
+            subnet_id,
+            fallback_node_id,
+            consensus_data.clone(),
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert!(SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).is_none());
+        let proposals = SubnetFallbackConsensusSubmissions::<Test>::get(subnet_id, subnet_epoch);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].validator_id, fallback_node_id);
+        assert!(proposals[0].attests.contains_key(&fallback_node_id));
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::FallbackValidatorSubmission {
+                subnet_id,
+                subnet_node_id: fallback_node_id,
+                account_id: fallback_hotkey.clone(),
+                epoch: subnet_epoch,
+            }
+        );
+
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(fallback_hotkey),
+                subnet_id,
+                fallback_node_id,
+                consensus_data,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::FallbackAlreadyProposed
+        );
+    });
+}
+
+#[test]
+fn test_fallback_proposers_drawn_in_election_and_pruned() {
+    new_test_ext().execute_with(|| {
+        let node_count = 4;
+        let max_proposers = 2;
+        let (subnet_id, subnet_epoch, elected_node_id, _, consensus_data) =
+            build_elected_subnet_for_fallback_consensus(
+                "subnet-name".into(),
+                node_count,
+                Some(FallbackProposerConfig {
+                    start_progression: 0,
+                    max_proposers,
+                }),
+            );
+
+        // --- Distinct nodes of the election slots, never the elected validator
+        let fallback_ids = SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch);
+        assert_eq!(fallback_ids.len(), max_proposers as usize);
+        assert!(!fallback_ids.contains(&elected_node_id));
+        assert_eq!(
+            fallback_ids.iter().collect::<BTreeSet<_>>().len(),
+            fallback_ids.len()
+        );
+        let election_slots = SubnetNodeElectionSlots::<Test>::get(subnet_id);
+        assert!(fallback_ids.iter().all(|id| election_slots.contains(id)));
+
+        let fallback_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, fallback_ids[0]).unwrap();
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(fallback_hotkey),
+            subnet_id,
+            fallback_ids[0],
+            consensus_data,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        // --- Kept through the rewards of the next epoch
+        Network::elect_validator(subnet_id, subnet_epoch + 1, System::block_number());
+        assert_eq!(
+            SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch),
+            fallback_ids
+        );
+        assert_eq!(
+            SubnetFallbackConsensusSubmissions::<Test>::get(subnet_id, subnet_epoch).len(),
+            1
+        );
+
+        // --- Pruned by the election two epochs later
+        Network::elect_validator(subnet_id, subnet_epoch + 2, System::block_number());
+        assert!(SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch).is_empty());
+        assert!(
+            SubnetFallbackConsensusSubmissions::<Test>::get(subnet_id, subnet_epoch).is_empty()
+        );
+        assert_eq!(
+            SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch + 2).len(),
+            max_proposers as usize
+        );
+    });
+}
+
+#[test]
+fn test_attest_fallback_one_attestation_per_epoch() {
+    new_test_ext().execute_with(|| {
+        let node_count = 4;
+        let (subnet_id, subnet_epoch, elected_node_id, hotkey, consensus_data) =
+            build_elected_subnet_for_fallback_consensus(
+                "subnet-name".into(),
+                node_count,
+                Some(FallbackProposerConfig {
+                    start_progression: 0,
+                    max_proposers: 2,
+                }),
+            );
+
+        let fallback_ids = SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch);
+        let fallback_node_id = fallback_ids[0];
+        let fallback_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, fallback_node_id).unwrap();
+        let attestor_node_id = fallback_ids[1];
+        let attestor_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, attestor_node_id).unwrap();
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(fallback_hotkey),
+            subnet_id,
+            fallback_node_id,
+            consensus_data.clone(),
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert_err!(
+            Network::attest_fallback(
+                RuntimeOrigin::signed(attestor_hotkey.clone()),
+                subnet_id,
+                attestor_node_id,
+                elected_node_id,
+                None
+            ),
+            Error::<Test>::InvalidFallbackProposal
+        );
+
+        assert_ok!(Network::attest_fallback(
+            RuntimeOrigin::signed(attestor_hotkey.clone()),
+            subnet_id,
+            attestor_node_id,
+            fallback_node_id,
+            None
+        ));
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::FallbackAttestation {
+                subnet_id,
+                subnet_node_id: attestor_node_id,
+                proposer_subnet_node_id: fallback_node_id,
+                epoch: subnet_epoch,
+            }
+        );
+
+        // --- The attestor can't propose or attest to another proposal this epoch
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(attestor_hotkey.clone()),
+                subnet_id,
+                attestor_node_id,
+                consensus_data.clone(),
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::AlreadyAttested
+        );
+
+        // --- The elected validator can still propose late
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(hotkey),
+            subnet_id,
+            elected_node_id,
+            consensus_data,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert_err!(
+            Network::attest(
+                RuntimeOrigin::signed(attestor_hotkey),
+                subnet_id,
+                attestor_node_id,
+                None
+            ),
+            Error::<Test>::AlreadyAttested
+        );
+    });
+}
+
+#[test]
+fn test_select_consensus_submission_fallback_super_majority() {
+    new_test_ext().execute_with(|| {
+        let node_count = 4;
+        let (subnet_id, subnet_epoch, elected_node_id, _, consensus_data) =
+            build_elected_subnet_for_fallback_consensus(
+                "subnet-name".into(),
+                node_count,
+                Some(FallbackProposerConfig {
+                    start_progression: 0,
+                    max_proposers: 1,
+                }),
+            );
+
+        let fallback_node_id = SubnetFallbackProposerIds::<Test>::get(subnet_id, subnet_epoch)[0];
+        let fallback_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, fallback_node_id).unwrap();
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(fallback_hotkey),
+            subnet_id,
+            fallback_node_id,
+            consensus_data,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        // --- Only the proposer attested, no consensus
+        let (selected, _) = Network::select_consensus_submission(subnet_id, subnet_epoch);
+        assert!(selected.is_none());
+
+        for subnet_node_id in Network::get_consensus_validator_ids(subnet_id) {
+            if subnet_node_id == fallback_node_id {
+                continue;
+            }
+            let attestor_hotkey =
+                Network::get_subnet_node_associated_hotkey(subnet_id, subnet_node_id).unwrap();
+            assert_ok!(Network::attest_fallback(
+                RuntimeOrigin::signed(attestor_hotkey),
+                subnet_id,
+                subnet_node_id,
+                fallback_node_id,
+                None
+            ));
+        }
+
+        let starting_subnet_rep = SubnetReputation::<Test>::get(subnet_id);
+        let starting_validator_rep =
+            SubnetNodeReputation::<Test>::get(subnet_id, elected_node_id).unwrap();
+
+        let (selected, _) = Network::select_consensus_submission(subnet_id, subnet_epoch);
+        let selected = selected.unwrap();
+        assert_eq!(selected.validator_id, fallback_node_id);
+        assert!(
+            Network::get_consensus_attestation_ratio(&selected)
+                >= SuperMajorityAttestationRatio::<Test>::get()
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::FallbackProposalSelected {
+                subnet_id,
+                subnet_epoch,
+                subnet_node_id: fallback_node_id,
+            }
+        );
+
+        // --- The absent elected validator is penalized, the subnet is not
+        assert_eq!(
+            starting_subnet_rep,
+            SubnetReputation::<Test>::get(subnet_id)
+        );
+        assert!(
+            starting_validator_rep
+                > SubnetNodeReputation::<Test>::get(subnet_id, elected_node_id).unwrap()
+        );
+    });
+}
+
+#[test]
+fn test_attestation_ratio_weighting() {
+    let percentage_factor = Network::percentage_factor_as_u128();
+
+    // 2 of 4 attestors, or 6 of 8 weight
+    for (weighting, expected_ratio) in [
+        (AttestationWeighting::Count, percentage_factor / 2),
+        (AttestationWeighting::Stake, percentage_factor * 3 / 4),
+        (AttestationWeighting::Reputation, percentage_factor * 3 / 4),
+    ] {
+        new_test_ext().execute_with(|| {
+            let node_count = 4;
+            let (subnet_id, subnet_epoch, elected_node_id, hotkey, consensus_data) =
+                build_elected_subnet_for_consensus("subnet-name".into(), node_count);
+            SubnetAttestationWeighting::<Test>::insert(subnet_id, weighting);
+
+            let validator_ids = Network::get_consensus_validator_ids(subnet_id);
+            assert_eq!(validator_ids.len(), node_count as usize);
+            let attestor_node_id = *validator_ids
+                .iter()
+                .find(|id| **id != elected_node_id)
+                .unwrap();
+            let attestor_hotkey =
+                Network::get_subnet_node_associated_hotkey(subnet_id, attestor_node_id).unwrap();
+
+            // --- The elected validator and attestor hold 3x the stake and reputation of the others
+            for subnet_node_id in validator_ids.iter() {
+                let multiplier =
+                    if *subnet_node_id == elected_node_id || *subnet_node_id == attestor_node_id {
+                        3
+                    } else {
+                        1
+                    };
+                NodeSubnetStake::<Test>::insert(subnet_node_id, subnet_id, 1000 * multiplier);
+                SubnetNodeReputation::<Test>::insert(
+                    subnet_id,
+                    subnet_node_id,
+                    percentage_factor / 4 * multiplier,
+                );
+            }
+
+            assert_ok!(Network::propose_attestation(
+                RuntimeOrigin::signed(hotkey),
+                subnet_id,
+                elected_node_id,
+                consensus_data,
+                None,
+                None,
+                None,
+                None,
+            ));
+
+            assert_ok!(Network::attest(
+                RuntimeOrigin::signed(attestor_hotkey),
+                subnet_id,
+                attestor_node_id,
+                None
+            ));
+
+            let attestation_ratio = || {
+                Network::precheck_subnet_consensus_submission(
+                    subnet_id,
+                    subnet_epoch,
+                    Network::get_current_epoch_as_u32(),
+                )
+                .0
+                .unwrap()
+                .attestation_ratio
+            };
+
+            assert_eq!(attestation_ratio(), expected_ratio);
+
+            // --- Weights are taken at proposal and attestation, later changes don't move the ratio
+            SubnetAttestationWeighting::<Test>::insert(subnet_id, AttestationWeighting::Count);
+            for subnet_node_id in validator_ids.iter() {
+                NodeSubnetStake::<Test>::insert(subnet_node_id, subnet_id, 0);
+                SubnetNodeReputation::<Test>::insert(subnet_id, subnet_node_id, 0);
+            }
+            assert_eq!(attestation_ratio(), expected_ratio);
+        });
+    }
+}
+
+#[test]
+fn test_migrate_attestation_weights() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, subnet_epoch, elected_node_id, hotkey, consensus_data) =
+            build_elected_subnet_for_consensus("subnet-name".into(), 4);
+
+        let validator_ids = Network::get_consensus_validator_ids(subnet_id);
+        let attestor_node_id = *validator_ids
+            .iter()
+            .find(|id| **id != elected_node_id)
+            .unwrap();
+        let attestor_hotkey =
+            Network::get_subnet_node_associated_hotkey(subnet_id, attestor_node_id).unwrap();
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(hotkey),
+            subnet_id,
+            elected_node_id,
+            consensus_data,
+            None,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Network::attest(
+            RuntimeOrigin::signed(attestor_hotkey),
+            subnet_id,
+            attestor_node_id,
+            None
+        ));
+
+        let submission = SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        assert_eq!(submission.attestation_weighting, AttestationWeighting::Count);
+
+        // --- Store the submission in the layout without attestation weights
+        let old_attests: BTreeMap<u32, _> = submission
+            .attests
+            .iter()
+            .map(|(subnet_node_id, attest)| {
+                (
+                    *subnet_node_id,
+                    (
+                        attest.block,
+                        attest.attestor_progress,
+                        attest.reward_factor,
+                        attest.data.clone(),
+                    ),
+                )
+            })
+            .collect();
+        let old_submission = (
+            submission.validator_id,
+            submission.block,
+            submission.validator_epoch_progress,
+            submission.validator_reward_factor,
+            submission.validator_ids.clone(),
+            old_attests,
+            submission.subnet_nodes.clone(),
+            submission.prioritize_queue_node_id,
+            submission.remove_queue_node_id,
+            submission.data.clone(),
+            submission.args.clone(),
+        );
+        frame_support::storage::unhashed::put_raw(
+            &SubnetConsensusSubmission::<Test>::hashed_key_for(subnet_id, subnet_epoch),
+            &old_submission.encode(),
+        );
+
+        StorageVersion::new(3).put::<Network>();
+        let _ = <crate::migrations::MigrateAttestationWeights<Test> as OnRuntimeUpgrade>::on_runtime_upgrade();
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(4));
+
+        // --- Count weights keep the share of attestors
+        assert_eq!(
+            SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch),
+            Some(submission)
+        );
+        let (result, _) = Network::precheck_subnet_consensus_submission(
+            subnet_id,
+            subnet_epoch,
+            Network::get_current_epoch_as_u32(),
+        );
+        assert_eq!(
+            result.unwrap().attestation_ratio,
+            Network::percentage_factor_as_u128() / 2
+        );
+    });
+}
+
 #[test]
 fn test_propose_attestation_no_validator_elected_error() {
     new_test_ext().execute_with(|| {
@@ -1866,6 +2530,288 @@ fn test_distribute_rewards() {
         assert!(post_dstake_balance > dstake_balance);
 
         assert!(SubnetReputation::<Test>::get(subnet_id) > set_rep);
+
+        // ⸺ Rewards ledger
+        let ledger_entry = SubnetRewardsLedger::<Test>::get(subnet_id, epoch).unwrap();
+        assert!(ledger_entry.in_consensus);
+        assert_eq!(ledger_entry.rewards_data, rewards_data);
+        assert_eq!(
+            ledger_entry.validator_subnet_node_id,
+            elected_node_id.unwrap()
+        );
+        assert_eq!(ledger_entry.validator_reward, expected_validator_reward);
+        assert_eq!(
+            ledger_entry.subnet_owner,
+            SubnetOwner::<Test>::get(subnet_id)
+        );
+        assert_eq!(
+            ledger_entry.subnet_owner_reward,
+            rewards_data.subnet_owner_reward
+        );
+        assert_eq!(ledger_entry.node_rewards.len(), max_subnet_nodes as usize);
+        assert!(ledger_entry
+            .node_rewards
+            .iter()
+            .all(|node_reward| node_reward.reward == expected_node_reward));
+        assert_eq!(
+            ledger_entry.delegate_stake_reward,
+            rewards_data.delegate_stake_rewards
+        );
+        assert_eq!(
+            SubnetRewardsLedgerEpochs::<Test>::get(subnet_id),
+            vec![epoch]
+        );
+
+        let validator_history =
+            Network::get_subnet_node_rewards_history(subnet_id, elected_node_id.unwrap());
+        assert_eq!(validator_history.len(), 2);
+        assert_eq!(validator_history[0].source, RewardSource::Validator);
+        assert_eq!(validator_history[0].amount, expected_validator_reward);
+        assert_eq!(validator_history[1].source, RewardSource::SubnetNode);
+        assert_eq!(validator_history[1].amount, expected_node_reward);
+
+        let owner_history = Network::get_account_rewards_history(
+            SubnetOwner::<Test>::get(subnet_id).unwrap(),
+            None,
+            16,
+        )
+        .items;
+        assert!(owner_history.iter().any(|record| {
+            record.source == RewardSource::SubnetOwner
+                && record.amount == rewards_data.subnet_owner_reward
+        }));
+    });
+}
+
+#[test]
+fn test_rewards_ledger_retention() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let retention: u32 = 3;
+        RewardsLedgerRetentionEpochs::<Test>::set(retention);
+
+        let ledger_entry = |epoch: u32| SubnetRewardsLedgerEntry {
+            rewards_data: RewardsData {
+                overall_subnet_reward: epoch as u128,
+                ..Default::default()
+            },
+            attestation_ratio: 0,
+            in_consensus: false,
+            validator_subnet_node_id: 1,
+            validator_id: None,
+            validator_reward: 0,
+            subnet_owner: None,
+            subnet_owner_reward: 0,
+            capacitor_reward: 0,
+            node_rewards: Vec::new(),
+            delegate_stake_reward: 0,
+            delegate_stake_total_shares: 0,
+            validator_delegate_stake_rewards: Vec::new(),
+            delegate_account_allocations: Vec::new(),
+        };
+
+        for epoch in 1..=5 {
+            Network::record_subnet_rewards(
+                &mut WeightMeter::new(),
+                subnet_id,
+                epoch,
+                ledger_entry(epoch),
+            );
+        }
+
+        assert_eq!(
+            SubnetRewardsLedgerEpochs::<Test>::get(subnet_id),
+            vec![3, 4, 5]
+        );
+        assert_eq!(SubnetRewardsLedger::<Test>::get(subnet_id, 1), None);
+        assert_eq!(SubnetRewardsLedger::<Test>::get(subnet_id, 2), None);
+        assert_eq!(
+            SubnetRewardsLedger::<Test>::get(subnet_id, 5),
+            Some(ledger_entry(5))
+        );
+
+        let ledger = Network::get_subnet_rewards_ledger(subnet_id, 4, u32::MAX);
+        assert_eq!(ledger, vec![(4, ledger_entry(4)), (5, ledger_entry(5))]);
+
+        // Lowering the retention prunes on the next write
+        RewardsLedgerRetentionEpochs::<Test>::set(1);
+        Network::record_subnet_rewards(&mut WeightMeter::new(), subnet_id, 6, ledger_entry(6));
+
+        assert_eq!(SubnetRewardsLedgerEpochs::<Test>::get(subnet_id), vec![6]);
+        assert_eq!(
+            SubnetRewardsLedger::<Test>::iter_prefix(subnet_id).count(),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_account_rewards_history_paged() {
+    new_test_ext().execute_with(|| {
+        let owner = account(1);
+
+        let ledger_entry = |epoch: u32| SubnetRewardsLedgerEntry {
+            rewards_data: RewardsData::default(),
+            attestation_ratio: 0,
+            in_consensus: true,
+            validator_subnet_node_id: 1,
+            validator_id: None,
+            validator_reward: 0,
+            subnet_owner: Some(owner.clone()),
+            subnet_owner_reward: epoch as u128,
+            capacitor_reward: 0,
+            node_rewards: Vec::new(),
+            delegate_stake_reward: 0,
+            delegate_stake_total_shares: 0,
+            validator_delegate_stake_rewards: Vec::new(),
+            delegate_account_allocations: Vec::new(),
+        };
+
+        for subnet_id in 1..=3 {
+            for epoch in 1..=2 {
+                Network::record_subnet_rewards(
+                    &mut WeightMeter::new(),
+                    subnet_id,
+                    epoch,
+                    ledger_entry(epoch),
+                );
+            }
+        }
+
+        // One subnet per page
+        let mut cursor = None;
+        let mut subnet_ids = Vec::new();
+        loop {
+            let page = Network::get_account_rewards_history(owner.clone(), cursor, 1);
+            assert_eq!(page.items.len(), 2);
+            assert!(page.items.iter().all(|record| {
+                record.subnet_id == page.items[0].subnet_id
+                    && record.source == RewardSource::SubnetOwner
+                    && record.amount == record.epoch as u128
+            }));
+            subnet_ids.push(page.items[0].subnet_id);
+
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        subnet_ids.sort();
+        assert_eq!(subnet_ids, vec![1, 2, 3]);
+
+        let page = Network::get_account_rewards_history(owner.clone(), None, 0);
+        assert!(page.items.is_empty());
+    });
+}
+
+#[test]
+fn test_simulate_consensus() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+
+        build_activated_subnet(
+            subnet_name.clone(),
+            0,
+            max_subnet_nodes,
+            deposit_amount,
+            stake_amount,
+        );
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+        let epoch_length = EpochLength::get();
+        let block_number = System::block_number();
+        let epoch = block_number / epoch_length;
+
+        set_block_to_subnet_slot_epoch(epoch, subnet_id);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+        let epoch = Network::get_current_epoch_as_u32();
+
+        // No elected validator
+        assert_eq!(
+            Network::simulate_consensus(subnet_id, Vec::new(), Vec::new()),
+            None
+        );
+
+        Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+        let _ = Network::handle_subnet_emission_weights(epoch);
+
+        let validator_subnet_node_id =
+            SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        let validator_stake = NodeSubnetStake::<Test>::get(validator_subnet_node_id, subnet_id);
+        let subnet_reputation = SubnetReputation::<Test>::get(subnet_id);
+
+        let subnet_node_data_vec =
+            get_subnet_node_consensus_data(subnets, max_subnet_nodes, 0, total_subnet_nodes);
+        let attestors: Vec<u32> = (1..=total_subnet_nodes).collect();
+
+        // ⸺ All validators attest
+        let simulation =
+            Network::simulate_consensus(subnet_id, subnet_node_data_vec.clone(), attestors)
+                .unwrap();
+
+        assert_eq!(simulation.subnet_epoch, subnet_epoch);
+        assert_eq!(
+            simulation.validator_subnet_node_id,
+            validator_subnet_node_id
+        );
+        assert_eq!(simulation.attestation_ratio, 1000000000000000000);
+        assert_eq!(
+            simulation.weight_sum,
+            500000000000000000 * max_subnet_nodes as u128
+        );
+        assert!(simulation.rewards_data.overall_subnet_reward > 0);
+        assert!(simulation.rewards.in_consensus);
+        assert!(simulation.rewards.validator_reward > 0);
+        assert_eq!(
+            simulation.rewards.node_rewards.len(),
+            total_subnet_nodes as usize
+        );
+        assert_eq!(simulation.nodes.len(), total_subnet_nodes as usize);
+        for node in &simulation.nodes {
+            assert!(node.reputation.is_some());
+            assert!(node.stake > node.prev_stake);
+        }
+
+        // Nothing was written
+        assert!(!SubnetConsensusSubmission::<Test>::contains_key(
+            subnet_id,
+            subnet_epoch
+        ));
+        assert_eq!(SubnetRewardsLedger::<Test>::get(subnet_id, epoch), None);
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(validator_subnet_node_id, subnet_id),
+            validator_stake
+        );
+
+        // ⸺ Only the validator attests, the validator is slashed
+        let simulation =
+            Network::simulate_consensus(subnet_id, subnet_node_data_vec, Vec::new()).unwrap();
+
+        assert!(simulation.attestation_ratio < MinAttestationPercentage::<Test>::get());
+        assert!(!simulation.rewards.in_consensus);
+        assert!(simulation.rewards.node_rewards.is_empty());
+        assert!(simulation.subnet_reputation < simulation.prev_subnet_reputation);
+
+        let validator_outcome = simulation
+            .nodes
+            .iter()
+            .find(|node| node.subnet_node_id == validator_subnet_node_id)
+            .unwrap();
+        assert_eq!(validator_outcome.stake, validator_outcome.prev_stake);
+        assert!(validator_outcome.pending_slash > 0);
+        assert_eq!(PendingSlashes::<Test>::iter().count(), 0);
+
+        assert_eq!(SubnetReputation::<Test>::get(subnet_id), subnet_reputation);
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(validator_subnet_node_id, subnet_id),
+            validator_stake
+        );
     });
 }
 
@@ -3107,6 +4053,20 @@ fn test_distribute_rewards_non_consensus_reputation() {
             super_majority_threshold,
         );
 
+        // ⸺ Stake is slashed once the appeal window passes
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(elected_node_id.unwrap(), subnet_id),
+            validator_stake
+        );
+        let (_, _, pending_slash) = PendingSlashes::<Test>::iter().next().unwrap();
+        assert_eq!(pending_slash.subnet_node_id, elected_node_id.unwrap());
+        assert_eq!(pending_slash.subnet_epoch, subnet_epoch - 1);
+        assert_eq!(
+            NodeSubnetPendingSlash::<Test>::get(elected_node_id.unwrap(), subnet_id),
+            pending_slash.amount
+        );
+        Network::apply_pending_slashes(&mut WeightMeter::new(), pending_slash.execute_epoch);
+
         let post_validator_stake =
             NodeSubnetStake::<Test>::get(elected_node_id.unwrap(), subnet_id);
         assert!(validator_stake > post_validator_stake);
@@ -3698,7 +4658,7 @@ fn test_distribute_rewards_non_attest_vast_majoriy_reputation() {
         let subnets = TotalActiveSubnets::<Test>::get() + 1;
         let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
         let max_subnets = MaxSubnets::<Test>::get();
-        let majority = (Network::get_percent_as_f64(SuperMajorityAttestationRatio::<Test>::get())
+        let majority = (SuperMajorityAttestationRatio::<Test>::get() as f64 / 1e18
             * max_subnet_nodes as f64) as u32
             + 1;
         build_activated_subnet(
@@ -3875,7 +4835,7 @@ fn test_distribute_rewards_non_attest_vast_majoriy_reputation_remove_node() {
         let subnets = TotalActiveSubnets::<Test>::get() + 1;
         let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
         let max_subnets = MaxSubnets::<Test>::get();
-        let majority = (Network::get_percent_as_f64(SuperMajorityAttestationRatio::<Test>::get())
+        let majority = (SuperMajorityAttestationRatio::<Test>::get() as f64 / 1e18
             * max_subnet_nodes as f64) as u32
             + 1;
         build_activated_subnet(
@@ -4182,6 +5142,20 @@ fn test_distribute_rewards_under_min_attest_slash_validator() {
             super_majority_threshold,
         );
 
+        // ⸺ Stake is slashed once the appeal window passes
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(elected_node_id.unwrap(), subnet_id),
+            validator_stake
+        );
+        let (_, _, pending_slash) = PendingSlashes::<Test>::iter().next().unwrap();
+        assert_eq!(pending_slash.subnet_node_id, elected_node_id.unwrap());
+        assert_eq!(pending_slash.subnet_epoch, subnet_epoch - 1);
+        assert_eq!(
+            NodeSubnetPendingSlash::<Test>::get(elected_node_id.unwrap(), subnet_id),
+            pending_slash.amount
+        );
+        Network::apply_pending_slashes(&mut WeightMeter::new(), pending_slash.execute_epoch);
+
         let post_validator_stake =
             NodeSubnetStake::<Test>::get(elected_node_id.unwrap(), subnet_id);
         assert!(validator_stake > post_validator_stake);
@@ -4331,6 +5305,20 @@ fn test_distribute_rewards_fork_under_min_attest_slash_validator() {
             super_majority_threshold,
         );
 
+        // ⸺ Stake is slashed once the appeal window passes
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(elected_node_id.unwrap(), subnet_id),
+            validator_stake
+        );
+        let (_, _, pending_slash) = PendingSlashes::<Test>::iter().next().unwrap();
+        assert_eq!(pending_slash.subnet_node_id, elected_node_id.unwrap());
+        assert_eq!(pending_slash.subnet_epoch, subnet_epoch - 1);
+        assert_eq!(
+            NodeSubnetPendingSlash::<Test>::get(elected_node_id.unwrap(), subnet_id),
+            pending_slash.amount
+        );
+        Network::apply_pending_slashes(&mut WeightMeter::new(), pending_slash.execute_epoch);
+
         let post_validator_stake =
             NodeSubnetStake::<Test>::get(elected_node_id.unwrap(), subnet_id);
         assert!(validator_stake > post_validator_stake);
@@ -121,10 +121,10 @@ use crate::{InflationSigmoidMidpoint, InflationSigmoidSteepness};
 fn test_get_inflation_v2() {
     new_test_ext().execute_with(|| {
         let inflation = Inflation::default();
-        let mut last_emissions = f64::MAX;
+        let mut last_emissions = u128::MAX;
 
-        for u in &[0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0] {
-            let emissions = Network::get_inflation(*u);
+        for u in 0..=10u128 {
+            let emissions = Network::get_inflation(u * 100_000_000_000_000_000);
             assert!(emissions < last_emissions);
             last_emissions = emissions;
         }
@@ -113,27 +113,71 @@ fn percentage_factor_is_correct() {
 }
 
 #[test]
-fn get_percent_as_f64_conversion() {
+fn exp_and_ln_basic_cases() {
     new_test_ext().execute_with(|| {
         let pf = Network::percentage_factor_as_u128();
 
-        // 100% as f64 should be 1.0
-        assert_eq!(Network::get_percent_as_f64(pf), 1.0);
+        assert_eq!(Network::exp(0), Some(pf));
+        assert_eq!(Network::exp_neg(0), pf);
+        assert_eq!(Network::ln(pf), Some(0));
+        assert_eq!(Network::ln(0), None);
 
-        // 50% should be 0.5
-        assert_eq!(Network::get_percent_as_f64(pf / 2), 0.5);
+        // e
+        let e = Network::exp(pf).unwrap();
+        assert!(e.abs_diff(2_718_281_828_459_045_235) < 100, "e={}", e);
 
-        // 0 should be 0.0
-        assert_eq!(Network::get_percent_as_f64(0), 0.0);
+        // 1/e
+        let e_inv = Network::exp_neg(pf);
+        assert!(
+            e_inv.abs_diff(367_879_441_171_442_321) < 100,
+            "1/e={}",
+            e_inv
+        );
+
+        // ln(e) = 1
+        assert!(Network::ln(e).unwrap().abs_diff(pf as i128) < 100);
+
+        // Overflow and underflow
+        assert!(Network::exp(47 * pf).is_some());
+        assert_eq!(Network::exp(48 * pf), None);
+        assert_eq!(Network::exp_neg(42 * pf), 0);
     });
 }
 
 #[test]
 fn pow_function_works() {
     new_test_ext().execute_with(|| {
-        assert_eq!(Network::pow(2.0, 3.0), 8.0);
-        assert_eq!(Network::pow(10.0, 0.0), 1.0);
-        assert_eq!(Network::pow(5.0, 1.0), 5.0);
+        let pf = Network::percentage_factor_as_u128();
+
+        assert_eq!(Network::pow(2 * pf, 3 * pf), 8 * pf);
+        assert_eq!(Network::pow(10 * pf, 0), pf);
+        assert_eq!(Network::pow(5 * pf, pf), 5 * pf);
+        assert_eq!(Network::pow(0, pf / 2), 0);
+        assert_eq!(Network::pow(pf, 7 * pf / 2), pf);
+
+        // sqrt(0.25) = 0.5
+        assert!(Network::pow(pf / 4, pf / 2).abs_diff(pf / 2) < 100);
+
+        // Saturates
+        assert_eq!(Network::pow(u128::MAX, 2 * pf), u128::MAX);
+        assert_eq!(Network::powi(10 * pf, 100), u128::MAX);
+    });
+}
+
+#[test]
+fn test_weighted_median() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Network::weighted_median(vec![]), 0);
+
+        // Majority weight wins regardless of order
+        assert_eq!(Network::weighted_median(vec![(9, 1), (1, 3), (5, 1)]), 1);
+        assert_eq!(Network::weighted_median(vec![(5, 2), (1, 1), (9, 2)]), 5);
+
+        // Exactly half averages the middle values
+        assert_eq!(Network::weighted_median(vec![(2, 1), (4, 1)]), 3);
+
+        // No weight, each value weighted equally
+        assert_eq!(Network::weighted_median(vec![(7, 0), (1, 0), (3, 0)]), 3);
     });
 }
 
@@ -261,18 +305,17 @@ fn test_checked_mul_div() {
 #[test]
 fn test_sigmoid_decreasing_symmetry() {
     new_test_ext().execute_with(|| {
-        let min = 0.0;
-        let max = 1.0;
-        let mid = 0.5;
-        let k = 5.0;
+        let pf = Network::percentage_factor_as_u128();
+        let mid = pf / 2;
+        let k = 5 * pf;
 
-        let y_left = Network::sigmoid_decreasing_v2(0.25, mid, k);
-        let y_right = Network::sigmoid_decreasing_v2(0.75, mid, k);
+        let y_left = Network::sigmoid_decreasing_v2(pf / 4, mid, k);
+        let y_right = Network::sigmoid_decreasing_v2(3 * pf / 4, mid, k);
 
-        let complement_diff = (y_left + y_right - 1.0).abs();
+        let complement_diff = (y_left + y_right).abs_diff(pf);
         log::error!("complement_diff={:?}", complement_diff);
         assert!(
-            complement_diff < 1e-6,
+            complement_diff < 10,
             "Expected y_left + y_right ≈ 1.0, got {} + {}",
             y_left,
             y_right
@@ -283,16 +326,15 @@ fn test_sigmoid_decreasing_symmetry() {
 #[test]
 fn test_sigmoid_decreasing_monotonicity() {
     new_test_ext().execute_with(|| {
-        let min = 0.0;
-        let max = 1.0;
-        let mid = 0.5;
-        let k = 5.0;
+        let pf = Network::percentage_factor_as_u128();
+        let mid = pf / 2;
+        let k = 5 * pf;
 
-        let y0 = Network::sigmoid_decreasing_v2(0.0, mid, k);
-        let y1 = Network::sigmoid_decreasing_v2(0.25, mid, k);
-        let y2 = Network::sigmoid_decreasing_v2(0.5, mid, k);
-        let y3 = Network::sigmoid_decreasing_v2(0.75, mid, k);
-        let y4 = Network::sigmoid_decreasing_v2(1.0, mid, k);
+        let y0 = Network::sigmoid_decreasing_v2(0, mid, k);
+        let y1 = Network::sigmoid_decreasing_v2(pf / 4, mid, k);
+        let y2 = Network::sigmoid_decreasing_v2(pf / 2, mid, k);
+        let y3 = Network::sigmoid_decreasing_v2(3 * pf / 4, mid, k);
+        let y4 = Network::sigmoid_decreasing_v2(pf, mid, k);
 
         assert!(
             y0 > y1 && y1 > y2 && y2 > y3 && y3 > y4,
@@ -304,28 +346,27 @@ fn test_sigmoid_decreasing_monotonicity() {
 #[test]
 fn test_sigmoid_decreasing_extreme_k() {
     new_test_ext().execute_with(|| {
-        let min = 0.0;
-        let max = 1.0;
-        let mid = 0.5;
+        let pf = Network::percentage_factor_as_u128();
+        let min = 0;
+        let max = pf;
+        let mid = pf / 2;
 
         // Very small k → almost linear
-        let y_low_k0 = Network::sigmoid_decreasing_v2(0.0, mid, 0.01);
-        let y_high_k0 = Network::sigmoid_decreasing_v2(1.0, mid, 0.01);
+        let y_low_k0 = Network::sigmoid_decreasing_v2(0, mid, pf / 100);
+        let y_high_k0 = Network::sigmoid_decreasing_v2(pf, mid, pf / 100);
         assert!(y_low_k0 > y_high_k0);
 
         // Very large k → almost step function
-        let y_low_k1 = Network::sigmoid_decreasing_v2(0.0, mid, 50.0);
-        let y_high_k1 = Network::sigmoid_decreasing_v2(1.0, mid, 50.0);
-        // assert!((y_low_k1 - max).abs() < 1e-12);
-        // assert!((y_high_k1 - min).abs() < 1e-12);
+        let y_low_k1 = Network::sigmoid_decreasing_v2(0, mid, 50 * pf);
+        let y_high_k1 = Network::sigmoid_decreasing_v2(pf, mid, 50 * pf);
         assert!(
-            (y_low_k1 - max).abs() < 1e-6,
+            y_low_k1.abs_diff(max) < pf / 1_000_000,
             "y_low_k1={} not close to max={}",
             y_low_k1,
             max
         );
         assert!(
-            (y_high_k1 - min).abs() < 1e-6,
+            y_high_k1.abs_diff(min) < pf / 1_000_000,
             "y_high_k1={} not close to min={}",
             y_high_k1,
             min
@@ -336,20 +377,21 @@ fn test_sigmoid_decreasing_extreme_k() {
 #[test]
 fn test_concave_down_decreasing_basic() {
     new_test_ext().execute_with(|| {
-        let min = 0.5;
-        let max = 2.0;
-        let power = 2.0;
+        let pf = Network::percentage_factor_as_u128();
+        let min = pf / 2;
+        let max = 2 * pf;
+        let power = 2 * pf;
 
         // x = 0.0 -> should return max
-        let y = Network::concave_down_decreasing(0.0, min, max, power);
-        assert!((y - max).abs() < 1e-12, "Expected {}, got {}", max, y);
+        let y = Network::concave_down_decreasing(0, min, max, power);
+        assert_eq!(y, max);
 
         // x = 1.0 -> should return min
-        let y = Network::concave_down_decreasing(1.0, min, max, power);
-        assert!((y - min).abs() < 1e-12, "Expected {}, got {}", min, y);
+        let y = Network::concave_down_decreasing(pf, min, max, power);
+        assert_eq!(y, min);
 
         // x = 0.5 -> should be between min and max
-        let y = Network::concave_down_decreasing(0.5, min, max, power);
+        let y = Network::concave_down_decreasing(pf / 2, min, max, power);
         assert!(
             y > min && y < max,
             "Expected between {} and {}, got {}",
@@ -363,33 +405,29 @@ fn test_concave_down_decreasing_basic() {
 #[test]
 fn test_concave_down_decreasing_power_edge() {
     new_test_ext().execute_with(|| {
-        let min = 0.0;
-        let max = 1.0;
-
-        // negative power -> should default to 1.0
-        let y = Network::concave_down_decreasing(0.5, min, max, -2.0);
-        // with power = 1: y = 1 - x = 1 - 0.5 = 0.5
-        assert!((y - 0.5).abs() < 1e-12, "Expected 0.5, got {}", y);
+        let pf = Network::percentage_factor_as_u128();
 
         // zero power -> should default to 1.0
-        let y = Network::concave_down_decreasing(0.5, min, max, 0.0);
-        assert!((y - 0.5).abs() < 1e-12, "Expected 0.5, got {}", y);
+        // with power = 1: y = 1 - x = 1 - 0.5 = 0.5
+        let y = Network::concave_down_decreasing(pf / 2, 0, pf, 0);
+        assert_eq!(y, pf / 2);
     });
 }
 
 #[test]
 fn test_concave_down_decreasing_monotonicity() {
     new_test_ext().execute_with(|| {
-        let min = 0.0;
-        let max = 1.0;
-        let power = 2.0;
+        let pf = Network::percentage_factor_as_u128();
+        let min = 0;
+        let max = pf;
+        let power = 2 * pf;
 
         // Check that the function decreases as x increases
-        let y0 = Network::concave_down_decreasing(0.0, min, max, power);
-        let y1 = Network::concave_down_decreasing(0.25, min, max, power);
-        let y2 = Network::concave_down_decreasing(0.5, min, max, power);
-        let y3 = Network::concave_down_decreasing(0.75, min, max, power);
-        let y4 = Network::concave_down_decreasing(1.0, min, max, power);
+        let y0 = Network::concave_down_decreasing(0, min, max, power);
+        let y1 = Network::concave_down_decreasing(pf / 4, min, max, power);
+        let y2 = Network::concave_down_decreasing(pf / 2, min, max, power);
+        let y3 = Network::concave_down_decreasing(3 * pf / 4, min, max, power);
+        let y4 = Network::concave_down_decreasing(pf, min, max, power);
 
         assert!(
             y0 > y1 && y1 > y2 && y2 > y3 && y3 > y4,
@@ -397,3 +435,261 @@ fn test_concave_down_decreasing_monotonicity() {
         );
     });
 }
+
+///
+///
+///
+///
+///
+///
+///
+/// Fixed-point properties
+///
+/// Randomized inputs from a seeded generator, compared against `f64` references.
+///
+///
+///
+///
+///
+///
+
+const PROPERTY_ITERATIONS: u32 = 2_000;
+
+/// 1e-12 in 1e18 precision
+const MAX_ABS_ERROR: u128 = 1_000_000;
+
+/// Deterministic xorshift generator so failures are reproducible
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u128(&mut self) -> u128 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x as u128
+    }
+
+    /// Uniform in `[low, high)`
+    fn range(&mut self, low: u128, high: u128) -> u128 {
+        let v = (self.next_u128() << 64) | self.next_u128();
+        low + v % (high - low)
+    }
+}
+
+fn to_f64(v: u128) -> f64 {
+    v as f64 / 1e18
+}
+
+fn from_f64(v: f64) -> u128 {
+    (v * 1e18) as u128
+}
+
+fn assert_close(actual: u128, expected: u128, tolerance: u128, context: &str) {
+    assert!(
+        actual.abs_diff(expected) <= tolerance,
+        "{}: expected {}, got {} (tolerance {})",
+        context,
+        expected,
+        actual,
+        tolerance
+    );
+}
+
+fn reference_sigmoid(x: f64, mid: f64, k: f64) -> f64 {
+    let exp = libm::exp(k * (x - mid).abs());
+    if x > mid {
+        1.0 / (1.0 + exp)
+    } else {
+        exp / (1.0 + exp)
+    }
+}
+
+#[test]
+fn property_exp_neg_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..PROPERTY_ITERATIONS {
+            let x = rng.range(0, 50 * pf);
+            let actual = Network::exp_neg(x);
+            let expected = from_f64(libm::exp(-to_f64(x)));
+
+            assert!(actual <= pf);
+            assert_close(actual, expected, MAX_ABS_ERROR, &format!("exp_neg({})", x));
+        }
+    });
+}
+
+#[test]
+fn property_exp_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+        let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..PROPERTY_ITERATIONS {
+            let x = rng.range(0, 47 * pf);
+            let actual = Network::exp(x).unwrap();
+            let expected = from_f64(libm::exp(to_f64(x)));
+
+            // 1e-12 relative
+            let tolerance = expected / 1_000_000_000_000 + 1;
+            assert_close(actual, expected, tolerance, &format!("exp({})", x));
+        }
+    });
+}
+
+#[test]
+fn property_ln_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let mut rng = XorShift(0xd1b5_4a32_d192_ed03);
+
+        for _ in 0..PROPERTY_ITERATIONS {
+            let x = rng.range(1, 1_000_000_000_000_000_000_000_000_000_000);
+            let actual = Network::ln(x).unwrap();
+            let expected = (libm::log(to_f64(x)) * 1e18) as i128;
+
+            assert!(
+                actual.abs_diff(expected) <= MAX_ABS_ERROR,
+                "ln({}): expected {}, got {}",
+                x,
+                expected,
+                actual
+            );
+        }
+    });
+}
+
+#[test]
+fn property_pow_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+        let mut rng = XorShift(0x8cb9_2ba7_2f3d_8dd7);
+
+        for _ in 0..PROPERTY_ITERATIONS {
+            // Fractional bases, as used by the curves
+            let base = rng.range(0, pf);
+            let exponent = rng.range(0, 20 * pf);
+            let actual = Network::pow(base, exponent);
+            let expected = from_f64(libm::pow(to_f64(base), to_f64(exponent)));
+
+            assert!(actual <= pf);
+            assert_close(
+                actual,
+                expected,
+                MAX_ABS_ERROR,
+                &format!("pow({}, {})", base, exponent),
+            );
+
+            // Balances, as used by the overwatch stake weights
+            let base = rng.range(pf, 1_000_000 * pf);
+            let exponent = rng.range(0, 2 * pf);
+            let actual = Network::pow(base, exponent);
+            let expected = from_f64(libm::pow(to_f64(base), to_f64(exponent)));
+
+            // 1e-12 relative
+            let tolerance = expected / 1_000_000_000_000 + 1;
+            assert_close(
+                actual,
+                expected,
+                tolerance,
+                &format!("pow({}, {})", base, exponent),
+            );
+        }
+    });
+}
+
+#[test]
+fn property_sigmoid_matches_f64_and_decreases() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+        let mut rng = XorShift(0xbf58_476d_1ce4_e5b9);
+
+        for _ in 0..PROPERTY_ITERATIONS {
+            let mid = rng.range(0, pf + 1);
+            let k = rng.range(0, 50 * pf);
+            let x1 = rng.range(0, pf + 1);
+            let x2 = rng.range(0, pf + 1);
+
+            let y1 = Network::sigmoid_decreasing_v2(x1, mid, k);
+            let y2 = Network::sigmoid_decreasing_v2(x2, mid, k);
+
+            assert!(y1 <= pf && y2 <= pf);
+            if x1 <= x2 {
+                assert!(
+                    y1 >= y2,
+                    "not decreasing at {} {} (mid {}, k {})",
+                    x1,
+                    x2,
+                    mid,
+                    k
+                );
+            } else {
+                assert!(
+                    y1 <= y2,
+                    "not decreasing at {} {} (mid {}, k {})",
+                    x1,
+                    x2,
+                    mid,
+                    k
+                );
+            }
+
+            let expected = from_f64(reference_sigmoid(to_f64(x1), to_f64(mid), to_f64(k)));
+            assert_close(
+                y1,
+                expected,
+                MAX_ABS_ERROR,
+                &format!("sigmoid({}, {}, {})", x1, mid, k),
+            );
+        }
+    });
+}
+
+#[test]
+fn property_concave_down_decreasing_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+        let mut rng = XorShift(0x94d0_49bb_1331_11eb);
+
+        for _ in 0..PROPERTY_ITERATIONS {
+            let x = rng.range(0, pf + 1);
+            let min = rng.range(0, pf);
+            let max = rng.range(min + 1, 2 * pf);
+            let power = rng.range(pf, 20 * pf);
+
+            let actual = Network::concave_down_decreasing(x, min, max, power);
+            let curve = 1.0 - libm::pow(to_f64(x), to_f64(power));
+            let expected = from_f64(to_f64(min) + to_f64(max - min) * curve);
+
+            assert!(actual >= min && actual <= max);
+            assert_close(
+                actual,
+                expected,
+                MAX_ABS_ERROR,
+                &format!(
+                    "concave_down_decreasing({}, {}, {}, {})",
+                    x, min, max, power
+                ),
+            );
+        }
+    });
+}
+
+#[test]
+fn property_fixed_math_is_deterministic() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+
+        // Pinned outputs, any change in rounding shows up here
+        assert_eq!(Network::exp(pf), Some(2_718_281_828_459_045_222));
+        assert_eq!(Network::ln(2 * pf), Some(693_147_180_559_945_309));
+        assert_eq!(Network::pow(2 * pf, pf / 2), 1_414_213_562_373_095_042);
+        assert_eq!(
+            Network::sigmoid_decreasing_v2(pf / 4, pf / 2, 5 * pf),
+            777_299_861_174_691_146
+        );
+    });
+}
@@ -221,10 +221,25 @@ parameter_types! {
     pub const NetworkMaxSwapQueueLength: u32 = 1000;
 }
 
+parameter_types! {
+    /// Beacon seeds returned by `MockRandomnessBeacon`, keyed by round
+    pub static MockBeaconSeeds: BTreeMap<u32, H256> = BTreeMap::new();
+}
+
+/// Test beacon, elections take the fallback path until a seed is set in `MockBeaconSeeds`
+pub struct MockRandomnessBeacon;
+
+impl randomness::RandomnessBeacon<H256> for MockRandomnessBeacon {
+    fn random_seed(round: u32) -> Option<H256> {
+        MockBeaconSeeds::get().get(&round).copied()
+    }
+}
+
 impl Config for Test {
     type WeightInfo = ();
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type MajorityCollectiveOrigin =
         pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
     type SuperMajorityCollectiveOrigin =
@@ -233,6 +248,7 @@ impl Config for Test {
     type EpochsPerYear = EpochsPerYear;
     type InitialTxRateLimit = ConstU32<0>;
     type Randomness = InsecureRandomnessCollectiveFlip;
+    type RandomnessBeacon = MockRandomnessBeacon;
     type PalletId = NetworkPalletId;
     type TreasuryAccount = TreasuryAccount;
     type OverwatchEpochEmissions = OverwatchEpochEmissions;
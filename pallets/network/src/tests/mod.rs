@@ -1,6 +1,8 @@
 mod admin;
+mod concentration;
 mod delegate_account;
 mod delegate_staking;
+mod election;
 mod era;
 mod incentives_protocol;
 mod inflation;
@@ -11,8 +13,10 @@ mod multiaddr;
 mod node_staking;
 mod on_initialize;
 mod overwatch_nodes;
+mod overwatch_nodes_challenges;
 mod overwatch_nodes_commit_reveal;
 mod owner;
+mod parameters;
 mod randomization;
 mod registration_queue;
 mod reputation;
@@ -1,7 +1,8 @@
 use super::mock::*;
 use super::*;
 use crate::multiaddr::{
-    encode_varint, Multiaddr, MultiaddrError, DNS4, DNS6, DNSADDR, IP4, IP6, P2P, TCP, UDP, WS, WSS,
+    base64url_decode, base64url_encode, encode_varint, Multiaddr, MultiaddrError, CERTHASH, DNS4,
+    DNS6, DNSADDR, IP4, IP6, P2P, P2P_CIRCUIT, QUIC_V1, TCP, UDP, WEBRTC_DIRECT, WS, WSS,
 };
 extern crate alloc;
 use alloc::string::String;
@@ -1055,3 +1056,204 @@ fn test_roundtrip_all_protocols() {
         assert_eq!(ma1.to_vec().unwrap(), ma2.to_vec().unwrap());
     });
 }
+
+// ============================================================================
+// QUIC / WEBRTC / CERTHASH / TLS / RELAY
+// ============================================================================
+
+/// sha2-256 multihash of a dummy certificate
+fn certhash_multihash() -> Vec<u8> {
+    let mut hash = vec![0x12, 32];
+    hash.extend_from_slice(&[9u8; 32]);
+    hash
+}
+
+#[test]
+fn test_base64url_roundtrip() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(base64url_encode(b"hello"), "aGVsbG8");
+        assert_eq!(base64url_encode(&[0xfb, 0xff]), "-_8");
+        assert_eq!(base64url_decode("aGVsbG8").unwrap(), b"hello".to_vec());
+        assert_eq!(base64url_decode("aGVsbG8=").unwrap(), b"hello".to_vec());
+        assert_eq!(base64url_decode("a"), Err(MultiaddrError::InvalidAddress));
+        assert_eq!(base64url_decode("a+Q"), Err(MultiaddrError::InvalidAddress));
+
+        let hash = certhash_multihash();
+        assert_eq!(base64url_decode(&base64url_encode(&hash)).unwrap(), hash);
+    });
+}
+
+#[test]
+fn test_roundtrip_from_str_quic_v1() {
+    new_test_ext().execute_with(|| {
+        let peer = PeerId([30u8; 32].to_vec());
+        let peer_str = bs58::encode(&peer.0).into_string();
+        let addr_str = format!("/ip4/10.0.0.1/udp/4001/quic-v1/p2p/{}", peer_str);
+
+        let ma = Multiaddr::from_str(&addr_str).unwrap();
+        let segs = ma.to_vec().unwrap();
+        assert_eq!(segs.len(), 4);
+        assert_eq!(segs[2], "/quic-v1");
+        assert_eq!(segs.concat(), addr_str);
+
+        let ma2 = Multiaddr::from_str(&segs.concat()).unwrap();
+        assert_eq!(ma, ma2);
+    });
+}
+
+#[test]
+fn test_roundtrip_from_str_webrtc_direct_certhash() {
+    new_test_ext().execute_with(|| {
+        let peer = PeerId([31u8; 32].to_vec());
+        let peer_str = bs58::encode(&peer.0).into_string();
+        let certhash = format!("u{}", base64url_encode(&certhash_multihash()));
+        let addr_str = format!(
+            "/ip6/::1/udp/9090/webrtc-direct/certhash/{}/p2p/{}",
+            certhash, peer_str
+        );
+
+        let ma = Multiaddr::from_str(&addr_str).unwrap();
+        let segs = ma.to_vec().unwrap();
+        assert_eq!(segs.len(), 5);
+        assert_eq!(segs[0], "/ip6/0:0:0:0:0:0:0:1");
+        assert_eq!(segs[2], "/webrtc-direct");
+        assert_eq!(segs[3], format!("/certhash/{}", certhash));
+
+        let ma2 = Multiaddr::from_str(&segs.concat()).unwrap();
+        assert_eq!(ma, ma2);
+    });
+}
+
+#[test]
+fn test_roundtrip_from_str_webtransport_multiple_certhashes() {
+    new_test_ext().execute_with(|| {
+        let peer = PeerId([32u8; 32].to_vec());
+        let peer_str = bs58::encode(&peer.0).into_string();
+        let certhash = format!("u{}", base64url_encode(&certhash_multihash()));
+        let addr_str = format!(
+            "/ip4/10.0.0.1/udp/443/quic-v1/webtransport/certhash/{}/certhash/{}/p2p/{}",
+            certhash, certhash, peer_str
+        );
+
+        let ma = Multiaddr::from_str(&addr_str).unwrap();
+        let segs = ma.to_vec().unwrap();
+        assert_eq!(segs.len(), 7);
+        assert_eq!(segs.concat(), addr_str);
+    });
+}
+
+#[test]
+fn test_roundtrip_from_str_tls_sni_p2p_circuit() {
+    new_test_ext().execute_with(|| {
+        let relay = bs58::encode(&[33u8; 32]).into_string();
+        let peer = bs58::encode(&[34u8; 32]).into_string();
+        let addr_str = format!(
+            "/dns/relay.example.com/tcp/443/tls/sni/relay.example.com/ws/p2p/{}/p2p-circuit/p2p/{}",
+            relay, peer
+        );
+
+        let ma = Multiaddr::from_str(&addr_str).unwrap();
+        let segs = ma.to_vec().unwrap();
+        assert_eq!(segs.len(), 9);
+        assert_eq!(segs[0], "/dns/relay.example.com");
+        assert_eq!(segs[2], "/tls");
+        assert_eq!(segs[3], "/sni/relay.example.com");
+        assert_eq!(segs[6], "/p2p-circuit");
+        assert_eq!(segs.concat(), addr_str);
+    });
+}
+
+#[test]
+fn test_from_str_certhash_multibase_prefixes() {
+    new_test_ext().execute_with(|| {
+        let peer_str = bs58::encode(&[35u8; 32]).into_string();
+        let hash = certhash_multihash();
+
+        let base64url = format!("u{}", base64url_encode(&hash));
+        let base58 = format!("z{}", bs58::encode(&hash).into_string());
+        let base16: String = core::iter::once(String::from("f"))
+            .chain(hash.iter().map(|b| format!("{:02x}", b)))
+            .collect();
+
+        let expected = Multiaddr::from_str(&format!(
+            "/ip4/1.2.3.4/udp/1/webrtc-direct/certhash/{}/p2p/{}",
+            base64url, peer_str
+        ))
+        .unwrap();
+
+        for encoded in [base58, base16] {
+            let ma = Multiaddr::from_str(&format!(
+                "/ip4/1.2.3.4/udp/1/webrtc-direct/certhash/{}/p2p/{}",
+                encoded, peer_str
+            ))
+            .unwrap();
+            assert_eq!(ma, expected);
+        }
+
+        assert_eq!(
+            Multiaddr::from_str(&format!("/ip4/1.2.3.4/udp/1/certhash/x00/p2p/{}", peer_str)),
+            Err(MultiaddrError::InvalidAddress)
+        );
+    });
+}
+
+#[test]
+fn test_roundtrip_bytes_quic_webrtc_certhash() {
+    new_test_ext().execute_with(|| {
+        let peer = PeerId::new([36u8; 32].to_vec());
+        let hash = certhash_multihash();
+
+        let mut bytes = vec![];
+        encode_varint(IP4, &mut bytes);
+        bytes.extend_from_slice(&[192, 168, 1, 1]);
+        encode_varint(UDP, &mut bytes);
+        bytes.extend_from_slice(&4001u16.to_be_bytes());
+        encode_varint(WEBRTC_DIRECT, &mut bytes);
+        encode_varint(CERTHASH, &mut bytes);
+        encode_varint(hash.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&hash);
+        encode_varint(P2P, &mut bytes);
+        encode_varint(peer.0.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&peer.0);
+        encode_varint(P2P_CIRCUIT, &mut bytes);
+        encode_varint(QUIC_V1, &mut bytes);
+        encode_varint(P2P, &mut bytes);
+        encode_varint(peer.0.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&peer.0);
+
+        let ma = Multiaddr::verify(&bytes).unwrap();
+        let segs = ma.to_vec().unwrap();
+        assert_eq!(segs.len(), 8);
+
+        let ma2 = Multiaddr::from_str(&segs.concat()).unwrap();
+        assert_eq!(ma2.to_bytes(), bytes);
+    });
+}
+
+#[test]
+fn test_verify_certhash_invalid_multihash() {
+    new_test_ext().execute_with(|| {
+        let peer = PeerId::new([37u8; 32].to_vec());
+
+        // Digest length claims 32 bytes but only 4 follow
+        let hash = vec![0x12, 32, 1, 2, 3, 4];
+
+        let mut bytes = vec![];
+        encode_varint(IP4, &mut bytes);
+        bytes.extend_from_slice(&[127, 0, 0, 1]);
+        encode_varint(UDP, &mut bytes);
+        bytes.extend_from_slice(&4001u16.to_be_bytes());
+        encode_varint(WEBRTC_DIRECT, &mut bytes);
+        encode_varint(CERTHASH, &mut bytes);
+        encode_varint(hash.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&hash);
+        encode_varint(P2P, &mut bytes);
+        encode_varint(peer.0.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&peer.0);
+
+        assert_eq!(
+            Multiaddr::verify(&bytes),
+            Err(MultiaddrError::InvalidAddress)
+        );
+    });
+}
@@ -1,14 +1,19 @@
+// The deprecated `set_*` extrinsics are tested until they are removed
+#![allow(deprecated)]
+
 use super::mock::*;
 use crate::tests::test_utils::*;
+use crate::Event;
 use crate::{
     Error, MaxOverwatchNodes, MaxSubnetNodes, MaxSubnets, MinSubnetMinStake, MinSubnetNodes,
-    OverwatchEpochLengthMultiplier, OverwatchMinAge, OverwatchMinStakeBalance,
-    OverwatchNodeBlacklist, OverwatchNodeIdHotkey, OverwatchNodeIndex, OverwatchNodeStakeBalance,
-    OverwatchNodeValidatorId, OverwatchNodeWeights, OverwatchNodes, OverwatchStakeWeightFactor,
-    OverwatchSubnetWeights, OverwatchValidatorWhitelist, PeerId, PeerIdOverwatchNodeId,
-    StakeCooldownEpochs, StakeUnbondingLedger, SubnetName, SubnetNodesData, SubnetState,
-    TotalOverwatchNodeStakeBalance, TotalOverwatchNodeUids, TotalOverwatchNodes, TotalValidatorIds,
-    ValidatorSubnetNodes,
+    OverwatchClipReputationFactor, OverwatchEpochLengthMultiplier, OverwatchMinAge,
+    OverwatchMinStakeBalance, OverwatchNodeBlacklist, OverwatchNodeIdHotkey, OverwatchNodeIndex,
+    OverwatchNodeStakeBalance, OverwatchNodeValidatorId, OverwatchNodeWeightDeviation,
+    OverwatchNodeWeights, OverwatchNodes, OverwatchStakeWeightFactor, OverwatchSubnetWeights,
+    OverwatchValidatorWhitelist, OverwatchWeightClipBand, OverwatchWeightQuorum, PeerId,
+    PeerIdOverwatchNodeId, StakeCooldownEpochs, StakeUnbondingLedger, SubnetName, SubnetNodesData,
+    SubnetState, TotalOverwatchNodeStakeBalance, TotalOverwatchNodeUids, TotalOverwatchNodes,
+    TotalValidatorIds, ValidatorReputation, ValidatorSubnetNodes,
 };
 use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok};
@@ -714,6 +719,46 @@ fn test_equal_stake_equal_weights_v3() {
     });
 }
 
+#[test]
+fn test_subnet_weight_requires_quorum() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let epoch = Network::get_current_overwatch_epoch_as_u32();
+
+        let validator_id_1 = 1;
+        let validator_id_2 = 2;
+
+        // Setup
+        manual_insert_validator(validator_id_1, validator_id_1, validator_id_1);
+        manual_insert_validator(validator_id_2, validator_id_2, validator_id_2);
+
+        let node_id_1 = insert_overwatch_node_v2(validator_id_1);
+        let _node_id_2 = insert_overwatch_node_v2(validator_id_2);
+        set_overwatch_node_stake(1, 10);
+        set_overwatch_node_stake(2, 90);
+
+        // Only the low stake node reveals
+        submit_weight(epoch, subnet_id, node_id_1, 100);
+
+        Network::calculate_overwatch_rewards();
+
+        // The subnet keeps `DefaultOverwatchSubnetWeight`
+        assert_eq!(OverwatchSubnetWeights::<Test>::get(epoch, subnet_id), None);
+
+        // The node is still scored on its reveal
+        assert!(OverwatchNodeWeights::<Test>::get(epoch, node_id_1).is_some());
+
+        OverwatchWeightQuorum::<Test>::set(0);
+
+        Network::calculate_overwatch_rewards();
+
+        assert_eq!(
+            OverwatchSubnetWeights::<Test>::get(epoch, subnet_id),
+            Some(100)
+        );
+    });
+}
+
 #[test]
 fn test_stake_no_dampening_effect() {
     new_test_ext().execute_with(|| {
@@ -758,7 +803,7 @@ fn test_stake_no_dampening_effect() {
         let subnet_weight = OverwatchSubnetWeights::<Test>::get(epoch, subnet_id);
 
         // Both users submitted the same score, subnet should be the score
-        assert_eq!(subnet_weight, Some(499999999999999999));
+        assert_eq!(subnet_weight, Some(500000000000000000));
 
         let score_1 = OverwatchNodeWeights::<Test>::get(epoch, node_id_1);
         let score_2 = OverwatchNodeWeights::<Test>::get(epoch, node_id_2);
@@ -885,8 +930,9 @@ fn test_multiple_subnets_score_accumulation_v3() {
         let subnet_weight_1 = OverwatchSubnetWeights::<Test>::get(epoch, subnet_id_1);
         let subnet_weight_2 = OverwatchSubnetWeights::<Test>::get(epoch, subnet_id_2);
 
-        assert_eq!(subnet_weight_1, Some(499999999999999999)); // Rounding err
-        assert_eq!(subnet_weight_2, Some(565108967975413320)); // Rounding err
+        assert_eq!(subnet_weight_1, Some(500000000000000000));
+        // Node 2 holds the majority of the stake weight
+        assert_eq!(subnet_weight_2, Some(600000000000000000));
 
         let score_1 = OverwatchNodeWeights::<Test>::get(epoch, node_id_1);
         let score_2 = OverwatchNodeWeights::<Test>::get(epoch, node_id_2);
@@ -1170,6 +1216,109 @@ fn test_multiple_subnets_check_percent_acccuracy() {
     });
 }
 
+#[test]
+fn test_stake_weighted_median_clips_outliers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let subnet_id = 1;
+        let epoch = Network::get_current_overwatch_epoch_as_u32();
+
+        let validator_id_1 = 1;
+        let validator_id_2 = 2;
+        let validator_id_3 = 3;
+
+        // Setup
+        manual_insert_validator(validator_id_1, validator_id_1, validator_id_1);
+        manual_insert_validator(validator_id_2, validator_id_2, validator_id_2);
+        manual_insert_validator(validator_id_3, validator_id_3, validator_id_3);
+
+        let node_id_1 = insert_overwatch_node_v2(validator_id_1);
+        let node_id_2 = insert_overwatch_node_v2(validator_id_2);
+        let node_id_3 = insert_overwatch_node_v2(validator_id_3);
+        set_overwatch_node_stake(node_id_1, 100);
+        set_overwatch_node_stake(node_id_2, 100);
+        // The largest single stake, but a minority of the total
+        set_overwatch_node_stake(node_id_3, 150);
+
+        submit_weight(epoch, subnet_id, node_id_1, 500000000000000000);
+        submit_weight(epoch, subnet_id, node_id_2, 500000000000000000);
+        submit_weight(epoch, subnet_id, node_id_3, 1000000000000000000);
+
+        let ow_score = ValidatorReputation::<Test>::get(validator_id_3).ow_score;
+
+        Network::calculate_overwatch_rewards();
+
+        // The stake-heavy minority can't move the median
+        assert_eq!(
+            OverwatchSubnetWeights::<Test>::get(epoch, subnet_id),
+            Some(500000000000000000)
+        );
+
+        // Node 3 is clipped, it doesn't score and loses `ow_score`
+        assert_eq!(OverwatchNodeWeights::<Test>::get(epoch, node_id_3), None);
+        assert_eq!(
+            OverwatchNodeWeights::<Test>::get(epoch, node_id_1),
+            OverwatchNodeWeights::<Test>::get(epoch, node_id_2)
+        );
+
+        let expected_ow_score = Network::decrease_rep(
+            ow_score,
+            OverwatchClipReputationFactor::<Test>::get(),
+            Some(1000000000000000000),
+        );
+        assert!(expected_ow_score < ow_score);
+        assert_eq!(
+            ValidatorReputation::<Test>::get(validator_id_3).ow_score,
+            expected_ow_score
+        );
+        assert_eq!(
+            ValidatorReputation::<Test>::get(validator_id_1).ow_score,
+            ow_score
+        );
+        assert!(
+            network_events().contains(&Event::OverwatchSubmissionsClipped {
+                overwatch_node_id: node_id_3,
+                overwatch_epoch: epoch,
+                subnet_ids: vec![subnet_id],
+                ow_score: expected_ow_score,
+            })
+        );
+
+        let breakdown = Network::get_overwatch_subnet_weight_breakdown(epoch, subnet_id).unwrap();
+        assert_eq!(breakdown.weight, 500000000000000000);
+        assert_eq!(breakdown.clip_band, OverwatchWeightClipBand::<Test>::get());
+        assert_eq!(
+            breakdown.nodes,
+            vec![
+                OverwatchNodeWeightDeviation {
+                    overwatch_node_id: node_id_1,
+                    weight: 500000000000000000,
+                    deviation: 0,
+                    clipped: false,
+                },
+                OverwatchNodeWeightDeviation {
+                    overwatch_node_id: node_id_2,
+                    weight: 500000000000000000,
+                    deviation: 0,
+                    clipped: false,
+                },
+                OverwatchNodeWeightDeviation {
+                    overwatch_node_id: node_id_3,
+                    weight: 1000000000000000000,
+                    deviation: 500000000000000000,
+                    clipped: true,
+                },
+            ]
+        );
+
+        assert_eq!(
+            Network::get_overwatch_subnet_weight_breakdown(epoch, subnet_id + 1),
+            None
+        );
+    });
+}
+
 #[test]
 fn test_add_to_overwatch_stake() {
     new_test_ext().execute_with(|| {
@@ -0,0 +1,452 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::Event;
+use crate::{
+    Error, HoldReason, NextOverwatchChallengeId, OverwatchChallengeBond,
+    OverwatchChallengeReputationFactor, OverwatchChallengeVotes, OverwatchChallenges,
+    OverwatchNodeChallengeId, OverwatchNodeStakeBalance, OverwatchStakeSnapshot,
+    OverwatchValidatorWhitelist, PendingChallengeRemovals, SubnetNodeChallengeId, SubnetNodeClass,
+    SubnetNodesData, SubnetState, TotalOverwatchNodeStakeBalance, TotalOverwatchStakeSnapshot,
+    ValidatorReputation,
+};
+use frame_support::traits::Currency;
+use frame_support::weights::WeightMeter;
+use frame_support::{assert_err, assert_ok};
+use sp_core::H256;
+
+//
+//
+//
+//
+//
+//
+// Overwatch subnet node challenges
+//
+//
+//
+//
+//
+//
+
+// Validator 1 owns subnet node 1, validators 2, 3 and 4 run overwatch nodes 1, 2 and 3
+fn build_challenge_setup(subnet_id: u32, stake: u128) {
+    System::set_block_number(System::block_number() + 1);
+
+    insert_subnet(subnet_id, SubnetState::Active, 0);
+    manual_insert_validator(1, 1, 101);
+    manual_insert_subnet_node_v2(1, subnet_id, 1, 1, 101, 1, SubnetNodeClass::Validator, 0);
+
+    for validator_id in 2..5 {
+        manual_insert_validator(validator_id, validator_id, 100 + validator_id);
+        OverwatchValidatorWhitelist::<Test>::insert(validator_id, true);
+        let overwatch_node_id = insert_overwatch_node_v2(validator_id);
+        set_overwatch_node_stake(overwatch_node_id, stake);
+
+        let coldkey = account(validator_id);
+        let _ = Balances::deposit_creating(&coldkey, stake + 500);
+        assert!(Network::hold_stake(
+            &coldkey,
+            HoldReason::OverwatchStake,
+            stake
+        ));
+    }
+}
+
+#[test]
+fn test_challenge_subnet_node_passes() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let subnet_node_id = 1;
+        let stake: u128 = 100e+18 as u128;
+        build_challenge_setup(subnet_id, stake);
+
+        let bond = OverwatchChallengeBond::<Test>::get();
+        let evidence_hash = H256::repeat_byte(1);
+        let epoch = Network::get_current_overwatch_epoch_as_u32();
+        let challenge_id = NextOverwatchChallengeId::<Test>::get();
+        let score = ValidatorReputation::<Test>::get(1).score;
+
+        assert_ok!(Network::challenge_subnet_node(
+            RuntimeOrigin::signed(account(102)),
+            1,
+            subnet_id,
+            subnet_node_id,
+            evidence_hash,
+        ));
+
+        assert_eq!(OverwatchNodeStakeBalance::<Test>::get(1), stake - bond);
+        assert_eq!(
+            Network::get_stake_hold(&account(2), HoldReason::ChallengeBond),
+            bond
+        );
+        assert_eq!(
+            Network::get_stake_hold(&account(2), HoldReason::OverwatchStake),
+            stake - bond
+        );
+        assert_eq!(OverwatchNodeChallengeId::<Test>::get(1), Some(challenge_id));
+        assert_eq!(
+            SubnetNodeChallengeId::<Test>::get(subnet_id, subnet_node_id),
+            Some(challenge_id)
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SubnetNodeChallenged {
+                challenge_id,
+                overwatch_node_id: 1,
+                subnet_id,
+                subnet_node_id,
+                evidence_hash,
+                bond,
+            }
+        );
+
+        set_overwatch_epoch(epoch + 1);
+
+        assert_ok!(Network::vote_subnet_node_challenge(
+            RuntimeOrigin::signed(account(103)),
+            2,
+            challenge_id,
+            true,
+        ));
+        assert_ok!(Network::vote_subnet_node_challenge(
+            RuntimeOrigin::signed(account(104)),
+            3,
+            challenge_id,
+            true,
+        ));
+
+        let challenge = OverwatchChallenges::<Test>::get(challenge_id).unwrap();
+        assert_eq!(challenge.approve_stake, stake * 2);
+        assert_eq!(challenge.reject_stake, 0);
+
+        // Not resolved until the voting overwatch epoch ends
+        Network::resolve_overwatch_challenges(epoch + 1);
+        assert!(OverwatchChallenges::<Test>::contains_key(challenge_id));
+
+        let free_balance = Balances::free_balance(&account(2));
+        Network::resolve_overwatch_challenges(epoch + 2);
+
+        // The subnet node is removed at the epoch boundary
+        assert!(SubnetNodesData::<Test>::contains_key(
+            subnet_id,
+            subnet_node_id
+        ));
+        assert_eq!(
+            PendingChallengeRemovals::<Test>::get(),
+            vec![(subnet_id, subnet_node_id)]
+        );
+        assert_eq!(
+            ValidatorReputation::<Test>::get(1).score,
+            Network::decrease_rep(
+                score,
+                OverwatchChallengeReputationFactor::<Test>::get(),
+                None
+            )
+        );
+
+        // Bond is released to the challenger
+        assert_eq!(
+            Network::get_stake_hold(&account(2), HoldReason::ChallengeBond),
+            0
+        );
+        assert_eq!(Balances::free_balance(&account(2)), free_balance + bond);
+        assert_eq!(OverwatchNodeStakeBalance::<Test>::get(1), stake - bond);
+
+        assert_eq!(OverwatchChallenges::<Test>::get(challenge_id), None);
+        assert_eq!(OverwatchNodeChallengeId::<Test>::get(1), None);
+        assert_eq!(
+            SubnetNodeChallengeId::<Test>::get(subnet_id, subnet_node_id),
+            None
+        );
+        assert_eq!(OverwatchChallengeVotes::<Test>::get(challenge_id, 2), None);
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SubnetNodeChallengeResolved {
+                challenge_id,
+                subnet_id,
+                subnet_node_id,
+                passed: true,
+                approve_stake: stake * 2,
+                reject_stake: 0,
+                slashed_bond: 0,
+            }
+        );
+
+        Network::remove_challenged_subnet_nodes(&mut WeightMeter::new());
+        assert_eq!(
+            SubnetNodesData::<Test>::try_get(subnet_id, subnet_node_id),
+            Err(())
+        );
+        assert!(PendingChallengeRemovals::<Test>::get().is_empty());
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ChallengedSubnetNodeRemoved {
+                subnet_id,
+                subnet_node_id,
+            }
+        );
+    });
+}
+
+#[test]
+fn test_challenge_subnet_node_fails_slashes_bond() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let subnet_node_id = 1;
+        let stake: u128 = 100e+18 as u128;
+        build_challenge_setup(subnet_id, stake);
+
+        let bond = OverwatchChallengeBond::<Test>::get();
+        let epoch = Network::get_current_overwatch_epoch_as_u32();
+        let challenge_id = NextOverwatchChallengeId::<Test>::get();
+
+        assert_ok!(Network::challenge_subnet_node(
+            RuntimeOrigin::signed(account(102)),
+            1,
+            subnet_id,
+            subnet_node_id,
+            H256::repeat_byte(1),
+        ));
+
+        set_overwatch_epoch(epoch + 1);
+
+        // A single approval doesn't reach the quorum
+        assert_ok!(Network::vote_subnet_node_challenge(
+            RuntimeOrigin::signed(account(103)),
+            2,
+            challenge_id,
+            true,
+        ));
+
+        let total_issuance = Balances::total_issuance();
+        Network::resolve_overwatch_challenges(epoch + 2);
+
+        assert!(SubnetNodesData::<Test>::contains_key(
+            subnet_id,
+            subnet_node_id
+        ));
+        assert!(PendingChallengeRemovals::<Test>::get().is_empty());
+
+        // The held bond is burned
+        assert_eq!(
+            Network::get_stake_hold(&account(2), HoldReason::ChallengeBond),
+            0
+        );
+        assert_eq!(Balances::total_issuance(), total_issuance - bond);
+        assert_eq!(OverwatchNodeStakeBalance::<Test>::get(1), stake - bond);
+        assert_eq!(
+            TotalOverwatchNodeStakeBalance::<Test>::get(),
+            stake * 3 - bond
+        );
+        assert_eq!(OverwatchChallenges::<Test>::get(challenge_id), None);
+        assert_eq!(OverwatchNodeChallengeId::<Test>::get(1), None);
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SubnetNodeChallengeResolved {
+                challenge_id,
+                subnet_id,
+                subnet_node_id,
+                passed: false,
+                approve_stake: stake,
+                reject_stake: 0,
+                slashed_bond: bond,
+            }
+        );
+    });
+}
+
+#[test]
+fn test_challenge_subnet_node_errors() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let subnet_node_id = 1;
+        let stake: u128 = 100e+18 as u128;
+        build_challenge_setup(subnet_id, stake);
+
+        let evidence_hash = H256::repeat_byte(1);
+        let epoch = Network::get_current_overwatch_epoch_as_u32();
+        let challenge_id = NextOverwatchChallengeId::<Test>::get();
+
+        assert_err!(
+            Network::challenge_subnet_node(
+                RuntimeOrigin::signed(account(103)),
+                1,
+                subnet_id,
+                subnet_node_id,
+                evidence_hash,
+            ),
+            Error::<Test>::NotKeyOwner
+        );
+
+        assert_err!(
+            Network::challenge_subnet_node(
+                RuntimeOrigin::signed(account(102)),
+                1,
+                subnet_id,
+                99,
+                evidence_hash,
+            ),
+            Error::<Test>::InvalidSubnetNodeId
+        );
+
+        OverwatchChallengeBond::<Test>::set(stake + 1);
+        assert_err!(
+            Network::challenge_subnet_node(
+                RuntimeOrigin::signed(account(102)),
+                1,
+                subnet_id,
+                subnet_node_id,
+                evidence_hash,
+            ),
+            Error::<Test>::NotEnoughStakeForChallengeBond
+        );
+        OverwatchChallengeBond::<Test>::set(stake);
+
+        assert_ok!(Network::challenge_subnet_node(
+            RuntimeOrigin::signed(account(102)),
+            1,
+            subnet_id,
+            subnet_node_id,
+            evidence_hash,
+        ));
+
+        assert_err!(
+            Network::challenge_subnet_node(
+                RuntimeOrigin::signed(account(103)),
+                2,
+                subnet_id,
+                subnet_node_id,
+                evidence_hash,
+            ),
+            Error::<Test>::SubnetNodeAlreadyChallenged
+        );
+
+        manual_insert_subnet_node_v2(1, subnet_id, 2, 1, 105, 2, SubnetNodeClass::Validator, 0);
+        assert_err!(
+            Network::challenge_subnet_node(
+                RuntimeOrigin::signed(account(102)),
+                1,
+                subnet_id,
+                2,
+                evidence_hash,
+            ),
+            Error::<Test>::OverwatchChallengeAlreadyOpen
+        );
+
+        // Voting opens in the next overwatch epoch
+        assert_err!(
+            Network::vote_subnet_node_challenge(
+                RuntimeOrigin::signed(account(103)),
+                2,
+                challenge_id,
+                true,
+            ),
+            Error::<Test>::NotChallengeVotingPeriod
+        );
+
+        set_overwatch_epoch(epoch + 1);
+
+        assert_err!(
+            Network::vote_subnet_node_challenge(
+                RuntimeOrigin::signed(account(103)),
+                2,
+                challenge_id + 1,
+                true,
+            ),
+            Error::<Test>::InvalidChallengeId
+        );
+
+        assert_err!(
+            Network::vote_subnet_node_challenge(
+                RuntimeOrigin::signed(account(102)),
+                1,
+                challenge_id,
+                true,
+            ),
+            Error::<Test>::ChallengerCannotVote
+        );
+
+        assert_ok!(Network::vote_subnet_node_challenge(
+            RuntimeOrigin::signed(account(103)),
+            2,
+            challenge_id,
+            false,
+        ));
+
+        assert_err!(
+            Network::vote_subnet_node_challenge(
+                RuntimeOrigin::signed(account(103)),
+                2,
+                challenge_id,
+                true,
+            ),
+            Error::<Test>::AlreadyVoted
+        );
+
+        set_overwatch_epoch(epoch + 2);
+
+        assert_err!(
+            Network::vote_subnet_node_challenge(
+                RuntimeOrigin::signed(account(104)),
+                3,
+                challenge_id,
+                true,
+            ),
+            Error::<Test>::NotChallengeVotingPeriod
+        );
+    });
+}
+
+#[test]
+fn test_challenge_votes_use_stake_snapshot() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let subnet_node_id = 1;
+        let stake: u128 = 100e+18 as u128;
+        build_challenge_setup(subnet_id, stake);
+
+        let epoch = Network::get_current_overwatch_epoch_as_u32();
+        let challenge_id = NextOverwatchChallengeId::<Test>::get();
+
+        assert_ok!(Network::challenge_subnet_node(
+            RuntimeOrigin::signed(account(102)),
+            1,
+            subnet_id,
+            subnet_node_id,
+            H256::repeat_byte(1),
+        ));
+
+        // Voting stake is snapshotted by the epoch's first challenge
+        assert_eq!(OverwatchStakeSnapshot::<Test>::get(epoch, 2), stake);
+        assert_eq!(
+            OverwatchChallenges::<Test>::get(challenge_id)
+                .unwrap()
+                .total_stake,
+            TotalOverwatchStakeSnapshot::<Test>::get(epoch)
+        );
+
+        // Stake added after the challenge was posted doesn't count
+        set_overwatch_node_stake(2, stake * 10);
+
+        set_overwatch_epoch(epoch + 1);
+
+        assert_ok!(Network::vote_subnet_node_challenge(
+            RuntimeOrigin::signed(account(103)),
+            2,
+            challenge_id,
+            true,
+        ));
+
+        let challenge = OverwatchChallenges::<Test>::get(challenge_id).unwrap();
+        assert_eq!(challenge.approve_stake, stake);
+
+        // Snapshots are kept while challenges can be voted on with them
+        Network::snapshot_overwatch_stake(epoch + 1);
+        assert!(TotalOverwatchStakeSnapshot::<Test>::contains_key(epoch));
+        Network::snapshot_overwatch_stake(epoch + 2);
+        assert!(!TotalOverwatchStakeSnapshot::<Test>::contains_key(epoch));
+        assert_eq!(OverwatchStakeSnapshot::<Test>::get(epoch, 2), 0);
+    });
+}
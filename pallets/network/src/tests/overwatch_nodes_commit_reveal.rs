@@ -1,14 +1,18 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
+use crate::Event;
 use crate::{
-    Error, MinSubnetMinStake, MinSubnetNodes, OverwatchCommit, OverwatchCommits, OverwatchNode,
-    OverwatchNodeBlacklist, OverwatchNodeIdHotkey, OverwatchNodeValidatorId, OverwatchNodes,
-    OverwatchReveal, OverwatchReveals, OverwatchValidatorWhitelist, SubnetData, SubnetName,
-    SubnetState, SubnetsData, TotalOverwatchNodeUids, TotalValidatorIds, ValidatorColdkey,
-    ValidatorIdHotkey,
+    Error, HoldReason, MinSubnetMinStake, MinSubnetNodes, OverwatchCommit, OverwatchCommits,
+    OverwatchEpochLengthMultiplier, OverwatchNode, OverwatchNodeBlacklist, OverwatchNodeIdHotkey,
+    OverwatchNodeStakeBalance, OverwatchNodeValidatorId, OverwatchNodes,
+    OverwatchNonRevealReputationFactor, OverwatchNonRevealStakePenalty, OverwatchReveal,
+    OverwatchRevealCutoffPercent, OverwatchReveals, OverwatchValidatorWhitelist, SubnetData,
+    SubnetName, SubnetState, SubnetsData, TotalOverwatchNodeUids, TotalValidatorIds,
+    ValidatorColdkey, ValidatorIdHotkey, ValidatorReputation,
 };
 use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok};
+use sp_std::collections::btree_map::BTreeMap;
 
 //
 //
@@ -1060,3 +1064,126 @@ fn test_commit_and_reveal_phase_errors() {
         );
     });
 }
+
+#[test]
+fn test_in_overwatch_reveal_period() {
+    new_test_ext().execute_with(|| {
+        let overwatch_epoch = 2;
+
+        set_block_to_overwatch_commit_block(overwatch_epoch);
+        assert!(Network::in_overwatch_commit_period());
+        assert!(!Network::in_overwatch_reveal_period());
+
+        set_block_to_overwatch_reveal_block(overwatch_epoch);
+        assert!(!Network::in_overwatch_commit_period());
+        assert!(Network::in_overwatch_reveal_period());
+
+        // Close the reveal window at 90% of the overwatch epoch
+        OverwatchRevealCutoffPercent::<Test>::set(900000000000000000);
+        let epoch_length = EpochLength::get();
+        let overwatch_epoch_length = epoch_length * OverwatchEpochLengthMultiplier::<Test>::get();
+        System::set_block_number(
+            overwatch_epoch * overwatch_epoch_length + overwatch_epoch_length * 9 / 10,
+        );
+        assert!(!Network::in_overwatch_commit_period());
+        assert!(!Network::in_overwatch_reveal_period());
+    });
+}
+
+#[test]
+fn test_penalize_overwatch_non_reveals() {
+    new_test_ext().execute_with(|| {
+        let overwatch_node_id = 1;
+        let validator_id = 1;
+        let overwatch_epoch = 1;
+        let stake: u128 = 1000000000000000000000;
+
+        OverwatchNodes::<Test>::insert(
+            overwatch_node_id,
+            OverwatchNode {
+                id: overwatch_node_id,
+                hotkey: account(2),
+            },
+        );
+        OverwatchNodeValidatorId::<Test>::insert(overwatch_node_id, validator_id);
+        let coldkey = account(1);
+        ValidatorColdkey::<Test>::insert(validator_id, coldkey.clone());
+        let _ = Balances::deposit_creating(&coldkey, stake + 500);
+        assert!(Network::hold_stake(
+            &coldkey,
+            HoldReason::OverwatchStake,
+            stake
+        ));
+        Network::increase_overwatch_node_stake(overwatch_node_id, stake);
+        let ow_score = ValidatorReputation::<Test>::get(validator_id).ow_score;
+        let total_issuance = Balances::total_issuance();
+
+        // Commit to two subnets, reveal only one
+        let commit_hash = make_commit(1, b"salt".to_vec());
+        OverwatchCommits::<Test>::insert((overwatch_epoch, overwatch_node_id, 1), commit_hash);
+        OverwatchCommits::<Test>::insert((overwatch_epoch, overwatch_node_id, 2), commit_hash);
+        OverwatchReveals::<Test>::insert((overwatch_epoch, 1, overwatch_node_id), 1);
+
+        let mut unrevealed = BTreeMap::new();
+        unrevealed.insert(overwatch_node_id, vec![2]);
+        assert_eq!(
+            Network::get_unrevealed_overwatch_commits(overwatch_epoch),
+            unrevealed
+        );
+
+        Network::penalize_overwatch_non_reveals(overwatch_epoch);
+
+        // Half of the commits are unrevealed, half of the penalties apply
+        let unrevealed_share = 500000000000000000;
+        let expected_ow_score = Network::decrease_rep(
+            ow_score,
+            OverwatchNonRevealReputationFactor::<Test>::get(),
+            Some(unrevealed_share),
+        );
+        let expected_stake_penalty = Network::percent_mul(
+            stake,
+            Network::percent_mul(
+                OverwatchNonRevealStakePenalty::<Test>::get(),
+                unrevealed_share,
+            ),
+        );
+        assert!(expected_ow_score < ow_score);
+        assert!(expected_stake_penalty > 0);
+        assert_eq!(
+            ValidatorReputation::<Test>::get(validator_id).ow_score,
+            expected_ow_score
+        );
+        assert_eq!(
+            OverwatchNodeStakeBalance::<Test>::get(overwatch_node_id),
+            stake - expected_stake_penalty
+        );
+        // The penalty is burned from the held stake
+        assert_eq!(
+            Network::get_stake_hold(&coldkey, HoldReason::OverwatchStake),
+            stake - expected_stake_penalty
+        );
+        assert_eq!(
+            Balances::total_issuance(),
+            total_issuance - expected_stake_penalty
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::OverwatchNonRevealPenalty {
+                overwatch_node_id,
+                overwatch_epoch,
+                unrevealed_subnet_ids: vec![2],
+                ow_score: expected_ow_score,
+                stake_penalty: expected_stake_penalty,
+            }
+        );
+
+        // Nodes that revealed everything are not penalized
+        OverwatchReveals::<Test>::insert((overwatch_epoch, 2, overwatch_node_id), 1);
+        assert!(Network::get_unrevealed_overwatch_commits(overwatch_epoch).is_empty());
+        Network::penalize_overwatch_non_reveals(overwatch_epoch);
+        assert_eq!(
+            ValidatorReputation::<Test>::get(validator_id).ow_score,
+            expected_ow_score
+        );
+    });
+}
@@ -1,24 +1,30 @@
 use super::mock::*;
+use crate::election::MAX_VALIDATOR_ELECTION_EXPONENT;
+use crate::subnet_validator::MAX_FALLBACK_PROPOSERS;
 use crate::tests::test_utils::*;
 use crate::Event;
 use crate::{
-    ChurnLimit, ChurnLimitMultiplier, EmergencySubnetNodeElectionData,
-    EmergencySubnetValidatorData, Error, IdleClassificationEpochs, IncludedClassificationEpochs,
-    LastSubnetDelegateStakeRewardsUpdate, MaxChurnLimit, MaxChurnLimitMultiplier,
-    MaxDelegateStakePercentage, MaxIdleClassificationEpochs, MaxIncludedClassificationEpochs,
-    MaxMaxRegisteredNodes, MaxQueueEpochs, MaxRegisteredNodes, MaxSubnetBootnodeAccess,
-    MaxSubnetMinStake, MaxSubnetNodeMinWeightDecreaseReputationThreshold, MaxSubnetNodes,
-    MaxSubnets, MinChurnLimit, MinChurnLimitMultiplier, MinDelegateStakePercentage,
-    MinIdleClassificationEpochs, MinIncludedClassificationEpochs, MinMaxRegisteredNodes,
-    MinNodeReputationFactor, MinQueueEpochs, MinSubnetMinStake, MinSubnetNodeReputation,
-    NetworkMaxStakeBalance, NodeBurnRateAlpha, NodeRegistrationInitialValidatorIds, PeerInfo,
-    PendingSubnetOwner, QueueImmunityEpochs, RegisteredSubnetNodesData, SubnetBootnodeAccess,
-    SubnetData, SubnetDelegateStakeRewardsPercentage, SubnetDelegateStakeRewardsUpdatePeriod,
-    SubnetMaxStakeBalance, SubnetMinStakeBalance, SubnetName, SubnetNode, SubnetNodeClass,
-    SubnetNodeClassification, SubnetNodeMinWeightDecreaseReputationThreshold,
-    SubnetNodeQueueEpochs, SubnetNodesData, SubnetOwner, SubnetPauseCooldownEpochs,
-    SubnetRemovalReason, SubnetRepo, SubnetReputationFactorSchedules,
-    SubnetReputationFactorUpdates, SubnetState, SubnetsData, TargetNodeRegistrationsPerEpoch,
+    AttestationWeighting, ChurnLimit, ChurnLimitMultiplier, ConsensusDataPolicy,
+    EmergencySubnetNodeElectionData, EmergencySubnetValidatorData, Error, FallbackProposerConfig,
+    IdleClassificationEpochs, IncludedClassificationEpochs, LastSubnetDelegateStakeRewardsUpdate,
+    MaxChurnLimit, MaxChurnLimitMultiplier, MaxDelegateStakePercentage,
+    MaxIdleClassificationEpochs, MaxIncludedClassificationEpochs, MaxMaxRegisteredNodes,
+    MaxQueueEpochs, MaxRegisteredNodes, MaxSubnetBootnodeAccess, MaxSubnetMinStake,
+    MaxSubnetNodeMinWeightDecreaseReputationThreshold, MaxSubnetNodes, MaxSubnets, MinChurnLimit,
+    MinChurnLimitMultiplier, MinDelegateStakePercentage, MinIdleClassificationEpochs,
+    MinIncludedClassificationEpochs, MinMaxRegisteredNodes, MinNodeReputationFactor,
+    MinQueueEpochs, MinSubnetMinStake, MinSubnetNodeReputation, NetworkMaxStakeBalance,
+    NodeBurnRateAlpha, NodeConcentrationCaps, NodeRegistrationInitialValidatorIds, PeerInfo,
+    PendingSubnetOwner, QueueImmunityEpochs, RegisteredSubnetNodesData, SubnetAttestationWeighting,
+    SubnetBootnodeAccess, SubnetConsensusDataPolicies, SubnetData,
+    SubnetDelegateStakeRewardsPercentage, SubnetDelegateStakeRewardsUpdatePeriod,
+    SubnetFallbackProposers, SubnetMaxStakeBalance, SubnetMinStakeBalance, SubnetName, SubnetNode,
+    SubnetNodeClass, SubnetNodeClassification, SubnetNodeConcentrationCaps,
+    SubnetNodeMinWeightDecreaseReputationThreshold, SubnetNodeQueueEpochs, SubnetNodesData,
+    SubnetOwner, SubnetPauseCooldownEpochs, SubnetRemovalReason, SubnetRepo,
+    SubnetReputationFactorSchedules, SubnetReputationFactorUpdates, SubnetSlot, SubnetState,
+    SubnetValidatorElectionMode, SubnetsData, TargetNodeRegistrationsPerEpoch,
+    ValidatorElectionMode, WeightedValidatorElection,
 };
 use codec::Decode;
 use frame_support::{assert_err, assert_ok};
@@ -65,6 +71,11 @@ use sp_std::collections::btree_set::BTreeSet;
 // do_owner_update_target_node_registrations_per_epoch -
 // do_owner_update_node_burn_rate_alpha -
 // do_owner_update_queue_immunity_epochs -
+// do_owner_update_consensus_data_policies -
+// do_owner_update_validator_election_mode -
+// do_owner_update_fallback_proposers -
+// do_owner_update_attestation_weighting -
+// do_owner_update_node_concentration_caps -
 // do_owner_update_subnet_node_min_weight_decrease_reputation_threshold -
 
 #[test]
@@ -402,6 +413,471 @@ fn test_do_owner_update_queue_immunity_epochs() {
     })
 }
 
+#[test]
+fn test_do_owner_update_consensus_data_policies() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_subnet(subnet_id, SubnetState::Active, 0);
+        let original_owner = account(1);
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+        SubnetSlot::<Test>::insert(subnet_id, 3);
+        set_block_to_subnet_slot_epoch(1, subnet_id);
+
+        let policies = vec![
+            ConsensusDataPolicy::MaxScore(1000),
+            ConsensusDataPolicy::MinScoredNodes(2),
+            ConsensusDataPolicy::TopK(4),
+            ConsensusDataPolicy::MinIncludedScore(10),
+            ConsensusDataPolicy::NormalizedScores,
+        ];
+
+        assert_err!(
+            Network::owner_update_consensus_data_policies(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                policies.clone()
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        // Can't require or allow more scored nodes than a subnet can have
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        for policy in [
+            ConsensusDataPolicy::MinScoredNodes(max_subnet_nodes + 1),
+            ConsensusDataPolicy::TopK(max_subnet_nodes + 1),
+        ] {
+            assert_err!(
+                Network::owner_update_consensus_data_policies(
+                    RuntimeOrigin::signed(original_owner.clone()),
+                    subnet_id,
+                    vec![policy]
+                ),
+                Error::<Test>::InvalidConsensusDataPolicy
+            );
+        }
+
+        assert_ok!(Network::owner_update_consensus_data_policies(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            policies.clone()
+        ));
+
+        // Applies from the next subnet epoch
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+        assert_eq!(
+            Network::get_consensus_data_policies(subnet_id, subnet_epoch),
+            Vec::new()
+        );
+        assert_eq!(
+            Network::get_consensus_data_policies(subnet_id, subnet_epoch + 1),
+            policies
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ConsensusDataPoliciesUpdate {
+                subnet_id,
+                owner: original_owner.clone(),
+                policies: policies.clone(),
+                effective_subnet_epoch: subnet_epoch + 1,
+            }
+        );
+
+        increase_epochs(1);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+        // Empty vector clears all policies
+        assert_ok!(Network::owner_update_consensus_data_policies(
+            RuntimeOrigin::signed(original_owner),
+            subnet_id,
+            Vec::new()
+        ));
+
+        // The previous update took effect and stays in place for this epoch
+        assert_eq!(
+            SubnetConsensusDataPolicies::<Test>::get(subnet_id),
+            policies
+        );
+        assert_eq!(
+            Network::get_consensus_data_policies(subnet_id, subnet_epoch),
+            policies
+        );
+        assert_eq!(
+            Network::get_consensus_data_policies(subnet_id, subnet_epoch + 1),
+            Vec::new()
+        );
+    })
+}
+
+#[test]
+fn test_do_owner_update_validator_election_mode() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_subnet(subnet_id, SubnetState::Active, 0);
+        let original_owner = account(1);
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+
+        let one = Network::percentage_factor_as_u128();
+        let weighted = WeightedValidatorElection {
+            stake_exponent: one,
+            node_reputation_exponent: one / 2,
+            validator_reputation_exponent: 2 * one,
+            max_validator_probability: one / 4,
+        };
+
+        assert_eq!(
+            SubnetValidatorElectionMode::<Test>::get(subnet_id),
+            ValidatorElectionMode::Uniform
+        );
+
+        assert_err!(
+            Network::owner_update_validator_election_mode(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                ValidatorElectionMode::Weighted(weighted)
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(Network::owner_update_validator_election_mode(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            ValidatorElectionMode::Weighted(weighted)
+        ));
+
+        assert_eq!(
+            SubnetValidatorElectionMode::<Test>::get(subnet_id),
+            ValidatorElectionMode::Weighted(weighted)
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ValidatorElectionModeUpdate {
+                subnet_id,
+                owner: original_owner.clone(),
+                mode: ValidatorElectionMode::Weighted(weighted),
+            }
+        );
+
+        let invalid = [
+            WeightedValidatorElection {
+                stake_exponent: MAX_VALIDATOR_ELECTION_EXPONENT + 1,
+                ..weighted
+            },
+            WeightedValidatorElection {
+                validator_reputation_exponent: MAX_VALIDATOR_ELECTION_EXPONENT + 1,
+                ..weighted
+            },
+            WeightedValidatorElection {
+                max_validator_probability: 0,
+                ..weighted
+            },
+            WeightedValidatorElection {
+                max_validator_probability: one + 1,
+                ..weighted
+            },
+        ];
+
+        for params in invalid {
+            assert_err!(
+                Network::owner_update_validator_election_mode(
+                    RuntimeOrigin::signed(original_owner.clone()),
+                    subnet_id,
+                    ValidatorElectionMode::Weighted(params)
+                ),
+                Error::<Test>::InvalidValidatorElectionMode
+            );
+        }
+
+        assert_ok!(Network::owner_update_validator_election_mode(
+            RuntimeOrigin::signed(original_owner),
+            subnet_id,
+            ValidatorElectionMode::Uniform
+        ));
+
+        assert_eq!(
+            SubnetValidatorElectionMode::<Test>::get(subnet_id),
+            ValidatorElectionMode::Uniform
+        );
+    })
+}
+
+#[test]
+fn test_do_owner_update_fallback_proposers() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_subnet(subnet_id, SubnetState::Active, 0);
+        let original_owner = account(1);
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+
+        let one = Network::percentage_factor_as_u128();
+        let config = FallbackProposerConfig {
+            start_progression: one / 2,
+            max_proposers: 2,
+        };
+
+        assert_eq!(SubnetFallbackProposers::<Test>::get(subnet_id), None);
+
+        assert_err!(
+            Network::owner_update_fallback_proposers(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                Some(config)
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(Network::owner_update_fallback_proposers(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            Some(config)
+        ));
+
+        assert_eq!(
+            SubnetFallbackProposers::<Test>::get(subnet_id),
+            Some(config)
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::FallbackProposersUpdate {
+                subnet_id,
+                owner: original_owner.clone(),
+                config: Some(config),
+            }
+        );
+
+        let invalid = [
+            FallbackProposerConfig {
+                start_progression: one + 1,
+                ..config
+            },
+            FallbackProposerConfig {
+                max_proposers: 0,
+                ..config
+            },
+            FallbackProposerConfig {
+                max_proposers: MAX_FALLBACK_PROPOSERS + 1,
+                ..config
+            },
+        ];
+
+        for config in invalid {
+            assert_err!(
+                Network::owner_update_fallback_proposers(
+                    RuntimeOrigin::signed(original_owner.clone()),
+                    subnet_id,
+                    Some(config)
+                ),
+                Error::<Test>::InvalidFallbackProposerConfig
+            );
+        }
+
+        assert_ok!(Network::owner_update_fallback_proposers(
+            RuntimeOrigin::signed(original_owner),
+            subnet_id,
+            None
+        ));
+
+        assert_eq!(SubnetFallbackProposers::<Test>::get(subnet_id), None);
+    })
+}
+
+#[test]
+fn test_do_owner_update_attestation_weighting() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_subnet(subnet_id, SubnetState::Active, 0);
+        let original_owner = account(1);
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+        SubnetSlot::<Test>::insert(subnet_id, 3);
+        set_block_to_subnet_slot_epoch(1, subnet_id);
+
+        assert_eq!(
+            SubnetAttestationWeighting::<Test>::get(subnet_id),
+            AttestationWeighting::Count
+        );
+
+        assert_err!(
+            Network::owner_update_attestation_weighting(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                AttestationWeighting::Stake
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(Network::owner_update_attestation_weighting(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            AttestationWeighting::Stake
+        ));
+
+        // Applies from the next subnet epoch
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+        assert_eq!(
+            Network::get_attestation_weighting(subnet_id, subnet_epoch),
+            AttestationWeighting::Count
+        );
+        assert_eq!(
+            Network::get_attestation_weighting(subnet_id, subnet_epoch + 1),
+            AttestationWeighting::Stake
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::AttestationWeightingUpdate {
+                subnet_id,
+                owner: original_owner.clone(),
+                weighting: AttestationWeighting::Stake,
+                effective_subnet_epoch: subnet_epoch + 1,
+            }
+        );
+
+        increase_epochs(1);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+        assert_ok!(Network::owner_update_attestation_weighting(
+            RuntimeOrigin::signed(original_owner),
+            subnet_id,
+            AttestationWeighting::Reputation
+        ));
+
+        // The previous update took effect and stays in place for this epoch
+        assert_eq!(
+            SubnetAttestationWeighting::<Test>::get(subnet_id),
+            AttestationWeighting::Stake
+        );
+        assert_eq!(
+            Network::get_attestation_weighting(subnet_id, subnet_epoch),
+            AttestationWeighting::Stake
+        );
+        assert_eq!(
+            Network::get_attestation_weighting(subnet_id, subnet_epoch + 1),
+            AttestationWeighting::Reputation
+        );
+    })
+}
+
+#[test]
+fn test_do_owner_update_node_concentration_caps() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_subnet(subnet_id, SubnetState::Active, 0);
+        let original_owner = account(1);
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+
+        assert_eq!(
+            SubnetNodeConcentrationCaps::<Test>::get(subnet_id),
+            NodeConcentrationCaps::default()
+        );
+
+        let caps = NodeConcentrationCaps {
+            max_nodes_per_coldkey: Some(4),
+            max_nodes_per_validator: Some(2),
+        };
+
+        assert_err!(
+            Network::owner_update_node_concentration_caps(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                caps
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_err!(
+            Network::owner_update_node_concentration_caps(
+                RuntimeOrigin::signed(original_owner.clone()),
+                subnet_id,
+                NodeConcentrationCaps {
+                    max_nodes_per_coldkey: Some(0),
+                    max_nodes_per_validator: None,
+                }
+            ),
+            Error::<Test>::InvalidNodeConcentrationCaps
+        );
+
+        assert_ok!(Network::owner_update_node_concentration_caps(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            caps
+        ));
+
+        assert_eq!(SubnetNodeConcentrationCaps::<Test>::get(subnet_id), caps);
+        assert_eq!(Network::get_max_nodes_per_validator(subnet_id), Some(2));
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::NodeConcentrationCapsUpdate {
+                subnet_id,
+                owner: original_owner,
+                caps,
+            }
+        );
+    })
+}
+
+#[test]
+fn test_do_owner_update_consensus_data_policies_invalid() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_subnet(subnet_id, SubnetState::Active, 0);
+        let original_owner = account(1);
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+
+        let cases = vec![
+            (
+                vec![ConsensusDataPolicy::TopK(0)],
+                Error::<Test>::InvalidConsensusDataPolicy,
+            ),
+            (
+                vec![ConsensusDataPolicy::MinScoredNodes(0)],
+                Error::<Test>::InvalidConsensusDataPolicy,
+            ),
+            (
+                vec![
+                    ConsensusDataPolicy::MaxScore(10),
+                    ConsensusDataPolicy::MaxScore(20),
+                ],
+                Error::<Test>::DuplicateConsensusDataPolicy,
+            ),
+            (
+                vec![
+                    ConsensusDataPolicy::NormalizedScores,
+                    ConsensusDataPolicy::NormalizedScores,
+                ],
+                Error::<Test>::DuplicateConsensusDataPolicy,
+            ),
+            (
+                vec![
+                    ConsensusDataPolicy::MinScoredNodes(5),
+                    ConsensusDataPolicy::TopK(4),
+                ],
+                Error::<Test>::ConflictingConsensusDataPolicies,
+            ),
+            (
+                vec![
+                    ConsensusDataPolicy::MinIncludedScore(11),
+                    ConsensusDataPolicy::MaxScore(10),
+                ],
+                Error::<Test>::ConflictingConsensusDataPolicies,
+            ),
+        ];
+
+        for (policies, error) in cases {
+            assert_err!(
+                Network::owner_update_consensus_data_policies(
+                    RuntimeOrigin::signed(original_owner.clone()),
+                    subnet_id,
+                    policies
+                ),
+                error
+            );
+        }
+
+        assert_eq!(
+            SubnetConsensusDataPolicies::<Test>::get(subnet_id),
+            Vec::new()
+        );
+    })
+}
+
 #[test]
 fn do_owner_update_subnet_node_min_weight_decrease_reputation_threshold() {
     new_test_ext().execute_with(|| {
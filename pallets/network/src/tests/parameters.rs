@@ -0,0 +1,620 @@
+// The deprecated `set_*` extrinsics are tested until they are removed
+#![allow(deprecated)]
+
+use super::mock::*;
+use crate::parameter_schedule::MAX_SCHEDULED_PARAMETER_CHANGES;
+use crate::parameters::MAX_NETWORK_PARAMETER_UPDATES;
+use crate::tests::test_utils::*;
+use crate::Event;
+use crate::{
+    Error, MaxBootnodes, MaxChurnLimit, MinChurnLimit, NetworkParameter,
+    NextScheduledParameterChangeId, ParameterChangeDelayEpochs, RegistrationCostAlpha,
+    ScheduledParameterChanges, SubnetOwnerPercentage, SuperMajorityAttestationRatio,
+};
+use frame_support::weights::WeightMeter;
+use frame_support::{assert_err, assert_noop, assert_ok};
+
+//
+//
+//
+//
+//
+//
+// Network parameter registry
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_set_parameter() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::MaxBootnodes(32)
+        ));
+
+        assert_eq!(MaxBootnodes::<Test>::get(), 32);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetMaxBootnodes(32)
+        );
+    });
+}
+
+#[test]
+fn test_set_parameter_requires_super_majority() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 150000000000000000; // 15%
+
+        // The majority schedules changes instead, even for parameters it can schedule
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                NetworkParameter::MaxBootnodes(32)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                NetworkParameter::SubnetOwnerPercentage(new_value)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::SubnetOwnerPercentage(new_value)
+        ));
+
+        assert_eq!(SubnetOwnerPercentage::<Test>::get(), new_value);
+    });
+}
+
+#[test]
+fn test_set_parameter_out_of_bounds() {
+    new_test_ext().execute_with(|| {
+        let one = Network::percentage_factor_as_u128();
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::RegistrationCostAlpha(one + 1)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::SubnetOwnerPercentage(one / 2 + 1)
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+    });
+}
+
+#[test]
+fn test_set_parameter_bounds() {
+    new_test_ext().execute_with(|| {
+        let one = Network::percentage_factor_as_u128();
+        let token: u128 = 1000000000000000000;
+        let cooldown_epochs = EpochsPerYear::get();
+
+        for parameter in [
+            NetworkParameter::BaseSlashPercentage(one + 1),
+            NetworkParameter::MinRegistrationCost(0),
+            NetworkParameter::MinRegistrationCost(u128::MAX),
+            NetworkParameter::BaseNodeBurnAmount(u128::MAX),
+            NetworkParameter::SuperMajorityAttestationRatio(one / 2),
+            NetworkParameter::OverwatchMinDiversificationRatio(one + 1),
+            NetworkParameter::OverwatchStakeWeightFactor(2 * one + 1),
+            NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(10 * one + 1),
+            NetworkParameter::DelegateStakeCooldownEpochs(cooldown_epochs + 1),
+            NetworkParameter::NodeDelegateStakeCooldownEpochs(cooldown_epochs + 1),
+            NetworkParameter::StakeCooldownEpochs(cooldown_epochs + 1),
+            NetworkParameter::NewRegistrationCostMultiplier(one - 1),
+            NetworkParameter::NewRegistrationCostMultiplier(10 * one + 1),
+            NetworkParameter::MaxMinDelegateStakeMultiplier(10 * one + 1),
+            NetworkParameter::SubnetStakes(0, token),
+            NetworkParameter::SubnetStakes(token, u128::MAX),
+            NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(one + 1),
+            NetworkParameter::BaseValidatorReward(u128::MAX),
+            NetworkParameter::MaxSlashAmount(u128::MAX),
+            NetworkParameter::NetworkMaxStakeBalance(0),
+            NetworkParameter::NetworkMaxStakeBalance(u128::MAX),
+            NetworkParameter::MinDelegateStakeDeposit(u128::MAX),
+            NetworkParameter::MaxRewardRateDecrease(one + 1),
+            NetworkParameter::SubnetDistributionPower(5 * one + 1),
+            NetworkParameter::InflationSigmoidSteepness(0),
+            NetworkParameter::InflationSigmoidSteepness(one),
+            NetworkParameter::OverwatchChallengeBond(0),
+            NetworkParameter::OverwatchChallengeBond(u128::MAX),
+            NetworkParameter::OverwatchChallengeQuorum(0),
+            NetworkParameter::OverwatchWeightQuorum(0),
+            NetworkParameter::OverwatchMinRepScore(one + 1),
+            NetworkParameter::OverwatchMinAvgAttestationRatio(one + 1),
+            NetworkParameter::OverwatchMinStakeBalance(u128::MAX),
+        ] {
+            assert_err!(
+                Network::set_parameter(
+                    RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                    parameter
+                ),
+                Error::<Test>::ParameterOutOfBounds
+            );
+        }
+
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::StakeCooldownEpochs(cooldown_epochs)
+        ));
+    });
+}
+
+#[test]
+fn test_set_min_attestation_percentage_above_super_majority() {
+    new_test_ext().execute_with(|| {
+        let super_majority = SuperMajorityAttestationRatio::<Test>::get();
+
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::MinAttestationPercentage(super_majority + 1)
+            ),
+            Error::<Test>::InvalidSuperMajorityAttestationRatio
+        );
+    });
+}
+
+#[test]
+fn test_set_parameter_runs_setter_checks() {
+    new_test_ext().execute_with(|| {
+        // Within bounds but min must be less than max
+        assert_err!(
+            Network::set_parameter(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                NetworkParameter::ChurnLimits(8, 2)
+            ),
+            Error::<Test>::InvalidValues
+        );
+    });
+}
+
+#[test]
+fn test_set_parameters() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        assert_ok!(Network::set_parameters(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            vec![
+                NetworkParameter::MaxBootnodes(32),
+                NetworkParameter::ChurnLimits(2, 8),
+            ]
+        ));
+
+        assert_eq!(MaxBootnodes::<Test>::get(), 32);
+        assert_eq!(MinChurnLimit::<Test>::get(), 2);
+        assert_eq!(MaxChurnLimit::<Test>::get(), 8);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetChurnLimits(2, 8)
+        );
+    });
+}
+
+#[test]
+fn test_set_parameters_requires_super_majority() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            Network::set_parameters(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![
+                    NetworkParameter::MaxBootnodes(32),
+                    NetworkParameter::ChurnLimits(2, 8),
+                ]
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_set_parameters_is_all_or_nothing() {
+    new_test_ext().execute_with(|| {
+        let one = Network::percentage_factor_as_u128();
+
+        assert_noop!(
+            Network::set_parameters(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                vec![
+                    NetworkParameter::MaxBootnodes(32),
+                    NetworkParameter::RegistrationCostAlpha(one + 1),
+                ]
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+
+        assert_ne!(RegistrationCostAlpha::<Test>::get(), one + 1);
+    });
+}
+
+#[test]
+fn test_set_parameters_too_many() {
+    new_test_ext().execute_with(|| {
+        let parameters =
+            vec![NetworkParameter::MaxBootnodes(32); MAX_NETWORK_PARAMETER_UPDATES as usize + 1];
+
+        assert_err!(
+            Network::set_parameters(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                parameters
+            ),
+            Error::<Test>::TooManyParameters
+        );
+    });
+}
+
+#[test]
+fn test_get_network_parameters() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Network::set_parameter(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            NetworkParameter::ChurnLimits(2, 8)
+        ));
+
+        let parameters = Network::get_network_parameters();
+        assert_eq!(
+            parameters.len(),
+            Network::current_network_parameters().len()
+        );
+
+        let churn_limits = parameters
+            .iter()
+            .find(|info| info.name == b"ChurnLimits".to_vec())
+            .unwrap();
+        assert_eq!(churn_limits.values, vec![2, 8]);
+        assert_eq!(churn_limits.min, 0);
+        assert_eq!(churn_limits.max, u32::MAX as u128);
+        assert!(!churn_limits.super_majority);
+
+        // Indexes follow the SCALE encoding of the variants
+        for (index, info) in parameters.iter().enumerate() {
+            assert_eq!(info.index as usize, index);
+        }
+    });
+}
+
+//
+//
+//
+//
+//
+//
+// Scheduled parameter changes
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_schedule_parameter_change() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let prev_value = SubnetOwnerPercentage::<Test>::get();
+        let new_value: u128 = 150000000000000000; // 15%
+        let epoch = Network::get_current_epoch_as_u32();
+        let enactment_epoch = epoch + ParameterChangeDelayEpochs::<Test>::get();
+
+        // Super majority parameters can only be scheduled by the super majority
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![NetworkParameter::SubnetOwnerPercentage(new_value)]
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![
+                    NetworkParameter::MaxBootnodes(32),
+                    NetworkParameter::ParameterChangeDelayEpochs(1),
+                ]
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Network::schedule_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            vec![NetworkParameter::SubnetOwnerPercentage(new_value)]
+        ));
+
+        let id = NextScheduledParameterChangeId::<Test>::get() - 1;
+        let scheduled = ScheduledParameterChanges::<Test>::get(id).unwrap();
+        assert_eq!(scheduled.epoch, epoch);
+        assert_eq!(scheduled.enactment_epoch, enactment_epoch);
+        assert!(scheduled.super_majority);
+        assert_eq!(SubnetOwnerPercentage::<Test>::get(), prev_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ParameterChangeScheduled {
+                id,
+                parameters: vec![NetworkParameter::SubnetOwnerPercentage(new_value)],
+                enactment_epoch,
+            }
+        );
+
+        Network::do_epoch_preliminaries(&mut WeightMeter::new(), 0, enactment_epoch - 1);
+        assert_eq!(SubnetOwnerPercentage::<Test>::get(), prev_value);
+        assert!(ScheduledParameterChanges::<Test>::contains_key(id));
+
+        Network::do_epoch_preliminaries(&mut WeightMeter::new(), 0, enactment_epoch);
+        assert_eq!(SubnetOwnerPercentage::<Test>::get(), new_value);
+        assert!(!ScheduledParameterChanges::<Test>::contains_key(id));
+        assert!(network_events().contains(&Event::ParameterChangeEnacted { id }));
+    });
+}
+
+#[test]
+fn test_schedule_parameter_change_errors() {
+    new_test_ext().execute_with(|| {
+        let one = Network::percentage_factor_as_u128();
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::signed(account(1)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![]
+            ),
+            Error::<Test>::InvalidValues
+        );
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![NetworkParameter::RegistrationCostAlpha(one + 1)]
+            ),
+            Error::<Test>::ParameterOutOfBounds
+        );
+
+        for _ in 0..MAX_SCHEDULED_PARAMETER_CHANGES {
+            assert_ok!(Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ));
+        }
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ),
+            Error::<Test>::TooManyScheduledParameterChanges
+        );
+    });
+}
+
+#[test]
+fn test_schedule_parameter_change_budget_per_collective() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        for _ in 0..MAX_SCHEDULED_PARAMETER_CHANGES {
+            assert_ok!(Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ));
+        }
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ),
+            Error::<Test>::TooManyScheduledParameterChanges
+        );
+
+        // A full majority budget doesn't block the super majority
+        for _ in 0..MAX_SCHEDULED_PARAMETER_CHANGES {
+            assert_ok!(Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ));
+        }
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ),
+            Error::<Test>::TooManyScheduledParameterChanges
+        );
+
+        // Vetoing a majority change frees a slot of the majority budget only
+        let id = NextScheduledParameterChangeId::<Test>::get() - 1;
+        assert!(
+            ScheduledParameterChanges::<Test>::get(id)
+                .unwrap()
+                .super_majority
+        );
+        assert_ok!(Network::veto_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            0
+        ));
+        assert!(!ScheduledParameterChanges::<Test>::contains_key(0));
+
+        assert_err!(
+            Network::schedule_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                vec![NetworkParameter::MaxBootnodes(32)]
+            ),
+            Error::<Test>::TooManyScheduledParameterChanges
+        );
+        assert_ok!(Network::schedule_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            vec![NetworkParameter::MaxBootnodes(32)]
+        ));
+    });
+}
+
+#[test]
+fn test_fast_track_parameter_change() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        assert_ok!(Network::schedule_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            vec![NetworkParameter::MaxBootnodes(32)]
+        ));
+        let id = NextScheduledParameterChangeId::<Test>::get() - 1;
+
+        assert_err!(
+            Network::fast_track_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                id
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Network::fast_track_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            id
+        ));
+
+        assert_eq!(MaxBootnodes::<Test>::get(), 32);
+        assert!(!ScheduledParameterChanges::<Test>::contains_key(id));
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ParameterChangeFastTracked { id }
+        );
+
+        assert_err!(
+            Network::fast_track_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                id
+            ),
+            Error::<Test>::ScheduledParameterChangeNotFound
+        );
+    });
+}
+
+#[test]
+fn test_veto_parameter_change() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let prev_value = MaxBootnodes::<Test>::get();
+
+        assert_ok!(Network::schedule_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            vec![NetworkParameter::MaxBootnodes(prev_value + 1)]
+        ));
+        let id = NextScheduledParameterChangeId::<Test>::get() - 1;
+        let enactment_epoch = ScheduledParameterChanges::<Test>::get(id)
+            .unwrap()
+            .enactment_epoch;
+
+        assert_err!(
+            Network::veto_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                id
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Network::veto_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            id
+        ));
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ParameterChangeVetoed { id }
+        );
+
+        Network::do_epoch_preliminaries(&mut WeightMeter::new(), 0, enactment_epoch);
+        assert_eq!(MaxBootnodes::<Test>::get(), prev_value);
+
+        assert_err!(
+            Network::veto_parameter_change(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+                id
+            ),
+            Error::<Test>::ScheduledParameterChangeNotFound
+        );
+    });
+}
+
+#[test]
+fn test_scheduled_parameter_change_fails_setter_check() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let prev_bootnodes = MaxBootnodes::<Test>::get();
+        let prev_min_churn_limit = MinChurnLimit::<Test>::get();
+
+        // Within bounds but min must be less than max
+        assert_ok!(Network::schedule_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            vec![
+                NetworkParameter::MaxBootnodes(prev_bootnodes + 1),
+                NetworkParameter::ChurnLimits(8, 2),
+            ]
+        ));
+        let id = NextScheduledParameterChangeId::<Test>::get() - 1;
+        let enactment_epoch = ScheduledParameterChanges::<Test>::get(id)
+            .unwrap()
+            .enactment_epoch;
+
+        Network::do_epoch_preliminaries(&mut WeightMeter::new(), 0, enactment_epoch);
+
+        // All or nothing
+        assert_eq!(MaxBootnodes::<Test>::get(), prev_bootnodes);
+        assert_eq!(MinChurnLimit::<Test>::get(), prev_min_churn_limit);
+        assert!(!ScheduledParameterChanges::<Test>::contains_key(id));
+        assert!(network_events().contains(&Event::ParameterChangeFailed {
+            id,
+            error: Error::<Test>::InvalidValues.into(),
+        }));
+    });
+}
+
+#[test]
+fn test_get_scheduled_parameter_changes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Network::schedule_parameter_change(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            vec![NetworkParameter::ChurnLimits(2, 8)]
+        ));
+        let id = NextScheduledParameterChangeId::<Test>::get() - 1;
+
+        let scheduled = Network::get_scheduled_parameter_changes();
+        assert_eq!(scheduled.len(), 1);
+        assert_eq!(scheduled[0].id, id);
+        assert_eq!(
+            scheduled[0].enactment_epoch,
+            Network::get_current_epoch_as_u32() + ParameterChangeDelayEpochs::<Test>::get()
+        );
+        assert_eq!(scheduled[0].parameters.len(), 1);
+        assert_eq!(scheduled[0].parameters[0].name, b"ChurnLimits".to_vec());
+        assert_eq!(scheduled[0].parameters[0].values, vec![2, 8]);
+    });
+}
@@ -1,6 +1,16 @@
 use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    BeaconEntropyCommits, BeaconRandomness, BeaconRoundEntropy, Error, Event, MinBeaconReveals,
+    OverwatchNodeIdHotkey, OverwatchNodeStakeBalance, OverwatchNodeValidatorId,
+    OverwatchNonRevealReputationFactor, OverwatchNonRevealStakePenalty,
+    OverwatchValidatorWhitelist, ValidatorReputation,
+};
 use frame_support::traits::OnInitialize;
-use sp_runtime::traits::Header;
+use frame_support::{assert_err, assert_ok};
+use sp_core::H256;
+use sp_runtime::traits::{Hash, Header};
+use sp_std::collections::btree_map::BTreeMap;
 
 ///
 ///
@@ -86,3 +96,258 @@ fn test_bounded_random_index_is_deterministic_and_bounded() {
         }
     });
 }
+
+//
+// Randomness beacon
+//
+
+fn insert_beacon_overwatch_node(n: u32) -> u32 {
+    manual_insert_validator(n, n, n);
+    let overwatch_node_id = insert_overwatch_node_v2(n);
+    OverwatchNodeIdHotkey::<Test>::insert(overwatch_node_id, account(n));
+    OverwatchValidatorWhitelist::<Test>::insert(n, true);
+    overwatch_node_id
+}
+
+fn make_beacon_commit(entropy: H256, overwatch_node_id: u32) -> H256 {
+    Hashing::hash_of(&(entropy, overwatch_node_id))
+}
+
+#[test]
+fn test_beacon_random_index_uses_beacon_seed() {
+    new_test_ext().execute_with(|| {
+        MockBeaconSeeds::set(BTreeMap::new());
+        set_overwatch_epoch(5);
+
+        assert_eq!(Network::get_beacon_seed(4), None);
+        // No seed falls back to `T::Randomness`
+        assert_eq!(
+            Network::get_beacon_random_index((1, 2, 3), 13),
+            Network::get_bounded_random_index((1, 2, 3), 13)
+        );
+
+        let seed = H256::repeat_byte(7);
+        MockBeaconSeeds::mutate(|seeds| {
+            seeds.insert(4, seed);
+        });
+
+        assert_eq!(Network::get_beacon_seed(4), Some(seed));
+
+        let first = Network::get_beacon_random_index((1, 2, 3), 13).unwrap();
+        let second = Network::get_beacon_random_index((1, 2, 3), 13).unwrap();
+        assert_eq!(first, second);
+
+        for upper_bound in 1..=64 {
+            let idx = Network::get_beacon_random_index((upper_bound, 1, 1), upper_bound).unwrap();
+            assert!(idx < upper_bound);
+        }
+
+        assert_eq!(Network::get_beacon_random_index((1, 2, 3), 0), None);
+        assert_eq!(Network::get_beacon_random_index((1, 2, 3), 1), Some(0));
+    });
+}
+
+#[test]
+fn test_beacon_seed_lookback_on_missed_round() {
+    new_test_ext().execute_with(|| {
+        MockBeaconSeeds::set(BTreeMap::new());
+
+        let seed = H256::repeat_byte(9);
+        MockBeaconSeeds::mutate(|seeds| {
+            seeds.insert(10, seed);
+        });
+
+        // Missed rounds reuse the latest seed mixed with the round
+        assert_eq!(
+            Network::get_beacon_seed(13),
+            Some(Hashing::hash_of(&(seed, 13u32)))
+        );
+        assert_ne!(Network::get_beacon_seed(13), Network::get_beacon_seed(14));
+
+        let last_round = 10 + crate::randomness::MAX_BEACON_LOOKBACK_ROUNDS;
+        assert!(Network::get_beacon_seed(last_round).is_some());
+        assert_eq!(Network::get_beacon_seed(last_round + 1), None);
+    });
+}
+
+#[test]
+fn test_beacon_commit_reveal_finalize() {
+    new_test_ext().execute_with(|| {
+        let overwatch_node_id_1 = insert_beacon_overwatch_node(1);
+        let overwatch_node_id_2 = insert_beacon_overwatch_node(2);
+
+        MinBeaconReveals::<Test>::set(2);
+
+        let round = 1;
+        set_block_to_overwatch_commit_block(round);
+        assert_eq!(Network::get_current_overwatch_epoch_as_u32(), round);
+
+        let entropy_1 = H256::repeat_byte(1);
+        let entropy_2 = H256::repeat_byte(2);
+
+        assert_ok!(Network::commit_beacon_entropy(
+            RuntimeOrigin::signed(account(1)),
+            overwatch_node_id_1,
+            make_beacon_commit(entropy_1, overwatch_node_id_1)
+        ));
+        assert_ok!(Network::commit_beacon_entropy(
+            RuntimeOrigin::signed(account(2)),
+            overwatch_node_id_2,
+            make_beacon_commit(entropy_2, overwatch_node_id_2)
+        ));
+
+        assert_err!(
+            Network::commit_beacon_entropy(
+                RuntimeOrigin::signed(account(1)),
+                overwatch_node_id_1,
+                make_beacon_commit(entropy_1, overwatch_node_id_1)
+            ),
+            Error::<Test>::AlreadyCommitted
+        );
+
+        assert_err!(
+            Network::reveal_beacon_entropy(
+                RuntimeOrigin::signed(account(1)),
+                overwatch_node_id_1,
+                entropy_1
+            ),
+            Error::<Test>::NotRevealPeriod
+        );
+
+        set_block_to_overwatch_reveal_block(round);
+
+        assert_err!(
+            Network::reveal_beacon_entropy(
+                RuntimeOrigin::signed(account(1)),
+                overwatch_node_id_1,
+                entropy_2
+            ),
+            Error::<Test>::RevealMismatch
+        );
+
+        assert_ok!(Network::reveal_beacon_entropy(
+            RuntimeOrigin::signed(account(2)),
+            overwatch_node_id_2,
+            entropy_2
+        ));
+        assert_ok!(Network::reveal_beacon_entropy(
+            RuntimeOrigin::signed(account(1)),
+            overwatch_node_id_1,
+            entropy_1
+        ));
+
+        assert_err!(
+            Network::reveal_beacon_entropy(
+                RuntimeOrigin::signed(account(1)),
+                overwatch_node_id_1,
+                entropy_1
+            ),
+            Error::<Test>::NoCommitFound
+        );
+
+        let (accumulator, reveals) = BeaconRoundEntropy::<Test>::get(round).unwrap();
+        assert_eq!(reveals, 2);
+        // The revealed entropy is folded in, never the public commits
+        assert_eq!(accumulator, entropy_1 ^ entropy_2);
+        assert_ne!(
+            accumulator,
+            make_beacon_commit(entropy_1, overwatch_node_id_1)
+                ^ make_beacon_commit(entropy_2, overwatch_node_id_2)
+        );
+
+        set_overwatch_epoch(round + 1);
+        Network::finalize_beacon_round(round);
+
+        assert_eq!(
+            BeaconRandomness::<Test>::get(round),
+            Some(Hashing::hash_of(&(accumulator, round)))
+        );
+        assert_eq!(BeaconRoundEntropy::<Test>::get(round), None);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::BeaconRoundFinalized { round, reveals: 2 }
+        );
+    });
+}
+
+#[test]
+fn test_beacon_round_missed_below_min_reveals() {
+    new_test_ext().execute_with(|| {
+        let overwatch_node_id_1 = insert_beacon_overwatch_node(1);
+        let overwatch_node_id_2 = insert_beacon_overwatch_node(2);
+
+        MinBeaconReveals::<Test>::set(2);
+
+        let round = 1;
+        set_block_to_overwatch_commit_block(round);
+
+        let entropy = H256::repeat_byte(1);
+        assert_ok!(Network::perform_commit_beacon_entropy(
+            overwatch_node_id_1,
+            make_beacon_commit(entropy, overwatch_node_id_1)
+        ));
+        assert_ok!(Network::perform_commit_beacon_entropy(
+            overwatch_node_id_2,
+            make_beacon_commit(entropy, overwatch_node_id_2)
+        ));
+
+        set_block_to_overwatch_reveal_block(round);
+        assert_ok!(Network::perform_reveal_beacon_entropy(
+            overwatch_node_id_1,
+            entropy
+        ));
+
+        let validator_id_2 = OverwatchNodeValidatorId::<Test>::get(overwatch_node_id_2).unwrap();
+        let ow_score = ValidatorReputation::<Test>::get(validator_id_2).ow_score;
+        let stake = OverwatchNodeStakeBalance::<Test>::get(overwatch_node_id_2);
+
+        set_overwatch_epoch(round + 1);
+        Network::finalize_beacon_round(round);
+
+        assert_eq!(BeaconRandomness::<Test>::get(round), None);
+        // Unrevealed commits are cleared
+        assert_eq!(
+            BeaconEntropyCommits::<Test>::get(round, overwatch_node_id_2),
+            None
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::BeaconRoundMissed { round, reveals: 1 }
+        );
+
+        // The node that didn't reveal is penalized in full
+        let expected_ow_score = Network::decrease_rep(
+            ow_score,
+            OverwatchNonRevealReputationFactor::<Test>::get(),
+            Some(Network::percentage_factor_as_u128()),
+        );
+        let expected_stake_penalty =
+            Network::percent_mul(stake, OverwatchNonRevealStakePenalty::<Test>::get());
+        assert_eq!(
+            ValidatorReputation::<Test>::get(validator_id_2).ow_score,
+            expected_ow_score
+        );
+        assert_eq!(
+            OverwatchNodeStakeBalance::<Test>::get(overwatch_node_id_2),
+            stake - expected_stake_penalty
+        );
+        assert!(network_events().contains(&Event::BeaconNonRevealPenalty {
+            overwatch_node_id: overwatch_node_id_2,
+            round,
+            ow_score: expected_ow_score,
+            stake_penalty: expected_stake_penalty,
+        }));
+
+        // The node that revealed isn't
+        let validator_id_1 = OverwatchNodeValidatorId::<Test>::get(overwatch_node_id_1).unwrap();
+        assert!(!network_events().iter().any(|event| matches!(
+            event,
+            Event::BeaconNonRevealPenalty { overwatch_node_id, .. }
+                if *overwatch_node_id == overwatch_node_id_1
+        )));
+        assert_eq!(
+            ValidatorReputation::<Test>::get(validator_id_1).ow_score,
+            ow_score
+        );
+    });
+}
@@ -2,10 +2,12 @@ use super::mock::*;
 use crate::tests::test_utils::*;
 use crate::Event;
 use crate::{
-    AccountNodeDelegateStakeShares, ColdkeyValidatorId, MaxSubnetNodes,
+    AccountNodeDelegateStakeShares, AttestationWeighting, ColdkeyValidatorId, MaxSubnetNodes,
     MaxSubnets, MinSubnetMinStake, OverwatchCommits, OverwatchReveals, PeerIdOverwatchNodeId,
-    PeerInfo, SubnetBootnodes, SubnetElectedValidator, SubnetName, SubnetNodeClass,
+    PeerInfo, SubnetBootnodes, SubnetElectedValidator, SubnetLifecycleNotification, SubnetName,
+    SubnetNodeClass, SubnetNodeElectionSlots, SubnetNodeInfoFilter, SubnetState,
     TotalActiveSubnets, TotalNodeDelegateStakeBalance, TotalNodeDelegateStakeShares,
+    ValidatorElectionMode,
 };
 use frame_support::assert_ok;
 use frame_support::traits::{Currency, ExistenceRequirement};
@@ -365,9 +367,19 @@ fn test_get_elected_validator_info_v2() {
         let validator_info = Network::get_elected_validator_info(subnet_id, subnet_epoch);
 
         assert!(
-            validator_info.is_some(),
+            validator_info.validator.is_some(),
             "Elected validator info should exist"
         );
+        assert_eq!(validator_info.election_mode, ValidatorElectionMode::Uniform);
+
+        // Uniform election, every electable node is equally likely
+        let slot_list = SubnetNodeElectionSlots::<Test>::get(subnet_id);
+        let probability = Network::percentage_factor_as_u128() / slot_list.len() as u128;
+        assert_eq!(validator_info.election_probabilities.len(), slot_list.len());
+        assert!(validator_info
+            .election_probabilities
+            .values()
+            .all(|p| *p == probability));
     })
 }
 
@@ -383,7 +395,20 @@ fn test_get_validators_and_attestors() {
 
         let validators = Network::get_validators_and_attestors(subnet_id);
 
-        assert!(validators.len() == 12, "Should have validators/attestors");
+        assert!(
+            validators.validators.len() == 12,
+            "Should have validators/attestors"
+        );
+        assert_eq!(
+            validators.attestation_weighting,
+            AttestationWeighting::Count
+        );
+        assert_eq!(validators.attestation_ratio, None);
+
+        // Count weighting, every validator has an equal share
+        let share = Network::percentage_factor_as_u128() / 12;
+        assert_eq!(validators.attestation_weights.len(), 12);
+        assert!(validators.attestation_weights.values().all(|w| *w == share));
     })
 }
 
@@ -566,3 +591,277 @@ fn test_get_overwatch_commits_and_reveals_for_epoch_and_node() {
         assert!(!reveals.contains(&(subnet_id_1, other_weight)));
     })
 }
+
+#[test]
+fn test_get_subnet_nodes_info_paged() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet1".into(), 0, 3, deposit_amount, stake_amount);
+        build_activated_subnet("subnet2".into(), 0, 3, deposit_amount, stake_amount);
+
+        let mut expected: Vec<(u32, u32)> = Network::get_all_subnet_nodes_info()
+            .iter()
+            .map(|info| (info.subnet_id, info.subnet_node_id))
+            .collect();
+        expected.sort();
+
+        let mut paged: Vec<(u32, u32)> = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page =
+                Network::get_subnet_nodes_info_paged(SubnetNodeInfoFilter::default(), cursor, 2);
+            assert!(page.items.len() <= 2);
+            paged.extend(
+                page.items
+                    .iter()
+                    .map(|info| (info.subnet_id, info.subnet_node_id)),
+            );
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        paged.sort();
+
+        assert_eq!(paged, expected);
+    })
+}
+
+#[test]
+fn test_get_subnet_nodes_info_paged_filters() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet1".into(), 0, 3, deposit_amount, stake_amount);
+        build_activated_subnet("subnet2".into(), 0, 3, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(Vec::from("subnet2")).unwrap();
+
+        let page = Network::get_subnet_nodes_info_paged(
+            SubnetNodeInfoFilter {
+                subnet_id: Some(subnet_id),
+                ..Default::default()
+            },
+            None,
+            100,
+        );
+        assert_eq!(
+            page.items.len(),
+            Network::get_subnet_nodes_info(subnet_id).len()
+        );
+        assert!(page.items.iter().all(|info| info.subnet_id == subnet_id));
+        assert_eq!(page.next_cursor, None);
+
+        let validator_id = page
+            .items
+            .iter()
+            .find_map(|info| info.validator_id)
+            .unwrap();
+        let page = Network::get_subnet_nodes_info_paged(
+            SubnetNodeInfoFilter {
+                validator_id: Some(validator_id),
+                ..Default::default()
+            },
+            None,
+            100,
+        );
+        assert!(page.items.len() > 0);
+        assert!(page
+            .items
+            .iter()
+            .all(|info| info.validator_id == Some(validator_id)));
+
+        let page = Network::get_subnet_nodes_info_paged(
+            SubnetNodeInfoFilter {
+                min_class: Some(SubnetNodeClass::Validator),
+                min_stake: Some(stake_amount),
+                ..Default::default()
+            },
+            None,
+            100,
+        );
+        assert!(page.items.len() > 0);
+        assert!(page.items.iter().all(|info| {
+            info.classification.node_class == SubnetNodeClass::Validator
+                && info.stake_balance >= stake_amount
+        }));
+
+        let page = Network::get_subnet_nodes_info_paged(
+            SubnetNodeInfoFilter {
+                min_stake: Some(u128::MAX),
+                ..Default::default()
+            },
+            None,
+            100,
+        );
+        assert_eq!(page.items.len(), 0);
+
+        let page = Network::get_subnet_nodes_info_paged(
+            SubnetNodeInfoFilter {
+                min_reputation: Some(1),
+                max_reputation: Some(0),
+                ..Default::default()
+            },
+            None,
+            100,
+        );
+        assert_eq!(page.items.len(), 0);
+    })
+}
+
+#[test]
+fn test_get_all_subnets_info_paged() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet1".into(), 0, 3, deposit_amount, stake_amount);
+        build_activated_subnet("subnet2".into(), 0, 3, deposit_amount, stake_amount);
+        build_activated_subnet("subnet3".into(), 0, 3, deposit_amount, stake_amount);
+
+        let mut expected: Vec<u32> = Network::get_all_subnets_info()
+            .iter()
+            .map(|info| info.id)
+            .collect();
+        expected.sort();
+
+        let mut paged: Vec<u32> = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = Network::get_all_subnets_info_paged(cursor, 1);
+            assert_eq!(page.items.len(), 1);
+            paged.extend(page.items.iter().map(|info| info.id));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        paged.sort();
+
+        assert_eq!(paged, expected);
+    })
+}
+
+#[test]
+fn test_get_delegate_stakes_paged() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet1".into(), 0, 4, deposit_amount, stake_amount);
+        build_activated_subnet("subnet2".into(), 0, 4, deposit_amount, stake_amount);
+
+        let delegator = account(100);
+        let _ = Balances::deposit_creating(&delegator, 2 * 1000000000000000000000 + 500);
+
+        for subnet_name in ["subnet1", "subnet2"] {
+            let subnet_id = SubnetName::<Test>::get(Vec::from(subnet_name)).unwrap();
+            assert_ok!(Network::add_delegate_stake(
+                RuntimeOrigin::signed(delegator.clone()),
+                subnet_id,
+                1000000000000000000000
+            ));
+        }
+
+        let first = Network::get_delegate_stakes_paged(delegator.clone(), None, 1);
+        assert_eq!(first.items.len(), 1);
+        assert!(first.next_cursor.is_some());
+
+        let second = Network::get_delegate_stakes_paged(delegator.clone(), first.next_cursor, 1);
+        assert_eq!(second.items.len(), 1);
+        assert_eq!(second.next_cursor, None);
+        assert_ne!(first.items[0].subnet_id, second.items[0].subnet_id);
+
+        let mut all = Network::get_delegate_stakes(delegator);
+        let mut paged = vec![first.items[0].clone(), second.items[0].clone()];
+        all.sort();
+        paged.sort();
+        assert_eq!(paged, all);
+    })
+}
+
+#[test]
+fn test_get_subnet_lifecycle_notifications_elected_validator() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet1".into(), 0, 4, deposit_amount, stake_amount);
+        build_activated_subnet("subnet2".into(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(Vec::from("subnet1")).unwrap();
+        let other_subnet_id = SubnetName::<Test>::get(Vec::from("subnet2")).unwrap();
+
+        System::reset_events();
+
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id) + 1;
+        Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+        Network::elect_validator(other_subnet_id, subnet_epoch, System::block_number());
+
+        let subnet_node_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+
+        let notifications =
+            Network::get_subnet_lifecycle_notifications(network_events(), Some(subnet_id));
+        assert_eq!(
+            notifications,
+            vec![SubnetLifecycleNotification::ValidatorElected {
+                subnet_id,
+                subnet_epoch,
+                subnet_node_id,
+            }]
+        );
+        assert_eq!(notifications[0].subnet_node_id(), Some(subnet_node_id));
+
+        let all_notifications = Network::get_subnet_lifecycle_notifications(network_events(), None);
+        assert_eq!(all_notifications.len(), 2);
+    })
+}
+
+#[test]
+fn test_get_subnet_lifecycle_notifications_from_events() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let events = vec![
+            Event::SubnetPaused {
+                subnet_id,
+                owner: account(0),
+            },
+            Event::SetMaxSubnets(10),
+            Event::QueuedNodeRemoved {
+                subnet_id,
+                subnet_node_id: 3,
+            },
+            Event::ValidatorSubmission {
+                subnet_id,
+                account_id: account(1),
+                epoch: 7,
+            },
+            Event::SubnetUnpaused {
+                subnet_id: 2,
+                owner: account(0),
+            },
+        ];
+
+        let notifications = Network::get_subnet_lifecycle_notifications(events, Some(subnet_id));
+
+        assert_eq!(
+            notifications,
+            vec![
+                SubnetLifecycleNotification::SubnetStateChanged {
+                    subnet_id,
+                    state: SubnetState::Paused,
+                },
+                SubnetLifecycleNotification::SubnetNodeRemoved {
+                    subnet_id,
+                    subnet_node_id: 3,
+                },
+                SubnetLifecycleNotification::AttestationWindowOpen {
+                    subnet_id,
+                    subnet_epoch: 7,
+                },
+            ]
+        );
+        assert_eq!(notifications[2].subnet_node_id(), None);
+    })
+}
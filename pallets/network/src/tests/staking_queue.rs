@@ -1,12 +1,14 @@
 use super::mock::*;
+use crate::stake::staking_queue::MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT;
 use crate::tests::test_utils::*;
 use crate::Event;
 use crate::{
-    AccountSubnetDelegateStakeShares, AccountValidatorDelegateStakeShares,
-    DelegateStakeCooldownEpochs, Error, MaxSubnetNodes, MaxSubnets, MinSubnetMinStake, NextSwapQueueId,
-    QueuedSwapCall, QueuedSwapItem, StakeUnbondingLedger, SubnetName, SwapCallQueue,
-    SwapQueueOrder, TotalSubnetDelegateStakeBalance, TotalSubnetDelegateStakeShares,
-    ValidatorDelegateStakeBalance, ValidatorDelegateStakeShares,
+    AccountSubnetDelegateStakeShares, AccountSwapQueueCalls, AccountValidatorDelegateStakeShares,
+    DelegateStakeCooldownEpochs, Error, HoldReason, MaxSubnetNodes, MaxSubnets,
+    MaxSwapQueueCallsPerBlock, MinSubnetMinStake, NextSwapQueueId, QueuedSwapCall, QueuedSwapItem,
+    StakeUnbondingLedger, SubnetName, SwapCallDeposits, SwapCallQueue, SwapQueueCursor,
+    SwapQueueDeposit, SwapQueueOrder, TotalSubnetDelegateStakeBalance,
+    TotalSubnetDelegateStakeShares, ValidatorDelegateStakeBalance, ValidatorDelegateStakeShares,
 };
 use frame_support::assert_err;
 use frame_support::assert_ok;
@@ -221,7 +223,7 @@ fn test_update_swap_queue_delegate_stake() {
                 assert_eq!(*to_subnet_id, starting_to_subnet_id);
                 assert_ne!(*balance, 0);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         let next_id = NextSwapQueueId::<Test>::get();
@@ -277,7 +279,7 @@ fn test_update_swap_queue_delegate_stake() {
                 assert_ne!(*balance, 0);
                 assert_ne!(*balance, u128::MAX);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         //
@@ -316,7 +318,6 @@ fn test_update_swap_queue_delegate_stake() {
         let call_queue = SwapCallQueue::<Test>::get(prev_next_id);
         assert_eq!(call_queue.clone().unwrap().id, prev_next_id);
         match &call_queue.clone().unwrap().call {
-            QueuedSwapCall::SwapToSubnetDelegateStake { .. } => assert!(false),
             QueuedSwapCall::SwapToValidatorDelegateStake {
                 account_id,
                 to_validator_id,
@@ -326,6 +327,7 @@ fn test_update_swap_queue_delegate_stake() {
                 assert_ne!(*balance, 0);
                 assert_ne!(*balance, u128::MAX);
             }
+            _ => assert!(false),
         };
     });
 }
@@ -440,7 +442,6 @@ fn test_update_swap_queue_node_delegate_stake() {
         let call_queue = SwapCallQueue::<Test>::get(prev_next_id);
         assert_eq!(call_queue.clone().unwrap().id, prev_next_id);
         match &call_queue.clone().unwrap().call {
-            QueuedSwapCall::SwapToSubnetDelegateStake { .. } => assert!(false),
             QueuedSwapCall::SwapToValidatorDelegateStake {
                 account_id,
                 to_validator_id,
@@ -449,6 +450,7 @@ fn test_update_swap_queue_node_delegate_stake() {
                 assert_eq!(*account_id, account(n_account));
                 assert_eq!(*to_validator_id, starting_to_validator_id);
             }
+            _ => assert!(false),
         };
 
         let next_id = NextSwapQueueId::<Test>::get();
@@ -504,7 +506,7 @@ fn test_update_swap_queue_node_delegate_stake() {
                 assert_ne!(*balance, 0);
                 assert_ne!(*balance, u128::MAX);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         //
@@ -543,7 +545,6 @@ fn test_update_swap_queue_node_delegate_stake() {
         let call_queue = SwapCallQueue::<Test>::get(prev_next_id);
         assert_eq!(call_queue.clone().unwrap().id, prev_next_id);
         match &call_queue.clone().unwrap().call {
-            QueuedSwapCall::SwapToSubnetDelegateStake { .. } => assert!(false),
             QueuedSwapCall::SwapToValidatorDelegateStake {
                 account_id,
                 to_validator_id,
@@ -552,6 +553,7 @@ fn test_update_swap_queue_node_delegate_stake() {
                 assert_eq!(*account_id, account(n_account));
                 assert_ne!(*balance, u128::MAX);
             }
+            _ => assert!(false),
         };
     });
 }
@@ -610,7 +612,7 @@ fn test_execute_ready_swap_calls() {
                         assert_ne!(*balance, 0);
                         assert_ne!(*balance, u128::MAX);
                     }
-                    QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+                    _ => assert!(false),
                 };
             } else {
                 //
@@ -650,3 +652,420 @@ fn test_execute_ready_swap_calls() {
         }
     });
 }
+
+#[test]
+fn test_schedule_remove_delegate_stake() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+        let _ = Balances::deposit_creating(
+            &account(n_account),
+            amount + 500 + SwapQueueDeposit::<Test>::get(),
+        );
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            subnet_id,
+            amount,
+        ));
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id);
+
+        assert_err!(
+            Network::schedule_remove_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                subnet_id,
+                delegate_shares + 1,
+                10,
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+
+        let id = NextSwapQueueId::<Test>::get();
+        assert_ok!(Network::schedule_remove_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            subnet_id,
+            delegate_shares,
+            10,
+        ));
+
+        // Scheduled calls hold the deposit until they leave the queue
+        let deposit = SwapQueueDeposit::<Test>::get();
+        assert_eq!(SwapCallDeposits::<Test>::get(id), Some(deposit));
+        assert_eq!(
+            Network::get_stake_hold(&account(n_account), HoldReason::SwapQueueDeposit),
+            deposit
+        );
+        assert_eq!(AccountSwapQueueCalls::<Test>::get(account(n_account)), 1);
+
+        let item = SwapCallQueue::<Test>::get(id).unwrap();
+        assert_eq!(item.execute_after_blocks, 10);
+        assert_eq!(
+            item.call,
+            QueuedSwapCall::RemoveSubnetDelegateStake {
+                account_id: account(n_account),
+                subnet_id,
+                shares: delegate_shares,
+            }
+        );
+
+        // Not ready
+        System::set_block_number(System::block_number() + 5);
+        Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+        assert!(SwapCallQueue::<Test>::get(id).is_some());
+        assert_eq!(
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id),
+            delegate_shares
+        );
+
+        System::set_block_number(System::block_number() + 5);
+        Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+        assert!(SwapCallQueue::<Test>::get(id).is_none());
+        assert_eq!(SwapQueueOrder::<Test>::get().len(), 0);
+        assert_eq!(
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id),
+            0
+        );
+        assert_eq!(SwapCallDeposits::<Test>::get(id), None);
+        assert_eq!(
+            Network::get_stake_hold(&account(n_account), HoldReason::SwapQueueDeposit),
+            0
+        );
+        assert_eq!(AccountSwapQueueCalls::<Test>::get(account(n_account)), 0);
+
+        let unbondings = StakeUnbondingLedger::<Test>::get(account(n_account));
+        assert_eq!(unbondings.len(), 1);
+        assert!(unbondings.values().all(|balance| *balance > 0));
+    });
+}
+
+#[test]
+fn test_schedule_transfer_delegate_stake_skips_calls_not_ready() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+        let to_account = 256;
+        let _ = Balances::deposit_creating(
+            &account(n_account),
+            amount + 500 + 2 * SwapQueueDeposit::<Test>::get(),
+        );
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            subnet_id,
+            amount,
+        ));
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id);
+
+        assert_err!(
+            Network::schedule_transfer_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                subnet_id,
+                account(n_account),
+                delegate_shares,
+                10,
+            ),
+            Error::<Test>::TransferToSelfNotAllowed
+        );
+
+        // Queued first with a longer delay
+        let remove_id = NextSwapQueueId::<Test>::get();
+        assert_ok!(Network::schedule_remove_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            subnet_id,
+            delegate_shares / 2,
+            100,
+        ));
+
+        let transfer_id = NextSwapQueueId::<Test>::get();
+        assert_ok!(Network::schedule_transfer_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            subnet_id,
+            account(to_account),
+            delegate_shares / 2,
+            10,
+        ));
+
+        System::set_block_number(System::block_number() + 10);
+        Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+
+        // The transfer isn't held back by the removal queued before it
+        assert!(SwapCallQueue::<Test>::get(transfer_id).is_none());
+        assert!(SwapCallQueue::<Test>::get(remove_id).is_some());
+        assert_eq!(SwapQueueOrder::<Test>::get().to_vec(), vec![remove_id]);
+
+        assert_eq!(
+            AccountSubnetDelegateStakeShares::<Test>::get(account(to_account), subnet_id),
+            delegate_shares / 2
+        );
+        assert_eq!(
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id),
+            delegate_shares - delegate_shares / 2
+        );
+    });
+}
+
+#[test]
+fn test_schedule_rebalance_delegate_stake() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let from_subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(from_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let from_subnet_id = SubnetName::<Test>::get(from_subnet_name.clone()).unwrap();
+
+        let to_subnet_name: Vec<u8> = "subnet-name-2".into();
+        build_activated_subnet(to_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let to_subnet_id = SubnetName::<Test>::get(to_subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+        let _ = Balances::deposit_creating(
+            &account(n_account),
+            amount + 500 + SwapQueueDeposit::<Test>::get(),
+        );
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            amount,
+        ));
+
+        let half: u128 = 500000000000000000;
+
+        assert_err!(
+            Network::schedule_rebalance_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                vec![(from_subnet_id, half), (to_subnet_id, half - 1)],
+                10,
+            ),
+            Error::<Test>::InvalidRebalanceTargets
+        );
+        assert_err!(
+            Network::schedule_rebalance_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                vec![(from_subnet_id, half), (from_subnet_id, half)],
+                10,
+            ),
+            Error::<Test>::InvalidRebalanceTargets
+        );
+        assert_err!(
+            Network::schedule_rebalance_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                vec![(from_subnet_id, half), (to_subnet_id, half)],
+                0,
+            ),
+            Error::<Test>::InvalidRebalanceInterval
+        );
+
+        let id = NextSwapQueueId::<Test>::get();
+        assert_ok!(Network::schedule_rebalance_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            vec![(from_subnet_id, half), (to_subnet_id, half)],
+            10,
+        ));
+
+        let starting_balance =
+            Network::convert_account_shares_to_balance(&account(n_account), from_subnet_id);
+
+        System::set_block_number(System::block_number() + 10);
+        Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+
+        let from_balance =
+            Network::convert_account_shares_to_balance(&account(n_account), from_subnet_id);
+        let to_balance =
+            Network::convert_account_shares_to_balance(&account(n_account), to_subnet_id);
+        assert!(to_balance > 0);
+        assert!(from_balance.abs_diff(starting_balance / 2) < starting_balance / 1000);
+        assert!(to_balance.abs_diff(starting_balance / 2) < starting_balance / 1000);
+
+        // Recurring calls are queued again
+        let item = SwapCallQueue::<Test>::get(id).unwrap();
+        assert_eq!(item.queued_at_block, System::block_number());
+        assert_eq!(item.execute_after_blocks, 10);
+        assert_eq!(SwapQueueOrder::<Test>::get().to_vec(), vec![id]);
+        assert_eq!(
+            Network::get_stake_hold(&account(n_account), HoldReason::SwapQueueDeposit),
+            SwapQueueDeposit::<Test>::get()
+        );
+    });
+}
+
+#[test]
+fn test_cancel_swap_queue() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let from_subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(from_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let from_subnet_id = SubnetName::<Test>::get(from_subnet_name.clone()).unwrap();
+
+        let to_subnet_name: Vec<u8> = "subnet-name-2".into();
+        build_activated_subnet(to_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let to_subnet_id = SubnetName::<Test>::get(to_subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+        let other_account = 256;
+        let _ = Balances::deposit_creating(&account(n_account), amount + 500);
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            amount,
+        ));
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), from_subnet_id);
+
+        let id = NextSwapQueueId::<Test>::get();
+        assert_ok!(Network::swap_from_subnet_to_subnet(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            to_subnet_id,
+            delegate_shares,
+        ));
+
+        let item = SwapCallQueue::<Test>::get(id).unwrap();
+        let balance = item.call.get_queue_balance();
+        assert_ne!(balance, 0);
+
+        // Only the owner can update or cancel
+        assert_err!(
+            Network::update_swap_queue(
+                RuntimeOrigin::signed(account(other_account)),
+                id,
+                QueuedSwapCall::SwapToSubnetDelegateStake {
+                    account_id: account(other_account),
+                    to_subnet_id: from_subnet_id,
+                    balance: 0,
+                },
+            ),
+            Error::<Test>::NotKeyOwner
+        );
+        assert_err!(
+            Network::cancel_swap_queue(RuntimeOrigin::signed(account(other_account)), id),
+            Error::<Test>::NotKeyOwner
+        );
+
+        assert_ok!(Network::cancel_swap_queue(
+            RuntimeOrigin::signed(account(n_account)),
+            id
+        ));
+
+        assert!(SwapCallQueue::<Test>::get(id).is_none());
+        assert_eq!(SwapQueueOrder::<Test>::get().len(), 0);
+
+        // The swapped balance is unbonding
+        let unbondings = StakeUnbondingLedger::<Test>::get(account(n_account));
+        assert_eq!(unbondings.values().sum::<u128>(), balance);
+
+        assert!(network_events().iter().any(|event| matches!(
+            event,
+            Event::SwapCallCancelled { id: cancelled_id, account_id, .. }
+                if *cancelled_id == id && *account_id == account(n_account)
+        )));
+
+        assert_err!(
+            Network::cancel_swap_queue(RuntimeOrigin::signed(account(n_account)), id),
+            Error::<Test>::SwapCallNotFound
+        );
+    });
+}
+
+#[test]
+fn test_queue_swap_per_account_cap() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT {
+            assert_ok!(Network::queue_swap(
+                account(1),
+                QueuedSwapCall::SwapToSubnetDelegateStake {
+                    account_id: account(1),
+                    to_subnet_id: 1,
+                    balance: 1,
+                },
+            ));
+        }
+        assert_eq!(
+            AccountSwapQueueCalls::<Test>::get(account(1)),
+            MAX_SWAP_QUEUE_CALLS_PER_ACCOUNT
+        );
+
+        assert_err!(
+            Network::queue_swap(
+                account(1),
+                QueuedSwapCall::SwapToSubnetDelegateStake {
+                    account_id: account(1),
+                    to_subnet_id: 1,
+                    balance: 1,
+                },
+            ),
+            Error::<Test>::TooManyQueuedSwapCalls
+        );
+
+        // Other accounts are not affected
+        assert_ok!(Network::queue_swap(
+            account(2),
+            QueuedSwapCall::SwapToSubnetDelegateStake {
+                account_id: account(2),
+                to_subnet_id: 1,
+                balance: 1,
+            },
+        ));
+    });
+}
+
+#[test]
+fn test_execute_ready_swap_calls_counts_every_read() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        MaxSwapQueueCallsPerBlock::<Test>::set(2);
+
+        // Calls that are not ready are queued before a ready call
+        for n in 0..3 {
+            insert_to_subnet_swap_call_queue(account(n), subnet_id, amount);
+        }
+        let ready_id = NextSwapQueueId::<Test>::get();
+        insert_to_subnet_swap_call_queue(account(3), subnet_id, amount);
+        SwapCallQueue::<Test>::mutate(ready_id, |item| {
+            item.as_mut().unwrap().execute_after_blocks = 0;
+        });
+
+        // Only 2 calls are read
+        Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+        assert!(SwapCallQueue::<Test>::get(ready_id).is_some());
+        assert_eq!(SwapQueueCursor::<Test>::get(), 2);
+
+        // The next block continues from the cursor
+        Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+        assert!(SwapCallQueue::<Test>::get(ready_id).is_none());
+        assert!(AccountSubnetDelegateStakeShares::<Test>::get(account(3), subnet_id) > 0);
+        assert_eq!(SwapQueueOrder::<Test>::get().len(), 3);
+    });
+}
@@ -17,9 +17,8 @@ fn test_do_epoch_preliminaries_remove_expired_pause() {
     new_test_ext().execute_with(|| {
         NewRegistrationCostMultiplier::<Test>::put(1000000000000000000);
 
-        let target = Network::get_percent_as_f64(MinSubnetReputation::<Test>::get());
-        let factor =
-            Network::get_percent_as_f64(MaxPauseEpochsSubnetReputationFactor::<Test>::get());
+        let target = MinSubnetReputation::<Test>::get() as f64 / 1e18;
+        let factor = MaxPauseEpochsSubnetReputationFactor::<Test>::get() as f64 / 1e18;
         // iters required to get to MinSubnetReputation
         let steps = ((target / 1.0).ln() / (1.0 - factor).ln()) as u32;
         log::error!("steps {:?}", steps);
@@ -2825,6 +2825,9 @@ fn test_get_total_network_issuance() {
         let one_pct = Network::percent_mul(starting_total_network_issuance, 10000000000000000);
 
         assert!(starting_total_network_issuance.abs_diff(post_total_network_issuance) < one_pct);
+
+        // Held stake is part of the currency issuance and is not counted twice
+        assert_eq!(post_total_network_issuance, Balances::total_issuance());
     });
 }
 
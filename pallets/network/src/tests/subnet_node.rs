@@ -3,11 +3,12 @@ use crate::tests::test_utils::*;
 use crate::Event;
 use crate::{
     BootnodePeerIdSubnetNodeId, ClientPeerIdSubnetNodeId, ConsensusSubmissionData,
-    CurrentNodeBurnRate, Error, InitialValidatorData,
-    MaxDelegateStakePercentage, MaxRegisteredNodes, MaxRewardRateDecrease, MaxSubnetNodes,
-    MaxSubnets, MinSubnetMinStake, MinSubnetNodes, MultiaddrSubnetNodeId,
-    NodeRegistrationInitialValidatorIds, NodeRewardRateUpdatePeriod, NodeSlotIndex,
-    NodeSubnetStake, PeerIdSubnetNodeId, PeerInfo, RegisteredSubnetNodesData,
+    CurrentNodeBurnRate, Error, InitialValidatorData, MaxDelegateStakePercentage,
+    MaxRegisteredNodes, MaxRewardRateDecrease, MaxSubnetNodes, MaxSubnets, MinSubnetMinStake,
+    MinSubnetNodes, MultiaddrSubnetNodeId, NodeRegistrationInitialValidatorIds,
+    NodeRewardRateUpdatePeriod, NodeSlotIndex, NodeSubnetPendingSlash, NodeSubnetStake,
+    PeerIdSubnetNodeId, PeerInfo, PendingSlashExecuteEpoch, PendingSlashes,
+    PendingSlashesAppliedEpoch, RegisteredSubnetNodesData, SlashAppealEpochs,
     SubnetElectedValidator, SubnetMinStakeBalance, SubnetName, SubnetNode, SubnetNodeClass,
     SubnetNodeClassification, SubnetNodeElectionSlots, SubnetNodeIdHotkey, SubnetNodeQueue,
     SubnetNodeQueueEpochs, SubnetNodeReputation, SubnetNodeValidatorId, SubnetNodesData,
@@ -608,8 +609,7 @@ fn test_register_subnet_node_and_then_update_a_param_v2() {
         let validator_id = TotalValidatorIds::<Test>::get();
 
         let unique: Vec<u8> = "a".into();
-        let bounded_unique: NetworkBytes<Test> =
-            unique.try_into().expect("String too long");
+        let bounded_unique: NetworkBytes<Test> = unique.try_into().expect("String too long");
 
         assert_ok!(Network::register_subnet_node(
             RuntimeOrigin::signed(coldkey.clone()),
@@ -925,8 +925,7 @@ fn test_remove_subnet_node_registered_v2() {
         let _ = Balances::deposit_creating(&coldkey.clone(), deposit_amount + burn_amount);
 
         let unique: Vec<u8> = "a".into();
-        let bounded_unique: NetworkBytes<Test> =
-            unique.try_into().expect("String too long");
+        let bounded_unique: NetworkBytes<Test> = unique.try_into().expect("String too long");
 
         let non_unique: Vec<u8> = "a".into();
         let bounded_non_unique: NetworkBytes<Test> =
@@ -3090,8 +3089,7 @@ fn test_update_node_unique() {
         let subnet_node = SubnetNodesData::<Test>::get(subnet_id, subnet_node_id);
 
         let unique: Vec<u8> = "a".into();
-        let bounded_unique: NetworkBytes<Test> =
-            unique.try_into().expect("String too long");
+        let bounded_unique: NetworkBytes<Test> = unique.try_into().expect("String too long");
 
         // sanity check
         assert_eq!(
@@ -3213,8 +3211,7 @@ fn test_update_unique_to_none() {
         let subnet_node = SubnetNodesData::<Test>::get(subnet_id, subnet_node_id);
 
         let unique: Vec<u8> = "a".into();
-        let bounded_unique: NetworkBytes<Test> =
-            unique.try_into().expect("String too long");
+        let bounded_unique: NetworkBytes<Test> = unique.try_into().expect("String too long");
 
         // sanity check
         assert_eq!(
@@ -4249,6 +4246,7 @@ fn test_slash_validator() {
             100000000000000000, // 10%
             1,
             1,
+            1,
             Network::get_reputation_factors_for_epoch(subnet_id, 1)
                 .validator_non_consensus_decrease,
         );
@@ -4256,12 +4254,240 @@ fn test_slash_validator() {
         assert!(starting_node_rep > SubnetNodeReputation::<Test>::get(subnet_id, subnet_node_id));
         assert!(starting_ck_rep > ValidatorReputation::<Test>::get(validator_id).score);
 
-        assert!(starting_account_stake > NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id));
+        // ⸺ Stake is untouched until the appeal window passes
+        let (_, _, pending_slash) = PendingSlashes::<Test>::iter().next().unwrap();
+        assert_eq!(pending_slash.subnet_id, subnet_id);
+        assert_eq!(pending_slash.subnet_node_id, subnet_node_id);
+        assert_eq!(pending_slash.validator_id, Some(validator_id));
+        assert_eq!(pending_slash.epoch, 1);
+        assert_eq!(pending_slash.subnet_epoch, 1);
+        assert_eq!(
+            pending_slash.execute_epoch,
+            1 + SlashAppealEpochs::<Test>::get()
+        );
+        assert!(pending_slash.amount > 0);
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id),
+            starting_account_stake
+        );
+
+        Network::apply_pending_slashes(&mut WeightMeter::new(), pending_slash.execute_epoch - 1);
+        assert!(PendingSlashes::<Test>::get(pending_slash.execute_epoch, 0).is_some());
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id),
+            starting_account_stake
+        );
+
+        Network::apply_pending_slashes(&mut WeightMeter::new(), pending_slash.execute_epoch);
+        assert!(PendingSlashes::<Test>::get(pending_slash.execute_epoch, 0).is_none());
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::PendingSlashApplied {
+                id: 0,
+                subnet_id,
+                subnet_node_id,
+                amount: pending_slash.amount,
+            }
+        );
+
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id),
+            starting_account_stake - pending_slash.amount
+        );
         assert!(starting_total_subnet_stake > TotalSubnetStake::<Test>::get(subnet_id));
         assert!(starting_total_stake > TotalStake::<Test>::get());
     });
 }
 
+#[test]
+fn test_slash_validator_without_appeal_window() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let subnet_node_id = end;
+        let starting_account_stake = NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id);
+
+        SlashAppealEpochs::<Test>::set(0);
+
+        Network::slash_validator(
+            subnet_id,
+            subnet_node_id,
+            500000000000000000, // 50%
+            660000000000000000, // 66%
+            100000000000000000, // 10%
+            100000000000000000, // 10%
+            1,
+            1,
+            1,
+            Network::get_reputation_factors_for_epoch(subnet_id, 1)
+                .validator_non_consensus_decrease,
+        );
+
+        assert_eq!(PendingSlashes::<Test>::iter().count(), 0);
+        assert!(starting_account_stake > NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id));
+    });
+}
+
+#[test]
+fn test_cancel_pending_slash() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let subnet_node_id = end;
+        let starting_account_stake = NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id);
+
+        Network::slash_validator(
+            subnet_id,
+            subnet_node_id,
+            500000000000000000, // 50%
+            660000000000000000, // 66%
+            100000000000000000, // 10%
+            100000000000000000, // 10%
+            1,
+            1,
+            1,
+            Network::get_reputation_factors_for_epoch(subnet_id, 1)
+                .validator_non_consensus_decrease,
+        );
+
+        let (_, _, pending_slash) = PendingSlashes::<Test>::iter().next().unwrap();
+
+        assert_err!(
+            Network::cancel_pending_slash(RuntimeOrigin::signed(account(0)), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Network::cancel_pending_slash(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            0
+        ));
+        assert!(PendingSlashes::<Test>::get(pending_slash.execute_epoch, 0).is_none());
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::PendingSlashCancelled {
+                id: 0,
+                subnet_id,
+                subnet_node_id,
+                amount: pending_slash.amount,
+            }
+        );
+
+        // ⸺ Cancelled slashes are never applied
+        Network::apply_pending_slashes(&mut WeightMeter::new(), pending_slash.execute_epoch);
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id),
+            starting_account_stake
+        );
+
+        assert_err!(
+            Network::cancel_pending_slash(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                0
+            ),
+            Error::<Test>::PendingSlashNotFound
+        );
+    });
+}
+
+#[test]
+fn test_pending_slash_locks_node_stake() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let subnet_node_id = end;
+        let validator_id = SubnetNodeValidatorId::<Test>::get(subnet_id, subnet_node_id).unwrap();
+        let coldkey = ValidatorColdkey::<Test>::get(validator_id).unwrap();
+        let stake = NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id);
+        let epoch = Network::get_current_epoch_as_u32();
+
+        // ⸺ The slash is capped by the unlocked stake
+        Network::queue_slash(subnet_id, subnet_node_id, stake * 2, epoch, 1);
+        Network::queue_slash(subnet_id, subnet_node_id, stake, epoch, 1);
+        assert_eq!(PendingSlashes::<Test>::iter().count(), 1);
+
+        let (execute_epoch, id, pending_slash) = PendingSlashes::<Test>::iter().next().unwrap();
+        assert_eq!(pending_slash.amount, stake);
+        assert_eq!(
+            PendingSlashExecuteEpoch::<Test>::get(id),
+            Some(execute_epoch)
+        );
+        assert_eq!(
+            NodeSubnetPendingSlash::<Test>::get(subnet_node_id, subnet_id),
+            stake
+        );
+
+        // ⸺ Locked stake can't leave during the appeal window
+        assert_err!(
+            Network::remove_node_stake(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                1,
+            ),
+            Error::<Test>::StakeLockedByPendingSlash
+        );
+
+        Network::apply_pending_slashes(&mut WeightMeter::new(), execute_epoch);
+        assert_eq!(NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id), 0);
+        assert_eq!(
+            NodeSubnetPendingSlash::<Test>::get(subnet_node_id, subnet_id),
+            0
+        );
+        assert_eq!(PendingSlashExecuteEpoch::<Test>::get(id), None);
+    });
+}
+
+#[test]
+fn test_apply_pending_slashes_catches_up_skipped_epochs() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let subnet_node_id = end;
+        let stake = NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id);
+        let appeal_epochs = SlashAppealEpochs::<Test>::get();
+        let epoch = Network::get_current_epoch_as_u32();
+
+        Network::apply_pending_slashes(&mut WeightMeter::new(), epoch);
+        Network::queue_slash(subnet_id, subnet_node_id, 100, epoch, 1);
+
+        // ⸺ Epochs between the last applied epoch and the current epoch are applied
+        Network::apply_pending_slashes(&mut WeightMeter::new(), epoch + appeal_epochs + 5);
+        assert_eq!(PendingSlashes::<Test>::iter().count(), 0);
+        assert_eq!(
+            NodeSubnetStake::<Test>::get(subnet_node_id, subnet_id),
+            stake - 100
+        );
+        assert_eq!(
+            PendingSlashesAppliedEpoch::<Test>::get(),
+            Some(epoch + appeal_epochs + 5)
+        );
+    });
+}
+
 // // #[test]
 // // fn test_slash_validator_removes_subnet_node() {
 // //     new_test_ext().execute_with(|| {
@@ -2,7 +2,8 @@ use super::mock::*;
 pub use crate::NetworkBytes;
 use crate::Event;
 use crate::{
-    multiaddr::*, AccountSubnetDelegateStakeShares, AttestEntry, BootnodePeerIdSubnetNodeId,
+    multiaddr::*, AccountSubnetDelegateStakeShares, AttestEntry, AttestationWeighting,
+    BootnodePeerIdSubnetNodeId,
     ClientPeerIdSubnetNodeId, ColdkeyValidatorId, ConsensusData, DelegateAccount,
     EmergencySubnetNodeElectionData, HotkeyValidatorId, InitialValidatorData, MaxMaxRegisteredNodes,
     MaxOverwatchNodes, MaxSubnetNodes, MaxSubnets, MinSubnetMinStake,
@@ -1768,6 +1769,7 @@ pub fn get_simulated_consensus_data(subnet_id: u32, node_count: u32) -> Consensu
                 block: block_number,
                 attestor_progress: 0,
                 reward_factor: Network::percentage_factor_as_u128(),
+                attestation_weight: 1,
                 data: None,
             },
         );
@@ -1791,6 +1793,12 @@ pub fn get_simulated_consensus_data(subnet_id: u32, node_count: u32) -> Consensu
         SubnetNodeElectionSlots::<Test>::get(subnet_id)
     };
 
+    let total_attestation_weight = Network::get_total_attestation_weight(
+        subnet_id,
+        &validator_ids,
+        AttestationWeighting::Count,
+    );
+
     ConsensusData::<Test> {
         validator_id: subnet_id * max_subnet_nodes,
         block: block_number,
@@ -1798,6 +1806,8 @@ pub fn get_simulated_consensus_data(subnet_id: u32, node_count: u32) -> Consensu
         validator_reward_factor: Network::percentage_factor_as_u128(),
         validator_ids,
         attests,
+        attestation_weighting: AttestationWeighting::Count,
+        total_attestation_weight,
         data,
         prioritize_queue_node_id: None,
         remove_queue_node_id: None,
@@ -1,18 +1,21 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
+use crate::Event;
 use crate::{
     AccountNodeDelegateStakeShares, AccountSubnetDelegateStakeShares,
-    AccountValidatorDelegateStakeShares, DelegateAccountStake, DelegateStakeCooldownEpochs, Error,
-    MaxSubnetNodes, MaxSubnets, MaxUnbondings, MinSubnetMinStake, NodeDelegateStakeCooldownEpochs,
-    NodeSubnetStake, OverwatchMinStakeBalance, OverwatchNodeStakeBalance, PeerInfo,
-    RegisteredSubnetNodesData, StakeCooldownEpochs, StakeUnbondingLedger, SubnetName,
-    SubnetNodeQueueEpochs, TotalAccountDelegateStake, TotalActiveSubnets, TotalDelegateStake,
-    TotalOverwatchNodeStakeBalance, TotalStake, TotalSubnetDelegateStakeBalance,
-    TotalSubnetDelegateStakeShares, TotalSubnetNodeUids, TotalSubnetNodes, TotalSubnetStake,
-    TotalUnbondingBalance, TotalValidatorDelegateStakeBalance, TotalValidatorIds,
-    ValidatorDelegateStakeBalance, ValidatorDelegateStakeShares, ValidatorSubnetNodes,
+    AccountValidatorDelegateStakeShares, DelegateAccountStake, DelegateStakeCooldownEpochs,
+    EarlyUnbondingPenalty, Error, HoldReason, MaxSubnetNodes, MaxSubnets, MaxUnbondings,
+    MinSubnetMinStake, NodeDelegateStakeCooldownEpochs, NodeSubnetStake, OverwatchMinStakeBalance,
+    OverwatchNodeStakeBalance, PeerInfo, RegisteredSubnetNodesData, RestakeTarget,
+    StakeCooldownEpochs, StakeHoldsMigration, StakeHoldsMigrationStage, StakeUnbondingLedger,
+    SubnetName, SubnetNodeQueueEpochs, SubnetNodeValidatorId, TotalAccountDelegateStake,
+    TotalActiveSubnets, TotalDelegateStake, TotalOverwatchNodeStakeBalance, TotalStake,
+    TotalSubnetDelegateStakeBalance, TotalSubnetDelegateStakeShares, TotalSubnetNodeUids,
+    TotalSubnetNodes, TotalSubnetStake, TotalUnbondingBalance, TotalValidatorDelegateStakeBalance,
+    TotalValidatorIds, ValidatorColdkey, ValidatorDelegateStakeBalance,
+    ValidatorDelegateStakeShares, ValidatorSubnetNodes,
 };
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 use frame_support::weights::WeightMeter;
 use frame_support::{assert_err, assert_ok};
 use sp_std::collections::btree_map::BTreeMap;
@@ -1272,3 +1275,447 @@ fn test_remove_to_stake_max_unlockings_reached_err() {
         }
     });
 }
+
+#[test]
+fn test_delegate_stake_held_then_released_on_claim() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-delegate-holds".into();
+        let deposit_amount: u128 = 1000000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name).unwrap();
+
+        let delegate_staker = account(999);
+        let delegate_stake_amount = 1000e+18 as u128;
+        let _ = Balances::deposit_creating(&delegate_staker, delegate_stake_amount + 500);
+        let starting_total = Balances::total_balance(&delegate_staker);
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_stake_amount,
+        ));
+
+        // Stake stays on the coldkey as held balance
+        assert_eq!(
+            Network::get_stake_hold(&delegate_staker, HoldReason::DelegateStake),
+            delegate_stake_amount
+        );
+        assert_eq!(Balances::total_balance(&delegate_staker), starting_total);
+        assert_eq!(Balances::free_balance(&delegate_staker), 500);
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(&delegate_staker, subnet_id);
+        assert_ok!(Network::remove_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_shares,
+        ));
+
+        let unbonding: u128 = StakeUnbondingLedger::<Test>::get(&delegate_staker)
+            .values()
+            .sum();
+        assert_eq!(
+            Network::get_stake_hold(&delegate_staker, HoldReason::DelegateStake),
+            0
+        );
+        assert_eq!(
+            Network::get_stake_hold(&delegate_staker, HoldReason::Unbonding),
+            unbonding
+        );
+
+        increase_epochs(DelegateStakeCooldownEpochs::<Test>::get() + 1);
+
+        assert_ok!(Network::claim_unbondings(RuntimeOrigin::signed(
+            delegate_staker.clone()
+        )));
+
+        assert_eq!(
+            Network::get_stake_hold(&delegate_staker, HoldReason::Unbonding),
+            0
+        );
+        assert_eq!(Balances::free_balance(&delegate_staker), 500 + unbonding);
+    });
+}
+
+#[test]
+fn test_migrate_stake_to_holds() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let coldkey = account(1000);
+        let delegate = account(1001);
+        let _ = Balances::deposit_creating(&coldkey, 500);
+        let _ = Balances::deposit_creating(&delegate, 500);
+
+        let validator_id = 1;
+        let subnet_node_id = 1;
+        ValidatorColdkey::<Test>::insert(validator_id, coldkey.clone());
+        SubnetNodeValidatorId::<Test>::insert(subnet_id, subnet_node_id, validator_id);
+        NodeSubnetStake::<Test>::insert(subnet_node_id, subnet_id, 1000);
+
+        AccountSubnetDelegateStakeShares::<Test>::insert(&delegate, subnet_id, 2000);
+        TotalSubnetDelegateStakeShares::<Test>::insert(subnet_id, 2000);
+        TotalSubnetDelegateStakeBalance::<Test>::insert(subnet_id, 2000);
+
+        let mut ledger = BTreeMap::new();
+        ledger.insert(10, 300);
+        ledger.insert(20, 400);
+        StakeUnbondingLedger::<Test>::insert(&delegate, ledger);
+
+        // Enough positions to take more than one block
+        let node_count = crate::migrations::STAKE_HOLDS_MIGRATION_ITEMS_PER_BLOCK + 10;
+        for subnet_node_id in 2..=node_count {
+            SubnetNodeValidatorId::<Test>::insert(subnet_id, subnet_node_id, validator_id);
+            NodeSubnetStake::<Test>::insert(subnet_node_id, subnet_id, 1);
+        }
+
+        StorageVersion::new(2).put::<Network>();
+
+        System::set_block_number(System::block_number() + 1);
+
+        let _ =
+            <crate::migrations::MigrateStakeToHolds<Test> as OnRuntimeUpgrade>::on_runtime_upgrade(
+            );
+
+        // The upgrade only starts the migration
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(3));
+        assert!(StakeHoldsMigration::<Test>::get().is_some());
+        assert_eq!(Network::get_stake_hold(&coldkey, HoldReason::NodeStake), 0);
+
+        crate::migrations::step_stake_to_holds::<Test>(&mut WeightMeter::new());
+
+        let cursor = StakeHoldsMigration::<Test>::get().unwrap();
+        assert_eq!(cursor.stage, StakeHoldsMigrationStage::NodeStake);
+        assert!(cursor.last_key.is_some());
+        assert_eq!(
+            cursor.holds,
+            crate::migrations::STAKE_HOLDS_MIGRATION_ITEMS_PER_BLOCK
+        );
+
+        let mut steps = 1;
+        while StakeHoldsMigration::<Test>::get().is_some() {
+            crate::migrations::step_stake_to_holds::<Test>(&mut WeightMeter::new());
+            steps += 1;
+            assert!(steps < 20);
+        }
+
+        assert_eq!(
+            Network::get_stake_hold(&coldkey, HoldReason::NodeStake),
+            1000 + (node_count - 1) as u128
+        );
+        assert_eq!(
+            Network::get_stake_hold(&delegate, HoldReason::DelegateStake),
+            Network::convert_to_balance(2000, 2000, 2000)
+        );
+        assert_eq!(
+            Network::get_stake_hold(&delegate, HoldReason::Unbonding),
+            700
+        );
+        assert_eq!(Balances::free_balance(&coldkey), 500);
+        assert_eq!(Balances::free_balance(&delegate), 500);
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::StakeHoldsMigrated {
+                holds: node_count + 2,
+                failed: 0,
+            }
+        );
+
+        // Runs once
+        let _ =
+            <crate::migrations::MigrateStakeToHolds<Test> as OnRuntimeUpgrade>::on_runtime_upgrade(
+            );
+        assert!(StakeHoldsMigration::<Test>::get().is_none());
+        crate::migrations::step_stake_to_holds::<Test>(&mut WeightMeter::new());
+        assert_eq!(
+            Network::get_stake_hold(&coldkey, HoldReason::NodeStake),
+            1000 + (node_count - 1) as u128
+        );
+    });
+}
+
+#[test]
+fn test_stake_changes_rejected_during_stake_to_holds_migration() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-holds-migration".into();
+        let deposit_amount: u128 = 1000000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name).unwrap();
+
+        let delegate_staker = account(999);
+        let delegate_stake_amount = 1000e+18 as u128;
+        let _ = Balances::deposit_creating(&delegate_staker, (delegate_stake_amount * 2) + 500);
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_stake_amount,
+        ));
+
+        StorageVersion::new(2).put::<Network>();
+        let _ =
+            <crate::migrations::MigrateStakeToHolds<Test> as OnRuntimeUpgrade>::on_runtime_upgrade(
+            );
+        assert!(StakeHoldsMigration::<Test>::get().is_some());
+
+        let free_balance = Balances::free_balance(&delegate_staker);
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(&delegate_staker, subnet_id);
+
+        // Staking and unstaking wait for the migration
+        assert_err!(
+            Network::add_delegate_stake(
+                RuntimeOrigin::signed(delegate_staker.clone()),
+                subnet_id,
+                delegate_stake_amount,
+            ),
+            Error::<Test>::StakeHoldsMigrationInProgress
+        );
+        assert_err!(
+            Network::remove_delegate_stake(
+                RuntimeOrigin::signed(delegate_staker.clone()),
+                subnet_id,
+                delegate_shares,
+            ),
+            Error::<Test>::StakeHoldsMigrationInProgress
+        );
+        assert_err!(
+            Network::claim_unbondings(RuntimeOrigin::signed(delegate_staker.clone())),
+            Error::<Test>::StakeHoldsMigrationInProgress
+        );
+
+        assert_eq!(Balances::free_balance(&delegate_staker), free_balance);
+        assert_eq!(
+            AccountSubnetDelegateStakeShares::<Test>::get(&delegate_staker, subnet_id),
+            delegate_shares
+        );
+        assert!(StakeUnbondingLedger::<Test>::get(&delegate_staker).is_empty());
+
+        let mut steps = 0;
+        while StakeHoldsMigration::<Test>::get().is_some() {
+            crate::migrations::step_stake_to_holds::<Test>(&mut WeightMeter::new());
+            steps += 1;
+            assert!(steps < 20);
+        }
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_stake_amount,
+        ));
+        assert_ok!(Network::remove_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_shares,
+        ));
+    });
+}
+
+#[test]
+fn test_restake_unbonding_partial_into_other_subnet() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-restake-a".into();
+        let other_subnet_name: Vec<u8> = "subnet-restake-b".into();
+        let deposit_amount: u128 = 1000000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        build_activated_subnet(
+            other_subnet_name.clone(),
+            0,
+            4,
+            deposit_amount,
+            stake_amount,
+        );
+        let subnet_id = SubnetName::<Test>::get(subnet_name).unwrap();
+        let other_subnet_id = SubnetName::<Test>::get(other_subnet_name).unwrap();
+
+        let delegate_staker = account(999);
+        let delegate_stake_amount = 1000e+18 as u128;
+        let _ = Balances::deposit_creating(&delegate_staker, delegate_stake_amount + 500);
+
+        assert_ok!(Network::add_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_stake_amount,
+        ));
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(&delegate_staker, subnet_id);
+        assert_ok!(Network::remove_delegate_stake(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            subnet_id,
+            delegate_shares,
+        ));
+
+        let unbonding: u128 = StakeUnbondingLedger::<Test>::get(&delegate_staker)
+            .values()
+            .sum();
+        let restake_amount = unbonding / 2;
+        let total_unbonding = TotalUnbondingBalance::<Test>::get();
+
+        increase_epochs(1);
+
+        assert_err!(
+            Network::restake_unbonding(
+                RuntimeOrigin::signed(delegate_staker.clone()),
+                RestakeTarget::SubnetDelegateStake(other_subnet_id),
+                unbonding + 1,
+            ),
+            Error::<Test>::NotEnoughUnbondingBalance
+        );
+
+        assert_ok!(Network::restake_unbonding(
+            RuntimeOrigin::signed(delegate_staker.clone()),
+            RestakeTarget::SubnetDelegateStake(other_subnet_id),
+            restake_amount,
+        ));
+
+        assert!(
+            AccountSubnetDelegateStakeShares::<Test>::get(&delegate_staker, other_subnet_id) > 0
+        );
+        assert_eq!(
+            StakeUnbondingLedger::<Test>::get(&delegate_staker)
+                .values()
+                .sum::<u128>(),
+            unbonding - restake_amount
+        );
+        assert_eq!(
+            TotalUnbondingBalance::<Test>::get(),
+            total_unbonding - restake_amount
+        );
+        assert_eq!(
+            Network::get_stake_hold(&delegate_staker, HoldReason::Unbonding),
+            unbonding - restake_amount
+        );
+        assert_eq!(
+            Network::get_stake_hold(&delegate_staker, HoldReason::DelegateStake),
+            restake_amount
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::UnbondingRestaked {
+                account_id: delegate_staker.clone(),
+                target: RestakeTarget::SubnetDelegateStake(other_subnet_id),
+                amount: restake_amount,
+            }
+        );
+    });
+}
+
+#[test]
+fn test_withdraw_unbonding_early_pays_penalty_to_treasury() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(100);
+
+        let account_id = account(1000);
+        let _ = Balances::deposit_creating(&account_id, 500);
+
+        let matured: u128 = 1_000_000;
+        let in_cooldown: u128 = 4_000_000;
+        let mut ledger = BTreeMap::new();
+        ledger.insert(90, matured);
+        ledger.insert(200, in_cooldown);
+        StakeUnbondingLedger::<Test>::insert(&account_id, ledger);
+        TotalUnbondingBalance::<Test>::set(matured + in_cooldown);
+        assert_ok!(Network::move_stake_hold(
+            &account_id,
+            None,
+            HoldReason::Unbonding,
+            matured + in_cooldown
+        ));
+
+        let treasury_balance = Balances::free_balance(&TreasuryAccount::get());
+        let amount = matured + in_cooldown;
+        let penalty = Network::percent_mul(in_cooldown, EarlyUnbondingPenalty::<Test>::get());
+
+        assert_ok!(Network::withdraw_unbonding_early(
+            RuntimeOrigin::signed(account_id.clone()),
+            amount,
+        ));
+
+        // Only the balance still in cooldown is penalized
+        assert!(penalty > 0);
+        assert_eq!(
+            Balances::free_balance(&TreasuryAccount::get()),
+            treasury_balance + penalty
+        );
+        assert_eq!(Balances::free_balance(&account_id), 500 + amount - penalty);
+        assert_eq!(StakeUnbondingLedger::<Test>::get(&account_id).len(), 0);
+        assert_eq!(TotalUnbondingBalance::<Test>::get(), 0);
+        assert_eq!(
+            Network::get_stake_hold(&account_id, HoldReason::Unbonding),
+            0
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::UnbondingWithdrawnEarly {
+                account_id: account_id.clone(),
+                amount,
+                penalty,
+            }
+        );
+    });
+}
+
+#[test]
+fn test_withdraw_unbonding_early_takes_matured_then_oldest_first() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(100);
+
+        let account_id = account(1000);
+        let _ = Balances::deposit_creating(&account_id, 500);
+
+        let matured: u128 = 1_000_000;
+        let oldest: u128 = 2_000_000;
+        let newest: u128 = 4_000_000;
+        let mut ledger = BTreeMap::new();
+        ledger.insert(90, matured);
+        ledger.insert(150, oldest);
+        ledger.insert(200, newest);
+        StakeUnbondingLedger::<Test>::insert(&account_id, ledger);
+        TotalUnbondingBalance::<Test>::set(matured + oldest + newest);
+        assert_ok!(Network::move_stake_hold(
+            &account_id,
+            None,
+            HoldReason::Unbonding,
+            matured + oldest + newest
+        ));
+
+        let treasury_balance = Balances::free_balance(&TreasuryAccount::get());
+        let from_oldest = oldest / 2;
+        let amount = matured + from_oldest;
+        let penalty = Network::percent_mul(from_oldest, EarlyUnbondingPenalty::<Test>::get());
+
+        assert_ok!(Network::withdraw_unbonding_early(
+            RuntimeOrigin::signed(account_id.clone()),
+            amount,
+        ));
+
+        // The matured entry is taken in full and only the part of the oldest entry in cooldown is
+        // penalized
+        assert!(penalty > 0);
+        assert_eq!(
+            Balances::free_balance(&TreasuryAccount::get()),
+            treasury_balance + penalty
+        );
+        assert_eq!(Balances::free_balance(&account_id), 500 + amount - penalty);
+
+        let ledger = StakeUnbondingLedger::<Test>::get(&account_id);
+        assert_eq!(ledger.get(&90), None);
+        assert_eq!(ledger.get(&150), Some(&(oldest - from_oldest)));
+        assert_eq!(ledger.get(&200), Some(&newest));
+        assert_eq!(
+            TotalUnbondingBalance::<Test>::get(),
+            oldest - from_oldest + newest
+        );
+        assert_eq!(
+            Network::get_stake_hold(&account_id, HoldReason::Unbonding),
+            oldest - from_oldest + newest
+        );
+    });
+}
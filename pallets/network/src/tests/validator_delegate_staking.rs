@@ -1418,7 +1418,7 @@ fn test_swap_from_validator_to_subnet() {
                 assert_eq!(*to_subnet_id, starting_to_subnet_id);
                 assert_ne!(*balance, 0);
             }
-            QueuedSwapCall::SwapToValidatorDelegateStake { .. } => assert!(false),
+            _ => assert!(false),
         };
 
         let next_id = NextSwapQueueId::<Test>::get();
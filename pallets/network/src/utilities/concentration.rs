@@ -0,0 +1,224 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Per-subnet node concentration caps and coldkey concentration reporting
+
+use super::*;
+use sp_std::cmp::Reverse;
+
+impl<T: Config> Pallet<T> {
+    /// Number of registered and active nodes of a validator ID in a subnet
+    pub fn get_validator_subnet_node_count(subnet_id: u32, validator_id: u32) -> u32 {
+        ValidatorSubnetNodes::<T>::get(validator_id)
+            .get(&subnet_id)
+            .map_or(0, |nodes| nodes.len() as u32)
+    }
+
+    /// The strictest of the subnet's coldkey and validator ID node caps
+    ///
+    /// A coldkey owns a single validator ID, so both caps bound the same set of nodes.
+    pub fn get_max_nodes_per_validator(subnet_id: u32) -> Option<u32> {
+        let caps = SubnetNodeConcentrationCaps::<T>::get(subnet_id);
+        match (caps.max_nodes_per_coldkey, caps.max_nodes_per_validator) {
+            (Some(coldkey_max), Some(validator_max)) => Some(coldkey_max.min(validator_max)),
+            (coldkey_max, validator_max) => coldkey_max.or(validator_max),
+        }
+    }
+
+    /// Ensure a validator ID with `node_count` nodes in a subnet can register another node
+    pub fn ensure_node_concentration_caps(subnet_id: u32, node_count: u32) -> DispatchResult {
+        let caps = SubnetNodeConcentrationCaps::<T>::get(subnet_id);
+
+        ensure!(
+            caps.max_nodes_per_coldkey
+                .map_or(true, |max| node_count < max),
+            Error::<T>::MaxNodesPerColdkeyReached
+        );
+
+        ensure!(
+            caps.max_nodes_per_validator
+                .map_or(true, |max| node_count < max),
+            Error::<T>::MaxNodesPerValidatorReached
+        );
+
+        Ok(())
+    }
+
+    /// Whether a validator ID has more nodes in a subnet than the subnet's caps allow
+    ///
+    /// Used on activation from the queue, where the activating node is already counted.
+    pub fn is_over_node_concentration_caps(subnet_id: u32, validator_id: u32) -> bool {
+        match Self::get_max_nodes_per_validator(subnet_id) {
+            Some(max) => Self::get_validator_subnet_node_count(subnet_id, validator_id) > max,
+            None => false,
+        }
+    }
+
+    /// Remove one active node of the validator ID furthest over the subnet's node caps
+    ///
+    /// Runs once per subnet epoch so nodes over a lowered cap are drained gradually. The node
+    /// with the lowest reputation is removed first, the newest on ties. The elected validator
+    /// of the epoch is never removed.
+    pub fn drain_node_concentration_excess(
+        weight_meter: &mut WeightMeter,
+        subnet_id: u32,
+        subnet_epoch: u32,
+    ) {
+        let db_weight = T::DbWeight::get();
+
+        // SubnetNodeConcentrationCaps
+        if !weight_meter.can_consume(db_weight.reads(1)) {
+            return;
+        }
+        weight_meter.consume(db_weight.reads(1));
+
+        let Some(max) = Self::get_max_nodes_per_validator(subnet_id) else {
+            return;
+        };
+
+        // Reads: TotalActiveSubnetNodes, SubnetElectedValidator, TotalSubnetElectableNodes
+        // Reads per node: SubnetNodesData
+        let total_active_nodes = TotalActiveSubnetNodes::<T>::get(subnet_id);
+        let scan_weight = db_weight.reads(3u64.saturating_add(total_active_nodes as u64));
+        if !weight_meter.can_consume(scan_weight) {
+            return;
+        }
+        weight_meter.consume(scan_weight);
+
+        let mut validator_nodes: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for (subnet_node_id, subnet_node) in SubnetNodesData::<T>::iter_prefix(subnet_id) {
+            validator_nodes
+                .entry(subnet_node.validator_id)
+                .or_default()
+                .push(subnet_node_id);
+        }
+
+        // The validator ID furthest over the cap, the lowest ID on ties
+        let Some((validator_id, subnet_node_ids)) = validator_nodes
+            .into_iter()
+            .filter(|(_, subnet_node_ids)| subnet_node_ids.len() as u32 > max)
+            .max_by_key(|(validator_id, subnet_node_ids)| {
+                (subnet_node_ids.len(), Reverse(*validator_id))
+            })
+        else {
+            return;
+        };
+
+        let elected_subnet_node_id = SubnetElectedValidator::<T>::get(subnet_id, subnet_epoch);
+
+        // Reads per candidate: SubnetNodeReputation
+        let reputation_weight = db_weight.reads(subnet_node_ids.len() as u64);
+        if !weight_meter.can_consume(reputation_weight) {
+            return;
+        }
+        weight_meter.consume(reputation_weight);
+
+        let Some(subnet_node_id) = subnet_node_ids
+            .into_iter()
+            .filter(|subnet_node_id| Some(*subnet_node_id) != elected_subnet_node_id)
+            .min_by_key(|subnet_node_id| {
+                (
+                    SubnetNodeReputation::<T>::get(subnet_id, subnet_node_id).unwrap_or(0),
+                    Reverse(*subnet_node_id),
+                )
+            })
+        else {
+            return;
+        };
+
+        let validator_subnet_nodes = ValidatorSubnetNodes::<T>::get(validator_id);
+        let remove_weight = T::WeightInfo::remove_active_subnet_node(
+            validator_subnet_nodes.len() as u32,
+            TotalSubnetElectableNodes::<T>::get(subnet_id),
+            validator_subnet_nodes
+                .get(&subnet_id)
+                .map_or(0, |nodes| nodes.len() as u32),
+        );
+        if !weight_meter.can_consume(remove_weight) {
+            return;
+        }
+        weight_meter.consume(remove_weight);
+
+        Self::remove_active_subnet_node(subnet_id, subnet_node_id);
+
+        Self::deposit_event(Event::NodeConcentrationCapRemoval {
+            subnet_id,
+            subnet_node_id,
+            validator_id,
+        });
+    }
+
+    /// Coldkey concentration of a subnet's active nodes
+    ///
+    /// Shares are 1e18 = 100%. `top_n` bounds both the shares and the returned coldkeys.
+    pub fn get_subnet_coldkey_concentration(
+        subnet_id: u32,
+        top_n: u32,
+    ) -> Option<SubnetColdkeyConcentration<T::AccountId>> {
+        if !SubnetsData::<T>::contains_key(subnet_id) {
+            return None;
+        }
+
+        let mut coldkeys: BTreeMap<u32, ColdkeyConcentration<T::AccountId>> = BTreeMap::new();
+        for (subnet_node_id, subnet_node) in SubnetNodesData::<T>::iter_prefix(subnet_id) {
+            let stake = NodeSubnetStake::<T>::get(subnet_node_id, subnet_id);
+            if let Some(entry) = coldkeys.get_mut(&subnet_node.validator_id) {
+                entry.nodes.saturating_inc();
+                entry.stake = entry.stake.saturating_add(stake);
+                continue;
+            }
+
+            let Some(coldkey) = ValidatorColdkey::<T>::get(subnet_node.validator_id) else {
+                continue;
+            };
+            coldkeys.insert(
+                subnet_node.validator_id,
+                ColdkeyConcentration {
+                    coldkey,
+                    nodes: 1,
+                    stake,
+                },
+            );
+        }
+
+        let mut coldkeys: Vec<ColdkeyConcentration<T::AccountId>> =
+            coldkeys.into_values().collect();
+        let total_nodes: u32 = coldkeys.iter().map(|c| c.nodes).sum();
+        let total_stake: u128 = coldkeys
+            .iter()
+            .fold(0u128, |acc, c| acc.saturating_add(c.stake));
+
+        // Top-N by stake
+        coldkeys.sort_by(|a, b| b.stake.cmp(&a.stake).then(b.nodes.cmp(&a.nodes)));
+        let top_stake: u128 = coldkeys
+            .iter()
+            .take(top_n as usize)
+            .fold(0u128, |acc, c| acc.saturating_add(c.stake));
+
+        // Top-N by nodes
+        coldkeys.sort_by(|a, b| b.nodes.cmp(&a.nodes).then(b.stake.cmp(&a.stake)));
+        coldkeys.truncate(top_n as usize);
+        let top_nodes: u32 = coldkeys.iter().map(|c| c.nodes).sum();
+
+        Some(SubnetColdkeyConcentration {
+            subnet_id,
+            total_nodes,
+            total_stake,
+            top_node_share: Self::percent_div(top_nodes as u128, total_nodes as u128),
+            top_stake_share: Self::percent_div(top_stake, total_stake),
+            coldkeys,
+        })
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Validator election probabilities, uniform or weighted by stake and reputation
+// See `ValidatorElectionMode` in `lib.rs`
+
+use super::*;
+use sp_core::U256;
+
+/// Maximum exponent of a weighted election factor, 4.0 in 1e18 precision
+pub const MAX_VALIDATOR_ELECTION_EXPONENT: u128 = 4_000_000_000_000_000_000;
+
+/// Weighted elections draw in 1e9 precision, probabilities are divided by this before the draw
+const ELECTION_DRAW_DIVISOR: u128 = 1_000_000_000;
+
+impl<T: Config> Pallet<T> {
+    /// Election probability of each node in `slot_list` under the subnet's election mode,
+    /// 1e18 = 100%
+    ///
+    /// Duplicate node IDs count once. A weighted election where every node has zero weight falls
+    /// back to uniform probabilities.
+    pub fn get_validator_election_probabilities(
+        subnet_id: u32,
+        slot_list: &[u32],
+    ) -> BTreeMap<u32, u128> {
+        let node_ids: BTreeSet<u32> = slot_list.iter().copied().collect();
+        if node_ids.is_empty() {
+            return BTreeMap::new();
+        }
+
+        if let ValidatorElectionMode::Weighted(params) =
+            SubnetValidatorElectionMode::<T>::get(subnet_id)
+        {
+            if let Some(probabilities) =
+                Self::get_weighted_election_probabilities(subnet_id, &node_ids, &params)
+            {
+                return probabilities;
+            }
+        }
+
+        let probability = Self::percentage_factor_as_u128() / node_ids.len() as u128;
+        node_ids
+            .into_iter()
+            .map(|subnet_node_id| (subnet_node_id, probability))
+            .collect()
+    }
+
+    /// Returns the validator ID of a subnet node and its election weight,
+    /// `stake_share^a * node_reputation^b * validator_reputation^c` in 1e18 precision
+    ///
+    /// The stake is taken as a share of `total_subnet_stake` so every base is at most 1.0 and the
+    /// powers can't saturate.
+    pub(crate) fn get_validator_election_weight(
+        subnet_id: u32,
+        subnet_node_id: u32,
+        total_subnet_stake: u128,
+        params: &WeightedValidatorElection,
+    ) -> Option<(u32, u128)> {
+        let validator_id = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+            .ok()?
+            .validator_id;
+
+        let stake = Self::percent_div(
+            NodeSubnetStake::<T>::get(subnet_node_id, subnet_id),
+            total_subnet_stake,
+        )
+        .min(Self::percentage_factor_as_u128());
+        let node_reputation =
+            SubnetNodeReputation::<T>::get(subnet_id, subnet_node_id).unwrap_or(0);
+        let validator_reputation = ValidatorReputation::<T>::get(validator_id).score;
+
+        let one = U256::from(Self::percentage_factor_as_u128());
+        let weight = [
+            (stake, params.stake_exponent),
+            (node_reputation, params.node_reputation_exponent),
+            (validator_reputation, params.validator_reputation_exponent),
+        ]
+        .into_iter()
+        .fold(
+            Self::percentage_factor_as_u128(),
+            |weight, (base, exponent)| {
+                let product = U256::from(weight) * U256::from(Self::pow(base, exponent)) / one;
+                product.try_into().unwrap_or(u128::MAX)
+            },
+        );
+
+        Some((validator_id, weight))
+    }
+
+    /// Weighted election probabilities, `None` if every node has zero weight
+    fn get_weighted_election_probabilities(
+        subnet_id: u32,
+        node_ids: &BTreeSet<u32>,
+        params: &WeightedValidatorElection,
+    ) -> Option<BTreeMap<u32, u128>> {
+        // Node weights grouped by validator, the probability cap applies to the group
+        let mut validator_nodes: BTreeMap<u32, Vec<(u32, u128)>> = BTreeMap::new();
+        let total_subnet_stake = TotalSubnetStake::<T>::get(subnet_id);
+        for subnet_node_id in node_ids {
+            if let Some((validator_id, weight)) = Self::get_validator_election_weight(
+                subnet_id,
+                *subnet_node_id,
+                total_subnet_stake,
+                params,
+            ) {
+                validator_nodes
+                    .entry(validator_id)
+                    .or_default()
+                    .push((*subnet_node_id, weight));
+            }
+        }
+
+        let validator_weights: BTreeMap<u32, U256> = validator_nodes
+            .iter()
+            .map(|(validator_id, nodes)| {
+                let weight = nodes
+                    .iter()
+                    .fold(U256::zero(), |sum, (_, weight)| sum + U256::from(*weight));
+                (*validator_id, weight)
+            })
+            .collect();
+
+        if validator_weights.values().all(|weight| weight.is_zero()) {
+            return None;
+        }
+
+        let validator_probabilities = Self::cap_validator_election_probabilities(
+            &validator_weights,
+            params.max_validator_probability,
+        );
+
+        let mut probabilities = BTreeMap::new();
+        for (validator_id, nodes) in validator_nodes {
+            let validator_probability =
+                U256::from(*validator_probabilities.get(&validator_id).unwrap_or(&0));
+            let validator_weight = validator_weights
+                .get(&validator_id)
+                .copied()
+                .unwrap_or_default();
+
+            let node_count = nodes.len() as u128;
+            for (subnet_node_id, weight) in nodes {
+                let probability = if validator_weight.is_zero() {
+                    // Zero weight validators can only hold a share when every other validator is
+                    // capped, split it evenly across their nodes
+                    validator_probability / U256::from(node_count)
+                } else {
+                    Self::checked_mul_div(
+                        validator_probability,
+                        U256::from(weight),
+                        validator_weight,
+                    )
+                    .unwrap_or_default()
+                };
+                probabilities.insert(subnet_node_id, probability.try_into().unwrap_or(0));
+            }
+        }
+
+        Some(probabilities)
+    }
+
+    /// Splits 100% across validators pro rata to weight with no validator above `cap`
+    ///
+    /// Validators whose share is above the cap are fixed at the cap and the rest is split pro rata
+    /// across the others, repeated until no share is above the cap. If the cap cannot hold for
+    /// every validator, i.e. `cap * validators < 100%`, validators share equally.
+    pub(crate) fn cap_validator_election_probabilities(
+        weights: &BTreeMap<u32, U256>,
+        cap: u128,
+    ) -> BTreeMap<u32, u128> {
+        let one = Self::percentage_factor_as_u128();
+        let validators = weights.len() as u128;
+        if validators == 0 {
+            return BTreeMap::new();
+        }
+
+        if cap.saturating_mul(validators) < one {
+            return weights
+                .keys()
+                .map(|validator_id| (*validator_id, one / validators))
+                .collect();
+        }
+
+        let mut capped: BTreeSet<u32> = BTreeSet::new();
+        let share = |capped: &BTreeSet<u32>, weight: U256| -> u128 {
+            let remaining = one.saturating_sub(cap.saturating_mul(capped.len() as u128));
+            let uncapped_weight = weights
+                .iter()
+                .filter(|(validator_id, _)| !capped.contains(validator_id))
+                .fold(U256::zero(), |sum, (_, weight)| sum + *weight);
+
+            if uncapped_weight.is_zero() {
+                // Only zero weight validators are left, split the rest evenly
+                let uncapped = validators.saturating_sub(capped.len() as u128).max(1);
+                return remaining / uncapped;
+            }
+
+            Self::checked_mul_div(U256::from(remaining), weight, uncapped_weight)
+                .and_then(|share| share.try_into().ok())
+                .unwrap_or(0)
+        };
+
+        loop {
+            let over_cap: Vec<u32> = weights
+                .iter()
+                .filter(|(validator_id, weight)| {
+                    !capped.contains(validator_id) && share(&capped, **weight) > cap
+                })
+                .map(|(validator_id, _)| *validator_id)
+                .collect();
+
+            if over_cap.is_empty() {
+                break;
+            }
+
+            capped.extend(over_cap);
+        }
+
+        weights
+            .iter()
+            .map(|(validator_id, weight)| {
+                let probability = if capped.contains(validator_id) {
+                    cap
+                } else {
+                    share(&capped, *weight)
+                };
+                (*validator_id, probability)
+            })
+            .collect()
+    }
+
+    /// Fallback proposers in the order they are drawn after the elected validator
+    ///
+    /// Each rank is drawn like the election, from the nodes of `slot_list` not drawn yet. A
+    /// weighted election where the remaining nodes have no weight draws them uniformly.
+    pub(crate) fn draw_fallback_proposer_ids<Domain: Encode + Copy>(
+        domain: Domain,
+        subnet_id: u32,
+        elected_subnet_node_id: u32,
+        slot_list: &[u32],
+        max_proposers: u32,
+    ) -> Vec<u32> {
+        let mut candidates: BTreeSet<u32> = slot_list
+            .iter()
+            .copied()
+            .filter(|id| *id != elected_subnet_node_id)
+            .collect();
+        let mut probabilities = match SubnetValidatorElectionMode::<T>::get(subnet_id) {
+            ValidatorElectionMode::Uniform => BTreeMap::new(),
+            ValidatorElectionMode::Weighted(_) => {
+                Self::get_validator_election_probabilities(subnet_id, slot_list)
+            }
+        };
+
+        let mut fallback_ids = Vec::new();
+        for rank in 1..=max_proposers {
+            if candidates.is_empty() {
+                break;
+            }
+
+            probabilities.retain(|id, _| candidates.contains(id));
+            let drawn =
+                Self::draw_weighted_validator((domain, rank), &probabilities).or_else(|| {
+                    let idx =
+                        Self::get_beacon_random_index((domain, rank), candidates.len() as u32)?;
+                    candidates.iter().nth(idx as usize).copied()
+                });
+
+            let Some(subnet_node_id) = drawn else {
+                break;
+            };
+            candidates.remove(&subnet_node_id);
+            fallback_ids.push(subnet_node_id);
+        }
+
+        fallback_ids
+    }
+
+    /// Draws a node from election probabilities with the randomness beacon
+    pub(crate) fn draw_weighted_validator<Domain: Encode>(
+        domain: Domain,
+        probabilities: &BTreeMap<u32, u128>,
+    ) -> Option<u32> {
+        let buckets: Vec<(u32, u32)> = probabilities
+            .iter()
+            .map(|(subnet_node_id, probability)| {
+                (
+                    *subnet_node_id,
+                    (probability / ELECTION_DRAW_DIVISOR) as u32,
+                )
+            })
+            .filter(|(_, bucket)| *bucket > 0)
+            .collect();
+
+        let total: u32 = buckets.iter().map(|(_, bucket)| *bucket).sum();
+        let mut target = Self::get_beacon_random_index(domain, total)?;
+
+        for (subnet_node_id, bucket) in buckets {
+            if target < bucket {
+                return Some(subnet_node_id);
+            }
+            target -= bucket;
+        }
+
+        None
+    }
+}
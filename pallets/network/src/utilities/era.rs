@@ -70,6 +70,22 @@ impl<T: Config> Pallet<T> {
         current_block < epoch_cutoff_block
     }
 
+    /// Whether the current block is between the overwatch commit and reveal cutoffs
+    pub fn in_overwatch_reveal_period() -> bool {
+        let current_block = Self::get_current_block_as_u32();
+        let epoch_length: u32 = T::EpochLength::get();
+        let multiplier: u32 = OverwatchEpochLengthMultiplier::<T>::get();
+        let overwatch_epoch_length = epoch_length.saturating_mul(multiplier);
+        let current_overwatch_epoch = current_block.saturating_div(overwatch_epoch_length);
+        let cutoff_percentage = OverwatchRevealCutoffPercent::<T>::get();
+        let block_increase_cutoff =
+            Self::percent_mul(overwatch_epoch_length as u128, cutoff_percentage);
+        // start_block + cutoff blocks
+        let epoch_cutoff_block =
+            overwatch_epoch_length * current_overwatch_epoch + block_increase_cutoff as u32;
+        !Self::in_overwatch_commit_period() && current_block < epoch_cutoff_block
+    }
+
     /// Return epoch, overwatch epoch
     pub fn get_current_epochs_as_u32() -> (u32, u32) {
         let current_block = Self::get_current_block_as_u32();
@@ -245,6 +261,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// This function iterates over all registered subnets and enforces several rules:
     ///
+    /// - Scheduled parameter changes whose enactment epoch arrived are enacted first.
     /// - Subnets in the **registration period** are allowed to exist without reputation decrease.
     /// - Subnets in the **enactment period** must meet minimum active node counts or get removed.
     /// - Subnets **out of enactment period** but not activated are removed.
@@ -253,6 +270,7 @@ impl<T: Config> Pallet<T> {
     /// - Activated subnets with insufficient active nodes decrease reputation.  
     /// - Subnets exceeding the minimum reputation are removed.
     /// - If the total number of subnets exceeds the configured maximum, the subnet with the lowest delegate stake is removed.
+    /// - Pending validator slashes whose appeal window ended are applied.
     ///
     /// Reputations are global and can be increased or decreased by other runtime logic as well, so this function enforces removal
     /// conditions based on the current reputation regardless of its origin.
@@ -274,6 +292,12 @@ impl<T: Config> Pallet<T> {
     pub fn do_epoch_preliminaries(weight_meter: &mut WeightMeter, block: u32, epoch: u32) {
         let db_weight = T::DbWeight::get();
 
+        // --- Enact parameter changes whose time lock ended before the parameters are read below
+        Self::enact_scheduled_parameter_changes(weight_meter, epoch);
+
+        // --- Remove subnet nodes of passed overwatch challenges before validators are elected
+        Self::remove_challenged_subnet_nodes(weight_meter);
+
         // Min reputation a subnet can have
         let min_reputation = MinSubnetReputation::<T>::get();
         // Total epochs of the registration phase
@@ -467,6 +491,9 @@ impl<T: Config> Pallet<T> {
             let subnet_id = subnet_delegate_stake[0].0.clone();
             Self::try_do_remove_subnet(weight_meter, subnet_id, SubnetRemovalReason::MaxSubnets);
         }
+
+        // --- Burn the stake of slashes the council did not cancel
+        Self::apply_pending_slashes(weight_meter, epoch);
     }
 
     pub fn elect_validator(subnet_id: u32, subnet_epoch: u32, block: u32) {
@@ -476,6 +503,11 @@ impl<T: Config> Pallet<T> {
             return;
         }
 
+        // --- Fallback proposals are no longer needed once the rewards of their epoch are paid
+        let prune_epoch = subnet_epoch.saturating_sub(2);
+        SubnetFallbackProposerIds::<T>::remove(subnet_id, prune_epoch);
+        SubnetFallbackConsensusSubmissions::<T>::remove(subnet_id, prune_epoch);
+
         // Check for emergency validators
         let slot_list = if let Some(emergency_validator_data) =
             EmergencySubnetNodeElectionData::<T>::get(subnet_id)
@@ -498,18 +530,45 @@ impl<T: Config> Pallet<T> {
             return;
         }
 
-        let Some(idx) = Self::get_bounded_random_index(
-            (subnet_id, subnet_epoch, block),
-            slot_list.len() as u32,
-        ) else {
-            return;
-        };
+        let subnet_node_id = match SubnetValidatorElectionMode::<T>::get(subnet_id) {
+            ValidatorElectionMode::Uniform => {
+                let Some(idx) = Self::get_beacon_random_index(
+                    (subnet_id, subnet_epoch, block),
+                    slot_list.len() as u32,
+                ) else {
+                    return;
+                };
 
-        let subnet_node_id = slot_list.get(idx as usize).cloned();
+                slot_list.get(idx as usize).cloned()
+            }
+            ValidatorElectionMode::Weighted(_) => {
+                let probabilities =
+                    Self::get_validator_election_probabilities(subnet_id, &slot_list);
+                Self::draw_weighted_validator((subnet_id, subnet_epoch, block), &probabilities)
+            }
+        };
 
         if let Some(node_id) = subnet_node_id {
             // --- Insert validator for next epoch
             SubnetElectedValidator::<T>::insert(subnet_id, subnet_epoch, node_id);
+
+            // --- Rank the fallback proposers by drawing again without the nodes already drawn
+            if let Some(config) = SubnetFallbackProposers::<T>::get(subnet_id) {
+                let fallback_ids = Self::draw_fallback_proposer_ids(
+                    (subnet_id, subnet_epoch, block),
+                    subnet_id,
+                    node_id,
+                    &slot_list,
+                    config.max_proposers,
+                );
+                SubnetFallbackProposerIds::<T>::insert(subnet_id, subnet_epoch, fallback_ids);
+            }
+
+            Self::deposit_event(Event::ValidatorElected {
+                subnet_id,
+                subnet_epoch,
+                subnet_node_id: node_id,
+            });
         }
     }
 }
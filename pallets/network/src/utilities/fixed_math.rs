@@ -0,0 +1,315 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Deterministic fixed-point math in 1e18 precision (1e18 = 1.0)
+//
+// Replaces `f64` in emission, weight and cost computations. `exp`, `exp_neg` and `ln` are exact
+// to within a few units of 1e-18 relative to their result. `pow` multiplies the error of `ln` by
+// the exponent. The curves stay within `1e-12` (1_000_000 in 1e18 precision) of the `f64`
+// implementations they replace, see the property tests in `tests/math.rs`.
+
+use super::*;
+use sp_core::U256;
+
+/// 1.0 in 1e18 precision
+const ONE: u128 = 1_000_000_000_000_000_000;
+/// ln(2) in 1e18 precision
+const LN_2: u128 = 693_147_180_559_945_309;
+/// `exp_neg` rounds to zero from here, e^-42 < 1e-18
+const EXP_NEG_ZERO_BOUND: u128 = 42 * ONE;
+
+impl<T: Config> Pallet<T> {
+    /// e^r for `0 <= r < ln(2)` by its Taylor series
+    fn exp_taylor(r: u128) -> U256 {
+        let one = U256::from(ONE);
+        let r = U256::from(r);
+
+        let mut sum = one;
+        let mut term = one;
+        let mut n: u64 = 1;
+        loop {
+            term = term * r / one / U256::from(n);
+            if term.is_zero() {
+                break;
+            }
+            sum = sum + term;
+            n += 1;
+        }
+
+        sum
+    }
+
+    /// e^x, `None` if the result overflows u128
+    pub fn exp(x: u128) -> Option<u128> {
+        // e^x = 2^k * e^r
+        let k = x / LN_2;
+        if k >= 128 {
+            return None;
+        }
+        let r = x % LN_2;
+
+        (Self::exp_taylor(r) << (k as usize)).try_into().ok()
+    }
+
+    /// e^-x
+    pub fn exp_neg(x: u128) -> u128 {
+        if x == 0 {
+            return ONE;
+        }
+        if x >= EXP_NEG_ZERO_BOUND {
+            return 0;
+        }
+
+        // e^-x = 2^-k / e^r
+        let k = x / LN_2;
+        let r = x % LN_2;
+        let exp_neg_r = U256::from(ONE) * U256::from(ONE) / Self::exp_taylor(r);
+
+        (exp_neg_r >> (k as usize)).try_into().unwrap_or(0)
+    }
+
+    /// Natural logarithm, `None` if `x` is zero
+    pub fn ln(x: u128) -> Option<i128> {
+        if x == 0 {
+            return None;
+        }
+
+        let one = U256::from(ONE);
+        let two = one * U256::from(2);
+
+        // x = m * 2^k with 1.0 <= m < 2.0
+        let mut m = U256::from(x);
+        let mut k: i128 = 0;
+        while m >= two {
+            m = m >> 1usize;
+            k += 1;
+        }
+        while m < one {
+            m = m << 1usize;
+            k -= 1;
+        }
+
+        // ln(m) = 2 * atanh(z) = 2 * (z + z^3/3 + z^5/5 + ...) with z = (m - 1) / (m + 1) < 1/3
+        let z = (m - one) * one / (m + one);
+        let z_squared = z * z / one;
+
+        let mut sum = U256::zero();
+        let mut term = z;
+        let mut n: u64 = 1;
+        while !term.is_zero() {
+            sum = sum + term / U256::from(n);
+            term = term * z_squared / one;
+            n += 2;
+        }
+
+        let ln_m: u128 = (sum * U256::from(2)).try_into().unwrap_or(0);
+
+        Some(k * LN_2 as i128 + ln_m as i128)
+    }
+
+    /// `base` raised to `exponent`, saturates at u128::MAX
+    ///
+    /// Whole exponents are computed by exponentiation by squaring, others as e^(exponent * ln(base))
+    pub fn pow(base: u128, exponent: u128) -> u128 {
+        if exponent == 0 || base == ONE {
+            return ONE;
+        }
+        if base == 0 {
+            return 0;
+        }
+        if exponent % ONE == 0 {
+            return Self::powi(base, exponent / ONE);
+        }
+
+        let ln_base = Self::ln(base).unwrap_or(0);
+        let magnitude = U256::from(ln_base.unsigned_abs()) * U256::from(exponent) / U256::from(ONE);
+
+        match u128::try_from(magnitude) {
+            Ok(magnitude) if ln_base < 0 => Self::exp_neg(magnitude),
+            Ok(magnitude) => Self::exp(magnitude).unwrap_or(u128::MAX),
+            Err(_) if ln_base < 0 => 0,
+            Err(_) => u128::MAX,
+        }
+    }
+
+    /// `base` raised to the whole number `n`, saturates at u128::MAX
+    pub fn powi(base: u128, n: u128) -> u128 {
+        let one = U256::from(ONE);
+        let max = U256::from(u128::MAX);
+
+        let mut result = one;
+        let mut base = U256::from(base);
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base / one;
+                if result > max {
+                    return u128::MAX;
+                }
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base / one;
+                // `base` is only squared further while it still has to be multiplied in
+                if base > max {
+                    return u128::MAX;
+                }
+            }
+        }
+
+        result.try_into().unwrap_or(u128::MAX)
+    }
+
+    /// Symmetric logistic curve around `mid`, 1.0 at `x = 0` and 0.0 at `x = 1.0` for large `k`
+    ///
+    /// The exponent is always taken as negative so `exp` can't overflow.
+    fn decreasing_logistic(x: u128, mid: u128, k: u128) -> u128 {
+        let c = x.abs_diff(mid);
+        let d = Self::checked_mul_div(U256::from(k), U256::from(c), U256::from(ONE))
+            .and_then(|d| d.try_into().ok())
+            .unwrap_or(u128::MAX);
+
+        let one = U256::from(ONE);
+        let exp_neg_d = U256::from(Self::exp_neg(d));
+
+        // x > mid: 1 / (1 + e^d) = e^-d / (1 + e^-d)
+        // x <= mid: e^d / (1 + e^d) = 1 / (1 + e^-d)
+        let sigmoid = if x > mid {
+            exp_neg_d * one / (one + exp_neg_d)
+        } else {
+            one * one / (one + exp_neg_d)
+        };
+
+        sigmoid.try_into().unwrap_or(ONE)
+    }
+
+    /// Computes a symmetric, decreasing sigmoid curve scaled to a specified output range.
+    ///
+    /// # Parameters
+    /// - `x`: The input value to evaluate the sigmoid at. Should be in the range `[0, 1e18]`.
+    /// - `mid`: The midpoint of the sigmoid. The curve is symmetric around this value.
+    /// - `k`: Controls the steepness of the sigmoid. Larger values make the transition sharper.
+    /// - `min`: Minimum value of the output range. The sigmoid will not go below this value.
+    /// - `max`: Maximum value of the output range. The sigmoid will not exceed this value.
+    ///
+    /// # Returns
+    /// - The value of the sigmoid at `x`. Guaranteed to be within `[min, max]`.
+    pub fn sigmoid_decreasing(x: u128, mid: u128, k: u128, min: u128, max: u128) -> u128 {
+        Self::decreasing_logistic(x, mid, k).max(min).min(max)
+    }
+
+    pub fn sigmoid_decreasing_v2(x: u128, mid: u128, k: u128) -> u128 {
+        Self::decreasing_logistic(x, mid, k)
+    }
+
+    /// Offset and scale the sigmoid curve
+    ///
+    /// # Parameters
+    /// - `x`: The input value to evaluate the sigmoid at. Should be in the range `[0, 1e18]`.
+    /// - `mid`: The midpoint of the sigmoid. The curve is symmetric around this value.
+    /// - `k`: Controls the steepness of the sigmoid. Larger values make the transition sharper.
+    /// - `min`: Minimum value of the output range.
+    /// - `max`: Maximum value of the output range.
+    ///
+    /// # Returns
+    /// - The sigmoid at `x` scaled from `[0, 1e18]` to `[min, max]`.
+    pub fn sigmoid_decreasing_v3(x: u128, mid: u128, k: u128, min: u128, max: u128) -> u128 {
+        let sigmoid = Self::decreasing_logistic(x, mid, k);
+
+        min.saturating_add(Self::percent_mul(max.saturating_sub(min), sigmoid))
+    }
+
+    pub fn sigmoid_decreasing_asymmetric(
+        x: u128,
+        mid: u128,
+        k_front: u128,
+        k_back: u128,
+        min: u128,
+        max: u128,
+    ) -> u128 {
+        let k = if x > mid { k_back } else { k_front };
+        let sigmoid = Self::decreasing_logistic(x, mid, k);
+
+        // scale sigmoid from [0, 1] → [min, max]
+        min.saturating_add(Self::percent_mul(max.saturating_sub(min), sigmoid))
+            .max(min)
+            .min(max)
+    }
+
+    /// Symmetric sigmoid decreasing function with x-axis offset support.
+    ///
+    /// This is specifically meant for the rewards factor for validator/attestor
+    ///
+    /// Normalizes the input `x` from the range `[x_start, 1e18]` to `[0, 1e18]` and applies a
+    /// symmetric sigmoid curve. Values of `x` below `x_start` are normalized to 0.
+    ///
+    /// # Parameters
+    /// - `x`: The input value, expected to be in range `[x_start, 1e18]`
+    /// - `mid`: The midpoint of the sigmoid in normalized space `[0, 1e18]`
+    /// - `k`: Controls the steepness of the sigmoid. Larger values make the transition sharper
+    /// - `x_start`: The starting point of the sigmoid on the x-axis
+    /// - `round_decimal_places`: The number of decimal places to round the output to
+    ///
+    /// # Returns
+    /// A value in `[0, 1e18]` representing the sigmoid output
+    ///
+    /// # Example
+    /// With `x_start = 0.05`:
+    /// - `x = 0.05` → normalized to 0.0 → output ≈ 1.0 (start of curve)
+    /// - `x = 0.06` → normalized to ~0.0105 → output ≈ 0.99999
+    /// - `x = 1.0` → normalized to 1.0 → output ≈ 0.0 (end of curve)
+    pub fn sigmoid_decreasing_start_offset(
+        x: u128,
+        mid: u128,
+        k: u128,
+        x_start: u128,
+        round_decimal_places: u32,
+    ) -> u128 {
+        let normalized_x =
+            Self::percent_div(x.saturating_sub(x_start), ONE.saturating_sub(x_start)).min(ONE);
+
+        let sigmoid = Self::decreasing_logistic(normalized_x, mid, k);
+
+        if round_decimal_places >= 18 {
+            return sigmoid;
+        }
+
+        let factor = 10_u128.pow(18 - round_decimal_places);
+        (sigmoid.saturating_add(factor / 2) / factor * factor).min(ONE)
+    }
+
+    /// Computes a concave-down decreasing curve scaled to a specified output range.
+    ///
+    /// # Parameters
+    /// - `x`: Input value in the range `[0, 1e18]`. Represents the normalized progress along the curve.
+    /// - `min`: Minimum value of the output range. Returned when `x = 1e18`.
+    /// - `max`: Maximum value of the output range. Returned when `x = 0`.
+    /// - `power`: Controls the steepness of the curve. Values > 1e18 make the curve flatter at the start
+    ///            and steeper at the end. Zero is treated as 1e18.
+    ///
+    /// # Returns
+    /// - `y` in the range `[min, max]` corresponding to the concave-down decreasing curve.
+    pub fn concave_down_decreasing(x: u128, min: u128, max: u128, power: u128) -> u128 {
+        let p = if power == 0 { ONE } else { power };
+
+        // Compute concave-down decreasing curve
+        let curve = ONE.saturating_sub(Self::pow(x, p));
+
+        // Scale to [min, max]
+        min.saturating_add(Self::percent_mul(max.saturating_sub(min), curve))
+            .max(min)
+            .min(max)
+    }
+}
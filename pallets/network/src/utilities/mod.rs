@@ -1,6 +1,9 @@
 use super::*;
+pub mod concentration;
 pub mod delegate_staking;
+pub mod election;
 pub mod era;
+pub mod fixed_math;
 pub mod keys;
 pub mod math;
 pub mod misc;
@@ -21,10 +21,21 @@ pub const IP4: u64 = 4;
 pub const IP6: u64 = 41;
 pub const TCP: u64 = 6;
 pub const UDP: u64 = 17;
+pub const DNS: u64 = 53;
 pub const DNS4: u64 = 54;
 pub const DNS6: u64 = 55;
 pub const DNSADDR: u64 = 56;
+pub const WEBRTC_DIRECT: u64 = 280;
+pub const WEBRTC: u64 = 281;
+pub const P2P_CIRCUIT: u64 = 290;
 pub const P2P: u64 = 421;
+pub const TLS: u64 = 448;
+pub const SNI: u64 = 449;
+pub const NOISE: u64 = 454;
+pub const QUIC: u64 = 460;
+pub const QUIC_V1: u64 = 461;
+pub const WEBTRANSPORT: u64 = 465;
+pub const CERTHASH: u64 = 466;
 pub const WS: u64 = 477;
 pub const WSS: u64 = 478;
 
@@ -50,9 +61,11 @@ impl Multiaddr {
                 IP4 => advance(bytes, &mut i, 4)?,
                 IP6 => advance(bytes, &mut i, 16)?,
                 TCP | UDP => advance(bytes, &mut i, 2)?,
-                WS | WSS => advance(bytes, &mut i, 0)?, // WebSockets have no payload
-                DNS4 | DNS6 | DNSADDR => {
-                    // DNS protocols have a length-prefixed string
+                // Transport and security markers have no payload
+                WS | WSS | QUIC | QUIC_V1 | WEBTRANSPORT | WEBRTC | WEBRTC_DIRECT | TLS | NOISE
+                | P2P_CIRCUIT => advance(bytes, &mut i, 0)?,
+                DNS | DNS4 | DNS6 | DNSADDR | SNI => {
+                    // DNS protocols and TLS SNI have a length-prefixed string
                     let (len, read) =
                         decode_varint(&bytes[i..]).ok_or(MultiaddrError::InvalidVarint)?;
                     i += read;
@@ -64,6 +77,15 @@ impl Multiaddr {
                     i += read;
                     advance(bytes, &mut i, len as usize)?;
                 }
+                CERTHASH => {
+                    // Length-prefixed multihash of the node certificate
+                    let (len, read) =
+                        decode_varint(&bytes[i..]).ok_or(MultiaddrError::InvalidVarint)?;
+                    i += read;
+                    let start = i;
+                    advance(bytes, &mut i, len as usize)?;
+                    verify_multihash(&bytes[start..i])?;
+                }
                 _ => return Err(MultiaddrError::InvalidProtocol),
             }
 
@@ -140,7 +162,7 @@ impl Multiaddr {
                         port
                     ));
                 }
-                DNS4 | DNS6 | DNSADDR => {
+                DNS | DNS4 | DNS6 | DNSADDR | SNI => {
                     let (len, read) =
                         decode_varint(&self.bytes[i..]).ok_or(MultiaddrError::InvalidVarint)?;
                     i += read;
@@ -152,9 +174,11 @@ impl Multiaddr {
                         .map_err(|_| MultiaddrError::InvalidAddress)?;
 
                     let proto_str = match proto {
+                        DNS => "dns",
                         DNS4 => "dns4",
                         DNS6 => "dns6",
                         DNSADDR => "dnsaddr",
+                        SNI => "sni",
                         _ => unreachable!(),
                     };
 
@@ -162,6 +186,24 @@ impl Multiaddr {
                 }
                 WS => out.push("/ws".into()),
                 WSS => out.push("/wss".into()),
+                QUIC => out.push("/quic".into()),
+                QUIC_V1 => out.push("/quic-v1".into()),
+                WEBTRANSPORT => out.push("/webtransport".into()),
+                WEBRTC => out.push("/webrtc".into()),
+                WEBRTC_DIRECT => out.push("/webrtc-direct".into()),
+                TLS => out.push("/tls".into()),
+                NOISE => out.push("/noise".into()),
+                P2P_CIRCUIT => out.push("/p2p-circuit".into()),
+                CERTHASH => {
+                    let (len, read) =
+                        decode_varint(&self.bytes[i..]).ok_or(MultiaddrError::InvalidVarint)?;
+                    i += read;
+                    let hash_bytes = &self.bytes[i..i + len as usize];
+                    i += len as usize;
+
+                    // Multibase `u` prefix, base64url without padding
+                    out.push(format!("/certhash/u{}", base64url_encode(hash_bytes)));
+                }
                 P2P => {
                     let (len, read) =
                         decode_varint(&self.bytes[i..]).ok_or(MultiaddrError::InvalidVarint)?;
@@ -210,11 +252,13 @@ impl Multiaddr {
                     }
                 }
 
-                "dns4" | "dns6" | "dnsaddr" => {
+                "dns" | "dns4" | "dns6" | "dnsaddr" | "sni" => {
                     let name = parts.next().ok_or(MultiaddrError::InvalidAddress)?;
                     let proto_code = match proto {
+                        "dns" => DNS,
                         "dns4" => DNS4,
                         "dns6" => DNS6,
+                        "sni" => SNI,
                         _ => DNSADDR,
                     };
 
@@ -239,6 +283,47 @@ impl Multiaddr {
                     encode_varint(WSS, &mut bytes);
                 }
 
+                "quic" => {
+                    encode_varint(QUIC, &mut bytes);
+                }
+
+                "quic-v1" => {
+                    encode_varint(QUIC_V1, &mut bytes);
+                }
+
+                "webtransport" => {
+                    encode_varint(WEBTRANSPORT, &mut bytes);
+                }
+
+                "webrtc" => {
+                    encode_varint(WEBRTC, &mut bytes);
+                }
+
+                "webrtc-direct" => {
+                    encode_varint(WEBRTC_DIRECT, &mut bytes);
+                }
+
+                "tls" => {
+                    encode_varint(TLS, &mut bytes);
+                }
+
+                "noise" => {
+                    encode_varint(NOISE, &mut bytes);
+                }
+
+                "p2p-circuit" => {
+                    encode_varint(P2P_CIRCUIT, &mut bytes);
+                }
+
+                "certhash" => {
+                    let hash = parts.next().ok_or(MultiaddrError::InvalidAddress)?;
+                    let hash_bytes = decode_multibase(hash)?;
+
+                    encode_varint(CERTHASH, &mut bytes);
+                    encode_varint(hash_bytes.len() as u64, &mut bytes);
+                    bytes.extend_from_slice(&hash_bytes);
+                }
+
                 "p2p" => {
                     let peer = parts.next().ok_or(MultiaddrError::InvalidAddress)?;
                     let peer_bytes = bs58::decode(peer)
@@ -296,6 +381,104 @@ fn parse_ipv6(addr: &str) -> Result<[u16; 8], MultiaddrError> {
     Ok(segs)
 }
 
+/// Verifies a multihash is `<varint code><varint digest length><digest>` with no trailing bytes
+fn verify_multihash(hash: &[u8]) -> Result<(), MultiaddrError> {
+    let (_, read) = decode_varint(hash).ok_or(MultiaddrError::InvalidVarint)?;
+    let mut i = read;
+    let (len, read) = decode_varint(&hash[i..]).ok_or(MultiaddrError::InvalidVarint)?;
+    i += read;
+
+    if i + len as usize != hash.len() {
+        return Err(MultiaddrError::InvalidAddress);
+    }
+
+    Ok(())
+}
+
+/// Decodes a multibase string, supporting base64url (`u`), base58btc (`z`) and base16 (`f`)
+#[cfg(feature = "std")]
+fn decode_multibase(s: &str) -> Result<Vec<u8>, MultiaddrError> {
+    let mut chars = s.chars();
+    let prefix = chars.next().ok_or(MultiaddrError::InvalidAddress)?;
+    let data = chars.as_str();
+
+    match prefix {
+        'u' => base64url_decode(data),
+        'z' => bs58::decode(data)
+            .into_vec()
+            .map_err(|_| MultiaddrError::InvalidAddress),
+        'f' | 'F' => {
+            if data.len() % 2 != 0 {
+                return Err(MultiaddrError::InvalidAddress);
+            }
+            (0..data.len())
+                .step_by(2)
+                .map(|i| {
+                    u8::from_str_radix(&data[i..i + 2], 16)
+                        .map_err(|_| MultiaddrError::InvalidAddress)
+                })
+                .collect()
+        }
+        _ => Err(MultiaddrError::InvalidAddress),
+    }
+}
+
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes bytes as base64url without padding
+pub fn base64url_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity((input.len() * 4).div_ceil(3));
+
+    for chunk in input.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+
+        // 2, 3 or 4 output characters for 1, 2 or 3 input bytes
+        for k in 0..chunk.len() + 1 {
+            let index = (n >> (18 - 6 * k)) & 0x3F;
+            out.push(BASE64URL_ALPHABET[index as usize] as char);
+        }
+    }
+
+    out
+}
+
+/// Decodes base64url, with or without trailing padding
+pub fn base64url_decode(input: &str) -> Result<Vec<u8>, MultiaddrError> {
+    let input = input.trim_end_matches('=');
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+
+    for c in input.bytes() {
+        let value = BASE64URL_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(MultiaddrError::InvalidAddress)? as u32;
+
+        buffer = (buffer << 6) | value;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    // A single leftover character cannot encode a full byte
+    if bits >= 6 {
+        return Err(MultiaddrError::InvalidAddress);
+    }
+
+    Ok(out)
+}
+
 pub fn advance(bytes: &[u8], i: &mut usize, len: usize) -> Result<(), MultiaddrError> {
     if *i + len > bytes.len() {
         Err(MultiaddrError::Truncated)
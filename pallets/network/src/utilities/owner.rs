@@ -17,7 +17,8 @@
 // See all storage elements for docs in `lib.rs`
 
 use super::*;
-use libm::{ceil, log};
+use crate::election::MAX_VALIDATOR_ELECTION_EXPONENT;
+use crate::subnet_validator::MAX_FALLBACK_PROPOSERS;
 
 impl<T: Config> Pallet<T> {
     /// Owner pause subnet for up to max period
@@ -282,27 +283,25 @@ impl<T: Config> Pallet<T> {
     /// based on the `AbsentDecreaseReputationFactor`
     /// i.e. if a node is not in consensus data, it will be removed after this many epochs
     fn get_max_steps_for_node_removal(subnet_id: u32) -> u32 {
-        let one: f64 = Self::get_percent_as_f64(Self::percentage_factor_as_u128());
+        let one = Self::percentage_factor_as_u128();
 
         // Based on network min max parameters
-        let min_min_reputation: f64 =
-            Self::get_percent_as_f64(MinMinSubnetNodeReputation::<T>::get());
-        let min_absent_factor: f64 = Self::get_percent_as_f64(MinNodeReputationFactor::<T>::get());
+        let min_min_reputation = MinMinSubnetNodeReputation::<T>::get();
+        let min_absent_factor = MinNodeReputationFactor::<T>::get();
 
-        let r = one - min_absent_factor;
-        let n = ceil(log(min_min_reputation / one) / log(r)) as u32 + 1;
+        let r = one.saturating_sub(min_absent_factor);
+        let n = Self::get_decay_steps(min_min_reputation, r);
 
         // Subnet parameters
-        let min_reputation: f64 =
-            Self::get_percent_as_f64(MinSubnetNodeReputation::<T>::get(subnet_id));
+        let min_reputation = MinSubnetNodeReputation::<T>::get(subnet_id);
         let reputation_factors = Self::get_reputation_factors_for_epoch(
             subnet_id,
             Self::get_current_subnet_epoch_as_u32(subnet_id),
         );
-        let absent_factor: f64 = Self::get_percent_as_f64(reputation_factors.absent_decrease);
+        let absent_factor = reputation_factors.absent_decrease;
 
-        let r2 = one - absent_factor;
-        let n2 = ceil(log(min_reputation / one) / log(r2)) as u32 + 1;
+        let r2 = one.saturating_sub(absent_factor);
+        let n2 = Self::get_decay_steps(min_reputation, r2);
 
         // Redundantly check steps
         if n < n2 {
@@ -312,6 +311,27 @@ impl<T: Config> Pallet<T> {
         n2
     }
 
+    /// Steps for 1.0 multiplied by `r` each step to reach `target`, `ceil(ln(target) / ln(r)) + 1`
+    fn get_decay_steps(target: u128, r: u128) -> u32 {
+        let Some(ln_target) = Self::ln(target) else {
+            return u32::MAX;
+        };
+
+        // A full decay reaches zero in one step, no decay never reaches `target`
+        let ln_r = match Self::ln(r) {
+            Some(ln_r) if ln_r < 0 => ln_r,
+            _ => return 1,
+        };
+
+        if ln_target >= 0 {
+            return 1;
+        }
+
+        let steps = ln_target.unsigned_abs().div_ceil(ln_r.unsigned_abs());
+
+        u32::try_from(steps).unwrap_or(u32::MAX).saturating_add(1)
+    }
+
     /// Owner can remove the emergency validator set at any time
     ///
     /// # Arguments
@@ -1105,6 +1125,228 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub fn do_owner_update_consensus_data_policies(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        policies: Vec<ConsensusDataPolicy>,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        // No subnet can score more nodes than this
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let mut max_score: Option<u128> = None;
+        let mut min_included_score: Option<u128> = None;
+        let mut min_scored_nodes: Option<u32> = None;
+        let mut top_k: Option<u32> = None;
+        let mut normalized = false;
+
+        for policy in &policies {
+            let duplicate = match *policy {
+                ConsensusDataPolicy::MaxScore(value) => max_score.replace(value).is_some(),
+                ConsensusDataPolicy::MinIncludedScore(value) => {
+                    min_included_score.replace(value).is_some()
+                }
+                ConsensusDataPolicy::MinScoredNodes(value) => {
+                    ensure!(
+                        value > 0 && value <= max_subnet_nodes,
+                        Error::<T>::InvalidConsensusDataPolicy
+                    );
+                    min_scored_nodes.replace(value).is_some()
+                }
+                ConsensusDataPolicy::TopK(value) => {
+                    ensure!(
+                        value > 0 && value <= max_subnet_nodes,
+                        Error::<T>::InvalidConsensusDataPolicy
+                    );
+                    top_k.replace(value).is_some()
+                }
+                ConsensusDataPolicy::NormalizedScores => core::mem::replace(&mut normalized, true),
+            };
+            ensure!(!duplicate, Error::<T>::DuplicateConsensusDataPolicy);
+        }
+
+        if let (Some(min), Some(k)) = (min_scored_nodes, top_k) {
+            ensure!(min <= k, Error::<T>::ConflictingConsensusDataPolicies);
+        }
+
+        if let (Some(min), Some(max)) = (min_included_score, max_score) {
+            ensure!(min <= max, Error::<T>::ConflictingConsensusDataPolicies);
+        }
+
+        // Proposals of the current epoch may already be built against the current policies
+        let current_subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+        if let Some((effective_subnet_epoch, pending)) =
+            PendingSubnetConsensusDataPolicies::<T>::get(subnet_id)
+        {
+            if effective_subnet_epoch <= current_subnet_epoch {
+                SubnetConsensusDataPolicies::<T>::insert(subnet_id, pending);
+            }
+        }
+
+        let effective_subnet_epoch = current_subnet_epoch.saturating_add(1);
+        PendingSubnetConsensusDataPolicies::<T>::insert(
+            subnet_id,
+            (effective_subnet_epoch, policies.clone()),
+        );
+
+        Self::deposit_event(Event::ConsensusDataPoliciesUpdate {
+            subnet_id,
+            owner: coldkey,
+            policies,
+            effective_subnet_epoch,
+        });
+
+        Ok(())
+    }
+
+    pub fn do_owner_update_validator_election_mode(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        mode: ValidatorElectionMode,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        if let ValidatorElectionMode::Weighted(params) = mode {
+            ensure!(
+                params.stake_exponent <= MAX_VALIDATOR_ELECTION_EXPONENT
+                    && params.node_reputation_exponent <= MAX_VALIDATOR_ELECTION_EXPONENT
+                    && params.validator_reputation_exponent <= MAX_VALIDATOR_ELECTION_EXPONENT,
+                Error::<T>::InvalidValidatorElectionMode
+            );
+
+            ensure!(
+                params.max_validator_probability > 0
+                    && params.max_validator_probability <= Self::percentage_factor_as_u128(),
+                Error::<T>::InvalidValidatorElectionMode
+            );
+        }
+
+        SubnetValidatorElectionMode::<T>::insert(subnet_id, mode);
+
+        Self::deposit_event(Event::ValidatorElectionModeUpdate {
+            subnet_id: subnet_id,
+            owner: coldkey,
+            mode,
+        });
+
+        Ok(())
+    }
+
+    pub fn do_owner_update_attestation_weighting(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        weighting: AttestationWeighting,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        // Proposals of the current epoch may already be attested under the current weighting
+        let current_subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+        if let Some((effective_subnet_epoch, pending)) =
+            PendingSubnetAttestationWeighting::<T>::get(subnet_id)
+        {
+            if effective_subnet_epoch <= current_subnet_epoch {
+                SubnetAttestationWeighting::<T>::insert(subnet_id, pending);
+            }
+        }
+
+        let effective_subnet_epoch = current_subnet_epoch.saturating_add(1);
+        PendingSubnetAttestationWeighting::<T>::insert(
+            subnet_id,
+            (effective_subnet_epoch, weighting),
+        );
+
+        Self::deposit_event(Event::AttestationWeightingUpdate {
+            subnet_id,
+            owner: coldkey,
+            weighting,
+            effective_subnet_epoch,
+        });
+
+        Ok(())
+    }
+
+    pub fn do_owner_update_node_concentration_caps(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        caps: NodeConcentrationCaps,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        ensure!(
+            caps.max_nodes_per_coldkey != Some(0) && caps.max_nodes_per_validator != Some(0),
+            Error::<T>::InvalidNodeConcentrationCaps
+        );
+
+        SubnetNodeConcentrationCaps::<T>::insert(subnet_id, caps);
+
+        Self::deposit_event(Event::NodeConcentrationCapsUpdate {
+            subnet_id: subnet_id,
+            owner: coldkey,
+            caps,
+        });
+
+        Ok(())
+    }
+
+    pub fn do_owner_update_fallback_proposers(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        config: Option<FallbackProposerConfig>,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        match config {
+            Some(config) => {
+                ensure!(
+                    config.start_progression <= Self::percentage_factor_as_u128(),
+                    Error::<T>::InvalidFallbackProposerConfig
+                );
+
+                ensure!(
+                    config.max_proposers > 0 && config.max_proposers <= MAX_FALLBACK_PROPOSERS,
+                    Error::<T>::InvalidFallbackProposerConfig
+                );
+
+                SubnetFallbackProposers::<T>::insert(subnet_id, config);
+            }
+            None => SubnetFallbackProposers::<T>::remove(subnet_id),
+        }
+
+        Self::deposit_event(Event::FallbackProposersUpdate {
+            subnet_id: subnet_id,
+            owner: coldkey,
+            config,
+        });
+
+        Ok(())
+    }
+
     pub fn do_owner_update_min_subnet_node_reputation(
         origin: T::RuntimeOrigin,
         subnet_id: u32,
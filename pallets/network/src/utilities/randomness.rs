@@ -15,6 +15,39 @@
 
 use super::*;
 use sp_runtime::traits::Hash;
+use sp_std::marker::PhantomData;
+
+/// Number of previous beacon rounds searched for a seed when the current round was missed.
+pub const MAX_BEACON_LOOKBACK_ROUNDS: u32 = 8;
+
+/// A source of unbiasable randomness that produces one output per round.
+///
+/// A round is an overwatch epoch. Validator election in overwatch epoch `n` reads round `n - 1`,
+/// which is finalized before any election of epoch `n` runs.
+pub trait RandomnessBeacon<Hash> {
+    /// Returns the beacon output for `round`, or `None` if the round was missed.
+    fn random_seed(round: u32) -> Option<Hash>;
+}
+
+/// No beacon. Election always takes the `T::Randomness` fallback path.
+impl<Hash> RandomnessBeacon<Hash> for () {
+    fn random_seed(_round: u32) -> Option<Hash> {
+        None
+    }
+}
+
+/// Commit-reveal beacon fed by overwatch nodes.
+///
+/// Overwatch nodes commit to entropy in the commit period of an overwatch epoch and reveal it in
+/// the reveal period, see `commit_beacon_entropy` and `reveal_beacon_entropy`. The round is
+/// finalized into `BeaconRandomness` at the start of the next overwatch epoch.
+pub struct OverwatchRandomnessBeacon<T>(PhantomData<T>);
+
+impl<T: Config> RandomnessBeacon<T::Hash> for OverwatchRandomnessBeacon<T> {
+    fn random_seed(round: u32) -> Option<T::Hash> {
+        BeaconRandomness::<T>::get(round)
+    }
+}
 
 impl<T: Config> Pallet<T> {
     pub fn get_random_number_v2(seed: u32) -> u32 {
@@ -65,8 +98,8 @@ impl<T: Config> Pallet<T> {
     ///
     /// This samples an index in `[0, upper_bound)`, mixes caller-provided domain data with pallet
     /// and parent-block domain data, and avoids the direct modulo path except as a deterministic
-    /// fallback. The runtime still uses `pallet_insecure_randomness_collective_flip`, so this is
-    /// not cryptographic randomness.
+    /// fallback. This reads `T::Randomness`, which is not cryptographic randomness, and is used by
+    /// `get_beacon_random_index` when the beacon has no seed.
     pub(crate) fn get_bounded_random_index<Domain: Encode>(
         domain: Domain,
         upper_bound: u32,
@@ -83,28 +116,86 @@ impl<T: Config> Pallet<T> {
         let parent_hash = frame_system::Pallet::<T>::parent_hash();
         let subject = (T::PalletId::get(), parent_hash, domain, modulus);
         let (random_seed, _) = T::Randomness::random(&subject.encode());
+
+        Some(Self::bounded_index_from_seed(
+            random_seed.as_ref(),
+            &subject.encode(),
+            modulus,
+        ))
+    }
+
+    fn first_u64_from_hash(hash: T::Hash) -> u64 {
+        let mut array = [0u8; 8];
+        for (dst, src) in array.iter_mut().zip(hash.as_ref().iter()) {
+            *dst = *src;
+        }
+        u64::from_le_bytes(array)
+    }
+
+    /// Return a bounded random index seeded by `T::RandomnessBeacon`.
+    ///
+    /// The beacon round is the previous overwatch epoch. If that round was missed, the most recent
+    /// seed within `MAX_BEACON_LOOKBACK_ROUNDS` rounds is rehashed with the missed round so the
+    /// output still changes every round. If no seed exists in that window, this falls back to
+    /// `get_bounded_random_index`.
+    pub(crate) fn get_beacon_random_index<Domain: Encode>(
+        domain: Domain,
+        upper_bound: u32,
+    ) -> Option<u32> {
+        if upper_bound == 0 {
+            return None;
+        }
+
+        if upper_bound == 1 {
+            return Some(0);
+        }
+
+        let round = Self::get_current_overwatch_epoch_as_u32().saturating_sub(1);
+
+        let Some(seed) = Self::get_beacon_seed(round) else {
+            return Self::get_bounded_random_index(domain, upper_bound);
+        };
+
+        let modulus = upper_bound as u64;
+        let subject = (T::PalletId::get(), round, domain, modulus);
+
+        Some(Self::bounded_index_from_seed(
+            seed.as_ref(),
+            &subject.encode(),
+            modulus,
+        ))
+    }
+
+    /// Returns the beacon seed for `round`.
+    ///
+    /// If the round was missed, the latest seed of the previous `MAX_BEACON_LOOKBACK_ROUNDS`
+    /// rounds is mixed with `round`.
+    pub fn get_beacon_seed(round: u32) -> Option<T::Hash> {
+        if let Some(seed) = T::RandomnessBeacon::random_seed(round) {
+            return Some(seed);
+        }
+
+        (1..=MAX_BEACON_LOOKBACK_ROUNDS)
+            .take_while(|offset| *offset <= round)
+            .find_map(|offset| T::RandomnessBeacon::random_seed(round - offset))
+            .map(|seed| T::Hashing::hash_of(&(seed, round)))
+    }
+
+    fn bounded_index_from_seed(random_seed: &[u8], subject: &[u8], modulus: u64) -> u32 {
         let rejection_zone = u64::MAX - (u64::MAX % modulus);
         let mut fallback = 0u64;
         const MAX_RANDOM_INDEX_ATTEMPTS: u32 = 8;
 
         for attempt in 0..MAX_RANDOM_INDEX_ATTEMPTS {
-            let entropy = T::Hashing::hash_of(&(random_seed.as_ref(), subject.encode(), attempt));
+            let entropy = T::Hashing::hash_of(&(random_seed, subject, attempt));
             let value = Self::first_u64_from_hash(entropy);
             fallback = value;
 
             if value < rejection_zone {
-                return Some((value % modulus) as u32);
+                return (value % modulus) as u32;
             }
         }
 
-        Some((fallback % modulus) as u32)
-    }
-
-    fn first_u64_from_hash(hash: T::Hash) -> u64 {
-        let mut array = [0u8; 8];
-        for (dst, src) in array.iter_mut().zip(hash.as_ref().iter()) {
-            *dst = *src;
-        }
-        u64::from_le_bytes(array)
+        (fallback % modulus) as u32
     }
 }
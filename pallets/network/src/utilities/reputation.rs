@@ -266,14 +266,10 @@ impl<T: Config> Pallet<T> {
             return prev_reputation;
         }
         let factor = Self::percent_mul(factor_1, factor_2.unwrap_or(one));
-        let one_f64 = Self::get_percent_as_f64(one);
-        let factor_f64 = Self::get_percent_as_f64(factor);
-        let prev_reputation_f64 = Self::get_percent_as_f64(prev_reputation);
-
-        let x = Self::pow(prev_reputation_f64, one_f64 + factor_f64);
-        let increase = x * factor_f64;
-        (((prev_reputation_f64 + increase) * Self::percentage_factor_as_f64()) as u128)
-            .min(Self::percentage_factor_as_u128())
+
+        let x = Self::pow(prev_reputation, one.saturating_add(factor));
+        let increase = Self::percent_mul(x, factor);
+        prev_reputation.saturating_add(increase).min(one)
     }
 
     /// Decrease reputation function designed to get a reputation back to 0.0
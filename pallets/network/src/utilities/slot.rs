@@ -30,8 +30,7 @@ impl<T: Config> Pallet<T> {
 
         let percentage_factor = Self::percentage_factor_as_u128();
 
-        let stake_weight_pow: f64 =
-            Self::get_percent_as_f64(OverwatchStakeWeightFactor::<T>::get());
+        let stake_weight_pow: u128 = OverwatchStakeWeightFactor::<T>::get();
         weight = weight.saturating_add(db_weight.reads(1));
         let mut total_stake_weight: u128 = 0;
 
@@ -47,7 +46,9 @@ impl<T: Config> Pallet<T> {
         // Step 1: Group reveals by subnet
         // {node_id, stake_weight}
         let mut node_stake_weights: BTreeMap<u32, u128> = BTreeMap::new();
-        // {subnet_id, (subnet_weight sum, {node_id, subnet_weight})}
+        // {node_id, stake_balance}
+        let mut node_stakes: BTreeMap<u32, u128> = BTreeMap::new();
+        // {subnet_id, (revealed stake sum, {node_id, subnet_weight})}
         let mut subnet_reveals: BTreeMap<u32, (u128, BTreeMap<u32, u128>)> = BTreeMap::new();
         for ((subnet_id, overwatch_node_id), subnet_weight) in
             OverwatchReveals::<T>::iter_prefix((current_overwatch_epoch.saturating_sub(1),))
@@ -66,19 +67,22 @@ impl<T: Config> Pallet<T> {
                 // OverwatchNodeStakeBalance
                 weight = weight.saturating_add(db_weight.reads(1));
 
-                let stake_weight_adj =
-                    Self::get_f64_as_percentage(Self::pow(stake_balance as f64, stake_weight_pow));
+                // The balance is taken as a 1e18 precision amount, the constant scale this adds
+                // cancels out when the stake weights are normalized below
+                let stake_weight_adj = Self::pow(stake_balance, stake_weight_pow);
 
                 total_stake_weight = total_stake_weight.saturating_add(stake_weight_adj);
 
                 node_stake_weights.insert(overwatch_node_id, stake_weight_adj);
+                node_stakes.insert(overwatch_node_id, stake_balance);
                 node_hotkeys.insert(overwatch_node_id, overwatch_node.hotkey.clone());
             }
 
             let entry = subnet_reveals
                 .entry(subnet_id)
                 .or_insert((0, BTreeMap::new()));
-            entry.0 = entry.0.saturating_add(subnet_weight); // sum all weights for this subnet
+            let stake_balance = node_stakes.get(&overwatch_node_id).copied().unwrap_or(0);
+            entry.0 = entry.0.saturating_add(stake_balance); // sum the stake that revealed for this subnet
             entry.1.insert(overwatch_node_id, subnet_weight); // store each node's weight per subnet (subnet weight the overwatch submitted)
         }
 
@@ -94,39 +98,65 @@ impl<T: Config> Pallet<T> {
         }
 
         // Step 2: Iterate each subnet
-        // - Get subnet weights from nodes
+        // - Get the stake-weighted median subnet weight from nodes
+        // - Only store it when the revealing stake reaches `OverwatchWeightQuorum`
+        // - Clip nodes that deviate beyond `OverwatchWeightClipBand`
         // - Score nodes
-        for (&subnet_id, (_sum_weights, node_weights)) in subnet_reveals.iter() {
-            // Get node stake weight
-            let total_adjusted: u128 = node_weights
-                .iter()
-                .filter_map(|(&node_id, subnet_weight)| {
-                    node_stake_weights
-                        .get(&node_id)
-                        .map(|stake_weight| Self::percent_mul(*subnet_weight, *stake_weight))
-                })
-                .fold(0u128, |acc, value| acc.saturating_add(value))
-                .min(percentage_factor);
+        let clip_band = OverwatchWeightClipBand::<T>::get();
+        // OverwatchWeightClipBand
+        weight = weight.saturating_add(db_weight.reads(1));
+
+        let quorum_stake = Self::percent_mul(total_stake, OverwatchWeightQuorum::<T>::get());
+        // OverwatchWeightQuorum
+        weight = weight.saturating_add(db_weight.reads(1));
+
+        // {node_id, (reveals, clipped subnet IDs)}
+        let mut node_clips: BTreeMap<u32, (u32, Vec<u32>)> = BTreeMap::new();
+        for (&subnet_id, (revealed_stake, node_weights)) in subnet_reveals.iter() {
+            let median_weight = Self::weighted_median(
+                node_weights
+                    .iter()
+                    .map(|(node_id, subnet_weight)| {
+                        (
+                            *subnet_weight,
+                            node_stake_weights.get(node_id).copied().unwrap_or(0),
+                        )
+                    })
+                    .collect(),
+            )
+            .min(percentage_factor);
 
             //
             // --- Score subnets
             //
 
-            // Data only (currently)
-            OverwatchSubnetWeights::<T>::insert(
-                current_overwatch_epoch.saturating_sub(1),
-                subnet_id,
-                total_adjusted,
-            );
-            weight = weight.saturating_add(db_weight.writes(1));
+            // Without a quorum of the overwatch stake the subnet keeps `DefaultOverwatchSubnetWeight`
+            // in the emissions, otherwise a few low stake nodes would set its weight alone
+            if *revealed_stake >= quorum_stake {
+                OverwatchSubnetWeights::<T>::insert(
+                    current_overwatch_epoch.saturating_sub(1),
+                    subnet_id,
+                    median_weight,
+                );
+                weight = weight.saturating_add(db_weight.writes(1));
+            }
 
             // Step 2c: Score nodes and accumulate
             for (&node_id, &subnet_weight) in node_weights.iter() {
                 // Get the deviation from the resulting score.
                 // We check the abs diff since the submitted weights can only be between 0.0-1.0 [*1e18]
-                let deviation = subnet_weight.abs_diff(total_adjusted);
+                let deviation = subnet_weight.abs_diff(median_weight);
+
+                let clips = node_clips.entry(node_id).or_insert((0, Vec::new()));
+                clips.0.saturating_inc();
+                if deviation > clip_band {
+                    // Clipped submissions don't score
+                    clips.1.push(subnet_id);
+                    continue;
+                }
+
                 let closeness_score = percentage_factor.saturating_sub(deviation);
-                let node_final_score = Self::percent_mul(closeness_score, total_adjusted);
+                let node_final_score = Self::percent_mul(closeness_score, median_weight);
 
                 // Step 3: Accumulate score
                 let score = node_total_scores.entry(node_id).or_insert(0);
@@ -134,6 +164,42 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // Step 3b: Decrease the `ow_score` of clipped nodes by the share of their reveals clipped
+        let clip_reputation_factor = OverwatchClipReputationFactor::<T>::get();
+        // OverwatchClipReputationFactor
+        weight = weight.saturating_add(db_weight.reads(1));
+        for (node_id, (reveals, clipped_subnet_ids)) in node_clips {
+            if clipped_subnet_ids.is_empty() {
+                continue;
+            }
+
+            let clipped_share =
+                Self::percent_div(clipped_subnet_ids.len() as u128, reveals as u128);
+
+            // OverwatchNodeValidatorId, ValidatorReputation
+            weight = weight.saturating_add(db_weight.reads_writes(2, 1));
+            let Some(validator_id) = OverwatchNodeValidatorId::<T>::get(node_id) else {
+                continue;
+            };
+
+            let mut ow_score = 0;
+            ValidatorReputation::<T>::mutate(validator_id, |reputation| {
+                reputation.ow_score = Self::decrease_rep(
+                    reputation.ow_score,
+                    clip_reputation_factor,
+                    Some(clipped_share),
+                );
+                ow_score = reputation.ow_score;
+            });
+
+            Self::deposit_event(Event::OverwatchSubmissionsClipped {
+                overwatch_node_id: node_id,
+                overwatch_epoch: current_overwatch_epoch.saturating_sub(1),
+                subnet_ids: clipped_subnet_ids,
+                ow_score,
+            });
+        }
+
         //
         // Step 4: Normalize node scores
         //
@@ -191,6 +257,7 @@ impl<T: Config> Pallet<T> {
     /// - Distributes emissions: `distribute_rewards`
     /// - Elects validator: `elect_validator`
     /// - Handles registration queue (i.e., activates nodes from the queue): `handle_registration_queue`
+    /// - Drains active nodes over the node concentration caps: `drain_node_concentration_excess`
     /// = Updates burn rate EMA: `update_burn_rate_for_epoch`
     pub fn emission_step(
         weight_meter: &mut WeightMeter,
@@ -285,10 +352,21 @@ impl<T: Config> Pallet<T> {
                     // See `calculate_subnet_weights`
                     Self::elect_validator(subnet_id, current_subnet_epoch, block);
                     // TotalSubnetElectableNodes
-                    weight_meter.consume(db_weight.reads(1));
-                    weight_meter.consume(T::WeightInfo::elect_validator(
-                        TotalSubnetElectableNodes::<T>::get(subnet_id),
-                    ));
+                    // SubnetValidatorElectionMode
+                    weight_meter.consume(db_weight.reads(2));
+                    // SubnetFallbackProposers | SubnetFallbackProposerIds
+                    // Pruned SubnetFallbackProposerIds | SubnetFallbackConsensusSubmissions
+                    weight_meter.consume(db_weight.reads_writes(1, 3));
+                    let electable_nodes = TotalSubnetElectableNodes::<T>::get(subnet_id);
+                    weight_meter.consume(T::WeightInfo::elect_validator(electable_nodes));
+                    if matches!(
+                        SubnetValidatorElectionMode::<T>::get(subnet_id),
+                        ValidatorElectionMode::Weighted(_)
+                    ) {
+                        // Per node in a weighted election:
+                        // SubnetNodesData, NodeSubnetStake, SubnetNodeReputation, ValidatorReputation
+                        weight_meter.consume(db_weight.reads(4 * electable_nodes as u64));
+                    }
 
                     // After election, we activate nodes in the queue
                     // We execute the queue here only if the subnet has weights
@@ -297,6 +375,13 @@ impl<T: Config> Pallet<T> {
                     // This will run if there is block weight remaining to call
                     Self::handle_registration_queue(weight_meter, subnet_id, current_subnet_epoch);
 
+                    // Remove an active node over the subnet's node concentration caps, if any
+                    Self::drain_node_concentration_excess(
+                        weight_meter,
+                        subnet_id,
+                        current_subnet_epoch,
+                    );
+
                     // This will run if there is block weight remaining to call
                     Self::update_burn_rate_for_epoch(weight_meter, subnet_id);
                 }
@@ -386,7 +471,7 @@ impl<T: Config> Pallet<T> {
             let total_weight_needed = per_node_processing_weight
                 .saturating_add(per_node_cleanup_weight)
                 .saturating_add(storage_write_weight)
-                .saturating_add(db_weight.reads_writes(5, 5)); // Account for do_activate_subnet_node weight consumption
+                .saturating_add(db_weight.reads_writes(7, 5)); // Account for do_activate_subnet_node weight consumption
 
             // Check if we can consume the complete operation (activation + cleanup + db updates)
             if !weight_meter.can_consume(total_weight_needed) {
@@ -469,22 +554,22 @@ impl<T: Config> Pallet<T> {
         let mut weight = Weight::zero();
         let db_weight = T::DbWeight::get();
 
-        let subnet_distribution_power =
-            Self::get_percent_as_f64(SubnetDistributionPower::<T>::get());
+        let percentage_factor = Self::percentage_factor_as_u128();
+        let subnet_distribution_power = SubnetDistributionPower::<T>::get();
         let total_delegate_stake = TotalDelegateStake::<T>::get();
 
         // {subnet_id, weight}
-        let mut subnet_weights: BTreeMap<u32, f64> = BTreeMap::new();
+        let mut subnet_weights: BTreeMap<u32, u128> = BTreeMap::new();
         // {subnet_id, count}
-        let mut subnet_weight_sum: f64 = 0.0;
+        let mut subnet_weight_sum: u128 = 0;
         let total_electable_nodes = TotalElectableNodes::<T>::get();
         let mut total_subnet_reads = 0u64;
 
         let weight_factors = SubnetWeightFactors::<T>::get();
         weight = weight.saturating_add(db_weight.reads(1));
-        let delegate_stake_factor = Self::get_percent_as_f64(weight_factors.delegate_stake);
-        let node_count_factor = Self::get_percent_as_f64(weight_factors.node_count);
-        let net_flow_factor = Self::get_percent_as_f64(weight_factors.net_flow);
+        let delegate_stake_factor = weight_factors.delegate_stake;
+        let node_count_factor = weight_factors.node_count;
+        let net_flow_factor = weight_factors.net_flow;
 
         // SubnetDistributionPower | TotalDelegateStake
         // TotalElectableNodes | DelegateStakeWeightFactor
@@ -512,31 +597,25 @@ impl<T: Config> Pallet<T> {
             let total_subnet_delegate_stake = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
             weight = weight.saturating_add(db_weight.reads(1));
 
-            // - Get delegate stake weight in f64
-            let subnet_dstake_weight: f64 = if total_delegate_stake == 0 {
-                0.0
-            } else {
-                (total_subnet_delegate_stake as f64 / total_delegate_stake as f64).clamp(0.0, 1.0)
-            };
+            // - Get delegate stake weight
+            let subnet_dstake_weight: u128 =
+                Self::percent_div(total_subnet_delegate_stake, total_delegate_stake)
+                    .min(percentage_factor);
 
-            // - Get node count weight in f64
+            // - Get node count weight
             let electable_nodes_count = TotalSubnetElectableNodes::<T>::get(subnet_id);
             weight = weight.saturating_add(db_weight.reads(1));
-            let subnet_nodes_weight = if total_electable_nodes == 0 {
-                0.0
-            } else {
-                electable_nodes_count as f64 / total_electable_nodes as f64
-            };
+            let subnet_nodes_weight: u128 =
+                Self::percent_div(electable_nodes_count as u128, total_electable_nodes as u128);
 
-            // - Get Overwatch weight in f64
+            // - Get Overwatch weight
             let overwatch_subnet_weight = match OverwatchSubnetWeights::<T>::try_get(
                 current_overwatch_epoch.saturating_sub(1),
                 subnet_id,
             ) {
-                Ok(weight) => (Self::get_percent_as_f64(weight)
-                    * Self::get_percent_as_f64(OverwatchWeightFactor::<T>::get()))
-                .min(1.0),
-                Err(()) => Self::get_percent_as_f64(DefaultOverwatchSubnetWeight::<T>::get()),
+                Ok(weight) => Self::percent_mul(weight, OverwatchWeightFactor::<T>::get())
+                    .min(percentage_factor),
+                Err(()) => DefaultOverwatchSubnetWeight::<T>::get(),
             };
 
             // OverwatchSubnetWeights
@@ -544,42 +623,34 @@ impl<T: Config> Pallet<T> {
 
             // - Get combined weight (stake + node count + inflow) * overwatchers weight
 
-            let subnet_inflow_weight =
-                Self::get_percent_as_f64(inflow_weights.get(&subnet_id).cloned().unwrap_or(0));
-            let subnet_weight = ((subnet_dstake_weight * delegate_stake_factor
-                + subnet_nodes_weight * node_count_factor
-                + subnet_inflow_weight * net_flow_factor)
-                * overwatch_subnet_weight)
-                .clamp(0.0, 1.0);
+            let subnet_inflow_weight = inflow_weights.get(&subnet_id).cloned().unwrap_or(0);
+            let combined_weight = Self::percent_mul(subnet_dstake_weight, delegate_stake_factor)
+                .saturating_add(Self::percent_mul(subnet_nodes_weight, node_count_factor))
+                .saturating_add(Self::percent_mul(subnet_inflow_weight, net_flow_factor));
+            let subnet_weight =
+                Self::percent_mul(combined_weight, overwatch_subnet_weight).min(percentage_factor);
 
             // - Adj weight (to later be normalized)
-            let adj_subnet_weight: f64 = Self::pow(subnet_weight, subnet_distribution_power);
-
-            if !adj_subnet_weight.is_finite() {
-                continue;
-            }
+            let adj_subnet_weight: u128 = Self::pow(subnet_weight, subnet_distribution_power);
 
             subnet_weights.insert(subnet_id, adj_subnet_weight);
-            subnet_weight_sum += adj_subnet_weight;
+            subnet_weight_sum = subnet_weight_sum.saturating_add(adj_subnet_weight);
             weight = weight.saturating_add(Weight::from_parts(400_000, 0));
         }
 
         weight = weight.saturating_add(db_weight.reads(total_subnet_reads));
         let mut subnet_weights_normalized: BTreeMap<u32, u128> = BTreeMap::new();
-        let percentage_factor = Self::percentage_factor_as_u128();
 
         // --- Normalize delegate stake weights from power
         for (subnet_id, subnet_weight) in subnet_weights {
-            if subnet_weight_sum <= 0.0 || !subnet_weight_sum.is_finite() {
+            if subnet_weight_sum == 0 {
                 continue;
             }
-            let weight_normalized_f64 =
-                subnet_weight / subnet_weight_sum * percentage_factor as f64;
-            if !weight_normalized_f64.is_finite() || weight_normalized_f64 <= 0.0 {
+            let weight_normalized: u128 =
+                Self::percent_div(subnet_weight, subnet_weight_sum).min(percentage_factor);
+            if weight_normalized == 0 {
                 continue;
             }
-            let weight_normalized: u128 =
-                weight_normalized_f64.min(percentage_factor as f64) as u128;
             subnet_weights_normalized.insert(subnet_id, weight_normalized);
             weight = weight.saturating_add(Weight::from_parts(400_000, 0));
         }
@@ -662,13 +733,13 @@ impl<T: Config> Pallet<T> {
         let mut weight = Weight::zero();
         let db_weight = T::DbWeight::get();
 
-        // SubnetConsensusSubmission
-        weight = weight.saturating_add(db_weight.reads(1));
+        let (submission, selection_weight) =
+            Self::select_consensus_submission(subnet_id, prev_subnet_epoch);
+        weight = weight.saturating_add(selection_weight);
 
-        let submission = match SubnetConsensusSubmission::<T>::try_get(subnet_id, prev_subnet_epoch)
-        {
-            Ok(submission) => submission,
-            Err(()) => {
+        let submission = match submission {
+            Some(submission) => submission,
+            None => {
                 // Check if a validator was elected
                 // - Make sure they did their job and submitted consensus data
                 // - If not, penalize the subnet and validator
@@ -691,41 +762,18 @@ impl<T: Config> Pallet<T> {
                     // - SubnetReputation
                     weight = weight.saturating_add(db_weight.reads_writes(2, 1));
 
-                    // The elected validator cannot remove self if elected so we don't check if they exist
-
-                    //
-                    // Update node rep
-                    //
-                    let reputation_factors =
-                        Self::get_reputation_factors_for_epoch(subnet_id, prev_subnet_epoch);
-                    if let Some(rep) = SubnetNodeReputation::<T>::get(subnet_id, validator_id) {
-                        Self::decrease_and_return_node_reputation(
-                            subnet_id,
-                            validator_id,
-                            rep,
-                            reputation_factors.validator_absent_decrease,
-                            None,
-                        );
-                    }
-
-                    // NOTE: We don't check if below minimum node reputation here to possibly
-                    // remove the node from the subnet, as this is done in the bank/rewards.rs ``distribute_rewards``
-
-                    // Reads:
-                    // - SubnetNodeReputation
-                    // - SubnetReputationFactorSchedules
-                    // Writes:
-                    // - SubnetNodeReputation
-                    weight = weight.saturating_add(db_weight.reads_writes(2, 1));
+                    weight = weight.saturating_add(Self::decrease_absent_validator_reputation(
+                        subnet_id,
+                        prev_subnet_epoch,
+                        validator_id,
+                    ));
                 }
 
                 return (None, weight);
             }
         };
 
-        // --- Get all qualified possible attestors
-        let validator_ids = Self::canonicalize_consensus_validator_ids(submission.validator_ids);
-        let max_attestors: u128 = validator_ids.len() as u128;
+        let attestation_ratio = Self::get_consensus_attestation_ratio(&submission);
 
         weight = weight.saturating_add(db_weight.reads(1));
 
@@ -738,8 +786,7 @@ impl<T: Config> Pallet<T> {
             validator_subnet_node_id: submission.validator_id,
             validator_epoch_progress: submission.validator_epoch_progress,
             validator_reward_factor: submission.validator_reward_factor,
-            attestation_ratio: Self::percent_div(submission.attests.len() as u128, max_attestors)
-                .clamp(0, Self::percentage_factor_as_u128()),
+            attestation_ratio,
             weight_sum,
             data_length: data.len() as u32,
             data,
@@ -752,6 +799,116 @@ impl<T: Config> Pallet<T> {
         (Some(consensus_data), weight)
     }
 
+    /// The proposal used for the rewards of a subnet epoch
+    ///
+    /// The elected validator's proposal, unless a fallback proposal reached a super-majority
+    /// attestation ratio and a higher ratio than it. On ties the elected validator's proposal
+    /// wins, then the earliest fallback proposal. If no proposal qualifies and the elected
+    /// validator didn't propose, returns `None`.
+    pub fn select_consensus_submission(
+        subnet_id: u32,
+        subnet_epoch: u32,
+    ) -> (Option<ConsensusData<T>>, Weight) {
+        let mut weight = Weight::zero();
+        let db_weight = T::DbWeight::get();
+
+        // SubnetConsensusSubmission
+        // SubnetFallbackConsensusSubmissions
+        weight = weight.saturating_add(db_weight.reads(2));
+
+        let submission = SubnetConsensusSubmission::<T>::get(subnet_id, subnet_epoch);
+        let fallback_proposals =
+            SubnetFallbackConsensusSubmissions::<T>::get(subnet_id, subnet_epoch);
+
+        if fallback_proposals.is_empty() {
+            return (submission, weight);
+        }
+
+        weight = weight.saturating_add(db_weight.reads(1));
+        let super_majority_attestation_ratio = SuperMajorityAttestationRatio::<T>::get();
+
+        // --- The fallback proposal with the highest super-majority, the earliest on ties
+        let mut selected: Option<(u128, ConsensusData<T>)> = None;
+        for proposal in fallback_proposals {
+            let attestation_ratio = Self::get_consensus_attestation_ratio(&proposal);
+            if attestation_ratio >= super_majority_attestation_ratio
+                && selected.as_ref().map_or(true, |(selected_ratio, _)| {
+                    attestation_ratio > *selected_ratio
+                })
+            {
+                selected = Some((attestation_ratio, proposal));
+            }
+        }
+
+        let Some((attestation_ratio, fallback_proposal)) = selected else {
+            return (submission, weight);
+        };
+
+        match submission {
+            Some(submission) => {
+                let submission_ratio = Self::get_consensus_attestation_ratio(&submission);
+                if submission_ratio >= attestation_ratio {
+                    return (Some(submission), weight);
+                }
+            }
+            None => {
+                // --- The elected validator was absent, the subnet isn't penalized because a
+                //     fallback proposal formed consensus
+                weight = weight.saturating_add(db_weight.reads(1));
+                if let Some(validator_id) =
+                    SubnetElectedValidator::<T>::get(subnet_id, subnet_epoch)
+                {
+                    weight = weight.saturating_add(Self::decrease_absent_validator_reputation(
+                        subnet_id,
+                        subnet_epoch,
+                        validator_id,
+                    ));
+                }
+            }
+        }
+
+        Self::deposit_event(Event::FallbackProposalSelected {
+            subnet_id,
+            subnet_epoch,
+            subnet_node_id: fallback_proposal.validator_id,
+        });
+
+        (Some(fallback_proposal), weight)
+    }
+
+    /// Decrease the node reputation of an elected validator that didn't propose
+    fn decrease_absent_validator_reputation(
+        subnet_id: u32,
+        subnet_epoch: u32,
+        validator_id: u32,
+    ) -> Weight {
+        // The elected validator cannot remove self if elected so we don't check if they exist
+
+        //
+        // Update node rep
+        //
+        let reputation_factors = Self::get_reputation_factors_for_epoch(subnet_id, subnet_epoch);
+        if let Some(rep) = SubnetNodeReputation::<T>::get(subnet_id, validator_id) {
+            Self::decrease_and_return_node_reputation(
+                subnet_id,
+                validator_id,
+                rep,
+                reputation_factors.validator_absent_decrease,
+                None,
+            );
+        }
+
+        // NOTE: We don't check if below minimum node reputation here to possibly
+        // remove the node from the subnet, as this is done in the bank/rewards.rs ``distribute_rewards``
+
+        // Reads:
+        // - SubnetNodeReputation
+        // - SubnetReputationFactorSchedules
+        // Writes:
+        // - SubnetNodeReputation
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+
     /// Calculate the subnets rewards and how they are distributed throughout the subnet
     ///
     /// # Arguments
@@ -780,9 +937,19 @@ impl<T: Config> Pallet<T> {
 
         let overall_subnet_reward: u128 = Self::percent_mul(overall_rewards, emission_weight);
 
+        // SubnetOwnerPercentage | SubnetDelegateStakeRewardsPercentage
+        weight = weight.saturating_add(db_weight.reads(2));
+
+        (
+            Self::split_subnet_rewards(subnet_id, overall_subnet_reward),
+            weight,
+        )
+    }
+
+    /// Split a subnet's rewards between the owner, the delegate stake pool and the subnet nodes
+    pub fn split_subnet_rewards(subnet_id: u32, overall_subnet_reward: u128) -> RewardsData {
         // --- Get owner rewards
         let subnet_owner_percentage = SubnetOwnerPercentage::<T>::get();
-        weight = weight.saturating_add(db_weight.reads(1));
         let subnet_owner_reward: u128 =
             Self::percent_mul(overall_subnet_reward, subnet_owner_percentage);
 
@@ -792,21 +959,18 @@ impl<T: Config> Pallet<T> {
         // --- Get delegators rewards
         let delegate_stake_rewards_percentage =
             SubnetDelegateStakeRewardsPercentage::<T>::get(subnet_id);
-        weight = weight.saturating_add(db_weight.reads(1));
         let delegate_stake_rewards: u128 =
             Self::percent_mul(subnet_rewards, delegate_stake_rewards_percentage);
 
         // --- Get subnet nodes rewards total
         let subnet_node_rewards: u128 = subnet_rewards.saturating_sub(delegate_stake_rewards);
 
-        let rewards_data = RewardsData {
+        RewardsData {
             overall_subnet_reward,
             subnet_owner_reward,
             subnet_rewards,
             delegate_stake_rewards,
             subnet_node_rewards,
-        };
-
-        (rewards_data, weight)
+        }
     }
 }
@@ -197,13 +197,9 @@ impl<T: Config> Pallet<T> {
         // Apply concave exponential: exponent α < 1
         // e.g., α = 0.5 = sqrt (concave)
         // concave factor = remaining_frac ^ alpha
-        let concave_factor = Self::pow(
-            Self::get_percent_as_f64(remaining_frac),
-            Self::get_percent_as_f64(alpha),
-        );
+        let concave_factor = Self::pow(remaining_frac, alpha);
 
-        let safe_diff_f64 = (diff as f64).min(u128::MAX as f64 / concave_factor); // prevent inf
-        let addend = (safe_diff_f64 * concave_factor).clamp(0.0, u128::MAX as f64) as u128;
+        let addend = Self::percent_mul(diff, concave_factor);
 
         let decayed = min_price.saturating_add(addend);
 
@@ -2,10 +2,11 @@ use core::marker::PhantomData;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
 use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
-use pallet_network::SubnetWeightFactorsData;
+use pallet_network::{NetworkParameter, SubnetWeightFactorsData};
 use precompile_utils::{EvmResult, prelude::*};
-use sp_core::U256;
+use sp_core::{Decode, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::vec::Vec;
 
 pub(crate) struct AdminPrecompile<R>(PhantomData<R>);
 
@@ -82,6 +83,9 @@ where
         )
     }
 
+    // Legacy parameter selectors, they forward to `set_parameter` until they are removed with the
+    // deprecated `set_*` extrinsics
+
     #[precompile::public("setMinSubnetDelegateStakeFactor(uint256)")]
     fn set_min_subnet_delegate_stake_factor(
         handle: &mut impl PrecompileHandle,
@@ -90,7 +94,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_subnet_delegate_stake_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinSubnetDelegateStakeFactor(value),
+            },
         )
     }
 
@@ -102,14 +108,21 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_owner_percentage { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetOwnerPercentage(value),
+            },
         )
     }
 
     #[precompile::public("setMaxSubnets(uint256)")]
     fn set_max_subnets(handle: &mut impl PrecompileHandle, value: U256) -> EvmResult<()> {
         let value = try_u256_to_u32(value)?;
-        dispatch_call::<R>(handle, pallet_network::Call::<R>::set_max_subnets { value })
+        dispatch_call::<R>(
+            handle,
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSubnets(value),
+            },
+        )
     }
 
     #[precompile::public("setMaxBootnodes(uint256)")]
@@ -117,7 +130,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_bootnodes { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxBootnodes(value),
+            },
         )
     }
 
@@ -129,7 +144,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_subnet_bootnodes_access { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSubnetBootnodeAccess(value),
+            },
         )
     }
 
@@ -138,7 +155,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_pause_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSubnetPauseEpochs(value),
+            },
         )
     }
 
@@ -150,7 +169,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_delegate_stake_subnet_removal_interval { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::DelegateStakeSubnetRemovalInterval(value),
+            },
         )
     }
 
@@ -164,7 +185,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_removal_intervals { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetRemovalIntervals(min, max),
+            },
         )
     }
 
@@ -176,7 +199,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_pause_cooldown_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetPauseCooldownEpochs(value),
+            },
         )
     }
 
@@ -185,7 +210,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_registration_cost { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinRegistrationCost(value),
+            },
         )
     }
 
@@ -197,7 +224,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_registration_cost_delay_blocks { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::RegistrationCostDecayBlocks(value),
+            },
         )
     }
 
@@ -209,7 +238,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_registration_cost_alpha { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::RegistrationCostAlpha(value),
+            },
         )
     }
 
@@ -221,7 +252,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_new_registration_cost_multiplier { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NewRegistrationCostMultiplier(value),
+            },
         )
     }
 
@@ -233,7 +266,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_min_delegate_stake_multiplier { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxMinDelegateStakeMultiplier(value),
+            },
         )
     }
 
@@ -243,7 +278,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_churn_limits { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ChurnLimits(min, max),
+            },
         )
     }
 
@@ -253,7 +290,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_queue_epochs { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::QueueEpochs(min, max),
+            },
         )
     }
 
@@ -265,7 +304,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_swap_queue_calls_per_block { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSwapQueueCallsPerBlock(value),
+            },
         )
     }
 
@@ -277,7 +318,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_idle_classification_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinIdleClassificationEpochs(value),
+            },
         )
     }
 
@@ -289,7 +332,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_idle_classification_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxIdleClassificationEpochs(value),
+            },
         )
     }
 
@@ -301,7 +346,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_activation_enactment_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetEnactmentEpochs(value),
+            },
         )
     }
 
@@ -315,7 +362,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_included_classification_epochs { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::IncludedClassificationEpochs(min, max),
+            },
         )
     }
 
@@ -329,7 +378,9 @@ where
         let max = try_u256_to_u128(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_stakes { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetStakes(min, max),
+            },
         )
     }
 
@@ -343,7 +394,9 @@ where
         let max = try_u256_to_u128(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_delegate_stake_percentages { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::DelegateStakePercentages(min, max),
+            },
         )
     }
 
@@ -357,7 +410,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_max_registered_nodes { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinMaxRegisteredNodes(min, max),
+            },
         )
     }
 
@@ -369,8 +424,8 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_subnet_delegate_stake_rewards_percentage_change {
-                value,
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSubnetDelegateStakeRewardsPercentageChange(value),
             },
         )
     }
@@ -383,7 +438,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_delegate_stake_rewards_update_period { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetDelegateStakeRewardsUpdatePeriod(value),
+            },
         )
     }
 
@@ -395,7 +452,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_attestation_percentage { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinAttestationPercentage(value),
+            },
         )
     }
 
@@ -407,7 +466,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_super_majority_attestation_ratio { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SuperMajorityAttestationRatio(value),
+            },
         )
     }
 
@@ -416,7 +477,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_base_validator_reward { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::BaseValidatorReward(value),
+            },
         )
     }
 
@@ -425,7 +488,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_base_slash_percentage { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::BaseSlashPercentage(value),
+            },
         )
     }
 
@@ -434,7 +499,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_slash_amount { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSlashAmount(value),
+            },
         )
     }
 
@@ -446,7 +513,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_reputation_increase_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ValidatorReputationIncreaseFactor(value),
+            },
         )
     }
 
@@ -458,7 +527,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_reputation_decrease_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ValidatorReputationDecreaseFactor(value),
+            },
         )
     }
 
@@ -470,7 +541,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_network_max_stake_balance { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NetworkMaxStakeBalance(value),
+            },
         )
     }
 
@@ -482,7 +555,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_delegate_stake_deposit { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinDelegateStakeDeposit(value),
+            },
         )
     }
 
@@ -494,7 +569,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_node_reward_rate_update_period { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NodeRewardRateUpdatePeriod(value),
+            },
         )
     }
 
@@ -506,7 +583,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_reward_rate_decrease { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxRewardRateDecrease(value),
+            },
         )
     }
 
@@ -518,7 +597,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_distribution_power { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetDistributionPower(value),
+            },
         )
     }
 
@@ -530,7 +611,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_delegate_stake_weight_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::DelegateStakeWeightFactor(value),
+            },
         )
     }
 
@@ -542,7 +625,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_inflation_sigmoid_steepness { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::InflationSigmoidSteepness(value),
+            },
         )
     }
 
@@ -551,7 +636,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_overwatch_nodes { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxOverwatchNodes(value),
+            },
         )
     }
 
@@ -563,7 +650,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_epoch_length_multiplier { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchEpochLengthMultiplier(value),
+            },
         )
     }
 
@@ -575,7 +664,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_commit_cutoff_percent { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchCommitCutoffPercent(value),
+            },
         )
     }
 
@@ -587,7 +678,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_min_diversification_ratio { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchMinDiversificationRatio(value),
+            },
         )
     }
 
@@ -599,7 +692,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_min_rep_score { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchMinRepScore(value),
+            },
         )
     }
 
@@ -611,7 +706,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_min_avg_attestation_ratio { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchMinAvgAttestationRatio(value),
+            },
         )
     }
 
@@ -620,7 +717,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_min_age { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchMinAge(value),
+            },
         )
     }
 
@@ -632,7 +731,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_min_stake_balance { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchMinStakeBalance(value),
+            },
         )
     }
 
@@ -646,7 +747,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_max_subnet_node { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinMaxSubnetNodes(min, max),
+            },
         )
     }
 
@@ -655,7 +758,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_tx_rate_limit { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::TxRateLimit(value),
+            },
         )
     }
 
@@ -683,7 +788,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_subnet_registration_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinSubnetRegistrationEpochs(value),
+            },
         )
     }
 
@@ -695,7 +802,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_registration_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetRegistrationEpochs(value),
+            },
         )
     }
 
@@ -707,7 +816,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_active_node_stake_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinActiveNodeStakeEpochs(value),
+            },
         )
     }
 
@@ -719,7 +830,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_delegate_stake_cooldown_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::DelegateStakeCooldownEpochs(value),
+            },
         )
     }
 
@@ -731,7 +844,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_node_delegate_stake_cooldown_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NodeDelegateStakeCooldownEpochs(value),
+            },
         )
     }
 
@@ -743,7 +858,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_stake_cooldown_epochs { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::StakeCooldownEpochs(value),
+            },
         )
     }
 
@@ -752,7 +869,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_unbondings { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxUnbondings(value),
+            },
         )
     }
 
@@ -761,7 +880,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_sigmoid_midpoint { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::InflationSigmoidMidpoint(value),
+            },
         )
     }
 
@@ -770,7 +891,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_maximum_hooks_weight { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaximumHooksWeight(value),
+            },
         )
     }
 
@@ -779,7 +902,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_base_node_burn_amount { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::BaseNodeBurnAmount(value),
+            },
         )
     }
 
@@ -793,7 +918,9 @@ where
         let max = try_u256_to_u128(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_node_burn_rates { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NodeBurnRates(min, max),
+            },
         )
     }
 
@@ -805,8 +932,10 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_subnet_node_min_weight_decrease_reputation_threshold {
-                value,
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxSubnetNodeMinWeightDecreaseReputationThreshold(
+                    value,
+                ),
             },
         )
     }
@@ -816,7 +945,9 @@ where
         let value = try_u256_to_u64(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_validator_reward_k { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ValidatorRewardK(value),
+            },
         )
     }
 
@@ -828,7 +959,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_validator_reward_midpoint { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ValidatorRewardMidpoint(value),
+            },
         )
     }
 
@@ -840,7 +973,9 @@ where
         let value = try_u256_to_u64(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_attestor_reward_exponent { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::AttestorRewardExponent(value),
+            },
         )
     }
 
@@ -852,7 +987,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_attestor_min_reward_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::AttestorMinRewardFactor(value),
+            },
         )
     }
 
@@ -866,7 +1003,9 @@ where
         let max = try_u256_to_u128(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_max_node_reputation { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NodeReputationLimits(min, max),
+            },
         )
     }
 
@@ -880,7 +1019,9 @@ where
         let max = try_u256_to_u128(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_max_node_reputation_factor { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NodeReputationFactors(min, max),
+            },
         )
     }
 
@@ -889,7 +1030,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_min_subnet_reputation { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MinSubnetReputation(value),
+            },
         )
     }
 
@@ -901,7 +1044,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_not_in_consensus_subnet_reputation_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::NotInConsensusSubnetReputationFactor(value),
+            },
         )
     }
 
@@ -913,7 +1058,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_pause_epochs_subnet_reputation_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxPauseEpochsSubnetReputationFactor(value),
+            },
         )
     }
 
@@ -925,7 +1072,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_less_than_min_nodes_subnet_reputation_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::LessThanMinNodesSubnetReputationFactor(value),
+            },
         )
     }
 
@@ -937,8 +1086,8 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_validator_proposal_absent_subnet_reputation_factor {
-                value,
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ValidatorAbsentSubnetReputationFactor(value),
             },
         )
     }
@@ -951,7 +1100,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_in_consensus_subnet_reputation_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::InConsensusSubnetReputationFactor(value),
+            },
         )
     }
 
@@ -963,7 +1114,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_weight_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchWeightFactor(value),
+            },
         )
     }
 
@@ -975,7 +1128,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_emergency_validator_epochs_multiplier { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxEmergencyValidatorEpochsMultiplier(value),
+            },
         )
     }
 
@@ -987,7 +1142,9 @@ where
         let value = try_u256_to_u32(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_max_emergency_subnet_nodes { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::MaxEmergencySubnetNodes(value),
+            },
         )
     }
 
@@ -999,7 +1156,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_overwatch_stake_weight_factor { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::OverwatchStakeWeightFactor(value),
+            },
         )
     }
 
@@ -1017,7 +1176,9 @@ where
         };
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_subnet_weight_factors { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::SubnetWeightFactors(value),
+            },
         )
     }
 
@@ -1031,7 +1192,9 @@ where
         let max = try_u256_to_u32(max)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_churn_limit_multipliers { min, max },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::ChurnLimitMultipliers(min, max),
+            },
         )
     }
 
@@ -1043,7 +1206,9 @@ where
         let value = try_u256_to_u128(value)?;
         dispatch_call::<R>(
             handle,
-            pallet_network::Call::<R>::set_default_overwatch_subnet_weight { value },
+            pallet_network::Call::<R>::set_parameter {
+                parameter: NetworkParameter::DefaultOverwatchSubnetWeight(value),
+            },
         )
     }
 
@@ -1062,6 +1227,66 @@ where
             },
         )
     }
+
+    /// `parameter` is a SCALE encoded `NetworkParameter`
+    #[precompile::public("setParameter(bytes)")]
+    fn set_parameter(
+        handle: &mut impl PrecompileHandle,
+        parameter: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let parameter = NetworkParameter::decode(&mut parameter.as_bytes())
+            .map_err(|_| revert("Invalid parameter encoding"))?;
+        dispatch_call::<R>(
+            handle,
+            pallet_network::Call::<R>::set_parameter { parameter },
+        )
+    }
+
+    /// `parameters` is a SCALE encoded `Vec<NetworkParameter>`
+    #[precompile::public("setParameters(bytes)")]
+    fn set_parameters(
+        handle: &mut impl PrecompileHandle,
+        parameters: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let parameters = Vec::<NetworkParameter>::decode(&mut parameters.as_bytes())
+            .map_err(|_| revert("Invalid parameters encoding"))?;
+        dispatch_call::<R>(
+            handle,
+            pallet_network::Call::<R>::set_parameters { parameters },
+        )
+    }
+
+    /// `parameters` is a SCALE encoded `Vec<NetworkParameter>`
+    #[precompile::public("scheduleParameterChange(bytes)")]
+    fn schedule_parameter_change(
+        handle: &mut impl PrecompileHandle,
+        parameters: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let parameters = Vec::<NetworkParameter>::decode(&mut parameters.as_bytes())
+            .map_err(|_| revert("Invalid parameters encoding"))?;
+        dispatch_call::<R>(
+            handle,
+            pallet_network::Call::<R>::schedule_parameter_change { parameters },
+        )
+    }
+
+    #[precompile::public("fastTrackParameterChange(uint256)")]
+    fn fast_track_parameter_change(handle: &mut impl PrecompileHandle, id: U256) -> EvmResult<()> {
+        let id = try_u256_to_u32(id)?;
+        dispatch_call::<R>(
+            handle,
+            pallet_network::Call::<R>::fast_track_parameter_change { id },
+        )
+    }
+
+    #[precompile::public("vetoParameterChange(uint256)")]
+    fn veto_parameter_change(handle: &mut impl PrecompileHandle, id: U256) -> EvmResult<()> {
+        let id = try_u256_to_u32(id)?;
+        dispatch_call::<R>(
+            handle,
+            pallet_network::Call::<R>::veto_parameter_change { id },
+        )
+    }
 }
 
 fn dispatch_call<R>(
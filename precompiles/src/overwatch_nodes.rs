@@ -220,6 +220,123 @@ where
         Ok(())
     }
 
+    #[precompile::public("commitBeaconEntropy(uint256,bytes32)")]
+    fn commit_beacon_entropy(
+        handle: &mut impl PrecompileHandle,
+        overwatch_node_id: U256,
+        commitment: H256,
+    ) -> EvmResult {
+        handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
+
+        let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+
+        let call = pallet_network::Call::<R>::commit_beacon_entropy {
+            overwatch_node_id,
+            commitment,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[precompile::public("revealBeaconEntropy(uint256,bytes32)")]
+    fn reveal_beacon_entropy(
+        handle: &mut impl PrecompileHandle,
+        overwatch_node_id: U256,
+        entropy: H256,
+    ) -> EvmResult {
+        handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
+
+        let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+
+        let call = pallet_network::Call::<R>::reveal_beacon_entropy {
+            overwatch_node_id,
+            entropy,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[precompile::public("challengeSubnetNode(uint256,uint256,uint256,bytes32)")]
+    fn challenge_subnet_node(
+        handle: &mut impl PrecompileHandle,
+        overwatch_node_id: U256,
+        subnet_id: U256,
+        subnet_node_id: U256,
+        evidence_hash: H256,
+    ) -> EvmResult {
+        handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
+
+        let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+
+        let call = pallet_network::Call::<R>::challenge_subnet_node {
+            overwatch_node_id,
+            subnet_id,
+            subnet_node_id,
+            evidence_hash,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[precompile::public("voteSubnetNodeChallenge(uint256,uint256,bool)")]
+    fn vote_subnet_node_challenge(
+        handle: &mut impl PrecompileHandle,
+        overwatch_node_id: U256,
+        challenge_id: U256,
+        approve: bool,
+    ) -> EvmResult {
+        handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
+
+        let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
+        let challenge_id = try_u256_to_u32(challenge_id)?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+
+        let call = pallet_network::Call::<R>::vote_subnet_node_challenge {
+            overwatch_node_id,
+            challenge_id,
+            approve,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
     #[precompile::public("addOverwatchStake(uint256,uint256)")]
     #[precompile::payable]
     fn add_overwatch_node_stake(
@@ -3,7 +3,7 @@ use fp_evm::Log;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
 use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
-use pallet_network::QueuedSwapCall;
+use pallet_network::{QueuedSwapCall, RestakeTarget};
 use precompile_utils::{EvmResult, prelude::*, solidity::Codec};
 use sp_core::Decode;
 use sp_core::{H160, H256, U256};
@@ -112,6 +112,66 @@ where
         Ok(())
     }
 
+    #[precompile::public("restakeUnbonding(uint256,uint256,uint256,uint256)")]
+    #[precompile::payable]
+    fn restake_unbonding(
+        handle: &mut impl PrecompileHandle,
+        target_type: U256,
+        id: U256,
+        subnet_node_id: U256,
+        amount: U256,
+    ) -> EvmResult<()> {
+        let target_type = try_u256_to_u32(target_type)?;
+        let id = try_u256_to_u32(id)?;
+        let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
+        let amount = amount.unique_saturated_into();
+
+        // `id` is the subnet ID for node and subnet targets, the validator ID otherwise
+        let target = match target_type {
+            0 => RestakeTarget::SubnetNode {
+                subnet_id: id,
+                subnet_node_id,
+            },
+            1 => RestakeTarget::SubnetDelegateStake(id),
+            2 => RestakeTarget::ValidatorDelegateStake(id),
+            _ => {
+                return Err(revert(
+                    "Invalid target type. Must be 0 (SubnetNode), 1 (SubnetDelegateStake) or 2 (ValidatorDelegateStake)",
+                ));
+            }
+        };
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::restake_unbonding { target, amount };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[precompile::public("withdrawUnbondingEarly(uint256)")]
+    #[precompile::payable]
+    fn withdraw_unbonding_early(handle: &mut impl PrecompileHandle, amount: U256) -> EvmResult<()> {
+        let amount = amount.unique_saturated_into();
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::withdraw_unbonding_early { amount };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
     #[precompile::public("addToDelegateStake(uint256,uint256)")]
     #[precompile::payable]
     fn add_delegate_stake(
@@ -520,6 +580,7 @@ where
         queue_id: U256,
     ) -> EvmResult<(u32, Address, u8, u32, u32, u128, u32, u32)> {
         // Returns: (id, account_id, call_type, to_subnet_id, to_validator_id, balance, queued_at_block, execute_after_blocks)
+        // call_type: 0=subnet swap, 1=validator swap, 2=remove, 3=transfer, 4=rebalance
         let queue_id = try_u256_to_u32(queue_id)?;
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
 
@@ -539,6 +600,21 @@ where
                             to_validator_id,
                             balance,
                         } => (1u8, account_id, *to_validator_id, 0u32, *balance),
+                        // Scheduled calls return the subnet ID and shares
+                        QueuedSwapCall::RemoveSubnetDelegateStake {
+                            account_id,
+                            subnet_id,
+                            shares,
+                        } => (2u8, account_id, 0u32, *subnet_id, *shares),
+                        QueuedSwapCall::TransferSubnetDelegateStake {
+                            account_id,
+                            subnet_id,
+                            shares,
+                            ..
+                        } => (3u8, account_id, 0u32, *subnet_id, *shares),
+                        QueuedSwapCall::RebalanceSubnetDelegateStake { account_id, .. } => {
+                            (4u8, account_id, 0u32, 0u32, 0u128)
+                        }
                     };
 
                 let account_address = Address(sp_core::H160::from((account_id.clone()).into()));
@@ -546,7 +622,7 @@ where
                 Ok((
                     item.id,                   // id
                     account_address,           // account_id (as Address)
-                    call_type,                 // type (0=subnet, 1=validator)
+                    call_type,                 // type, see above
                     to_validator_id,           // to_validator_id (0 is swapping to subnet)
                     to_subnet_id,              // to_subnet_id (0 is swapping to validator)
                     balance,                   // balance
@@ -7,8 +7,9 @@ use frame_support::{
 use frame_system::RawOrigin;
 use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
 use pallet_network::{
-    DelegateAccount, IdentityData, NetworkBytes, PeerInfo, SubnetNodeConsensusData,
-    SubnetReputationFactorUpdates,
+    AttestationWeighting, ConsensusDataPolicy, DelegateAccount, FallbackProposerConfig,
+    IdentityData, NetworkBytes, NodeConcentrationCaps, PeerInfo, SubnetNodeConsensusData,
+    SubnetReputationFactorUpdates, ValidatorElectionMode, WeightedValidatorElection,
 };
 use precompile_utils::{EvmResult, prelude::*};
 use sp_core::{H160, H256, OpaquePeerId, U256};
@@ -853,6 +854,40 @@ where
         Ok(())
     }
 
+    #[precompile::public("attestFallback(uint256,uint256,uint256,bytes)")]
+    #[precompile::payable]
+    fn attest_fallback(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        subnet_node_id: U256,
+        proposer_subnet_node_id: U256,
+        data: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
+        let proposer_subnet_node_id = try_u256_to_u32(proposer_subnet_node_id)?;
+        let data = unbounded_bytes_to_option_bounded_vec::<
+            <R as pallet_network::Config>::ValidatorArgsLimit,
+        >(&data, "Data too long")?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::attest_fallback {
+            subnet_id,
+            subnet_node_id,
+            proposer_subnet_node_id,
+            data,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
     // #[precompile::public(
     //     "updateValidatorIdentity(address,string,string,string,string,string,string,string,string,string,string)"
     // )]
@@ -1481,6 +1516,177 @@ where
         Ok(())
     }
 
+    /// `policies` are `(kind, value)` pairs where kind is 0 `MaxScore`, 1 `MinScoredNodes`,
+    /// 2 `NormalizedScores` (value ignored), 3 `TopK` and 4 `MinIncludedScore`
+    #[precompile::public("ownerUpdateConsensusDataPolicies(uint256,(uint256,uint256)[])")]
+    fn owner_update_consensus_data_policies(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        policies: Vec<(U256, U256)>,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let policies: Vec<ConsensusDataPolicy> = policies
+            .into_iter()
+            .map(|(kind, value)| {
+                Ok::<_, PrecompileFailure>(match try_u256_to_u32(kind)? {
+                    0 => ConsensusDataPolicy::MaxScore(try_u256_to_u128(value)?),
+                    1 => ConsensusDataPolicy::MinScoredNodes(try_u256_to_u32(value)?),
+                    2 => ConsensusDataPolicy::NormalizedScores,
+                    3 => ConsensusDataPolicy::TopK(try_u256_to_u32(value)?),
+                    4 => ConsensusDataPolicy::MinIncludedScore(try_u256_to_u128(value)?),
+                    _ => return Err(revert("Invalid consensus data policy")),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::owner_update_consensus_data_policies {
+            subnet_id,
+            policies,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// `weighted` false selects the uniform election and ignores the other parameters
+    #[precompile::public(
+        "ownerUpdateValidatorElectionMode(uint256,bool,uint256,uint256,uint256,uint256)"
+    )]
+    fn owner_update_validator_election_mode(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        weighted: bool,
+        stake_exponent: U256,
+        node_reputation_exponent: U256,
+        validator_reputation_exponent: U256,
+        max_validator_probability: U256,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let mode = if weighted {
+            ValidatorElectionMode::Weighted(WeightedValidatorElection {
+                stake_exponent: try_u256_to_u128(stake_exponent)?,
+                node_reputation_exponent: try_u256_to_u128(node_reputation_exponent)?,
+                validator_reputation_exponent: try_u256_to_u128(validator_reputation_exponent)?,
+                max_validator_probability: try_u256_to_u128(max_validator_probability)?,
+            })
+        } else {
+            ValidatorElectionMode::Uniform
+        };
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call =
+            pallet_network::Call::<R>::owner_update_validator_election_mode { subnet_id, mode };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[precompile::public("ownerUpdateFallbackProposers(uint256,bool,uint256,uint256)")]
+    fn owner_update_fallback_proposers(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        enabled: bool,
+        start_progression: U256,
+        max_proposers: U256,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let config = if enabled {
+            Some(FallbackProposerConfig {
+                start_progression: try_u256_to_u128(start_progression)?,
+                max_proposers: try_u256_to_u32(max_proposers)?,
+            })
+        } else {
+            None
+        };
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::owner_update_fallback_proposers { subnet_id, config };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// `weighting` 0 = count, 1 = stake, 2 = reputation
+    #[precompile::public("ownerUpdateAttestationWeighting(uint256,uint256)")]
+    fn owner_update_attestation_weighting(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        weighting: U256,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let weighting = match try_u256_to_u32(weighting)? {
+            0 => AttestationWeighting::Count,
+            1 => AttestationWeighting::Stake,
+            2 => AttestationWeighting::Reputation,
+            _ => return Err(revert("Invalid attestation weighting")),
+        };
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::owner_update_attestation_weighting {
+            subnet_id,
+            weighting,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// `max_nodes_per_coldkey` and `max_nodes_per_validator` of 0 = no cap
+    #[precompile::public("ownerUpdateNodeConcentrationCaps(uint256,uint256,uint256)")]
+    fn owner_update_node_concentration_caps(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        max_nodes_per_coldkey: U256,
+        max_nodes_per_validator: U256,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let max_nodes_per_coldkey = try_u256_to_u32(max_nodes_per_coldkey)?;
+        let max_nodes_per_validator = try_u256_to_u32(max_nodes_per_validator)?;
+        let caps = NodeConcentrationCaps {
+            max_nodes_per_coldkey: (max_nodes_per_coldkey != 0).then_some(max_nodes_per_coldkey),
+            max_nodes_per_validator: (max_nodes_per_validator != 0)
+                .then_some(max_nodes_per_validator),
+        };
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call =
+            pallet_network::Call::<R>::owner_update_node_concentration_caps { subnet_id, caps };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
     #[precompile::public("ownerUpdateMinSubnetNodeReputation(uint256,uint256)")]
     fn owner_update_min_subnet_node_reputation(
         handle: &mut impl PrecompileHandle,
@@ -151,6 +151,8 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 type Migrations = (
     pallet_network::migrations::CleanupStaleValidatorColdkeys<Runtime>,
     pallet_network::migrations::CleanupStaleValidatorHotkeys<Runtime>,
+    pallet_network::migrations::MigrateStakeToHolds<Runtime>,
+    pallet_network::migrations::MigrateAttestationWeights<Runtime>,
 );
 
 /// Executive: handles dispatch to the various modules.
@@ -214,7 +216,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: Cow::Borrowed("hypertensor-node"),
     impl_name: Cow::Borrowed("hypertensor-node"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 2,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -735,6 +737,7 @@ impl pallet_network::Config for Runtime {
     type WeightInfo = pallet_network::weights::SubstrateWeight<Runtime>;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type MajorityCollectiveOrigin =
         pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
     type SuperMajorityCollectiveOrigin =
@@ -744,6 +747,7 @@ impl pallet_network::Config for Runtime {
     type InitialTxRateLimit = InitialTxRateLimit;
     type PalletId = NetworkPalletId;
     type Randomness = InsecureRandomnessCollectiveFlip;
+    type RandomnessBeacon = pallet_network::randomness::OverwatchRandomnessBeacon<Runtime>;
     type TreasuryAccount = TreasuryAccount;
     type OverwatchEpochEmissions = OverwatchEpochEmissions;
     type MaximumHooksWeight = MaximumHooksWeight;
@@ -1497,6 +1501,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl network_custom_rpc_runtime_api::NetworkRuntimeApi<Block> for Runtime {
         fn get_subnet_info(subnet_id: u32) -> Vec<u8> {
             let result = Network::get_subnet_info(subnet_id);
@@ -1561,6 +1566,70 @@ impl_runtime_apis! {
             let result = Network::get_all_overwatch_nodes_info();
             result.encode()
         }
+        fn get_subnet_nodes_info_paged(filter: pallet_network::SubnetNodeInfoFilter, cursor: Option<(u32, u32)>, limit: u32) -> Vec<u8> {
+            let result = Network::get_subnet_nodes_info_paged(filter, cursor, limit);
+            result.encode()
+        }
+        fn get_all_subnets_info_paged(cursor: Option<u32>, limit: u32) -> Vec<u8> {
+            let result = Network::get_all_subnets_info_paged(cursor, limit);
+            result.encode()
+        }
+        fn get_delegate_stakes_paged(account_id: AccountId, cursor: Option<u32>, limit: u32) -> Vec<u8> {
+            let result = Network::get_delegate_stakes_paged(account_id, cursor, limit);
+            result.encode()
+        }
+        fn get_subnet_rewards_ledger(subnet_id: u32, from_epoch: u32, to_epoch: u32) -> Vec<u8> {
+            let result = Network::get_subnet_rewards_ledger(subnet_id, from_epoch, to_epoch);
+            result.encode()
+        }
+        fn get_subnet_node_rewards_history(subnet_id: u32, subnet_node_id: u32) -> Vec<u8> {
+            let result = Network::get_subnet_node_rewards_history(subnet_id, subnet_node_id);
+            result.encode()
+        }
+        fn get_account_rewards_history(account_id: AccountId, cursor: Option<u32>, limit: u32) -> Vec<u8> {
+            let result = Network::get_account_rewards_history(account_id, cursor, limit);
+            result.encode()
+        }
+        fn simulate_consensus(subnet_id: u32, data: Vec<pallet_network::SubnetNodeConsensusData>, attestor_subnet_node_ids: Vec<u32>) -> Vec<u8> {
+            let result = Network::simulate_consensus(subnet_id, data, attestor_subnet_node_ids);
+            result.encode()
+        }
+        fn get_subnet_delegate_stake_projection(subnet_id: u32, amount: u128) -> Vec<u8> {
+            let result = Network::get_subnet_delegate_stake_projection(subnet_id, amount);
+            result.encode()
+        }
+        fn get_validator_delegate_stake_projection(validator_id: u32, amount: u128) -> Vec<u8> {
+            let result = Network::get_validator_delegate_stake_projection(validator_id, amount);
+            result.encode()
+        }
+        fn get_subnet_lifecycle_notifications(subnet_id: Option<u32>) -> Vec<u8> {
+            let events = System::read_events_no_consensus().filter_map(|record| match record.event {
+                RuntimeEvent::Network(event) => Some(event),
+                _ => None,
+            });
+            let result = Network::get_subnet_lifecycle_notifications(events, subnet_id);
+            result.encode()
+        }
+        fn get_subnet_coldkey_concentration(subnet_id: u32, top_n: u32) -> Vec<u8> {
+            let result = Network::get_subnet_coldkey_concentration(subnet_id, top_n);
+            result.encode()
+        }
+        fn get_unrevealed_overwatch_commits(overwatch_epoch: u32) -> Vec<u8> {
+            let result = Network::get_unrevealed_overwatch_commits(overwatch_epoch);
+            result.encode()
+        }
+        fn get_overwatch_subnet_weight_breakdown(overwatch_epoch: u32, subnet_id: u32) -> Vec<u8> {
+            let result = Network::get_overwatch_subnet_weight_breakdown(overwatch_epoch, subnet_id);
+            result.encode()
+        }
+        fn get_network_parameters() -> Vec<u8> {
+            let result = Network::get_network_parameters();
+            result.encode()
+        }
+        fn get_scheduled_parameter_changes() -> Vec<u8> {
+            let result = Network::get_scheduled_parameter_changes();
+            result.encode()
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]